@@ -3,12 +3,15 @@ use leptos::prelude::*;
 use crate::spinner::Spinner;
 use crate::types::{ButtonVariant, Size};
 
+/// While `loading` is true the button is disabled and shows a spinner before its label. The
+/// spinner's space is reserved whenever `loading` is given, so toggling it does not resize
+/// the button.
 #[component]
 pub fn Button(
     #[prop(default = ButtonVariant::Default)] variant: ButtonVariant,
     #[prop(default = Size::Md)] size: Size,
     #[prop(default = false)] disabled: bool,
-    #[prop(default = false)] loading: bool,
+    #[prop(optional, into)] loading: Option<Signal<bool>>,
     #[prop(optional, into)] class: String,
     #[prop(optional)] on_click: Option<Box<dyn Fn() + 'static>>,
     #[prop(default = "button")] r#type: &'static str,
@@ -46,27 +49,74 @@ pub fn Button(
         size_cls, variant_cls, class
     );
 
-    let is_disabled = disabled || loading;
+    let is_loading = move || loading.is_some_and(|loading| loading.get());
 
     view! {
         <button
             type=r#type
             class=full_class
-            disabled=is_disabled
+            disabled=move || disabled || is_loading()
+            aria-busy=move || is_loading().then_some("true")
             on:click=move |_| {
                 if let Some(ref handler) = on_click {
                     handler();
                 }
             }
         >
-            {move || {
-                if loading {
-                    Some(view! { <Spinner size=Size::Sm /> })
-                } else {
-                    None
-                }
-            }}
+            {loading.map(|_| view! {
+                <span class=move || {
+                    if is_loading() { "inline-flex" } else { "inline-flex invisible" }
+                }>
+                    <Spinner size=Size::Sm />
+                </span>
+            })}
             {children()}
         </button>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Whether the rendered `<button>` carries attribute `name` (class names like
+    /// `disabled:opacity-50` do not count).
+    fn has_attr(html: &str, name: &str) -> bool {
+        html.split([' ', '>'])
+            .any(|token| token == name || token.starts_with(&format!("{name}=")))
+    }
+
+    fn render(loading: Option<bool>) -> String {
+        Owner::new().with(|| {
+            match loading {
+                Some(loading) => {
+                    let loading = Signal::derive(move || loading);
+                    view! { <Button loading=loading>"Save"</Button> }.into_any()
+                }
+                None => view! { <Button>"Save"</Button> }.into_any(),
+            }
+            .to_html()
+        })
+    }
+
+    #[test]
+    fn loading_disables_button_and_shows_spinner() {
+        let html = render(Some(true));
+
+        assert!(has_attr(&html, "disabled"), "{html}");
+        assert!(html.contains(r#"aria-busy="true""#), "{html}");
+        assert!(html.contains(r#"role="status""#), "{html}");
+        assert!(!html.contains("invisible"), "{html}");
+        assert!(html.contains("Save"), "{html}");
+    }
+
+    #[test]
+    fn idle_button_reserves_spinner_space_only_when_loading_is_given() {
+        let idle = render(Some(false));
+        assert!(!has_attr(&idle, "disabled"), "{idle}");
+        assert!(idle.contains("inline-flex invisible"), "{idle}");
+
+        let plain = render(None);
+        assert!(!plain.contains(r#"role="status""#), "{plain}");
+    }
+}
@@ -2,6 +2,9 @@ use leptos::prelude::*;
 
 use crate::types::Size;
 
+/// `error` (e.g. a `leptos-forms` field error) marks the input invalid and is shown below it
+/// in place of `helper_text`; both messages are linked through `aria-describedby`, with ids
+/// derived from `id`, or `name` when no `id` is given.
 #[component]
 pub fn Input(
     #[prop(default = "text")] r#type: &'static str,
@@ -12,7 +15,10 @@ pub fn Input(
     #[prop(optional)] value: Option<ReadSignal<String>>,
     #[prop(optional)] set_value: Option<WriteSignal<String>>,
     #[prop(optional, into)] class: String,
+    #[prop(optional, into)] id: String,
     #[prop(optional, into)] name: String,
+    #[prop(optional)] error: Option<Signal<Option<String>>>,
+    #[prop(optional, into)] helper_text: String,
 ) -> impl IntoView {
     let size_cls = match size {
         Size::Sm => "h-8 text-xs px-2",
@@ -20,26 +26,49 @@ pub fn Input(
         Size::Lg | Size::Icon => "h-10 text-sm px-4 py-2",
     };
 
-    let state_cls = if invalid {
-        "border-destructive focus-visible:ring-destructive"
-    } else {
-        "border-input focus-visible:ring-ring"
+    let error_message = move || error.and_then(|error| error.get());
+    let has_error = move || error.is_some_and(|error| error.with(Option::is_some));
+    let is_invalid = move || invalid || has_error();
+
+    let id_base = if id.is_empty() { &name } else { &id };
+    let error_id = (!id_base.is_empty()).then(|| format!("{id_base}-error"));
+    let helper_id =
+        (!id_base.is_empty() && !helper_text.is_empty()).then(|| format!("{id_base}-helper"));
+    let described_by = {
+        let error_id = error_id.clone();
+        let helper_id = helper_id.clone();
+        move || {
+            if has_error() {
+                error_id.clone()
+            } else {
+                helper_id.clone()
+            }
+        }
     };
 
     view! {
         <input
             type=r#type
-            class=format!(
-                "flex w-full rounded-md border bg-background text-foreground shadow-sm \
-                 transition-colors file:border-0 file:bg-transparent file:text-sm file:font-medium \
-                 placeholder:text-muted-foreground \
-                 focus-visible:outline-none focus-visible:ring-1 \
-                 disabled:cursor-not-allowed disabled:opacity-50 {} {} {}",
-                size_cls, state_cls, class
-            )
+            class=move || {
+                let state_cls = if is_invalid() {
+                    "border-destructive focus-visible:ring-destructive"
+                } else {
+                    "border-input focus-visible:ring-ring"
+                };
+                format!(
+                    "flex w-full rounded-md border bg-background text-foreground shadow-sm \
+                     transition-colors file:border-0 file:bg-transparent file:text-sm file:font-medium \
+                     placeholder:text-muted-foreground \
+                     focus-visible:outline-none focus-visible:ring-1 \
+                     disabled:cursor-not-allowed disabled:opacity-50 {} {} {}",
+                    size_cls, state_cls, class
+                )
+            }
             disabled=disabled
-            aria-invalid=invalid
+            aria-invalid=move || is_invalid().then_some("true")
+            aria-describedby=described_by
             placeholder=placeholder
+            id=(!id.is_empty()).then(|| id.clone())
             name=name
             prop:value=move || value.map(|v| v.get()).unwrap_or_default()
             on:input=move |ev| {
@@ -48,5 +77,51 @@ pub fn Input(
                 }
             }
         />
+        {move || error_message().map(|message| view! {
+            <p id=error_id.clone() class="mt-1.5 text-xs text-destructive">{message}</p>
+        })}
+        {move || (!has_error() && !helper_text.is_empty()).then(|| view! {
+            <p id=helper_id.clone() class="mt-1.5 text-xs text-muted-foreground">
+                {helper_text.clone()}
+            </p>
+        })}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(view: impl FnOnce() -> AnyView) -> String {
+        Owner::new().with(|| view().to_html())
+    }
+
+    #[test]
+    fn error_sets_aria_invalid_and_renders_message() {
+        let html = render(|| {
+            let error = Signal::derive(|| Some("Email is required".to_string()));
+            view! { <Input name="email" error=error helper_text="We never share it" /> }.into_any()
+        });
+
+        assert!(html.contains(r#"aria-invalid="true""#), "{html}");
+        assert!(html.contains(r#"aria-describedby="email-error""#), "{html}");
+        assert!(html.contains(r#"id="email-error""#), "{html}");
+        assert!(html.contains("Email is required"), "{html}");
+        assert!(!html.contains("We never share it"), "{html}");
+    }
+
+    #[test]
+    fn helper_text_is_described_without_error() {
+        let html = render(|| {
+            let error = Signal::derive(|| None::<String>);
+            view! { <Input name="email" error=error helper_text="We never share it" /> }.into_any()
+        });
+
+        assert!(!html.contains("aria-invalid"), "{html}");
+        assert!(
+            html.contains(r#"aria-describedby="email-helper""#),
+            "{html}"
+        );
+        assert!(html.contains("We never share it"), "{html}");
     }
 }
@@ -0,0 +1,3 @@
+pub use crate::shared::context::features::{
+    use_feature, use_features, FeatureRequirement, Features, FeaturesProvider, CREATE_PRODUCT,
+};
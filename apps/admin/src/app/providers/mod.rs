@@ -1,2 +1,3 @@
 pub mod enabled_modules;
+pub mod features;
 pub mod locale;
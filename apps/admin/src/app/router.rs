@@ -12,6 +12,7 @@ use crate::pages::{
     users::Users, workflow_detail::WorkflowDetailPage, workflows::Workflows,
 };
 use crate::widgets::app_shell::AppLayout;
+use crate::widgets::locale_switcher::LocaleResolver;
 use crate::I18nContextProvider;
 
 #[component]
@@ -19,42 +20,44 @@ pub fn App() -> impl IntoView {
     view! {
         <I18nContextProvider>
             <AuthProvider>
-                <Router>
-                    <Routes fallback=|| view! { <NotFound /> }>
-                        <Route path=path!("/login") view=Login />
-                        <Route path=path!("/register") view=Register />
-                        <Route path=path!("/reset") view=ResetPassword />
-                        <Route path=path!("/install") view=InstallerPage />
+                <LocaleResolver>
+                    <Router>
+                        <Routes fallback=|| view! { <NotFound /> }>
+                            <Route path=path!("/login") view=Login />
+                            <Route path=path!("/register") view=Register />
+                            <Route path=path!("/reset") view=ResetPassword />
+                            <Route path=path!("/install") view=InstallerPage />
 
-                        <ParentRoute path=path!("") view=ProtectedRoute>
-                            <ParentRoute path=path!("") view=AppLayout>
-                                <Route path=path!("/dashboard") view=Dashboard />
-                                <Route path=path!("/profile") view=Profile />
-                                <Route path=path!("/security") view=Security />
-                                <Route path=path!("/modules/:module_slug") view=ModuleAdminPage />
-                                <Route
-                                    path=path!("/modules/:module_slug/*module_path")
-                                    view=ModuleAdminPage
-                                />
-                                <Route path=path!("/modules") view=Modules />
-                                <Route path=path!("/users") view=Users />
-                                <Route path=path!("/users/:id") view=UserDetails />
-                                <Route path=path!("/apps") view=OAuthAppsPage />
-                                <Route path=path!("/ai") view=rustok_ai_admin::AiAdmin />
-                                <Route path=path!("/ai/diagnostics") view=rustok_ai_admin::AiAdmin />
-                                <Route path=path!("/workflows") view=Workflows />
-                                <Route path=path!("/workflows/:id") view=WorkflowDetailPage />
-                                <Route path=path!("/roles") view=RolesPage />
-                                <Route path=path!("/email") view=EmailSettingsPage />
-                                <Route path=path!("/cache") view=CachePage />
-                                <Route path=path!("/events") view=EventsPage />
-                                <Route path=path!("") view=Dashboard />
+                            <ParentRoute path=path!("") view=ProtectedRoute>
+                                <ParentRoute path=path!("") view=AppLayout>
+                                    <Route path=path!("/dashboard") view=Dashboard />
+                                    <Route path=path!("/profile") view=Profile />
+                                    <Route path=path!("/security") view=Security />
+                                    <Route path=path!("/modules/:module_slug") view=ModuleAdminPage />
+                                    <Route
+                                        path=path!("/modules/:module_slug/*module_path")
+                                        view=ModuleAdminPage
+                                    />
+                                    <Route path=path!("/modules") view=Modules />
+                                    <Route path=path!("/users") view=Users />
+                                    <Route path=path!("/users/:id") view=UserDetails />
+                                    <Route path=path!("/apps") view=OAuthAppsPage />
+                                    <Route path=path!("/ai") view=rustok_ai_admin::AiAdmin />
+                                    <Route path=path!("/ai/diagnostics") view=rustok_ai_admin::AiAdmin />
+                                    <Route path=path!("/workflows") view=Workflows />
+                                    <Route path=path!("/workflows/:id") view=WorkflowDetailPage />
+                                    <Route path=path!("/roles") view=RolesPage />
+                                    <Route path=path!("/email") view=EmailSettingsPage />
+                                    <Route path=path!("/cache") view=CachePage />
+                                    <Route path=path!("/events") view=EventsPage />
+                                    <Route path=path!("") view=Dashboard />
+                                </ParentRoute>
                             </ParentRoute>
-                        </ParentRoute>
 
-                        <Route path=path!("/*") view=NotFound />
-                    </Routes>
-                </Router>
+                            <Route path=path!("/*") view=NotFound />
+                        </Routes>
+                    </Router>
+                </LocaleResolver>
             </AuthProvider>
         </I18nContextProvider>
     }
@@ -5,12 +5,14 @@ use leptos_auth::hooks::{use_current_user, use_tenant, use_token};
 #[cfg(feature = "ssr")]
 use sea_orm::{ConnectionTrait, DbBackend, Statement};
 use serde::{Deserialize, Serialize};
-use serde_json::json;
 
 use crate::app::modules::{components_for_slot, AdminSlot};
 use crate::app::providers::enabled_modules::use_enabled_modules;
-use crate::shared::api::queries::{DASHBOARD_STATS_QUERY, RECENT_ACTIVITY_QUERY};
+use crate::shared::api::queries::{
+    DASHBOARD_STATS_QUERY, RECENT_ACTIVITY_LIMIT, RECENT_ACTIVITY_QUERY,
+};
 use crate::shared::api::request;
+use crate::shared::api::variables::{NoVariables, RecentActivityVariables};
 use crate::shared::api::ApiError;
 use crate::shared::ui::{
     Badge, BadgeVariant, Card, CardContent, CardDescription, CardHeader, CardTitle, PageHeader,
@@ -110,7 +112,13 @@ async fn fetch_dashboard_stats_graphql(
     token: Option<String>,
     tenant_slug: Option<String>,
 ) -> Result<DashboardStatsResponse, ApiError> {
-    request::<_, DashboardStatsResponse>(DASHBOARD_STATS_QUERY, json!({}), token, tenant_slug).await
+    request::<_, DashboardStatsResponse>(
+        DASHBOARD_STATS_QUERY,
+        NoVariables::default(),
+        token,
+        tenant_slug,
+    )
+    .await
 }
 
 async fn fetch_recent_activity_graphql(
@@ -120,7 +128,7 @@ async fn fetch_recent_activity_graphql(
 ) -> Result<RecentActivityResponse, ApiError> {
     request::<_, RecentActivityResponse>(
         RECENT_ACTIVITY_QUERY,
-        json!({ "limit": limit }),
+        RecentActivityVariables { limit },
         token,
         tenant_slug,
     )
@@ -292,7 +300,7 @@ pub fn Dashboard() -> impl IntoView {
     let recent_activity = local_resource(
         move || (token.get(), tenant.get()),
         move |(token_value, tenant_value)| async move {
-            fetch_recent_activity(token_value, tenant_value, 10).await
+            fetch_recent_activity(token_value, tenant_value, RECENT_ACTIVITY_LIMIT).await
         },
     );
 
@@ -1,7 +1,12 @@
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
 use base64::{engine::general_purpose::STANDARD, Engine};
 use leptos::prelude::*;
 use leptos::task::spawn_local;
 use leptos_auth::hooks::{use_tenant, use_token};
+use leptos_graphql::QueryCache;
 use leptos_router::components::A;
 use leptos_router::hooks::{use_navigate, use_query_map};
 use leptos_ui::{Badge, BadgeVariant};
@@ -13,7 +18,8 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::shared::api::queries::{CREATE_USER_MUTATION, USERS_QUERY, USERS_QUERY_HASH};
-use crate::shared::api::{request, request_with_persisted, ApiError};
+use crate::shared::api::variables::{CreateUserVariables, UsersVariables};
+use crate::shared::api::{get_stored_locale, request, request_with_persisted, ApiError};
 use crate::shared::ui::{Button, Input, PageHeader};
 use crate::{t_string, use_i18n};
 
@@ -29,20 +35,6 @@ where
     LocalResource::new(move || fetcher(source()))
 }
 
-#[derive(Clone, Debug, Serialize)]
-struct CreateUserVariables {
-    input: CreateUserInput,
-}
-
-#[derive(Clone, Debug, Serialize)]
-struct CreateUserInput {
-    email: String,
-    password: String,
-    name: Option<String>,
-    role: Option<String>,
-    status: Option<String>,
-}
-
 #[derive(Clone, Debug, Deserialize)]
 struct CreateUserResponse {
     #[serde(rename = "createUser")]
@@ -81,25 +73,8 @@ struct GraphqlUser {
 struct GraphqlPageInfo {
     #[serde(rename = "totalCount")]
     total_count: i64,
-}
-
-#[derive(Clone, Debug, Serialize)]
-struct UsersVariables {
-    pagination: PaginationInput,
-    filter: Option<UsersFilterInput>,
-    search: Option<String>,
-}
-
-#[derive(Clone, Debug, Serialize)]
-struct PaginationInput {
-    first: i64,
-    after: Option<String>,
-}
-
-#[derive(Clone, Debug, Serialize)]
-struct UsersFilterInput {
-    role: Option<String>,
-    status: Option<String>,
+    #[serde(rename = "hasNextPage", default)]
+    has_next_page: bool,
 }
 
 #[cfg(feature = "ssr")]
@@ -124,29 +99,11 @@ async fn fetch_users_graphql(
 
     request_with_persisted::<UsersVariables, GraphqlUsersResponse>(
         USERS_QUERY,
-        UsersVariables {
-            pagination: PaginationInput {
-                first: limit,
-                after,
-            },
-            filter: Some(UsersFilterInput {
-                role: if role.is_empty() {
-                    None
-                } else {
-                    Some(role.to_uppercase())
-                },
-                status: if status.is_empty() {
-                    None
-                } else {
-                    Some(status.to_uppercase())
-                },
-            }),
-            search: if search.is_empty() {
-                None
-            } else {
-                Some(search)
-            },
-        },
+        UsersVariables::new(limit)
+            .after(after)
+            .role(role)
+            .status(status)
+            .search(search),
         USERS_QUERY_HASH,
         token,
         tenant_slug,
@@ -371,10 +328,15 @@ COALESCE((
             })
             .collect::<Result<Vec<_>, ServerFnError>>()?;
 
+        let has_next_page = offset + (edges.len() as i64) < total_count;
+
         Ok(GraphqlUsersResponse {
             users: GraphqlUsersConnection {
                 edges,
-                page_info: GraphqlPageInfo { total_count },
+                page_info: GraphqlPageInfo {
+                    total_count,
+                    has_next_page,
+                },
             },
         })
     }
@@ -392,6 +354,149 @@ fn cursor_for_page(page: i64, limit: i64) -> String {
     STANDARD.encode(index.to_string())
 }
 
+#[derive(Clone, Debug, PartialEq)]
+struct UsersQueryArgs {
+    page: i64,
+    limit: i64,
+    search: String,
+    role: String,
+    status: String,
+}
+
+impl UsersQueryArgs {
+    fn next_page(&self) -> Self {
+        Self {
+            page: self.page + 1,
+            ..self.clone()
+        }
+    }
+
+    fn cache_key(&self, tenant_slug: Option<&str>, token: Option<&str>) -> String {
+        let after = (self.page > 1).then(|| cursor_for_page(self.page, self.limit));
+        let variables = UsersVariables::new(self.limit)
+            .after(after)
+            .role(self.role.clone())
+            .status(self.status.clone())
+            .search(self.search.clone());
+
+        QueryCache::key(
+            USERS_QUERY,
+            Some(&variables),
+            tenant_slug,
+            get_stored_locale().as_deref(),
+            token,
+        )
+    }
+}
+
+/// Prefix shared by every cached users page of this session, whatever the filters or locale.
+fn users_cache_prefix(tenant_slug: Option<&str>, token: Option<&str>) -> String {
+    QueryCache::query_prefix(USERS_QUERY, tenant_slug, token)
+}
+
+async fn fetch_users_page(
+    args: UsersQueryArgs,
+    token: Option<String>,
+    tenant_slug: Option<String>,
+) -> Result<GraphqlUsersResponse, String> {
+    fetch_users(
+        args.page,
+        args.limit,
+        args.search,
+        args.role,
+        args.status,
+        token,
+        tenant_slug,
+    )
+    .await
+}
+
+/// Serves a page from the shared query cache, loading (and caching) it through `load` on a
+/// miss; the page passes [`fetch_users_page`].
+async fn fetch_users_cached<F, Fut>(
+    cache: QueryCache,
+    args: UsersQueryArgs,
+    token: Option<String>,
+    tenant_slug: Option<String>,
+    load: F,
+) -> Result<GraphqlUsersResponse, String>
+where
+    F: FnOnce(UsersQueryArgs, Option<String>, Option<String>) -> Fut,
+    Fut: Future<Output = Result<GraphqlUsersResponse, String>>,
+{
+    let key = args.cache_key(tenant_slug.as_deref(), token.as_deref());
+    let value = cache
+        .get_or_fetch(key, false, || async move {
+            let response = load(args, token, tenant_slug).await?;
+            serde_json::to_value(response).map_err(|err| err.to_string())
+        })
+        .await?;
+    serde_json::from_value(value).map_err(|err| err.to_string())
+}
+
+/// Warms the cache with the page after the one on screen so "Next" renders instantly.
+///
+/// Each planned prefetch carries the generation it was started in; a filter change bumps
+/// the generation and the stale result is dropped instead of landing in the cache.
+#[derive(Clone, Default)]
+struct UsersPrefetch {
+    generation: Arc<AtomicU64>,
+}
+
+impl UsersPrefetch {
+    fn cancel(&self) {
+        self.generation.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn is_current(&self, ticket: u64) -> bool {
+        self.generation.load(Ordering::Relaxed) == ticket
+    }
+
+    fn plan(
+        &self,
+        cache: &QueryCache,
+        tenant_slug: Option<&str>,
+        token: Option<&str>,
+        loaded: &UsersQueryArgs,
+        has_next_page: bool,
+    ) -> Option<(u64, UsersQueryArgs)> {
+        if !has_next_page {
+            return None;
+        }
+
+        let next = loaded.next_page();
+        if cache.contains(&next.cache_key(tenant_slug, token)) {
+            return None;
+        }
+
+        Some((self.generation.load(Ordering::Relaxed), next))
+    }
+
+    /// Loads the planned page and caches it unless the plan went stale while it was in flight.
+    async fn run<F, Fut>(
+        &self,
+        cache: &QueryCache,
+        ticket: u64,
+        next: UsersQueryArgs,
+        token: Option<String>,
+        tenant_slug: Option<String>,
+        load: F,
+    ) where
+        F: FnOnce(UsersQueryArgs, Option<String>, Option<String>) -> Fut,
+        Fut: Future<Output = Result<GraphqlUsersResponse, String>>,
+    {
+        let key = next.cache_key(tenant_slug.as_deref(), token.as_deref());
+        let Ok(response) = load(next, token, tenant_slug).await else {
+            return;
+        };
+        if let Ok(value) = serde_json::to_value(response) {
+            if self.is_current(ticket) {
+                cache.insert(key, value);
+            }
+        }
+    }
+}
+
 fn users_table_skeleton() -> impl IntoView {
     view! {
         <div>
@@ -431,6 +536,9 @@ pub fn Users() -> impl IntoView {
         .and_then(|p| p.parse::<i64>().ok())
         .unwrap_or(1);
 
+    let cache = use_context::<QueryCache>().unwrap_or_default();
+    let prefetch = UsersPrefetch::default();
+
     let (refresh_counter, set_refresh_counter) = signal(0u32);
     let (page, set_page) = signal(initial_page);
     let (limit, _set_limit) = signal(12i64);
@@ -449,10 +557,12 @@ pub fn Users() -> impl IntoView {
         debounce_search();
     });
 
+    let prefetch_for_filters = prefetch.clone();
     Effect::new(move |_| {
         let _ = debounced_search.get();
         let _ = role_filter.get();
         let _ = status_filter.get();
+        prefetch_for_filters.cancel();
         set_page.set(1);
     });
 
@@ -485,36 +595,80 @@ pub fn Users() -> impl IntoView {
         navigate(&format!("/users{}", search_string), Default::default());
     });
 
+    let current_args = Memo::new(move |_| UsersQueryArgs {
+        page: page.get(),
+        limit: limit.get(),
+        search: debounced_search.get(),
+        role: role_filter.get(),
+        status: status_filter.get(),
+    });
+
+    let cache_for_resource = cache.clone();
     let users_resource = local_resource(
-        move || {
-            (
-                refresh_counter.get(),
-                page.get(),
-                limit.get(),
-                debounced_search.get(),
-                role_filter.get(),
-                status_filter.get(),
-            )
-        },
-        move |(_, page_val, limit_val, search_val, role_val, status_val)| {
+        move || (refresh_counter.get(), current_args.get()),
+        move |(_, args)| {
+            let cache = cache_for_resource.clone();
             let token_value = token.get();
             let tenant_value = tenant.get();
             async move {
-                fetch_users(
-                    page_val,
-                    limit_val,
-                    search_val,
-                    role_val,
-                    status_val,
-                    token_value,
-                    tenant_value,
-                )
-                .await
+                fetch_users_cached(cache, args, token_value, tenant_value, fetch_users_page).await
             }
         },
     );
 
-    let refresh = move |_| set_refresh_counter.update(|value| *value += 1);
+    let cache_for_prefetch = cache.clone();
+    let prefetch_for_load = prefetch.clone();
+    Effect::new(move |_| {
+        let Some(Ok(response)) = users_resource.get() else {
+            return;
+        };
+        let loaded = current_args.get_untracked();
+        let tenant_value = tenant.get_untracked();
+        let token_value = token.get_untracked();
+        let Some((ticket, next)) = prefetch_for_load.plan(
+            &cache_for_prefetch,
+            tenant_value.as_deref(),
+            token_value.as_deref(),
+            &loaded,
+            response.users.page_info.has_next_page,
+        ) else {
+            return;
+        };
+
+        let cache = cache_for_prefetch.clone();
+        let prefetch = prefetch_for_load.clone();
+        spawn_local(async move {
+            prefetch
+                .run(
+                    &cache,
+                    ticket,
+                    next,
+                    token_value,
+                    tenant_value,
+                    fetch_users_page,
+                )
+                .await;
+        });
+    });
+
+    // Refresh and create drop every cached users page: a new user can shift any page or filter.
+    let invalidate_users = {
+        let cache = cache.clone();
+        let prefetch = prefetch.clone();
+        move || {
+            prefetch.cancel();
+            cache.invalidate_prefix(&users_cache_prefix(
+                tenant.get_untracked().as_deref(),
+                token.get_untracked().as_deref(),
+            ));
+        }
+    };
+
+    let invalidate_on_refresh = invalidate_users.clone();
+    let refresh = move |_| {
+        invalidate_on_refresh();
+        set_refresh_counter.update(|value| *value += 1);
+    };
     let next_page = move |_| set_page.update(|value| *value += 1);
     let previous_page = move |_| set_page.update(|value| *value = (*value - 1).max(1));
 
@@ -561,28 +715,12 @@ pub fn Users() -> impl IntoView {
             set_is_creating.set(true);
             set_create_error.set(None);
 
+            let invalidate_users = invalidate_users.clone();
             spawn_local(async move {
-                let vars = CreateUserVariables {
-                    input: CreateUserInput {
-                        email: email_val,
-                        password: password_val,
-                        name: if name_val.is_empty() {
-                            None
-                        } else {
-                            Some(name_val)
-                        },
-                        role: if role_val.is_empty() {
-                            None
-                        } else {
-                            Some(role_val.to_uppercase())
-                        },
-                        status: if status_val.is_empty() {
-                            None
-                        } else {
-                            Some(status_val.to_uppercase())
-                        },
-                    },
-                };
+                let vars = CreateUserVariables::new(email_val, password_val)
+                    .name(name_val)
+                    .role(role_val)
+                    .status(status_val);
                 match request::<CreateUserVariables, CreateUserResponse>(
                     CREATE_USER_MUTATION,
                     vars,
@@ -594,6 +732,7 @@ pub fn Users() -> impl IntoView {
                     Ok(_) => {
                         set_is_creating.set(false);
                         set_show_create_modal.set(false);
+                        invalidate_users();
                         set_refresh_counter.update(|value| *value += 1);
                     }
                     Err(e) => {
@@ -830,3 +969,214 @@ pub fn Users() -> impl IntoView {
         </section>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::future::{ready, Ready};
+    use std::sync::Mutex;
+
+    type Loads = Arc<Mutex<Vec<(i64, Option<String>)>>>;
+
+    fn users_page(total_count: i64) -> GraphqlUsersResponse {
+        GraphqlUsersResponse {
+            users: GraphqlUsersConnection {
+                edges: Vec::new(),
+                page_info: GraphqlPageInfo {
+                    total_count,
+                    has_next_page: true,
+                },
+            },
+        }
+    }
+
+    /// Stands in for [`fetch_users_page`], recording which page was loaded for which token.
+    fn recording_load(
+        loads: &Loads,
+    ) -> impl FnOnce(
+        UsersQueryArgs,
+        Option<String>,
+        Option<String>,
+    ) -> Ready<Result<GraphqlUsersResponse, String>> {
+        let loads = loads.clone();
+        move |args, token, _tenant| {
+            loads.lock().unwrap().push((args.page, token));
+            ready(Ok(users_page(args.page)))
+        }
+    }
+
+    fn first_page() -> UsersQueryArgs {
+        UsersQueryArgs {
+            page: 1,
+            limit: 12,
+            search: "alice".to_string(),
+            role: "admin".to_string(),
+            status: String::new(),
+        }
+    }
+
+    #[test]
+    fn loading_first_page_prefetches_second_page() {
+        let cache = QueryCache::new(8);
+        let prefetch = UsersPrefetch::default();
+        let first = first_page();
+
+        let (ticket, next) = prefetch
+            .plan(&cache, Some("acme"), Some("token"), &first, true)
+            .expect("page two is prefetched");
+
+        assert_eq!(
+            next,
+            UsersQueryArgs {
+                page: 2,
+                ..first.clone()
+            }
+        );
+        assert!(prefetch.is_current(ticket));
+        assert_ne!(
+            next.cache_key(Some("acme"), Some("token")),
+            first.cache_key(Some("acme"), Some("token"))
+        );
+
+        cache.insert(next.cache_key(Some("acme"), Some("token")), json!({}));
+        assert!(prefetch
+            .plan(&cache, Some("acme"), Some("token"), &first, true)
+            .is_none());
+    }
+
+    #[test]
+    fn mutation_invalidates_every_cached_users_page() {
+        let cache = QueryCache::new(8);
+        let first = first_page();
+        let unfiltered = UsersQueryArgs {
+            search: String::new(),
+            role: String::new(),
+            ..first.next_page()
+        };
+        for args in [&first, &first.next_page(), &unfiltered] {
+            cache.insert(args.cache_key(Some("acme"), Some("token")), json!({}));
+        }
+        cache.insert("unrelated", json!({}));
+
+        cache.invalidate_prefix(&users_cache_prefix(Some("acme"), Some("token")));
+
+        assert_eq!(cache.len(), 1);
+        assert!(cache.contains("unrelated"));
+    }
+
+    #[test]
+    fn last_page_is_not_prefetched_and_filter_change_cancels() {
+        let cache = QueryCache::new(8);
+        let prefetch = UsersPrefetch::default();
+        let first = first_page();
+
+        assert!(prefetch
+            .plan(&cache, Some("acme"), Some("token"), &first, false)
+            .is_none());
+
+        let (ticket, _) = prefetch
+            .plan(&cache, Some("acme"), Some("token"), &first, true)
+            .expect("page two is prefetched");
+        prefetch.cancel();
+        assert!(!prefetch.is_current(ticket));
+    }
+
+    #[tokio::test]
+    async fn prefetched_page_is_served_from_cache() {
+        let cache = QueryCache::new(8);
+        let prefetch = UsersPrefetch::default();
+        let loads = Loads::default();
+        let token = Some("alice-token".to_string());
+        let tenant = Some("acme".to_string());
+        let first = first_page();
+
+        fetch_users_cached(
+            cache.clone(),
+            first.clone(),
+            token.clone(),
+            tenant.clone(),
+            recording_load(&loads),
+        )
+        .await
+        .expect("first page loads");
+        let (ticket, next) = prefetch
+            .plan(&cache, tenant.as_deref(), token.as_deref(), &first, true)
+            .expect("page two is prefetched");
+        prefetch
+            .run(
+                &cache,
+                ticket,
+                next.clone(),
+                token.clone(),
+                tenant.clone(),
+                recording_load(&loads),
+            )
+            .await;
+        let second = fetch_users_cached(cache, next, token, tenant, recording_load(&loads))
+            .await
+            .expect("second page is served");
+
+        assert_eq!(second.users.page_info.total_count, 2);
+        assert_eq!(
+            *loads.lock().unwrap(),
+            vec![
+                (1, Some("alice-token".to_string())),
+                (2, Some("alice-token".to_string()))
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn users_with_different_tokens_both_miss() {
+        let cache = QueryCache::new(8);
+        let loads = Loads::default();
+        let tenant = Some("acme".to_string());
+
+        for token in ["alice-token", "bob-token", "alice-token"] {
+            fetch_users_cached(
+                cache.clone(),
+                first_page(),
+                Some(token.to_string()),
+                tenant.clone(),
+                recording_load(&loads),
+            )
+            .await
+            .expect("page loads");
+        }
+
+        assert_eq!(
+            *loads.lock().unwrap(),
+            vec![
+                (1, Some("alice-token".to_string())),
+                (1, Some("bob-token".to_string()))
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn stale_prefetch_is_not_cached() {
+        let cache = QueryCache::new(8);
+        let prefetch = UsersPrefetch::default();
+        let loads = Loads::default();
+        let first = first_page();
+
+        let (ticket, next) = prefetch
+            .plan(&cache, Some("acme"), Some("token"), &first, true)
+            .expect("page two is prefetched");
+        prefetch.cancel();
+        prefetch
+            .run(
+                &cache,
+                ticket,
+                next.clone(),
+                Some("token".to_string()),
+                Some("acme".to_string()),
+                recording_load(&loads),
+            )
+            .await;
+
+        assert_eq!(loads.lock().unwrap().len(), 1);
+        assert!(!cache.contains(&next.cache_key(Some("acme"), Some("token"))));
+    }
+}
@@ -1,4 +1,5 @@
 pub mod queries;
+pub mod variables;
 
 #[cfg(target_arch = "wasm32")]
 use gloo_storage::Storage as GlooStorage;
@@ -84,7 +85,10 @@ pub type ApiError = GraphqlHttpError;
 pub fn get_stored_locale() -> Option<String> {
     #[cfg(target_arch = "wasm32")]
     {
-        gloo_storage::LocalStorage::get::<String>("rustok-admin-locale").ok()
+        gloo_storage::LocalStorage::get::<String>(
+            crate::widgets::locale_switcher::LOCALE_STORAGE_KEY,
+        )
+        .ok()
     }
     #[cfg(not(target_arch = "wasm32"))]
     {
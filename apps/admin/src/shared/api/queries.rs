@@ -14,6 +14,9 @@ pub const DASHBOARD_STATS_QUERY: &str =
 
 pub const RECENT_ACTIVITY_QUERY: &str = "query RecentActivity($limit: Int!) { recentActivity(limit: $limit) { id type description timestamp user { id name } } }";
 
+/// `$limit` the dashboard sends with `RECENT_ACTIVITY_QUERY`.
+pub const RECENT_ACTIVITY_LIMIT: i64 = 10;
+
 pub const RECENT_ACTIVITY_QUERY_HASH: &str =
     "a1b2c3d4e5f6g7h8i9j0k1l2m3n4o5p6q7r8s9t0u1v2w3x4y5z6a7b8c9d0e1f2";
 
@@ -29,6 +32,11 @@ mutation CreateUser($input: CreateUserInput!) {
 
 pub const ROLES_QUERY: &str = "query Roles { roles { slug displayName permissions } }";
 
+pub const FEATURES_QUERY: &str = "query Features { features { permissions enabledModules } }";
+
+pub const TENANT_LOCALES_QUERY: &str =
+    "query TenantLocales { tenantLocales { code name nativeName isDefault } }";
+
 pub const PLATFORM_SETTINGS_QUERY: &str =
     "query PlatformSettings($category: String!) { platformSettings(category: $category) { category settings } }";
 
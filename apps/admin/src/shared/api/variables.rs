@@ -0,0 +1,214 @@
+//! Typed variables for the admin's GraphQL operations.
+//!
+//! Each struct mirrors the variable definitions of the matching document in
+//! [`super::queries`], so a renamed or retyped argument fails to compile instead of
+//! silently sending the wrong shape.
+
+use serde::Serialize;
+
+fn non_empty(value: impl Into<String>) -> Option<String> {
+    let value = value.into();
+    let trimmed = value.trim();
+    if trimmed.is_empty() {
+        None
+    } else {
+        Some(trimmed.to_string())
+    }
+}
+
+/// GraphQL enum values (`UserRole`, `UserStatus`) are SCREAMING_SNAKE_CASE.
+fn enum_value(value: impl Into<String>) -> Option<String> {
+    non_empty(value).map(|value| value.to_uppercase())
+}
+
+/// Variables for operations without arguments, serialized as `{}`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct NoVariables {}
+
+/// `RECENT_ACTIVITY_QUERY`: `($limit: Int!)`.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct RecentActivityVariables {
+    pub limit: i64,
+}
+
+/// `PaginationInput`.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct PaginationInput {
+    pub first: i64,
+    pub after: Option<String>,
+}
+
+/// `UsersFilter`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct UsersFilterInput {
+    pub role: Option<String>,
+    pub status: Option<String>,
+}
+
+/// `USERS_QUERY`: `($pagination: PaginationInput, $filter: UsersFilter, $search: String)`.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct UsersVariables {
+    pub pagination: PaginationInput,
+    pub filter: Option<UsersFilterInput>,
+    pub search: Option<String>,
+}
+
+impl UsersVariables {
+    pub fn new(first: i64) -> Self {
+        Self {
+            pagination: PaginationInput { first, after: None },
+            filter: None,
+            search: None,
+        }
+    }
+
+    pub fn after(mut self, cursor: Option<String>) -> Self {
+        self.pagination.after = cursor;
+        self
+    }
+
+    /// Empty values are dropped; role is sent as the GraphQL enum value.
+    pub fn role(mut self, role: impl Into<String>) -> Self {
+        self.filter.get_or_insert_with(Default::default).role = enum_value(role);
+        self.normalize_filter()
+    }
+
+    /// Empty values are dropped; status is sent as the GraphQL enum value.
+    pub fn status(mut self, status: impl Into<String>) -> Self {
+        self.filter.get_or_insert_with(Default::default).status = enum_value(status);
+        self.normalize_filter()
+    }
+
+    pub fn search(mut self, search: impl Into<String>) -> Self {
+        self.search = non_empty(search);
+        self
+    }
+
+    fn normalize_filter(mut self) -> Self {
+        if self.filter == Some(UsersFilterInput::default()) {
+            self.filter = None;
+        }
+        self
+    }
+}
+
+/// `CreateUserInput`.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct CreateUserInput {
+    pub email: String,
+    pub password: String,
+    pub name: Option<String>,
+    pub role: Option<String>,
+    pub status: Option<String>,
+}
+
+/// `CREATE_USER_MUTATION`: `($input: CreateUserInput!)`.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct CreateUserVariables {
+    pub input: CreateUserInput,
+}
+
+impl CreateUserVariables {
+    pub fn new(email: impl Into<String>, password: impl Into<String>) -> Self {
+        Self {
+            input: CreateUserInput {
+                email: email.into(),
+                password: password.into(),
+                name: None,
+                role: None,
+                status: None,
+            },
+        }
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.input.name = non_empty(name);
+        self
+    }
+
+    pub fn role(mut self, role: impl Into<String>) -> Self {
+        self.input.role = enum_value(role);
+        self
+    }
+
+    pub fn status(mut self, status: impl Into<String>) -> Self {
+        self.input.status = enum_value(status);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::api::queries::RECENT_ACTIVITY_LIMIT;
+    use serde_json::{json, to_value};
+
+    #[test]
+    fn no_variables_serialize_to_empty_object() {
+        assert_eq!(to_value(NoVariables::default()).unwrap(), json!({}));
+    }
+
+    #[test]
+    fn recent_activity_variables_match_query_arguments() {
+        assert_eq!(
+            to_value(RecentActivityVariables {
+                limit: RECENT_ACTIVITY_LIMIT
+            })
+            .unwrap(),
+            json!({ "limit": 10 })
+        );
+    }
+
+    #[test]
+    fn users_variables_serialize_filters_and_cursor() {
+        let variables = UsersVariables::new(20)
+            .after(Some("Y3Vyc29yOjQw".to_string()))
+            .role("admin")
+            .status("active")
+            .search("  alice ");
+
+        assert_eq!(
+            to_value(variables).unwrap(),
+            json!({
+                "pagination": { "first": 20, "after": "Y3Vyc29yOjQw" },
+                "filter": { "role": "ADMIN", "status": "ACTIVE" },
+                "search": "alice"
+            })
+        );
+    }
+
+    #[test]
+    fn users_variables_drop_empty_filters() {
+        let variables = UsersVariables::new(10).role("").status(" ").search("");
+
+        assert_eq!(
+            to_value(variables).unwrap(),
+            json!({
+                "pagination": { "first": 10, "after": null },
+                "filter": null,
+                "search": null
+            })
+        );
+    }
+
+    #[test]
+    fn create_user_variables_wrap_input() {
+        let variables = CreateUserVariables::new("new@example.com", "s3cret!")
+            .name("New User")
+            .role("manager")
+            .status("");
+
+        assert_eq!(
+            to_value(variables).unwrap(),
+            json!({
+                "input": {
+                    "email": "new@example.com",
+                    "password": "s3cret!",
+                    "name": "New User",
+                    "role": "MANAGER",
+                    "status": null
+                }
+            })
+        );
+    }
+}
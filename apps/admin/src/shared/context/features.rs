@@ -0,0 +1,166 @@
+use std::collections::HashSet;
+
+use leptos::prelude::*;
+use leptos_auth::hooks::{use_tenant, use_token};
+use serde::Deserialize;
+
+use crate::shared::api::queries::FEATURES_QUERY;
+use crate::shared::api::variables::NoVariables;
+use crate::shared::api::{request, ApiError};
+
+/// What an admin action needs from the server: an enabled module and/or a permission.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FeatureRequirement {
+    pub module: Option<&'static str>,
+    pub permission: Option<&'static str>,
+}
+
+impl FeatureRequirement {
+    pub const fn new(module: &'static str, permission: &'static str) -> Self {
+        Self {
+            module: Some(module),
+            permission: Some(permission),
+        }
+    }
+}
+
+pub const CREATE_PRODUCT: FeatureRequirement =
+    FeatureRequirement::new("commerce", "products:create");
+
+/// Server-reported capabilities of the current user (`features` query).
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Features {
+    pub permissions: HashSet<String>,
+    pub enabled_modules: HashSet<String>,
+}
+
+impl Features {
+    pub fn is_module_enabled(&self, slug: &str) -> bool {
+        self.enabled_modules.contains(slug)
+    }
+
+    /// Mirrors the server check: `resource:manage` grants every action on `resource`.
+    pub fn can(&self, permission: &str) -> bool {
+        if self.permissions.contains(permission) {
+            return true;
+        }
+
+        permission
+            .split_once(':')
+            .is_some_and(|(resource, _)| self.permissions.contains(&format!("{resource}:manage")))
+    }
+
+    pub fn allows(&self, requirement: &FeatureRequirement) -> bool {
+        requirement
+            .module
+            .is_none_or(|module| self.is_module_enabled(module))
+            && requirement
+                .permission
+                .is_none_or(|permission| self.can(permission))
+    }
+}
+
+#[derive(Deserialize)]
+struct FeaturesResponse {
+    features: Features,
+}
+
+pub async fn fetch_features(
+    token: Option<String>,
+    tenant_slug: Option<String>,
+) -> Result<Features, ApiError> {
+    let response: FeaturesResponse =
+        request(FEATURES_QUERY, NoVariables::default(), token, tenant_slug).await?;
+    Ok(response.features)
+}
+
+#[derive(Clone)]
+pub struct FeaturesContext {
+    pub features: RwSignal<Features>,
+    pub is_loading: RwSignal<bool>,
+}
+
+/// Loads `features` for the signed-in user. Until it resolves (or if it fails) nothing
+/// gated is shown: hiding an allowed action is cheaper than offering a rejected one.
+#[component]
+pub fn FeaturesProvider(children: Children) -> impl IntoView {
+    let context = FeaturesContext {
+        features: RwSignal::new(Features::default()),
+        is_loading: RwSignal::new(true),
+    };
+    provide_context(context.clone());
+
+    let token = use_token();
+    let tenant = use_tenant();
+
+    let resource = LocalResource::new(move || {
+        let token = token.get();
+        let tenant = tenant.get();
+        async move {
+            if token.is_none() || tenant.is_none() {
+                return Ok(Features::default());
+            }
+            fetch_features(token, tenant).await
+        }
+    });
+
+    Effect::new(move |_| match resource.get() {
+        Some(result) => {
+            context.features.set(result.unwrap_or_default());
+            context.is_loading.set(false);
+        }
+        None => context.is_loading.set(true),
+    });
+
+    children()
+}
+
+pub fn use_features() -> Signal<Features> {
+    let context = use_context::<FeaturesContext>()
+        .expect("FeaturesContext not found. Make sure to wrap your app with <FeaturesProvider>");
+    Signal::derive(move || context.features.get())
+}
+
+pub fn use_feature(requirement: FeatureRequirement) -> Signal<bool> {
+    let features = use_features();
+    Signal::derive(move || features.with(|features| features.allows(&requirement)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn features(value: serde_json::Value) -> Features {
+        serde_json::from_value::<FeaturesResponse>(json!({ "features": value }))
+            .unwrap()
+            .features
+    }
+
+    #[test]
+    fn product_action_is_hidden_when_commerce_is_disabled() {
+        let features = features(json!({
+            "permissions": ["products:create", "users:read"],
+            "enabledModules": ["content", "pages"]
+        }));
+
+        assert!(!features.allows(&CREATE_PRODUCT));
+    }
+
+    #[test]
+    fn product_action_requires_permission_or_manage() {
+        let without_permission = features(json!({
+            "permissions": ["products:read"],
+            "enabledModules": ["commerce"]
+        }));
+        let with_manage = features(json!({
+            "permissions": ["products:manage"],
+            "enabledModules": ["commerce"]
+        }));
+
+        assert!(!without_permission.allows(&CREATE_PRODUCT));
+        assert!(with_manage.allows(&CREATE_PRODUCT));
+        assert!(!Features::default().allows(&CREATE_PRODUCT));
+    }
+}
@@ -3,26 +3,39 @@ use leptos::prelude::*;
 use serde_json::{Map, Value};
 use std::sync::OnceLock;
 
+use crate::widgets::locale_switcher::{resolve_locale_code, LOCALE_STORAGE_KEY};
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum Locale {
     En,
     Ru,
 }
 
+/// Every supported locale with its code; adding a locale only means adding a row here.
+const LOCALES: &[(Locale, &str)] = &[(Locale::En, "en"), (Locale::Ru, "ru")];
+
+pub const DEFAULT_LOCALE: Locale = Locale::En;
+
 impl Locale {
+    /// Supported locale for a code such as `ru` or `ru-RU`; region subtags are ignored.
+    pub fn parse(code: &str) -> Option<Self> {
+        let language = code.trim().split(['-', '_']).next().unwrap_or_default();
+        LOCALES
+            .iter()
+            .find(|(_, known)| known.eq_ignore_ascii_case(language))
+            .map(|(locale, _)| *locale)
+    }
+
     pub fn from_code(code: &str) -> Self {
-        match code.to_lowercase().as_str() {
-            "en" => Locale::En,
-            "ru" => Locale::Ru,
-            _ => Locale::En,
-        }
+        Self::parse(code).unwrap_or(DEFAULT_LOCALE)
     }
 
     pub fn code(&self) -> &'static str {
-        match self {
-            Locale::En => "en",
-            Locale::Ru => "ru",
-        }
+        LOCALES
+            .iter()
+            .find(|(locale, _)| locale == self)
+            .map(|(_, code)| *code)
+            .unwrap_or("en")
     }
 }
 
@@ -56,14 +69,17 @@ pub struct LocaleContext {
     pub set_locale: WriteSignal<Locale>,
 }
 
-pub fn provide_locale_context() -> LocaleContext {
-    let initial_locale = load_locale_from_storage().unwrap_or(Locale::En);
+/// Provides the legacy [`LocaleContext`] from the admin's locale fallback chain.
+/// Mounted by `LocaleResolver`, which keeps it in step with the active locale; the
+/// choice itself is persisted by `select_locale`, not here.
+pub fn provide_locale_context(tenant_default: Option<&str>) -> LocaleContext {
+    let initial_locale = Locale::from_code(&resolve_locale_code(
+        load_locale_from_storage().as_deref(),
+        tenant_default,
+        browser_language().as_deref(),
+    ));
     let (locale, set_locale) = signal(initial_locale);
 
-    Effect::new(move |_| {
-        let _ = LocalStorage::set("rustok-admin-locale", locale.get().code());
-    });
-
     let context = LocaleContext { locale, set_locale };
     provide_context(context.clone());
     context
@@ -78,7 +94,29 @@ pub fn translate(key: &str) -> String {
     translate_locale(locale, key)
 }
 
-fn load_locale_from_storage() -> Option<Locale> {
-    let value: String = LocalStorage::get("rustok-admin-locale").ok()?;
-    Some(Locale::from_code(&value))
+fn load_locale_from_storage() -> Option<String> {
+    LocalStorage::get(LOCALE_STORAGE_KEY).ok()
+}
+
+fn browser_language() -> Option<String> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        web_sys::window().and_then(|window| window.navigator().language())
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unknown_codes_fall_back_to_default() {
+        assert_eq!(Locale::parse("ru-RU"), Some(Locale::Ru));
+        assert_eq!(Locale::from_code("zz"), DEFAULT_LOCALE);
+        assert_eq!(Locale::from_code("RU").code(), "ru");
+    }
 }
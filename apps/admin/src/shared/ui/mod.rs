@@ -4,7 +4,11 @@ pub use leptos_ui::*;
 pub mod page_header;
 pub use page_header::PageHeader;
 
-use crate::{t_string, use_i18n, Locale};
+use crate::widgets::locale_switcher::{
+    fallback_locale_options, locale_code, select_locale, ui_locale, use_active_locale,
+    BrowserLocaleStorage,
+};
+use crate::{t_string, use_i18n};
 
 #[cfg(target_arch = "wasm32")]
 const THEME_STORAGE_KEY: &str = "rustok-admin-theme";
@@ -97,18 +101,16 @@ pub fn Input(
 #[component]
 pub fn LanguageToggle() -> impl IntoView {
     let i18n = use_i18n();
+    let active = use_active_locale();
 
     view! {
         <select
             aria-label=move || t_string!(i18n, app.nav.language).to_string()
-            prop:value=move || match i18n.get_locale() {
-                Locale::ru => "ru",
-                Locale::en => "en",
-            }
-            on:change=move |ev| match event_target_value(&ev).as_str() {
-                "ru" => i18n.set_locale(Locale::ru),
-                "en" => i18n.set_locale(Locale::en),
-                _ => {}
+            prop:value=move || locale_code(ui_locale(&active.get()))
+            on:change=move |ev| {
+                let code = event_target_value(&ev);
+                let options = fallback_locale_options(i18n);
+                select_locale(&code, &options, active, &BrowserLocaleStorage);
             }
             class="h-9 min-w-32 rounded-md border border-input bg-background px-3 py-1 text-sm font-medium text-foreground shadow-xs outline-none transition-[color,box-shadow] focus-visible:border-ring focus-visible:ring-[3px] focus-visible:ring-ring/50"
         >
@@ -11,7 +11,8 @@ use serde::{Deserialize, Serialize};
 use crate::features::auth::UserMenu;
 use crate::shared::api::queries::ADMIN_GLOBAL_SEARCH_QUERY;
 use crate::shared::api::request;
-use crate::shared::ui::{LanguageToggle, ThemeModeToggle};
+use crate::shared::ui::ThemeModeToggle;
+use crate::widgets::locale_switcher::LocaleSwitcher;
 use crate::{t_string, use_i18n};
 
 #[derive(Clone, Copy, PartialEq)]
@@ -310,7 +311,7 @@ pub fn Header(
 
             <div class="flex shrink-0 items-center gap-2">
                 <HeaderGlobalSearch />
-                <LanguageToggle />
+                <LocaleSwitcher />
                 <ThemeModeToggle />
                 <UserMenu />
             </div>
@@ -0,0 +1,396 @@
+use leptos::ev::{self, KeyboardEvent, MouseEvent};
+use leptos::prelude::*;
+use leptos_router::hooks::use_navigate;
+use leptos_router::NavigateOptions;
+
+use crate::app::providers::features::{use_features, Features, CREATE_PRODUCT};
+use crate::widgets::locale_switcher::{
+    fallback_locale_options, locale_code, select_locale, use_active_locale, BrowserLocaleStorage,
+};
+use crate::{t_string, use_i18n, Locale};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PaletteGroup {
+    Navigation,
+    Actions,
+}
+
+#[derive(Clone)]
+pub enum PaletteAction {
+    Navigate(&'static str),
+    SetLocale(Locale),
+}
+
+#[derive(Clone)]
+pub struct PaletteCommand {
+    pub id: &'static str,
+    pub label: String,
+    pub group: PaletteGroup,
+    pub action: PaletteAction,
+}
+
+impl PaletteCommand {
+    fn matches(&self, terms: &[String]) -> bool {
+        let haystack = match &self.action {
+            PaletteAction::Navigate(href) => format!("{} {}", self.label, href),
+            PaletteAction::SetLocale(_) => self.label.clone(),
+        }
+        .to_lowercase();
+
+        terms.iter().all(|term| haystack.contains(term.as_str()))
+    }
+}
+
+/// Keeps commands whose label (or route) contains every whitespace-separated term.
+pub fn filter_commands(commands: &[PaletteCommand], query: &str) -> Vec<PaletteCommand> {
+    let terms = query
+        .split_whitespace()
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>();
+
+    commands
+        .iter()
+        .filter(|command| command.matches(&terms))
+        .cloned()
+        .collect()
+}
+
+pub fn is_palette_shortcut(key: &str, ctrl: bool, meta: bool) -> bool {
+    (ctrl || meta) && key.eq_ignore_ascii_case("k")
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PaletteState {
+    pub open: bool,
+    pub query: String,
+    pub selected: usize,
+}
+
+impl PaletteState {
+    pub fn open(&mut self) {
+        *self = Self {
+            open: true,
+            ..Self::default()
+        };
+    }
+
+    pub fn close(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn toggle(&mut self) {
+        if self.open {
+            self.close();
+        } else {
+            self.open();
+        }
+    }
+
+    pub fn set_query(&mut self, query: String) {
+        self.query = query;
+        self.selected = 0;
+    }
+
+    /// Moves the highlighted row, wrapping around the `len` visible commands.
+    pub fn move_selection(&mut self, delta: isize, len: usize) {
+        if len == 0 {
+            self.selected = 0;
+            return;
+        }
+        let len = len as isize;
+        self.selected = (self.selected as isize + delta).rem_euclid(len) as usize;
+    }
+
+    pub fn selected_command<'a>(
+        &self,
+        visible: &'a [PaletteCommand],
+    ) -> Option<&'a PaletteCommand> {
+        visible.get(self.selected)
+    }
+}
+
+fn palette_commands(
+    i18n: leptos_i18n::I18nContext<Locale>,
+    features: &Features,
+) -> Vec<PaletteCommand> {
+    let navigate = |id, label: &str, href| PaletteCommand {
+        id,
+        label: label.to_string(),
+        group: PaletteGroup::Navigation,
+        action: PaletteAction::Navigate(href),
+    };
+    let language = t_string!(i18n, app.nav.language);
+
+    let mut commands = vec![
+        navigate(
+            "dashboard",
+            t_string!(i18n, app.nav.dashboard),
+            "/dashboard",
+        ),
+        navigate("users", t_string!(i18n, app.nav.users), "/users"),
+        navigate("roles", t_string!(i18n, app.nav.roles), "/roles"),
+        navigate("modules", t_string!(i18n, app.nav.modules), "/modules"),
+        navigate("apps", t_string!(i18n, app.nav.apps), "/apps"),
+        navigate("workflows", t_string!(i18n, workflows.title), "/workflows"),
+        navigate("ai", t_string!(i18n, app.nav.ai), "/ai"),
+        navigate("email", t_string!(i18n, app.nav.email), "/email"),
+        navigate("cache", t_string!(i18n, app.nav.cache), "/cache"),
+        navigate("events", t_string!(i18n, events.title), "/events"),
+        navigate("profile", t_string!(i18n, app.nav.profile), "/profile"),
+        navigate("security", t_string!(i18n, app.nav.security), "/security"),
+        PaletteCommand {
+            id: "locale-en",
+            label: format!("{language}: {}", t_string!(i18n, app.nav.languageEn)),
+            group: PaletteGroup::Actions,
+            action: PaletteAction::SetLocale(Locale::en),
+        },
+        PaletteCommand {
+            id: "locale-ru",
+            label: format!("{language}: {}", t_string!(i18n, app.nav.languageRu)),
+            group: PaletteGroup::Actions,
+            action: PaletteAction::SetLocale(Locale::ru),
+        },
+    ];
+
+    if features.allows(&CREATE_PRODUCT) {
+        commands.push(PaletteCommand {
+            id: "new-product",
+            label: t_string!(i18n, app.palette.newProduct).to_string(),
+            group: PaletteGroup::Actions,
+            action: PaletteAction::Navigate("/modules/commerce"),
+        });
+    }
+
+    commands
+}
+
+/// Ctrl/Cmd-K command palette: quick navigation and admin actions.
+#[component]
+pub fn CommandPalette() -> impl IntoView {
+    let i18n = use_i18n();
+    let navigate = use_navigate();
+    let features = use_features();
+    let active_locale = use_active_locale();
+    let state = RwSignal::new(PaletteState::default());
+    let input_ref = NodeRef::<leptos::html::Input>::new();
+
+    let visible = Signal::derive(move || {
+        let commands = features.with(|features| palette_commands(i18n, features));
+        state.with(|state| filter_commands(&commands, &state.query))
+    });
+
+    let shortcut = window_event_listener(ev::keydown, move |ev| {
+        if is_palette_shortcut(&ev.key(), ev.ctrl_key(), ev.meta_key()) {
+            ev.prevent_default();
+            state.update(PaletteState::toggle);
+        }
+    });
+    on_cleanup(move || shortcut.remove());
+
+    Effect::new(move |_| {
+        if state.with(|state| state.open) {
+            if let Some(input) = input_ref.get() {
+                let _ = input.focus();
+            }
+        }
+    });
+
+    let run_command = Callback::new(move |command: PaletteCommand| {
+        state.update(PaletteState::close);
+        match command.action {
+            PaletteAction::Navigate(href) => navigate(href, NavigateOptions::default()),
+            // Same path as the header switcher, so the choice is persisted and shown there.
+            PaletteAction::SetLocale(locale) => {
+                select_locale(
+                    locale_code(locale),
+                    &fallback_locale_options(i18n),
+                    active_locale,
+                    &BrowserLocaleStorage,
+                );
+            }
+        }
+    });
+
+    let on_keydown = move |ev: KeyboardEvent| match ev.key().as_str() {
+        "ArrowDown" | "ArrowUp" => {
+            ev.prevent_default();
+            let delta = if ev.key() == "ArrowDown" { 1 } else { -1 };
+            let len = visible.with_untracked(Vec::len);
+            state.update(|state| state.move_selection(delta, len));
+        }
+        "Enter" => {
+            ev.prevent_default();
+            let command = visible.with_untracked(|visible| {
+                state.with_untracked(|state| state.selected_command(visible).cloned())
+            });
+            if let Some(command) = command {
+                run_command.run(command);
+            }
+        }
+        "Escape" => {
+            ev.prevent_default();
+            state.update(PaletteState::close);
+        }
+        // The search input is the only focusable element: keep focus inside the dialog.
+        "Tab" => ev.prevent_default(),
+        _ => {}
+    };
+
+    view! {
+        <Show when=move || state.with(|state| state.open)>
+            <div
+                class="fixed inset-0 z-50 flex items-start justify-center bg-black/40 px-4 pt-[15vh]"
+                on:mousedown=move |_| state.update(PaletteState::close)
+            >
+                <div
+                    role="dialog"
+                    aria-modal="true"
+                    aria-label=move || t_string!(i18n, app.palette.title).to_string()
+                    class="w-full max-w-lg overflow-hidden rounded-xl border border-border bg-card shadow-xl"
+                    on:mousedown=move |ev: MouseEvent| ev.stop_propagation()
+                >
+                    <input
+                        node_ref=input_ref
+                        type="text"
+                        role="combobox"
+                        aria-expanded="true"
+                        prop:value=move || state.with(|state| state.query.clone())
+                        placeholder=move || t_string!(i18n, app.palette.placeholder).to_string()
+                        class="h-12 w-full border-b border-border bg-transparent px-4 text-sm text-foreground outline-none placeholder:text-muted-foreground"
+                        on:input=move |ev| {
+                            let query = event_target_value(&ev);
+                            state.update(|state| state.set_query(query));
+                        }
+                        on:keydown=on_keydown
+                    />
+
+                    <ul role="listbox" class="max-h-80 overflow-y-auto py-2">
+                        {move || {
+                            let commands = visible.get();
+                            if commands.is_empty() {
+                                return view! {
+                                    <li class="px-4 py-3 text-sm text-muted-foreground">
+                                        {t_string!(i18n, app.palette.empty)}
+                                    </li>
+                                }
+                                .into_any();
+                            }
+
+                            let selected = state.with(|state| state.selected);
+                            commands
+                                .into_iter()
+                                .enumerate()
+                                .map(|(index, command)| {
+                                    let is_selected = index == selected;
+                                    let group_label = match command.group {
+                                        PaletteGroup::Navigation => t_string!(i18n, app.palette.navigation),
+                                        PaletteGroup::Actions => t_string!(i18n, app.palette.actions),
+                                    };
+                                    let label = command.label.clone();
+                                    view! {
+                                        <li
+                                            role="option"
+                                            id=format!("command-palette-{}", command.id)
+                                            aria-selected=is_selected.to_string()
+                                            class=if is_selected {
+                                                "flex cursor-pointer items-center justify-between bg-accent px-4 py-2 text-sm text-accent-foreground"
+                                            } else {
+                                                "flex cursor-pointer items-center justify-between px-4 py-2 text-sm text-card-foreground hover:bg-accent/60"
+                                            }
+                                            on:mousedown=move |ev: MouseEvent| {
+                                                ev.prevent_default();
+                                                run_command.run(command.clone());
+                                            }
+                                        >
+                                            <span>{label}</span>
+                                            <span class="text-xs text-muted-foreground">{group_label}</span>
+                                        </li>
+                                    }
+                                })
+                                .collect_view()
+                                .into_any()
+                        }}
+                    </ul>
+
+                    <div class="border-t border-border px-4 py-2 text-xs text-muted-foreground">
+                        {t_string!(i18n, app.palette.hint)}
+                    </div>
+                </div>
+            </div>
+        </Show>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn route(id: &'static str, label: &str, href: &'static str) -> PaletteCommand {
+        PaletteCommand {
+            id,
+            label: label.to_string(),
+            group: PaletteGroup::Navigation,
+            action: PaletteAction::Navigate(href),
+        }
+    }
+
+    fn commands() -> Vec<PaletteCommand> {
+        vec![
+            route("dashboard", "Dashboard", "/dashboard"),
+            route("users", "Users", "/users"),
+            route("roles", "Roles & Permissions", "/roles"),
+            route("cache", "Cache", "/cache"),
+        ]
+    }
+
+    #[test]
+    fn shortcut_requires_ctrl_or_cmd() {
+        assert!(is_palette_shortcut("k", true, false));
+        assert!(is_palette_shortcut("K", false, true));
+        assert!(!is_palette_shortcut("k", false, false));
+        assert!(!is_palette_shortcut("j", true, false));
+    }
+
+    #[test]
+    fn typing_filters_commands_and_enter_navigates_to_selection() {
+        let commands = commands();
+        let mut state = PaletteState::default();
+
+        state.toggle();
+        assert!(state.open);
+        assert_eq!(
+            filter_commands(&commands, &state.query).len(),
+            commands.len()
+        );
+
+        state.set_query("ro".to_string());
+        let visible = filter_commands(&commands, &state.query);
+        let ids = visible.iter().map(|command| command.id).collect::<Vec<_>>();
+        assert_eq!(ids, vec!["roles"]);
+
+        state.set_query("s".to_string());
+        let visible = filter_commands(&commands, &state.query);
+        state.move_selection(1, visible.len());
+        let selected = state
+            .selected_command(&visible)
+            .expect("a command is highlighted");
+
+        assert_eq!(selected.id, "users");
+        assert!(matches!(selected.action, PaletteAction::Navigate("/users")));
+    }
+
+    #[test]
+    fn selection_wraps_and_close_resets_query() {
+        let mut state = PaletteState::default();
+        state.open();
+        state.set_query("cache".to_string());
+
+        state.move_selection(-1, 3);
+        assert_eq!(state.selected, 2);
+        state.move_selection(1, 3);
+        assert_eq!(state.selected, 0);
+
+        state.close();
+        assert_eq!(state, PaletteState::default());
+    }
+}
@@ -0,0 +1,370 @@
+use leptos::prelude::*;
+use leptos_auth::hooks::{use_tenant, use_token};
+use serde::Deserialize;
+
+use crate::shared::api::queries::TENANT_LOCALES_QUERY;
+use crate::shared::api::variables::NoVariables;
+use crate::shared::api::{get_stored_locale, request, ApiError};
+use crate::shared::i18n::{provide_locale_context, Locale as LegacyLocale};
+use crate::{t_string, use_i18n, Locale};
+
+/// Same key the API client reads the request locale from.
+pub const LOCALE_STORAGE_KEY: &str = "rustok-admin-locale";
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TenantLocale {
+    pub code: String,
+    pub name: String,
+    pub native_name: String,
+    pub is_default: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TenantLocalesResponse {
+    tenant_locales: Vec<TenantLocale>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LocaleOption {
+    pub code: String,
+    pub label: String,
+}
+
+/// Tenant locales in server order; `fallback` (En/Ru) when the list is unavailable or empty.
+pub fn locale_options(
+    tenant_locales: Option<&[TenantLocale]>,
+    fallback: Vec<LocaleOption>,
+) -> Vec<LocaleOption> {
+    match tenant_locales {
+        Some(locales) if !locales.is_empty() => locales
+            .iter()
+            .map(|locale| LocaleOption {
+                code: locale.code.clone(),
+                label: locale.native_name.clone(),
+            })
+            .collect(),
+        _ => fallback,
+    }
+}
+
+/// Admin UI messages exist only for En/Ru; other tenant locales keep the English UI
+/// while content requests still carry the selected code.
+pub fn ui_locale(code: &str) -> Locale {
+    let language = code.split(['-', '_']).next().unwrap_or_default();
+    if language.eq_ignore_ascii_case("ru") {
+        Locale::ru
+    } else {
+        Locale::en
+    }
+}
+
+/// Locale code used when neither the user, the tenant nor the browser names one.
+pub const DEFAULT_LOCALE_CODE: &str = "en";
+
+/// The admin's locale fallback chain: the user's stored choice, then the tenant default,
+/// then the browser language, then [`DEFAULT_LOCALE_CODE`]. Blank values are skipped.
+pub fn resolve_locale_code(
+    user: Option<&str>,
+    tenant_default: Option<&str>,
+    browser: Option<&str>,
+) -> String {
+    [user, tenant_default, browser]
+        .into_iter()
+        .flatten()
+        .map(str::trim)
+        .find(|code| !code.is_empty())
+        .unwrap_or(DEFAULT_LOCALE_CODE)
+        .to_string()
+}
+
+/// Active locale code, provided by [`LocaleResolver`] and shared with [`LocaleSwitcher`].
+#[derive(Clone, Copy)]
+pub struct ActiveLocale(pub RwSignal<String>);
+
+pub trait LocaleStorage {
+    fn persist(&self, code: &str);
+}
+
+/// Browser LocalStorage under [`LOCALE_STORAGE_KEY`]; a no-op outside wasm.
+pub struct BrowserLocaleStorage;
+
+impl LocaleStorage for BrowserLocaleStorage {
+    fn persist(&self, code: &str) {
+        #[cfg(target_arch = "wasm32")]
+        {
+            use gloo_storage::{LocalStorage, Storage};
+            let _ = LocalStorage::set(LOCALE_STORAGE_KEY, code);
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let _ = code;
+        }
+    }
+}
+
+/// The shared [`ActiveLocale`] signal, or a standalone one seeded from storage or the
+/// current UI locale when no [`LocaleResolver`] is mounted.
+pub fn use_active_locale() -> RwSignal<String> {
+    let i18n = use_i18n();
+    use_context::<ActiveLocale>()
+        .map(|ActiveLocale(active)| active)
+        .unwrap_or_else(|| {
+            RwSignal::new(
+                get_stored_locale()
+                    .unwrap_or_else(|| locale_code(i18n.get_locale_untracked()).into()),
+            )
+        })
+}
+
+/// The En/Ru options offered when tenant locales are not loaded.
+pub fn fallback_locale_options(i18n: leptos_i18n::I18nContext<Locale>) -> Vec<LocaleOption> {
+    vec![
+        LocaleOption {
+            code: "en".to_string(),
+            label: t_string!(i18n, app.nav.languageEn).to_string(),
+        },
+        LocaleOption {
+            code: "ru".to_string(),
+            label: t_string!(i18n, app.nav.languageRu).to_string(),
+        },
+    ]
+}
+
+/// Makes `code` the active locale if it is one of `options`, persisting the choice.
+pub fn select_locale(
+    code: &str,
+    options: &[LocaleOption],
+    active: RwSignal<String>,
+    storage: &impl LocaleStorage,
+) -> bool {
+    if !options.iter().any(|option| option.code == code) {
+        return false;
+    }
+
+    active.set(code.to_string());
+    storage.persist(code);
+    true
+}
+
+async fn fetch_tenant_locales(
+    token: Option<String>,
+    tenant: Option<String>,
+) -> Result<Vec<TenantLocale>, ApiError> {
+    let response: TenantLocalesResponse =
+        request(TENANT_LOCALES_QUERY, NoVariables::default(), token, tenant).await?;
+    Ok(response.tenant_locales)
+}
+
+fn browser_language() -> Option<String> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        web_sys::window().and_then(|window| window.navigator().language())
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        None
+    }
+}
+
+pub fn locale_code(locale: Locale) -> &'static str {
+    match locale {
+        Locale::ru => "ru",
+        Locale::en => "en",
+    }
+}
+
+/// Resolves the initial locale through [`resolve_locale_code`] and applies it to the
+/// leptos_i18n context and the legacy `shared::i18n` context; the tenant default is
+/// fetched once a tenant session exists and only matters while the user has no stored
+/// choice. Mount inside `AuthProvider` and `I18nContextProvider`.
+#[component]
+pub fn LocaleResolver(children: Children) -> impl IntoView {
+    let i18n = use_i18n();
+    let token = use_token();
+    let tenant = use_tenant();
+
+    let active = RwSignal::new(resolve_locale_code(
+        get_stored_locale().as_deref(),
+        None,
+        browser_language().as_deref(),
+    ));
+    provide_context(ActiveLocale(active));
+    // Tenant locales load later; the effect below carries their default over.
+    let legacy = provide_locale_context(None);
+
+    let tenant_locales = LocalResource::new(move || {
+        let token = token.get();
+        let tenant = tenant.get();
+        async move {
+            if token.is_none() || tenant.is_none() || get_stored_locale().is_some() {
+                return None;
+            }
+            fetch_tenant_locales(token, tenant).await.ok()
+        }
+    });
+
+    Effect::new(move |_| {
+        let Some(locales) = tenant_locales.get().flatten() else {
+            return;
+        };
+        let tenant_default = locales
+            .iter()
+            .find(|locale| locale.is_default)
+            .map(|locale| locale.code.as_str());
+        active.set(resolve_locale_code(
+            get_stored_locale().as_deref(),
+            tenant_default,
+            browser_language().as_deref(),
+        ));
+    });
+
+    Effect::new(move |_| {
+        let code = active.get();
+        legacy.set_locale.set(LegacyLocale::from_code(&code));
+        let locale = ui_locale(&code);
+        if i18n.get_locale_untracked() != locale {
+            i18n.set_locale(locale);
+        }
+    });
+
+    children()
+}
+
+/// Language selector listing the tenant's enabled locales.
+#[component]
+pub fn LocaleSwitcher() -> impl IntoView {
+    let i18n = use_i18n();
+    let token = use_token();
+    let tenant = use_tenant();
+
+    let active = use_active_locale();
+
+    let tenant_locales = LocalResource::new(move || {
+        let token = token.get();
+        let tenant = tenant.get();
+        async move {
+            if token.is_none() || tenant.is_none() {
+                return None;
+            }
+            fetch_tenant_locales(token, tenant).await.ok()
+        }
+    });
+
+    let options = Signal::derive(move || {
+        let loaded = tenant_locales.get().flatten();
+        locale_options(loaded.as_deref(), fallback_locale_options(i18n))
+    });
+
+    Effect::new(move |_| {
+        let locale = ui_locale(&active.get());
+        if i18n.get_locale_untracked() != locale {
+            i18n.set_locale(locale);
+        }
+    });
+
+    view! {
+        <select
+            aria-label=move || t_string!(i18n, app.nav.language).to_string()
+            prop:value=move || active.get()
+            on:change=move |ev| {
+                let code = event_target_value(&ev);
+                options.with_untracked(|options| {
+                    select_locale(&code, options, active, &BrowserLocaleStorage)
+                });
+            }
+            class="h-9 min-w-32 rounded-md border border-input bg-background px-3 py-1 text-sm font-medium text-foreground shadow-xs outline-none transition-[color,box-shadow] focus-visible:border-ring focus-visible:ring-[3px] focus-visible:ring-ring/50"
+        >
+            <For
+                each=move || options.get()
+                key=|option| option.code.clone()
+                children=move |option| {
+                    let code = option.code.clone();
+                    view! {
+                        <option value=option.code selected=move || active.get() == code>
+                            {option.label}
+                        </option>
+                    }
+                }
+            />
+        </select>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+
+    #[derive(Default)]
+    struct MemoryStorage(RefCell<Vec<String>>);
+
+    impl LocaleStorage for MemoryStorage {
+        fn persist(&self, code: &str) {
+            self.0.borrow_mut().push(code.to_string());
+        }
+    }
+
+    fn tenant_locale(code: &str, native_name: &str, is_default: bool) -> TenantLocale {
+        TenantLocale {
+            code: code.to_string(),
+            name: native_name.to_string(),
+            native_name: native_name.to_string(),
+            is_default,
+        }
+    }
+
+    fn fallback() -> Vec<LocaleOption> {
+        vec![
+            LocaleOption {
+                code: "en".to_string(),
+                label: "English".to_string(),
+            },
+            LocaleOption {
+                code: "ru".to_string(),
+                label: "Русский".to_string(),
+            },
+        ]
+    }
+
+    #[test]
+    fn locale_chain_prefers_user_then_tenant_then_browser() {
+        assert_eq!(
+            resolve_locale_code(Some("ru"), Some("de"), Some("en-US")),
+            "ru"
+        );
+        assert_eq!(resolve_locale_code(None, Some("de"), Some("en-US")), "de");
+        assert_eq!(resolve_locale_code(Some(" "), None, Some("ru-RU")), "ru-RU");
+        assert_eq!(resolve_locale_code(None, None, None), DEFAULT_LOCALE_CODE);
+    }
+
+    #[test]
+    fn options_fall_back_to_en_ru_without_tenant_locales() {
+        assert_eq!(locale_options(None, fallback()), fallback());
+        assert_eq!(locale_options(Some(&[]), fallback()), fallback());
+    }
+
+    #[test]
+    fn selecting_tenant_locale_updates_signal_and_persists_it() {
+        let tenant_locales = vec![
+            tenant_locale("de", "Deutsch", true),
+            tenant_locale("ru", "Русский", false),
+        ];
+        let options = locale_options(Some(&tenant_locales), fallback());
+        let active = RwSignal::new("en".to_string());
+        let storage = MemoryStorage::default();
+
+        assert!(select_locale("ru", &options, active, &storage));
+        assert_eq!(active.get_untracked(), "ru");
+        assert_eq!(ui_locale(&active.get_untracked()), Locale::ru);
+
+        assert!(!select_locale("en", &options, active, &storage));
+        assert_eq!(active.get_untracked(), "ru");
+
+        assert!(select_locale("de", &options, active, &storage));
+        assert_eq!(ui_locale(&active.get_untracked()), Locale::en);
+        assert_eq!(*storage.0.borrow(), vec!["ru", "de"]);
+    }
+}
@@ -1,3 +1,5 @@
 pub mod app_shell;
+pub mod command_palette;
+pub mod locale_switcher;
 pub mod oauth_apps_list;
 pub mod stats_card;
@@ -116,6 +116,8 @@ impl MigratorTrait for Migrator {
         all.extend(rustok_search::migrations::migrations());
         all.extend(rustok_taxonomy::migrations::migrations());
         all.extend(rustok_workflow::migrations::migrations());
+        all.extend(rustok_email::migrations::migrations());
+        all.extend(rustok_outbox::migrations::migrations());
         all.push(Box::new(
             m20260501_000001_create_platform_composition_state::Migration,
         ));
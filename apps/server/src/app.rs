@@ -21,6 +21,7 @@ use crate::seeds;
 use crate::services::app_lifecycle::{apply_boot_database_fallback, connect_runtime_workers};
 use crate::services::app_router::compose_application_router;
 use crate::services::app_runtime::bootstrap_app_runtime;
+use crate::services::db_query_metrics::DbQueryMetrics;
 use crate::tasks;
 use loco_rs::prelude::Queue;
 
@@ -67,6 +68,11 @@ impl Hooks for App {
     async fn after_context(mut ctx: AppContext) -> Result<AppContext> {
         check_production_secrets(&ctx)?;
 
+        let database_settings = RustokSettings::from_settings(&ctx.config.settings)
+            .map(|settings| settings.runtime.database)
+            .unwrap_or_default();
+        DbQueryMetrics::from_settings(&database_settings).install(&mut ctx.db);
+
         // Initialise Loco's ctx.mailer when email.provider = "loco".
         // This must happen before after_routes so every request handler
         // can call email_service_from_ctx() and get a working Loco mailer.
@@ -123,7 +129,10 @@ impl Hooks for App {
                 .add_route(controllers::marketplace_registry::routes())
                 .add_route(controllers::metrics::routes())
                 .add_route(controllers::swagger::routes())
+                .add_route(controllers::health::api_routes())
+                .add_route(controllers::admin_body_capture::routes())
                 .add_route(controllers::admin_events::routes())
+                .add_route(controllers::admin_logging::routes())
                 .add_route(controllers::auth::routes())
                 .add_route(controllers::channel::routes())
                 .add_route(controllers::flex::routes())
@@ -0,0 +1,73 @@
+//! Tracing layer for tests that records the fields of every event logged to one target.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use tracing::field::{Field, Visit};
+use tracing::subscriber::DefaultGuard;
+use tracing_subscriber::layer::{Context, SubscriberExt};
+use tracing_subscriber::Layer;
+
+/// Field values of one event, keyed by field name; `message` holds the formatted message.
+pub type EventFields = HashMap<String, String>;
+
+#[derive(Default)]
+struct FieldVisitor(EventFields);
+
+impl Visit for FieldVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.0
+            .insert(field.name().to_string(), format!("{value:?}"));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name().to_string(), value.to_string());
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.0.insert(field.name().to_string(), value.to_string());
+    }
+}
+
+#[derive(Clone)]
+pub struct LogCapture {
+    target: &'static str,
+    events: Arc<Mutex<Vec<EventFields>>>,
+}
+
+impl LogCapture {
+    pub fn new(target: &'static str) -> Self {
+        Self {
+            target,
+            events: Arc::default(),
+        }
+    }
+
+    /// Makes this capture the thread's default subscriber until the guard is dropped.
+    pub fn install(&self) -> DefaultGuard {
+        tracing::subscriber::set_default(tracing_subscriber::registry().with(self.clone()))
+    }
+
+    pub fn events(&self) -> Vec<EventFields> {
+        self.events.lock().unwrap().clone()
+    }
+
+    /// Values of `field` across all captured events, in logging order.
+    pub fn field_values(&self, field: &str) -> Vec<String> {
+        self.events()
+            .iter()
+            .filter_map(|event| event.get(field).cloned())
+            .collect()
+    }
+}
+
+impl<S: tracing::Subscriber> Layer<S> for LogCapture {
+    fn on_event(&self, event: &tracing::Event<'_>, _ctx: Context<'_, S>) {
+        if event.metadata().target() != self.target {
+            return;
+        }
+        let mut fields = FieldVisitor::default();
+        event.record(&mut fields);
+        self.events.lock().unwrap().push(fields.0);
+    }
+}
@@ -1,4 +1,6 @@
 pub mod context;
+#[cfg(test)]
+pub mod log_capture;
 pub mod pagination;
 pub mod request_trust;
 pub mod response;
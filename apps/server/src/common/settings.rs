@@ -35,6 +35,8 @@ pub struct RustokSettings {
     pub runtime: RuntimeSettings,
     #[serde(default)]
     pub storage: StorageConfig,
+    #[serde(default)]
+    pub cors: CorsSettings,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -386,6 +388,35 @@ pub struct RateLimitSettings {
     pub trusted_auth_dimensions: bool,
 }
 
+/// Cross-origin policy applied to `/api/*` routes.
+///
+/// The `strict` preset allows only the explicitly listed origins. The
+/// `dev_permissive` preset additionally allows any `localhost`/`127.0.0.1`
+/// origin so the admin dev server can call the API from another port.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CorsSettings {
+    #[serde(default)]
+    pub preset: CorsPreset,
+    #[serde(default)]
+    pub allowed_origins: Vec<String>,
+    #[serde(default = "default_cors_allowed_methods")]
+    pub allowed_methods: Vec<String>,
+    #[serde(default = "default_cors_allowed_headers")]
+    pub allowed_headers: Vec<String>,
+    #[serde(default)]
+    pub allow_credentials: bool,
+    #[serde(default = "default_cors_max_age_secs")]
+    pub max_age_secs: u64,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, Default, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CorsPreset {
+    #[default]
+    Strict,
+    DevPermissive,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct RuntimeSettings {
     #[serde(default)]
@@ -396,6 +427,70 @@ pub struct RuntimeSettings {
     pub guardrails: RuntimeGuardrailSettings,
     #[serde(default)]
     pub request_trust: RequestTrustSettings,
+    #[serde(default)]
+    pub metrics: RuntimeMetricsSettings,
+    #[serde(default)]
+    pub database: RuntimeDatabaseSettings,
+    #[serde(default)]
+    pub access_log: AccessLogSettings,
+    #[serde(default)]
+    pub body_capture: BodyCaptureSettings,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct RuntimeMetricsSettings {
+    /// When set, `GET /metrics` requires `Authorization: Bearer <token>`.
+    #[serde(default)]
+    pub bearer_token: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct AccessLogSettings {
+    /// Emits one `access_log` event per request at info level.
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default)]
+    pub format: AccessLogFormat,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, Default, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum AccessLogFormat {
+    /// Structured fields only; rendered as JSON by the JSON log formatter.
+    #[default]
+    Json,
+    /// Structured fields plus an Apache combined-style message line.
+    Combined,
+}
+
+/// Debug capture of request/response bodies; can be changed at runtime through
+/// `PUT /api/admin/body-capture`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct BodyCaptureSettings {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Fraction of matching requests to capture, `0.0..=1.0`.
+    #[serde(default)]
+    pub sample_rate: f64,
+    /// Path prefixes eligible for capture; empty captures nothing.
+    #[serde(default)]
+    pub routes: Vec<String>,
+    /// Each logged body is truncated to this many bytes.
+    #[serde(default = "default_body_capture_max_body_bytes")]
+    pub max_body_bytes: usize,
+    /// Upper bound on captured requests per minute, regardless of `sample_rate`.
+    #[serde(default = "default_body_capture_max_per_minute")]
+    pub max_captures_per_minute: u32,
+    /// JSON fields (case-insensitive, at any depth) whose values are replaced with `REDACTED`.
+    #[serde(default = "default_body_capture_redact_fields")]
+    pub redact_fields: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RuntimeDatabaseSettings {
+    /// Queries at or above this duration are logged as slow; `0` disables the log.
+    #[serde(default = "default_slow_query_threshold_ms")]
+    pub slow_query_threshold_ms: u64,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -404,6 +499,8 @@ pub struct RuntimeBackgroundWorkerSettings {
     pub workflow_cron_enabled: bool,
     #[serde(default = "default_true")]
     pub seo_bulk_enabled: bool,
+    #[serde(default = "default_true")]
+    pub webhook_health_enabled: bool,
 }
 
 #[derive(Debug, Clone, Copy, Deserialize, Serialize, Default, Eq, PartialEq)]
@@ -586,6 +683,19 @@ impl Default for RateLimitSettings {
     }
 }
 
+impl Default for CorsSettings {
+    fn default() -> Self {
+        Self {
+            preset: CorsPreset::default(),
+            allowed_origins: Vec::new(),
+            allowed_methods: default_cors_allowed_methods(),
+            allowed_headers: default_cors_allowed_headers(),
+            allow_credentials: false,
+            max_age_secs: default_cors_max_age_secs(),
+        }
+    }
+}
+
 impl Default for RuntimeSettings {
     fn default() -> Self {
         Self {
@@ -593,6 +703,10 @@ impl Default for RuntimeSettings {
             background_workers: RuntimeBackgroundWorkerSettings::default(),
             guardrails: RuntimeGuardrailSettings::default(),
             request_trust: RequestTrustSettings::default(),
+            metrics: RuntimeMetricsSettings::default(),
+            database: RuntimeDatabaseSettings::default(),
+            access_log: AccessLogSettings::default(),
+            body_capture: BodyCaptureSettings::default(),
         }
     }
 }
@@ -602,6 +716,28 @@ impl Default for RuntimeBackgroundWorkerSettings {
         Self {
             workflow_cron_enabled: true,
             seo_bulk_enabled: true,
+            webhook_health_enabled: true,
+        }
+    }
+}
+
+impl Default for BodyCaptureSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            sample_rate: 0.0,
+            routes: Vec::new(),
+            max_body_bytes: default_body_capture_max_body_bytes(),
+            max_captures_per_minute: default_body_capture_max_per_minute(),
+            redact_fields: default_body_capture_redact_fields(),
+        }
+    }
+}
+
+impl Default for RuntimeDatabaseSettings {
+    fn default() -> Self {
+        Self {
+            slow_query_threshold_ms: default_slow_query_threshold_ms(),
         }
     }
 }
@@ -997,6 +1133,60 @@ fn default_header_name() -> String {
     "X-Tenant-ID".to_string()
 }
 
+fn default_cors_allowed_methods() -> Vec<String> {
+    ["GET", "POST", "PUT", "PATCH", "DELETE"]
+        .into_iter()
+        .map(str::to_string)
+        .collect()
+}
+
+fn default_cors_allowed_headers() -> Vec<String> {
+    [
+        "authorization",
+        "content-type",
+        "accept-language",
+        "x-tenant-id",
+        "x-request-id",
+    ]
+    .into_iter()
+    .map(str::to_string)
+    .collect()
+}
+
+fn default_cors_max_age_secs() -> u64 {
+    600
+}
+
+fn default_slow_query_threshold_ms() -> u64 {
+    500
+}
+
+fn default_body_capture_max_body_bytes() -> usize {
+    4096
+}
+
+fn default_body_capture_max_per_minute() -> u32 {
+    60
+}
+
+fn default_body_capture_redact_fields() -> Vec<String> {
+    [
+        "password",
+        "token",
+        "access_token",
+        "refresh_token",
+        "secret",
+        "client_secret",
+        "api_key",
+        "authorization",
+        "card_number",
+        "cvv",
+    ]
+    .into_iter()
+    .map(str::to_string)
+    .collect()
+}
+
 fn default_true() -> bool {
     true
 }
@@ -0,0 +1,139 @@
+use axum::{extract::State, http::StatusCode, Json};
+use loco_rs::app::AppContext;
+use loco_rs::controller::{ErrorDetail, Routes};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::common::settings::BodyCaptureSettings;
+use crate::error::{Error, Result};
+use crate::extractors::rbac::RequireLogsManage;
+use crate::middleware::body_capture::BodyCaptureControl;
+
+/// Partial update; omitted fields keep their current value.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateBodyCaptureRequest {
+    pub enabled: Option<bool>,
+    /// Fraction of matching requests to capture, `0.0..=1.0`.
+    pub sample_rate: Option<f64>,
+    /// Path prefixes eligible for capture, e.g. `/api/commerce/orders`.
+    pub routes: Option<Vec<String>>,
+    pub max_body_bytes: Option<usize>,
+    pub max_captures_per_minute: Option<u32>,
+    pub redact_fields: Option<Vec<String>>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BodyCaptureResponse {
+    pub enabled: bool,
+    pub sample_rate: f64,
+    pub routes: Vec<String>,
+    pub max_body_bytes: usize,
+    pub max_captures_per_minute: u32,
+    pub redact_fields: Vec<String>,
+}
+
+impl From<BodyCaptureSettings> for BodyCaptureResponse {
+    fn from(settings: BodyCaptureSettings) -> Self {
+        Self {
+            enabled: settings.enabled,
+            sample_rate: settings.sample_rate,
+            routes: settings.routes,
+            max_body_bytes: settings.max_body_bytes,
+            max_captures_per_minute: settings.max_captures_per_minute,
+            redact_fields: settings.redact_fields,
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/body-capture",
+    responses(
+        (status = 200, description = "Active body capture settings", body = BodyCaptureResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 503, description = "Body capture not initialised"),
+    ),
+    security(("bearer_auth" = [])),
+    tag = "admin"
+)]
+pub async fn get_body_capture(
+    State(ctx): State<AppContext>,
+    _user: RequireLogsManage,
+) -> Result<Json<BodyCaptureResponse>> {
+    Ok(Json(body_capture(&ctx)?.settings().into()))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/admin/body-capture",
+    request_body = UpdateBodyCaptureRequest,
+    responses(
+        (status = 200, description = "Body capture settings updated", body = BodyCaptureResponse),
+        (status = 400, description = "Invalid settings"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 503, description = "Body capture not initialised"),
+    ),
+    security(("bearer_auth" = [])),
+    tag = "admin"
+)]
+pub async fn update_body_capture(
+    State(ctx): State<AppContext>,
+    RequireLogsManage(user): RequireLogsManage,
+    Json(request): Json<UpdateBodyCaptureRequest>,
+) -> Result<Json<BodyCaptureResponse>> {
+    let control = body_capture(&ctx)?;
+    let mut settings = control.settings();
+    if let Some(enabled) = request.enabled {
+        settings.enabled = enabled;
+    }
+    if let Some(sample_rate) = request.sample_rate {
+        settings.sample_rate = sample_rate;
+    }
+    if let Some(routes) = request.routes {
+        settings.routes = routes;
+    }
+    if let Some(max_body_bytes) = request.max_body_bytes {
+        settings.max_body_bytes = max_body_bytes;
+    }
+    if let Some(max_captures_per_minute) = request.max_captures_per_minute {
+        settings.max_captures_per_minute = max_captures_per_minute;
+    }
+    if let Some(redact_fields) = request.redact_fields {
+        settings.redact_fields = redact_fields;
+    }
+
+    control
+        .update(settings.clone())
+        .map_err(Error::BadRequest)?;
+
+    tracing::warn!(
+        user_id = %user.user.id,
+        enabled = settings.enabled,
+        sample_rate = settings.sample_rate,
+        routes = ?settings.routes,
+        "body capture settings updated"
+    );
+
+    Ok(Json(settings.into()))
+}
+
+pub fn routes() -> Routes {
+    Routes::new().prefix("api/admin/body-capture").add(
+        "/",
+        axum::routing::get(get_body_capture).put(update_body_capture),
+    )
+}
+
+fn body_capture(ctx: &AppContext) -> Result<BodyCaptureControl> {
+    ctx.shared_store.get::<BodyCaptureControl>().ok_or_else(|| {
+        Error::CustomError(
+            StatusCode::SERVICE_UNAVAILABLE,
+            ErrorDetail::new(
+                "body_capture_unavailable",
+                "Body capture is not initialised in this process",
+            ),
+        )
+    })
+}
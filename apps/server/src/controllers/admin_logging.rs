@@ -0,0 +1,148 @@
+use std::time::Duration;
+
+use axum::{extract::State, http::StatusCode, Json};
+use chrono::{DateTime, Utc};
+use loco_rs::app::AppContext;
+use loco_rs::controller::{ErrorDetail, Routes};
+use rustok_telemetry::{LogFilterHandle, LogFilterSnapshot, TelemetryError};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::error::{Error, Result};
+use crate::extractors::rbac::RequireLogsManage;
+
+/// Overrides expire after this long unless the request asks otherwise.
+pub const DEFAULT_LOG_OVERRIDE_TTL_SECS: u64 = 15 * 60;
+pub const MAX_LOG_OVERRIDE_TTL_SECS: u64 = 24 * 60 * 60;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SetLogLevelsRequest {
+    /// Comma-separated `EnvFilter` directives, e.g. `rustok_core::events=debug`.
+    pub directives: String,
+    pub ttl_secs: Option<u64>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LogLevelsResponse {
+    pub base: String,
+    pub overrides: Option<String>,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl From<LogFilterSnapshot> for LogLevelsResponse {
+    fn from(snapshot: LogFilterSnapshot) -> Self {
+        Self {
+            base: snapshot.base,
+            overrides: snapshot.overrides,
+            expires_at: snapshot.expires_at.map(DateTime::<Utc>::from),
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/log-levels",
+    responses(
+        (status = 200, description = "Active log filter", body = LogLevelsResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 503, description = "Telemetry not initialised"),
+    ),
+    security(("bearer_auth" = [])),
+    tag = "admin"
+)]
+pub async fn get_log_levels(
+    State(_ctx): State<AppContext>,
+    _user: RequireLogsManage,
+) -> Result<Json<LogLevelsResponse>> {
+    Ok(Json(log_filter()?.snapshot().into()))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/admin/log-levels",
+    request_body = SetLogLevelsRequest,
+    responses(
+        (status = 200, description = "Override applied until it expires", body = LogLevelsResponse),
+        (status = 400, description = "Invalid directives"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 503, description = "Telemetry not initialised"),
+    ),
+    security(("bearer_auth" = [])),
+    tag = "admin"
+)]
+pub async fn set_log_levels(
+    State(_ctx): State<AppContext>,
+    RequireLogsManage(user): RequireLogsManage,
+    Json(request): Json<SetLogLevelsRequest>,
+) -> Result<Json<LogLevelsResponse>> {
+    let ttl_secs = request
+        .ttl_secs
+        .unwrap_or(DEFAULT_LOG_OVERRIDE_TTL_SECS)
+        .clamp(1, MAX_LOG_OVERRIDE_TTL_SECS);
+    let snapshot = log_filter()?
+        .set_overrides(&request.directives, Duration::from_secs(ttl_secs))
+        .map_err(telemetry_error)?;
+
+    tracing::warn!(
+        user_id = %user.user.id,
+        directives = snapshot.overrides.as_deref().unwrap_or_default(),
+        ttl_secs,
+        "log level override applied"
+    );
+
+    Ok(Json(snapshot.into()))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/admin/log-levels",
+    responses(
+        (status = 200, description = "Startup log filter restored", body = LogLevelsResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 503, description = "Telemetry not initialised"),
+    ),
+    security(("bearer_auth" = [])),
+    tag = "admin"
+)]
+pub async fn reset_log_levels(
+    State(_ctx): State<AppContext>,
+    RequireLogsManage(user): RequireLogsManage,
+) -> Result<Json<LogLevelsResponse>> {
+    let handle = log_filter()?;
+    handle.reset().map_err(telemetry_error)?;
+
+    tracing::warn!(user_id = %user.user.id, "log level override reset");
+
+    Ok(Json(handle.snapshot().into()))
+}
+
+pub fn routes() -> Routes {
+    Routes::new().prefix("api/admin/log-levels").add(
+        "/",
+        axum::routing::get(get_log_levels)
+            .put(set_log_levels)
+            .delete(reset_log_levels),
+    )
+}
+
+fn log_filter() -> Result<LogFilterHandle> {
+    rustok_telemetry::log_filter_handle().ok_or_else(|| {
+        Error::CustomError(
+            StatusCode::SERVICE_UNAVAILABLE,
+            ErrorDetail::new(
+                "telemetry_unavailable",
+                "Log filter is not managed by telemetry in this process",
+            ),
+        )
+    })
+}
+
+fn telemetry_error(error: TelemetryError) -> Error {
+    match error {
+        TelemetryError::InvalidLogFilter { .. } => Error::BadRequest(error.to_string()),
+        other => Error::Message(other.to_string()),
+    }
+}
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use async_graphql::http::{GraphQLPlaygroundConfig, WebSocketProtocols, WsMessage};
-use async_graphql::Data;
+use async_graphql::{BatchRequest, BatchResponse, Data, ObjectType, Schema, SubscriptionType};
 use axum::{
     extract::{
         ws::{CloseFrame, Message, WebSocket, WebSocketUpgrade},
@@ -25,6 +25,10 @@ use crate::graphql::persisted::is_cataloged_admin_hash;
 use crate::graphql::AppSchema;
 use rustok_core::ModuleRegistry;
 
+/// Upper bound on operations in one array-form request; each operation is still
+/// subject to the schema's own depth and complexity limits.
+const MAX_BATCH_OPERATIONS: usize = 16;
+
 async fn graphql_handler(
     State(ctx): State<AppContext>,
     Extension(registry): Extension<ModuleRegistry>,
@@ -33,18 +37,20 @@ async fn graphql_handler(
     request_context: RequestContext,
     OptionalCurrentUser(current_user): OptionalCurrentUser,
     headers: HeaderMap,
-    Json(req): Json<async_graphql::Request>,
-) -> Json<async_graphql::Response> {
+    Json(batch): Json<BatchRequest>,
+) -> Json<BatchResponse> {
     let locale = Locale::parse(&request_context.locale).unwrap_or_default();
-    if let Some(hash) = persisted_query_hash(&req) {
-        tracing::debug!(
-            persisted_query_hash = hash,
-            cataloged_admin_hash = is_cataloged_admin_hash(hash),
-            "Observed persisted query hash for GraphQL telemetry"
-        );
+    for req in batch.iter() {
+        if let Some(hash) = persisted_query_hash(req) {
+            tracing::debug!(
+                persisted_query_hash = hash,
+                cataloged_admin_hash = is_cataloged_admin_hash(hash),
+                "Observed persisted query hash for GraphQL telemetry"
+            );
+        }
     }
 
-    let mut request = req
+    let mut batch = batch
         .data(ctx)
         .data(tenant_ctx)
         .data(request_context)
@@ -62,10 +68,43 @@ async fn graphql_handler(
             scopes: current_user.scopes.clone(),
             grant_type: current_user.grant_type.clone(),
         };
-        request = request.data(auth_ctx);
+        batch = batch.data(auth_ctx);
+    }
+
+    Json(execute_batch(schema.as_ref(), batch).await)
+}
+
+/// Executes a single or array-form GraphQL request.
+///
+/// Operations run independently, so an error in one (auth, complexity, resolver
+/// failure) is reported in its own slot and never fails its siblings. Results are
+/// returned in request order.
+async fn execute_batch<Query, Mutation, Subscription>(
+    schema: &Schema<Query, Mutation, Subscription>,
+    batch: BatchRequest,
+) -> BatchResponse
+where
+    Query: ObjectType + 'static,
+    Mutation: ObjectType + 'static,
+    Subscription: SubscriptionType + 'static,
+{
+    if let BatchRequest::Batch(requests) = &batch {
+        if requests.len() > MAX_BATCH_OPERATIONS {
+            tracing::warn!(
+                operations = requests.len(),
+                limit = MAX_BATCH_OPERATIONS,
+                "Rejected oversized GraphQL batch"
+            );
+            return BatchResponse::Single(async_graphql::Response::from_errors(vec![
+                async_graphql::ServerError::new(
+                    format!("Batch exceeds the limit of {MAX_BATCH_OPERATIONS} operations"),
+                    None,
+                ),
+            ]));
+        }
     }
 
-    Json(schema.execute(request).await)
+    schema.execute_batch(batch).await
 }
 
 fn persisted_query_hash(req: &async_graphql::Request) -> Option<&str> {
@@ -250,3 +289,109 @@ pub fn routes() -> Routes {
         .add("/", get(graphql_playground).post(graphql_handler))
         .add("/ws", get(graphql_ws_handler))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_graphql::{EmptyMutation, EmptySubscription, Object, Request};
+    use serde_json::json;
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        async fn ping(&self) -> &str {
+            "pong"
+        }
+
+        async fn answer(&self) -> i32 {
+            42
+        }
+
+        async fn broken(&self) -> async_graphql::Result<i32> {
+            Err("resolver failed".into())
+        }
+    }
+
+    fn schema() -> Schema<QueryRoot, EmptyMutation, EmptySubscription> {
+        Schema::build(QueryRoot, EmptyMutation, EmptySubscription).finish()
+    }
+
+    fn responses(response: BatchResponse) -> Vec<async_graphql::Response> {
+        match response {
+            BatchResponse::Batch(responses) => responses,
+            BatchResponse::Single(_) => panic!("expected array-form response"),
+        }
+    }
+
+    #[tokio::test]
+    async fn batch_of_two_queries_returns_two_results_in_order() {
+        let batch: BatchRequest = serde_json::from_value(json!([
+            { "query": "{ ping }" },
+            { "query": "{ answer }" }
+        ]))
+        .expect("array-form request should parse");
+
+        let responses = responses(execute_batch(&schema(), batch).await);
+
+        assert_eq!(responses.len(), 2);
+        assert_eq!(
+            responses[0].data.clone().into_json().unwrap(),
+            json!({ "ping": "pong" })
+        );
+        assert_eq!(
+            responses[1].data.clone().into_json().unwrap(),
+            json!({ "answer": 42 })
+        );
+    }
+
+    #[tokio::test]
+    async fn failing_operation_does_not_fail_the_others() {
+        let batch = BatchRequest::Batch(vec![
+            Request::new("{ broken }"),
+            Request::new("{ ping }"),
+            Request::new("{ notAField }"),
+        ]);
+
+        let responses = responses(execute_batch(&schema(), batch).await);
+
+        assert_eq!(responses.len(), 3);
+        assert!(responses[0].errors[0].message.contains("resolver failed"));
+        assert!(responses[1].errors.is_empty());
+        assert_eq!(
+            responses[1].data.clone().into_json().unwrap(),
+            json!({ "ping": "pong" })
+        );
+        assert!(!responses[2].errors.is_empty());
+    }
+
+    #[tokio::test]
+    async fn single_request_keeps_object_response_shape() {
+        let batch: BatchRequest =
+            serde_json::from_value(json!({ "query": "{ ping }" })).expect("single request");
+
+        let response = execute_batch(&schema(), batch).await;
+
+        assert!(matches!(response, BatchResponse::Single(_)));
+        assert_eq!(
+            serde_json::to_value(&response).unwrap()["data"],
+            json!({ "ping": "pong" })
+        );
+    }
+
+    #[tokio::test]
+    async fn oversized_batch_is_rejected() {
+        let batch = BatchRequest::Batch(
+            (0..=MAX_BATCH_OPERATIONS)
+                .map(|_| Request::new("{ ping }"))
+                .collect(),
+        );
+
+        let response = execute_batch(&schema(), batch).await;
+
+        let BatchResponse::Single(response) = response else {
+            panic!("oversized batch should collapse into a single error response");
+        };
+        assert!(response.errors[0].message.contains("limit"));
+    }
+}
@@ -2,7 +2,8 @@
 
 use crate::error::Result;
 use axum::extract::State;
-use axum::response::Response;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
 use axum::routing::get;
 use axum::Extension;
 use loco_rs::app::AppContext;
@@ -25,6 +26,7 @@ use crate::middleware::rate_limit::{
 use crate::middleware::tenant::{
     tenant_invalidation_listener_snapshot, TenantInvalidationListenerStatus,
 };
+use crate::services::module_event_dispatcher::{DispatcherLiveness, DispatcherState};
 use crate::services::runtime_guardrails::{
     collect_runtime_guardrail_snapshot, RuntimeGuardrailSnapshot, RuntimeGuardrailStatus,
 };
@@ -111,6 +113,37 @@ pub struct ModulesHealthResponse {
     pub modules: Vec<ModuleHealth>,
 }
 
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+struct DependencyStatus {
+    name: &'static str,
+    ready: bool,
+    latency_ms: u128,
+    reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct DependencyReadinessResponse {
+    ready: bool,
+    dependencies: Vec<DependencyStatus>,
+}
+
+impl DependencyReadinessResponse {
+    fn from_dependencies(dependencies: Vec<DependencyStatus>) -> Self {
+        Self {
+            ready: dependencies.iter().all(|dependency| dependency.ready),
+            dependencies,
+        }
+    }
+
+    fn status_code(&self) -> StatusCode {
+        if self.ready {
+            StatusCode::OK
+        } else {
+            StatusCode::SERVICE_UNAVAILABLE
+        }
+    }
+}
+
 #[derive(Debug, Default, Clone)]
 struct CircuitState {
     consecutive_failures: u32,
@@ -313,6 +346,32 @@ pub async fn ready(
     })
 }
 
+/// GET /api/health/ready - Strict dependency readiness probe
+/// Verifies database connectivity, the module event dispatcher, and a cache ping.
+/// Any failing dependency makes the whole probe not-ready (503).
+#[utoipa::path(
+    get,
+    path = "/api/health/ready",
+    tag = "health",
+    responses(
+        (status = 200, description = "All dependencies are ready"),
+        (status = 503, description = "At least one dependency is not ready")
+    )
+)]
+pub async fn dependencies_ready(State(ctx): State<AppContext>) -> Result<Response> {
+    let dependencies = vec![
+        probe_dependency("database", || check_database(&ctx.db)).await,
+        probe_dependency("event_dispatcher", || async {
+            check_event_dispatcher(ctx.shared_store.get::<DispatcherLiveness>())
+        })
+        .await,
+        probe_dependency("cache", || check_cache_backend(&ctx)).await,
+    ];
+
+    let response = DependencyReadinessResponse::from_dependencies(dependencies);
+    Ok((response.status_code(), axum::Json(response)).into_response())
+}
+
 /// GET /health/runtime - Runtime guardrail snapshot for operators
 /// Returns the current rollout-aware guardrail state plus component-level details.
 #[utoipa::path(
@@ -417,6 +476,14 @@ async fn check_cache_backend(ctx: &AppContext) -> std::result::Result<(), String
     }
 }
 
+fn check_event_dispatcher(liveness: Option<DispatcherLiveness>) -> std::result::Result<(), String> {
+    match liveness.map(|liveness| liveness.state()) {
+        Some(DispatcherState::Running | DispatcherState::Idle) => Ok(()),
+        Some(DispatcherState::Stopped) => Err("event dispatcher has stopped".to_string()),
+        None => Err("event dispatcher not initialized in shared_store".to_string()),
+    }
+}
+
 async fn check_tenant_invalidation_listener(ctx: &AppContext) -> std::result::Result<(), String> {
     let snapshot = tenant_invalidation_listener_snapshot(ctx).await;
 
@@ -579,6 +646,26 @@ where
     }
 }
 
+async fn probe_dependency<F, Fut>(name: &'static str, check_fn: F) -> DependencyStatus
+where
+    F: FnOnce() -> Fut,
+    Fut: std::future::Future<Output = std::result::Result<(), String>>,
+{
+    let started_at = Instant::now();
+    let reason = match tokio::time::timeout(HEALTH_CHECK_TIMEOUT, check_fn()).await {
+        Ok(Ok(())) => None,
+        Ok(Err(reason)) => Some(reason),
+        Err(_) => Some("health check timed out".to_string()),
+    };
+
+    DependencyStatus {
+        name,
+        ready: reason.is_none(),
+        latency_ms: started_at.elapsed().as_millis(),
+        reason,
+    }
+}
+
 fn status_for_failure(criticality: DependencyCriticality) -> ReadinessStatus {
     match criticality {
         DependencyCriticality::Critical => ReadinessStatus::Unhealthy,
@@ -678,6 +765,12 @@ pub fn routes() -> Routes {
         .add("/modules", get(modules))
 }
 
+pub fn api_routes() -> Routes {
+    Routes::new()
+        .prefix("api/health")
+        .add("/ready", get(dependencies_ready))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -811,4 +904,60 @@ mod tests {
         assert!(!profile.includes_runtime_dependencies());
         assert!(!profile.includes_module_health());
     }
+
+    #[tokio::test]
+    async fn dependency_readiness_is_not_ready_when_cache_ping_fails() {
+        let dependencies = vec![
+            probe_dependency("database", || async { Ok(()) }).await,
+            probe_dependency("event_dispatcher", || async {
+                check_event_dispatcher(Some(DispatcherLiveness::new(DispatcherState::Running)))
+            })
+            .await,
+            probe_dependency("cache", || async { Err("PING failed".to_string()) }).await,
+        ];
+
+        let response = DependencyReadinessResponse::from_dependencies(dependencies);
+
+        assert!(!response.ready);
+        assert_eq!(response.status_code(), StatusCode::SERVICE_UNAVAILABLE);
+        let cache = response
+            .dependencies
+            .iter()
+            .find(|dependency| dependency.name == "cache")
+            .expect("cache dependency reported");
+        assert!(!cache.ready);
+        assert_eq!(cache.reason.as_deref(), Some("PING failed"));
+        assert!(response
+            .dependencies
+            .iter()
+            .filter(|dependency| dependency.name != "cache")
+            .all(|dependency| dependency.ready));
+    }
+
+    #[tokio::test]
+    async fn dependency_readiness_is_ready_when_all_dependencies_pass() {
+        let dependencies = vec![
+            probe_dependency("database", || async { Ok(()) }).await,
+            probe_dependency("event_dispatcher", || async {
+                check_event_dispatcher(Some(DispatcherLiveness::new(DispatcherState::Idle)))
+            })
+            .await,
+            probe_dependency("cache", || async { Ok(()) }).await,
+        ];
+
+        let response = DependencyReadinessResponse::from_dependencies(dependencies);
+
+        assert!(response.ready);
+        assert_eq!(response.status_code(), StatusCode::OK);
+        assert_eq!(response.dependencies.len(), 3);
+    }
+
+    #[test]
+    fn event_dispatcher_check_fails_when_stopped_or_missing() {
+        assert!(check_event_dispatcher(None).is_err());
+        assert!(
+            check_event_dispatcher(Some(DispatcherLiveness::new(DispatcherState::Stopped)))
+                .is_err()
+        );
+    }
 }
@@ -1,11 +1,15 @@
 use axum::{
     extract::State,
-    http::{header::CONTENT_TYPE, StatusCode},
+    http::{
+        header::{AUTHORIZATION, CONTENT_TYPE},
+        HeaderMap, StatusCode,
+    },
     response::{IntoResponse, Response},
     routing::get,
 };
 use loco_rs::{app::AppContext, controller::Routes};
 
+use crate::common::settings::SharedRustokSettings;
 use crate::error::Result;
 use rustok_outbox::entity::{Column as SysEventsColumn, Entity as SysEventsEntity, SysEventStatus};
 use sea_orm::{
@@ -25,6 +29,7 @@ use crate::services::runtime_guardrails::{
     collect_runtime_guardrail_snapshot, RuntimeGuardrailSnapshot,
 };
 use rustok_telemetry::metrics::update_queue_depth;
+use rustok_telemetry::MetricsHandle;
 use tracing::warn;
 
 static RBAC_CONSISTENCY_QUERY_FAILURES_TOTAL: AtomicU64 = AtomicU64::new(0);
@@ -38,31 +43,70 @@ static RBAC_CONSISTENCY_QUERY_LATENCY_SAMPLES: AtomicU64 = AtomicU64::new(0);
     tag = "observability",
     responses(
         (status = 200, description = "Prometheus metrics in text format", content_type = "text/plain"),
+        (status = 401, description = "Missing or invalid metrics bearer token"),
         (status = 503, description = "Metrics collection disabled")
     )
 )]
-pub async fn metrics(State(ctx): State<AppContext>) -> Result<Response> {
-    match rustok_telemetry::metrics_handle() {
-        Some(handle) => {
-            sync_rate_limit_metrics(&ctx).await;
-            let mut payload = handle.render();
-            payload.push('\n');
-            payload.push_str(&render_tenant_cache_metrics(&ctx).await);
-            payload.push_str(&render_outbox_metrics(&ctx).await);
-            payload.push_str(&render_auth_lifecycle_metrics());
-            payload.push_str(&render_rbac_metrics(&ctx).await);
-            payload.push_str(&render_search_metrics(&ctx).await);
-            payload.push_str(&render_runtime_guardrail_metrics(&ctx).await);
-
-            Ok((
-                StatusCode::OK,
-                [(CONTENT_TYPE, "text/plain; version=0.0.4; charset=utf-8")],
-                payload,
-            )
-                .into_response())
-        }
-        None => Ok((StatusCode::SERVICE_UNAVAILABLE, "metrics disabled").into_response()),
+pub async fn metrics(State(ctx): State<AppContext>, headers: HeaderMap) -> Result<Response> {
+    let expected_token = ctx
+        .shared_store
+        .get::<SharedRustokSettings>()
+        .and_then(|settings| settings.0.runtime.metrics.bearer_token.clone());
+    if !metrics_access_allowed(expected_token.as_deref(), &headers) {
+        return Ok((StatusCode::UNAUTHORIZED, "metrics bearer token required").into_response());
     }
+
+    let handle = rustok_telemetry::metrics_handle();
+    let server_metrics = match handle {
+        Some(_) => render_server_metrics(&ctx).await,
+        None => String::new(),
+    };
+
+    Ok(exposition_response(handle.as_deref(), &server_metrics))
+}
+
+fn metrics_access_allowed(expected_token: Option<&str>, headers: &HeaderMap) -> bool {
+    let Some(expected_token) = expected_token
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+    else {
+        return true;
+    };
+
+    headers
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .is_some_and(|token| token.trim() == expected_token)
+}
+
+/// Prometheus text exposition for `handle`, or 503 when metrics are disabled.
+fn exposition_response(handle: Option<&MetricsHandle>, server_metrics: &str) -> Response {
+    let Some(handle) = handle else {
+        return (StatusCode::SERVICE_UNAVAILABLE, "metrics disabled").into_response();
+    };
+
+    let mut payload = handle.render();
+    payload.push('\n');
+    payload.push_str(server_metrics);
+
+    (
+        StatusCode::OK,
+        [(CONTENT_TYPE, "text/plain; version=0.0.4; charset=utf-8")],
+        payload,
+    )
+        .into_response()
+}
+
+async fn render_server_metrics(ctx: &AppContext) -> String {
+    sync_rate_limit_metrics(ctx).await;
+    let mut payload = render_tenant_cache_metrics(ctx).await;
+    payload.push_str(&render_outbox_metrics(ctx).await);
+    payload.push_str(&render_auth_lifecycle_metrics());
+    payload.push_str(&render_rbac_metrics(ctx).await);
+    payload.push_str(&render_search_metrics(ctx).await);
+    payload.push_str(&render_runtime_guardrail_metrics(ctx).await);
+    payload
 }
 
 pub fn routes() -> Routes {
@@ -489,8 +533,8 @@ fn format_rbac_metrics(
 #[cfg(test)]
 mod tests {
     use super::{
-        format_outbox_metrics, format_rbac_metrics, format_runtime_guardrail_metrics,
-        render_auth_lifecycle_metrics,
+        exposition_response, format_outbox_metrics, format_rbac_metrics,
+        format_runtime_guardrail_metrics, metrics_access_allowed, render_auth_lifecycle_metrics,
     };
     use crate::services::auth_lifecycle::AuthLifecycleService;
     use crate::services::rbac_service::RbacService;
@@ -499,6 +543,11 @@ mod tests {
         RateLimitPolicySnapshot, RemoteExecutorGuardrailSnapshot, RuntimeGuardrailRollout,
         RuntimeGuardrailSnapshot, RuntimeGuardrailStatus,
     };
+    use axum::http::{
+        header::{AUTHORIZATION, CONTENT_TYPE},
+        HeaderMap, HeaderValue, StatusCode,
+    };
+    use rustok_telemetry::MetricsHandle;
 
     fn assert_metric_line(payload: &str, metric_name: &str) {
         let has_exact_line = payload.lines().any(|line| {
@@ -680,4 +729,52 @@ mod tests {
         assert!(payload.contains("rustok_runtime_guardrail_remote_executor_enabled 1"));
         assert!(payload.contains("rustok_runtime_guardrail_remote_executor_expired_claims 1"));
     }
+
+    #[tokio::test]
+    async fn enabled_metrics_return_prometheus_text_exposition() {
+        let handle = MetricsHandle::new();
+        rustok_telemetry::metrics::register_all(handle.registry()).unwrap();
+        rustok_telemetry::metrics::record_event_published("node.published", "tenant-a");
+
+        let response = exposition_response(Some(&handle), "rustok_tenant_cache_hits 3\n");
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers().get(CONTENT_TYPE).unwrap(),
+            "text/plain; version=0.0.4; charset=utf-8"
+        );
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let payload = String::from_utf8(body.to_vec()).unwrap();
+        assert!(payload.contains("# TYPE rustok_event_bus_published_total counter"));
+        assert_metric_labeled_line(
+            &payload,
+            "rustok_event_bus_published_total",
+            "{event_type=\"node.published\",tenant_id=\"tenant-a\"}",
+        );
+        assert_metric_line(&payload, "rustok_tenant_cache_hits");
+    }
+
+    #[test]
+    fn disabled_metrics_return_service_unavailable() {
+        let response = exposition_response(None, "");
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    #[test]
+    fn metrics_bearer_token_is_enforced_only_when_configured() {
+        let mut headers = HeaderMap::new();
+        assert!(metrics_access_allowed(None, &headers));
+        assert!(!metrics_access_allowed(Some("scrape-secret"), &headers));
+
+        headers.insert(AUTHORIZATION, HeaderValue::from_static("Bearer wrong"));
+        assert!(!metrics_access_allowed(Some("scrape-secret"), &headers));
+
+        headers.insert(
+            AUTHORIZATION,
+            HeaderValue::from_static("Bearer scrape-secret"),
+        );
+        assert!(metrics_access_allowed(Some("scrape-secret"), &headers));
+    }
 }
@@ -1,4 +1,6 @@
+pub mod admin_body_capture;
 pub mod admin_events;
+pub mod admin_logging;
 pub mod auth;
 #[cfg(feature = "mod-blog")]
 pub mod blog;
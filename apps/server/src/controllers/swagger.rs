@@ -36,6 +36,7 @@ use utoipa::OpenApi;
         crate::controllers::health::health,
         crate::controllers::health::live,
         crate::controllers::health::ready,
+        crate::controllers::health::dependencies_ready,
         crate::controllers::health::modules,
         // Metrics
         crate::controllers::metrics::metrics,
@@ -53,9 +54,16 @@ use utoipa::OpenApi;
         // Swagger
         crate::controllers::swagger::openapi_json,
         crate::controllers::swagger::openapi_yaml,
+        // Admin Body Capture
+        crate::controllers::admin_body_capture::get_body_capture,
+        crate::controllers::admin_body_capture::update_body_capture,
         // Admin Events
         crate::controllers::admin_events::list_dlq,
         crate::controllers::admin_events::replay_dlq_event,
+        // Admin Logging
+        crate::controllers::admin_logging::get_log_levels,
+        crate::controllers::admin_logging::set_log_levels,
+        crate::controllers::admin_logging::reset_log_levels,
         // Flex standalone
         crate::controllers::flex::list_schemas,
         crate::controllers::flex::get_schema,
@@ -107,11 +115,19 @@ use utoipa::OpenApi;
             crate::controllers::health::ModuleHealth,
             crate::controllers::health::ModulesHealthResponse,
 
+            // Admin Body Capture
+            crate::controllers::admin_body_capture::UpdateBodyCaptureRequest,
+            crate::controllers::admin_body_capture::BodyCaptureResponse,
+
             // Admin Events
             crate::controllers::admin_events::DlqEventItem,
             crate::controllers::admin_events::DlqListResponse,
             crate::controllers::admin_events::DlqReplayResponse,
 
+            // Admin Logging
+            crate::controllers::admin_logging::SetLogLevelsRequest,
+            crate::controllers::admin_logging::LogLevelsResponse,
+
             // Flex standalone
             crate::controllers::flex::CreateFlexSchemaRequest,
             crate::controllers::flex::UpdateFlexSchemaRequest,
@@ -211,6 +227,8 @@ pub struct ForumApiDoc;
         crate::controllers::pages::create_page,
         crate::controllers::pages::update_page,
         crate::controllers::pages::delete_page,
+        crate::controllers::pages::issue_preview_token,
+        crate::controllers::pages::preview_page,
         crate::controllers::pages::create_block,
         crate::controllers::pages::update_block,
         crate::controllers::pages::delete_block,
@@ -224,7 +242,9 @@ pub struct ForumApiDoc;
             rustok_pages::UpdateBlockInput,
             rustok_pages::BlockResponse,
             rustok_pages::PageResponse,
+            rustok_pages::PagePreviewToken,
             crate::controllers::pages::GetPageParams,
+            crate::controllers::pages::PreviewPageParams,
             crate::controllers::pages::ReorderBlocksInput,
         )
     ),
@@ -315,6 +315,7 @@ define_permission_extractor!(
 );
 
 define_permission_extractor!(RequireLogsRead, rustok_core::Permission::LOGS_READ);
+define_permission_extractor!(RequireLogsManage, rustok_core::Permission::LOGS_MANAGE);
 
 /// Helper to check permission inline without extractor
 ///
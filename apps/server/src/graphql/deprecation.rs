@@ -0,0 +1,235 @@
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+
+use async_graphql::extensions::{
+    Extension, ExtensionContext, ExtensionFactory, NextResolve, ResolveInfo,
+};
+use async_graphql::registry::Deprecation;
+use async_graphql::{ServerResult, Value};
+use uuid::Uuid;
+
+use crate::context::AuthContext;
+
+pub const DEPRECATION_LOG_TARGET: &str = "graphql.deprecation";
+
+/// Reports resolutions of fields marked `#[graphql(deprecation = "...")]`, so we know
+/// which clients still depend on them before removing them from the schema.
+///
+/// Every resolution is counted in `rustok_graphql_deprecated_field_usage_total`; the warning is
+/// logged once per field and client (OAuth client id, or just the client kind for users
+/// and anonymous callers) for the lifetime of the schema.
+#[derive(Default)]
+pub struct GraphqlDeprecationTracker {
+    logged: Arc<Mutex<HashSet<DeprecatedUsage>>>,
+}
+
+impl ExtensionFactory for GraphqlDeprecationTracker {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(GraphqlDeprecationTrackerExtension {
+            logged: Arc::clone(&self.logged),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct DeprecatedUsage {
+    parent_type: String,
+    field_name: String,
+    client_kind: &'static str,
+    client_id: Option<Uuid>,
+}
+
+struct GraphqlDeprecationTrackerExtension {
+    logged: Arc<Mutex<HashSet<DeprecatedUsage>>>,
+}
+
+impl GraphqlDeprecationTrackerExtension {
+    /// `true` the first time `usage` is seen.
+    fn first_usage(&self, usage: DeprecatedUsage) -> bool {
+        self.logged
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .insert(usage)
+    }
+}
+
+fn client_kind(auth: Option<&AuthContext>) -> &'static str {
+    match auth {
+        Some(auth) if auth.client_id.is_some() => "oauth_client",
+        Some(_) => "user",
+        None => "anonymous",
+    }
+}
+
+fn deprecation_reason<'a>(
+    ctx: &'a ExtensionContext<'_>,
+    info: &ResolveInfo<'_>,
+) -> Option<Option<&'a str>> {
+    let field = ctx
+        .schema_env
+        .registry
+        .types
+        .get(info.parent_type)?
+        .field_by_name(info.name)?;
+
+    match &field.deprecation {
+        Deprecation::Deprecated { reason } => Some(reason.as_deref()),
+        Deprecation::NoDeprecated => None,
+    }
+}
+
+#[async_trait::async_trait]
+impl Extension for GraphqlDeprecationTrackerExtension {
+    async fn resolve(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        info: ResolveInfo<'_>,
+        next: NextResolve<'_>,
+    ) -> ServerResult<Option<Value>> {
+        if !info.is_for_introspection {
+            if let Some(reason) = deprecation_reason(ctx, &info) {
+                let auth = ctx.data_opt::<AuthContext>();
+                let client_kind = client_kind(auth);
+                let client_id = auth.and_then(|auth| auth.client_id);
+
+                if self.first_usage(DeprecatedUsage {
+                    parent_type: info.parent_type.to_string(),
+                    field_name: info.name.to_string(),
+                    client_kind,
+                    client_id,
+                }) {
+                    tracing::warn!(
+                        target: DEPRECATION_LOG_TARGET,
+                        parent_type = info.parent_type,
+                        field_name = info.name,
+                        reason = reason.unwrap_or_default(),
+                        client_kind,
+                        tenant_id = ?auth.map(|auth| auth.tenant_id),
+                        user_id = ?auth.map(|auth| auth.user_id),
+                        client_id = ?client_id,
+                        "deprecated graphql field resolved"
+                    );
+                }
+                rustok_telemetry::metrics::record_graphql_deprecated_field_usage(
+                    info.parent_type,
+                    info.name,
+                    client_kind,
+                );
+            }
+        }
+
+        next.run(ctx, info).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use async_graphql::{EmptyMutation, EmptySubscription, Object, Request, Schema};
+    use uuid::Uuid;
+
+    use rustok_telemetry::metrics::GRAPHQL_DEPRECATED_FIELD_USAGE_TOTAL;
+
+    use super::{GraphqlDeprecationTracker, DEPRECATION_LOG_TARGET};
+    use crate::common::log_capture::LogCapture;
+    use crate::context::AuthContext;
+
+    struct DeprecationProbeQuery;
+
+    #[Object]
+    impl DeprecationProbeQuery {
+        #[graphql(deprecation = "Use `currentLabel` instead.")]
+        async fn legacy_label(&self) -> &str {
+            "legacy"
+        }
+
+        async fn current_label(&self) -> &str {
+            "current"
+        }
+    }
+
+    async fn execute_with_capture(request: Request) -> LogCapture {
+        let capture = LogCapture::new(DEPRECATION_LOG_TARGET);
+        let _guard = capture.install();
+
+        let response = probe_schema().execute(request).await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+        capture
+    }
+
+    fn probe_schema() -> Schema<DeprecationProbeQuery, EmptyMutation, EmptySubscription> {
+        Schema::build(DeprecationProbeQuery, EmptyMutation, EmptySubscription)
+            .extension(GraphqlDeprecationTracker::default())
+            .finish()
+    }
+
+    fn user_auth() -> AuthContext {
+        AuthContext {
+            user_id: Uuid::new_v4(),
+            session_id: Uuid::new_v4(),
+            tenant_id: Uuid::new_v4(),
+            permissions: Vec::new(),
+            client_id: None,
+            scopes: Vec::new(),
+            grant_type: "password".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn deprecated_field_usage_is_logged_with_client_identity() {
+        let client_id = Uuid::new_v4();
+        let auth = AuthContext {
+            user_id: Uuid::new_v4(),
+            session_id: Uuid::new_v4(),
+            tenant_id: Uuid::new_v4(),
+            permissions: Vec::new(),
+            client_id: Some(client_id),
+            scopes: Vec::new(),
+            grant_type: "client_credentials".to_string(),
+        };
+
+        let capture =
+            execute_with_capture(Request::new("{ legacyLabel currentLabel }").data(auth)).await;
+
+        assert_eq!(capture.field_values("field_name"), vec!["legacyLabel"]);
+        assert_eq!(
+            capture.field_values("reason"),
+            vec!["Use `currentLabel` instead."]
+        );
+        assert_eq!(capture.field_values("client_kind"), vec!["oauth_client"]);
+        assert_eq!(
+            capture.field_values("client_id"),
+            vec![format!("{:?}", Some(client_id))]
+        );
+    }
+
+    #[tokio::test]
+    async fn current_field_usage_is_not_logged() {
+        let capture = execute_with_capture(Request::new("{ currentLabel }")).await;
+
+        assert!(capture.events().is_empty());
+    }
+
+    #[tokio::test]
+    async fn repeated_usage_is_logged_once_and_counted_every_time() {
+        let usage = GRAPHQL_DEPRECATED_FIELD_USAGE_TOTAL.with_label_values(&[
+            "DeprecationProbeQuery",
+            "legacyLabel",
+            "user",
+        ]);
+        let before = usage.get();
+
+        let capture = LogCapture::new(DEPRECATION_LOG_TARGET);
+        let _guard = capture.install();
+        let schema = probe_schema();
+        for _ in 0..3 {
+            let response = schema
+                .execute(Request::new("{ legacyLabel }").data(user_auth()))
+                .await;
+            assert!(response.errors.is_empty(), "{:?}", response.errors);
+        }
+
+        assert_eq!(capture.field_values("field_name"), vec!["legacyLabel"]);
+        assert_eq!(usage.get() - before, 3);
+    }
+}
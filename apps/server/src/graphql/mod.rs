@@ -6,6 +6,7 @@ pub mod blog;
 pub mod commerce;
 pub mod common;
 pub mod connection;
+pub mod deprecation;
 pub mod errors;
 pub mod flex;
 #[cfg(feature = "mod-forum")]
@@ -4,9 +4,10 @@ use async_graphql::{Context, FieldError, Object, Result};
 use chrono::{Duration, Utc};
 use rustok_core::{ModuleRegistry, Permission};
 use rustok_telemetry::metrics;
+use sea_orm::sea_query::{Alias, Expr, Order, Query};
 use sea_orm::{
-    ColumnTrait, Condition, ConnectionTrait, DbBackend, EntityTrait, PaginatorTrait, QueryFilter,
-    QueryOrder, QuerySelect, Statement,
+    ColumnTrait, Condition, ConnectionTrait, DbBackend, EntityTrait, FromQueryResult,
+    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, QueryTrait, Select, Statement,
 };
 use semver::{Version, VersionReq};
 use std::time::Instant;
@@ -18,9 +19,9 @@ use crate::graphql::errors::GraphQLError;
 #[cfg(feature = "mod-content")]
 use crate::graphql::types::ResolvedCanonicalRoute;
 use crate::graphql::types::{
-    ActivityItem, ActivityUser, BuildJob, DashboardStats, InstalledModule, MarketplaceModule,
-    MarketplaceModuleVersion, ModuleRegistryItem, ModuleSettingField, ReleaseInfo, Tenant,
-    TenantModule, User, UserConnection, UserEdge, UsersFilter,
+    ActivityItem, ActivityUser, BuildJob, DashboardStats, FeatureFlags, InstalledModule,
+    MarketplaceModule, MarketplaceModuleVersion, ModuleRegistryItem, ModuleSettingField,
+    ReleaseInfo, Tenant, TenantLocale, TenantModule, User, UserConnection, UserEdge, UsersFilter,
 };
 use crate::models::_entities::tenant_modules::Column as TenantModulesColumn;
 use crate::models::_entities::tenant_modules::Entity as TenantModulesEntity;
@@ -174,6 +175,80 @@ async fn load_period_count_snapshot(
     })
 }
 
+/// Users of `tenant_id` matching the `users` resolver's filter and search; the page and its
+/// `total_count` are both read from this one query so they cannot disagree.
+async fn filtered_users_query(
+    db: &sea_orm::DatabaseConnection,
+    tenant_id: uuid::Uuid,
+    filter: Option<UsersFilter>,
+    search: Option<String>,
+) -> crate::error::Result<Select<users::Entity>> {
+    let mut query = users::Entity::find().filter(UsersColumn::TenantId.eq(tenant_id));
+
+    if let Some(filter) = filter {
+        if let Some(role) = filter.role {
+            let role: rustok_core::UserRole = role.into();
+            let user_ids = RbacService::get_user_ids_for_role(db, &tenant_id, role).await?;
+            query = query.filter(UsersColumn::Id.is_in(user_ids));
+        }
+
+        if let Some(status) = filter.status {
+            let status: rustok_core::UserStatus = status.into();
+            query = query.filter(UsersColumn::Status.eq(status.to_string()));
+        }
+    }
+
+    if let Some(search) = search {
+        let search = search.trim();
+        if !search.is_empty() {
+            let condition = Condition::any()
+                .add(UsersColumn::Email.contains(search))
+                .add(UsersColumn::Name.contains(search));
+            query = query.filter(condition);
+        }
+    }
+
+    Ok(query)
+}
+
+/// Loads one page of `query` together with the number of rows matching it, using
+/// `COUNT(*) OVER ()` so both come back in a single round trip. A page past the end has
+/// no row to carry the count, so only then is a separate `COUNT` issued.
+async fn load_counted_page<E>(
+    db: &sea_orm::DatabaseConnection,
+    query: Select<E>,
+    offset: i64,
+    limit: i64,
+) -> std::result::Result<(Vec<E::Model>, i64), sea_orm::DbErr>
+where
+    E: EntityTrait,
+    E::Model: Sync,
+{
+    let statement = db.get_database_backend().build(
+        &query
+            .clone()
+            .column_as(Expr::cust("COUNT(*) OVER ()"), "total_count")
+            .offset(offset as u64)
+            .limit(limit as u64)
+            .into_query(),
+    );
+
+    let mut total = None;
+    let mut items = Vec::new();
+    for row in db.query_all(statement).await? {
+        total.get_or_insert(row.try_get::<i64>("", "total_count")?);
+        items.push(E::Model::from_query_result(&row, "")?);
+    }
+
+    let total = match total {
+        Some(total) => total,
+        None if offset == 0 => 0,
+        None => query.count(db).await? as i64,
+    };
+
+    Ok((items, total))
+}
+
 async fn load_order_stats_snapshot(
     db: &sea_orm::DatabaseConnection,
     tenant_id: uuid::Uuid,
@@ -746,6 +821,40 @@ async fn load_marketplace_module(
     Ok(projected.pop())
 }
 
+async fn load_enabled_tenant_locales(
+    db: &sea_orm::DatabaseConnection,
+    tenant_id: uuid::Uuid,
+) -> Result<Vec<TenantLocale>, sea_orm::DbErr> {
+    let statement = Query::select()
+        .from(Alias::new("tenant_locales"))
+        .columns([
+            Alias::new("locale"),
+            Alias::new("name"),
+            Alias::new("native_name"),
+            Alias::new("is_default"),
+        ])
+        .and_where(Expr::col(Alias::new("tenant_id")).eq(tenant_id))
+        .and_where(Expr::col(Alias::new("is_enabled")).eq(true))
+        .order_by(Alias::new("is_default"), Order::Desc)
+        .order_by(Alias::new("locale"), Order::Asc)
+        .to_owned();
+
+    let rows = db
+        .query_all(db.get_database_backend().build(&statement))
+        .await?;
+
+    rows.into_iter()
+        .map(|row| {
+            Ok(TenantLocale {
+                code: row.try_get("", "locale")?,
+                name: row.try_get("", "name")?,
+                native_name: row.try_get("", "native_name")?,
+                is_default: row.try_get("", "is_default")?,
+            })
+        })
+        .collect()
+}
+
 #[derive(Default)]
 pub struct RootQuery;
 
@@ -768,6 +877,16 @@ impl RootQuery {
         })
     }
 
+    /// Locales enabled for the current tenant, default locale first.
+    async fn tenant_locales(&self, ctx: &Context<'_>) -> Result<Vec<TenantLocale>> {
+        let app_ctx = ctx.data::<loco_rs::app::AppContext>()?;
+        let tenant = ctx.data::<TenantContext>()?;
+
+        load_enabled_tenant_locales(&app_ctx.db, tenant.id)
+            .await
+            .map_err(|err| <FieldError as GraphQLError>::internal_error(&err.to_string()))
+    }
+
     #[cfg(feature = "mod-content")]
     async fn resolve_canonical_route(
         &self,
@@ -819,6 +938,33 @@ impl RootQuery {
         Ok(modules)
     }
 
+    /// Permissions and enabled modules of the current user, for gating admin UI actions.
+    async fn features(&self, ctx: &Context<'_>) -> Result<FeatureFlags> {
+        let auth = ctx
+            .data::<AuthContext>()
+            .map_err(|_| <FieldError as GraphQLError>::unauthenticated())?;
+        let app_ctx = ctx.data::<loco_rs::app::AppContext>()?;
+        let tenant = ctx.data::<TenantContext>()?;
+        let registry = ctx.data::<ModuleRegistry>()?;
+
+        let enabled_modules =
+            EffectiveModulePolicyService::list_enabled(&app_ctx.db, registry, tenant.id)
+                .await
+                .map_err(|err| err.to_string())?;
+        let mut permissions = auth
+            .permissions
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        permissions.sort();
+        permissions.dedup();
+
+        Ok(FeatureFlags {
+            permissions,
+            enabled_modules,
+        })
+    }
+
     async fn module_registry(
         &self,
         ctx: &Context<'_>,
@@ -1305,56 +1451,15 @@ impl RootQuery {
 
         let requested_limit = pagination.requested_limit();
         let (offset, limit) = pagination.normalize()?;
-        let mut query = users::Entity::find().filter(UsersColumn::TenantId.eq(tenant.id));
-
-        if let Some(filter) = filter {
-            if let Some(role) = filter.role {
-                let role: rustok_core::UserRole = role.into();
-                let user_ids = RbacService::get_user_ids_for_role(&app_ctx.db, &tenant.id, role)
-                    .await
-                    .map_err(|err| {
-                        <FieldError as GraphQLError>::internal_error(&err.to_string())
-                    })?;
-                query = query.filter(UsersColumn::Id.is_in(user_ids));
-            }
-
-            if let Some(status) = filter.status {
-                let status: rustok_core::UserStatus = status.into();
-                query = query.filter(UsersColumn::Status.eq(status.to_string()));
-            }
-        }
-
-        if let Some(search) = search {
-            let search = search.trim();
-            if !search.is_empty() {
-                let condition = Condition::any()
-                    .add(UsersColumn::Email.contains(search))
-                    .add(UsersColumn::Name.contains(search));
-                query = query.filter(condition);
-            }
-        }
-        let count_started_at = Instant::now();
-        let total = query
-            .clone()
-            .count(&app_ctx.db)
+        let query = filtered_users_query(&app_ctx.db, tenant.id, filter, search)
             .await
-            .map_err(|err| <FieldError as GraphQLError>::internal_error(&err.to_string()))?
-            as i64;
-        metrics::record_read_path_query(
-            "graphql",
-            "root.users",
-            "count",
-            count_started_at.elapsed().as_secs_f64(),
-            total.max(0) as u64,
-        );
+            .map_err(|err| <FieldError as GraphQLError>::internal_error(&err.to_string()))?;
 
         let page_started_at = Instant::now();
-        let users = query
-            .offset(offset as u64)
-            .limit(limit as u64)
-            .all(&app_ctx.db)
+        let (users, total) = load_counted_page(&app_ctx.db, query, offset, limit)
             .await
             .map_err(|err| <FieldError as GraphQLError>::internal_error(&err.to_string()))?;
+        // The total comes back with the page, so there is no separate `count` query to time.
         metrics::record_read_path_query(
             "graphql",
             "root.users",
@@ -1534,12 +1639,19 @@ impl RootQuery {
 #[cfg(test)]
 mod tests {
     use super::{
-        is_catalog_module_compatible, marketplace_module_from_catalog_entry, normalize_version_req,
-        source_matches, trust_level_matches,
+        filtered_users_query, is_catalog_module_compatible, load_counted_page,
+        marketplace_module_from_catalog_entry, normalize_version_req, source_matches,
+        trust_level_matches,
     };
-    use crate::graphql::types::MarketplaceModule;
+    use crate::graphql::types::{GqlUserRole, MarketplaceModule, UsersFilter};
+    use crate::models::{tenants, users};
     use crate::modules::{CatalogManifestModule, InstalledManifestModule};
-    use rustok_core::ModuleRegistry;
+    use crate::services::rbac_service::RbacService;
+    use chrono::Utc;
+    use migration::Migrator;
+    use rustok_core::{ModuleRegistry, UserRole, UserStatus};
+    use rustok_test_utils::db::setup_test_db_with_migrations;
+    use sea_orm::{DatabaseConnection, EntityTrait, Set};
     use std::collections::HashMap;
 
     fn catalog_module(min: Option<&str>, max: Option<&str>) -> CatalogManifestModule {
@@ -1804,4 +1916,106 @@ mod tests {
 
         assert_eq!(module.category, "extensions");
     }
+
+    async fn seed_tenant_with_users(db: &DatabaseConnection, emails: &[&str]) -> uuid::Uuid {
+        let tenant_id = rustok_core::generate_id();
+        tenants::Entity::insert(tenants::ActiveModel {
+            id: Set(tenant_id),
+            name: Set("Test tenant".to_string()),
+            slug: Set("users-total-count".to_string()),
+            domain: Set(None),
+            settings: Set(serde_json::json!({})),
+            default_locale: Set("en".to_string()),
+            is_active: Set(true),
+            created_at: Set(Utc::now().into()),
+            updated_at: Set(Utc::now().into()),
+        })
+        .exec(db)
+        .await
+        .expect("failed to insert tenant");
+
+        for email in emails {
+            let user_id = rustok_core::generate_id();
+            users::Entity::insert(users::ActiveModel {
+                id: Set(user_id),
+                tenant_id: Set(tenant_id),
+                email: Set(email.to_string()),
+                password_hash: Set("hash".to_string()),
+                name: Set(None),
+                status: Set(UserStatus::Active),
+                email_verified_at: Set(None),
+                last_login_at: Set(None),
+                metadata: Set(serde_json::json!({})),
+                created_at: Set(Utc::now().into()),
+                updated_at: Set(Utc::now().into()),
+            })
+            .exec(db)
+            .await
+            .expect("failed to insert user");
+
+            let role = if email.starts_with("manager") {
+                UserRole::Manager
+            } else {
+                UserRole::Customer
+            };
+            RbacService::assign_role_permissions(db, &user_id, &tenant_id, role)
+                .await
+                .expect("role assignment should succeed");
+        }
+
+        tenant_id
+    }
+
+    #[tokio::test]
+    async fn users_total_count_follows_role_filter() {
+        let db = setup_test_db_with_migrations::<Migrator>().await;
+        let tenant_id = seed_tenant_with_users(
+            &db,
+            &[
+                "manager-1@example.com",
+                "manager-2@example.com",
+                "customer-1@example.com",
+                "customer-2@example.com",
+                "customer-3@example.com",
+            ],
+        )
+        .await;
+
+        let all = filtered_users_query(&db, tenant_id, None, None)
+            .await
+            .unwrap();
+        let (page, total) = load_counted_page(&db, all, 0, 2).await.unwrap();
+        assert_eq!((page.len(), total), (2, 5));
+
+        let managers = UsersFilter {
+            role: Some(GqlUserRole::Manager),
+            status: None,
+        };
+        let query = filtered_users_query(&db, tenant_id, Some(managers.clone()), None)
+            .await
+            .unwrap();
+        let (page, total) = load_counted_page(&db, query, 0, 10).await.unwrap();
+        assert_eq!(total, 2);
+        assert!(page.iter().all(|user| user.email.starts_with("manager")));
+
+        let query = filtered_users_query(&db, tenant_id, Some(managers), Some("2@".to_string()))
+            .await
+            .unwrap();
+        let (page, total) = load_counted_page(&db, query, 0, 10).await.unwrap();
+        assert_eq!((page.len(), total), (1, 1));
+    }
+
+    #[tokio::test]
+    async fn users_total_count_survives_page_past_the_end() {
+        let db = setup_test_db_with_migrations::<Migrator>().await;
+        let tenant_id =
+            seed_tenant_with_users(&db, &["manager-1@example.com", "customer-1@example.com"]).await;
+
+        let query = filtered_users_query(&db, tenant_id, None, None)
+            .await
+            .unwrap();
+        let (page, total) = load_counted_page(&db, query, 10, 10).await.unwrap();
+        assert!(page.is_empty());
+        assert_eq!(total, 2);
+    }
 }
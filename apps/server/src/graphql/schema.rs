@@ -19,6 +19,7 @@ mod schema_codegen {
 
 use super::ai::{AiMutation, AiQuery, AiSubscription};
 use super::auth::{AuthMutation, AuthQuery};
+use super::deprecation::GraphqlDeprecationTracker;
 use super::flex::{FlexMutation, FlexQuery};
 use super::loaders::TenantNameLoader;
 #[cfg(feature = "mod-content")]
@@ -105,6 +106,7 @@ pub fn build_schema(
     .extension(Analyzer)
     .extension(GraphqlSecurityPolicy)
     .extension(GraphqlObservability)
+    .extension(GraphqlDeprecationTracker::default())
     // DataLoaders for efficient batched queries
     .data(DataLoader::new(
         TenantNameLoader::new(db.clone()),
@@ -26,6 +26,23 @@ pub struct Tenant {
     pub slug: String,
 }
 
+/// Capabilities of the current user in the current tenant, used by the admin to hide
+/// actions the server would reject anyway.
+#[derive(SimpleObject, Clone, Debug)]
+pub struct FeatureFlags {
+    /// Effective permissions as `resource:action`; `resource:manage` implies every action.
+    pub permissions: Vec<String>,
+    pub enabled_modules: Vec<String>,
+}
+
+#[derive(SimpleObject, Clone, Debug)]
+pub struct TenantLocale {
+    pub code: String,
+    pub name: String,
+    pub native_name: String,
+    pub is_default: bool,
+}
+
 #[derive(SimpleObject, Debug, Clone)]
 #[graphql(complex)]
 pub struct User {
@@ -222,6 +239,9 @@ pub struct RegistryPrincipal {
     pub user_id: Option<String>,
     pub subject: String,
     pub display_label: String,
+    #[graphql(
+        deprecation = "Use `displayLabel`. Legacy principals are normalized into `subject`/`displayLabel`."
+    )]
     pub legacy_label: Option<String>,
 }
 
@@ -0,0 +1,282 @@
+/// Access Log Middleware
+///
+/// Emits one info-level event per request under the [`ACCESS_LOG_TARGET`] target with
+/// `method`, `path`, `status`, `latency_ms`, `request_id`, `tenant_id` and `user_id`:
+/// - toggled by `settings.rustok.runtime.access_log.enabled`;
+/// - `format: combined` additionally renders an Apache combined-style message line;
+/// - values of sensitive query params (tokens, passwords, codes…) are replaced with
+///   `REDACTED` before logging.
+///
+/// Two pieces are mounted in `app_router.rs::compose_application_router()`: [`log`] sits
+/// just inside `request_id::propagate` so it also sees requests rejected by rate limiting or
+/// tenant resolution, and [`capture_identity`] is the innermost layer, where tenant and
+/// auth context are already resolved, and reports them back to [`log`].
+use std::net::SocketAddr;
+use std::sync::{Arc, OnceLock};
+use std::time::Instant;
+
+use axum::{
+    extract::{ConnectInfo, Request, State},
+    http::{
+        header::{CONTENT_LENGTH, REFERER, USER_AGENT},
+        HeaderMap, Uri,
+    },
+    middleware::Next,
+    response::Response,
+};
+use rustok_api::context::{AuthContextExtension, TenantContextExtension};
+use uuid::Uuid;
+
+use crate::common::settings::{AccessLogFormat, AccessLogSettings};
+use crate::middleware::request_id::RequestId;
+
+pub const ACCESS_LOG_TARGET: &str = "access_log";
+
+const REDACTED: &str = "REDACTED";
+
+/// Query params whose values never reach the access log.
+const SENSITIVE_QUERY_PARAMS: &[&str] = &[
+    "access_token",
+    "api_key",
+    "apikey",
+    "client_secret",
+    "code",
+    "id_token",
+    "password",
+    "refresh_token",
+    "secret",
+    "signature",
+    "token",
+];
+
+#[derive(Debug, Default)]
+struct ResolvedIdentity {
+    tenant_id: OnceLock<Uuid>,
+    user_id: OnceLock<Uuid>,
+}
+
+/// Carries tenant/user resolved by inner middleware back out to [`log`].
+#[derive(Debug, Clone, Default)]
+struct AccessLogIdentity(Arc<ResolvedIdentity>);
+
+pub async fn log(
+    State(settings): State<Arc<AccessLogSettings>>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    if !settings.enabled {
+        return next.run(request).await;
+    }
+
+    let started_at = Instant::now();
+    let method = request.method().to_string();
+    let path = redact_path(request.uri());
+    let version = format!("{:?}", request.version());
+    let request_id = request
+        .extensions()
+        .get::<RequestId>()
+        .map(|id| id.0.clone())
+        .unwrap_or_else(|| "-".to_string());
+    let remote_addr = request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| addr.ip().to_string())
+        .unwrap_or_else(|| "-".to_string());
+    let referer = header_or_dash(request.headers(), REFERER);
+    let user_agent = header_or_dash(request.headers(), USER_AGENT);
+
+    let identity = AccessLogIdentity::default();
+    request.extensions_mut().insert(identity.clone());
+
+    let response = next.run(request).await;
+
+    let status = response.status().as_u16();
+    let latency_ms = started_at.elapsed().as_millis() as u64;
+    let tenant_id = display_or_dash(identity.0.tenant_id.get());
+    let user_id = display_or_dash(identity.0.user_id.get());
+    let message = match settings.format {
+        AccessLogFormat::Json => "request completed".to_string(),
+        AccessLogFormat::Combined => format!(
+            "{remote_addr} - {user_id} [{time}] \"{method} {path} {version}\" {status} {bytes} \"{referer}\" \"{user_agent}\"",
+            time = chrono::Utc::now().format("%d/%b/%Y:%H:%M:%S %z"),
+            bytes = header_or_dash(response.headers(), CONTENT_LENGTH),
+        ),
+    };
+
+    tracing::info!(
+        target: ACCESS_LOG_TARGET,
+        method = %method,
+        path = %path,
+        status,
+        latency_ms,
+        request_id = %request_id,
+        tenant_id = %tenant_id,
+        user_id = %user_id,
+        "{message}"
+    );
+
+    response
+}
+
+pub async fn capture_identity(request: Request, next: Next) -> Response {
+    if let Some(identity) = request.extensions().get::<AccessLogIdentity>() {
+        if let Some(tenant) = request.extensions().get::<TenantContextExtension>() {
+            let _ = identity.0.tenant_id.set(tenant.0.id);
+        }
+        if let Some(auth) = request.extensions().get::<AuthContextExtension>() {
+            let _ = identity.0.user_id.set(auth.0.user_id);
+            let _ = identity.0.tenant_id.set(auth.0.tenant_id);
+        }
+    }
+
+    next.run(request).await
+}
+
+fn redact_path(uri: &Uri) -> String {
+    let Some(query) = uri.query() else {
+        return uri.path().to_string();
+    };
+
+    let query = query
+        .split('&')
+        .map(|pair| match pair.split_once('=') {
+            Some((key, _)) if is_sensitive_param(key) => format!("{key}={REDACTED}"),
+            _ => pair.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("&");
+
+    format!("{}?{query}", uri.path())
+}
+
+fn is_sensitive_param(key: &str) -> bool {
+    SENSITIVE_QUERY_PARAMS
+        .iter()
+        .any(|sensitive| key.eq_ignore_ascii_case(sensitive))
+}
+
+fn header_or_dash(headers: &HeaderMap, name: axum::http::HeaderName) -> String {
+    headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string)
+        .unwrap_or_else(|| "-".to_string())
+}
+
+fn display_or_dash(value: Option<&Uuid>) -> String {
+    value
+        .map(Uuid::to_string)
+        .unwrap_or_else(|| "-".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::Router;
+    use axum::{body::Body, middleware::from_fn, middleware::from_fn_with_state, routing::get};
+    use rustok_api::context::{AuthContext, TenantContext};
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::common::log_capture::{EventFields, LogCapture};
+    use crate::middleware::request_id::propagate;
+
+    const TENANT_ID: Uuid = Uuid::from_u128(0x1111);
+    const USER_ID: Uuid = Uuid::from_u128(0x2222);
+
+    async fn authenticate(mut request: Request, next: Next) -> Response {
+        request
+            .extensions_mut()
+            .insert(TenantContextExtension(TenantContext {
+                id: TENANT_ID,
+                name: "Acme".to_string(),
+                slug: "acme".to_string(),
+                domain: None,
+                settings: serde_json::json!({}),
+                default_locale: "en".to_string(),
+                is_active: true,
+            }));
+        request
+            .extensions_mut()
+            .insert(AuthContextExtension(AuthContext {
+                user_id: USER_ID,
+                session_id: Uuid::new_v4(),
+                tenant_id: TENANT_ID,
+                permissions: Vec::new(),
+                client_id: None,
+                scopes: Vec::new(),
+                grant_type: "password".to_string(),
+            }));
+        next.run(request).await
+    }
+
+    async fn request_with_capture(settings: AccessLogSettings) -> Vec<EventFields> {
+        let capture = LogCapture::new(ACCESS_LOG_TARGET);
+        let _guard = capture.install();
+
+        let app = Router::new()
+            .route("/api/orders", get(|| async { "ok" }))
+            .layer(from_fn(capture_identity))
+            .layer(from_fn(authenticate))
+            .layer(from_fn_with_state(Arc::new(settings), log))
+            .layer(from_fn(propagate));
+        app.oneshot(
+            Request::builder()
+                .uri("/api/orders?page=2&access_token=s3cr3t&Password=hunter2")
+                .header("X-Request-Id", "req-access-1")
+                .header(USER_AGENT, "curl/8.0")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+        capture.events()
+    }
+
+    #[tokio::test]
+    async fn request_produces_one_access_log_event() {
+        let events = request_with_capture(AccessLogSettings {
+            enabled: true,
+            format: AccessLogFormat::Json,
+        })
+        .await;
+
+        assert_eq!(events.len(), 1);
+        let event = &events[0];
+        assert_eq!(event["method"], "GET");
+        assert_eq!(
+            event["path"],
+            "/api/orders?page=2&access_token=REDACTED&Password=REDACTED"
+        );
+        assert_eq!(event["status"], "200");
+        assert!(event["latency_ms"].parse::<u64>().is_ok());
+        assert_eq!(event["request_id"], "req-access-1");
+        assert_eq!(event["tenant_id"], TENANT_ID.to_string());
+        assert_eq!(event["user_id"], USER_ID.to_string());
+        assert_eq!(event["message"], "request completed");
+    }
+
+    #[tokio::test]
+    async fn combined_format_renders_a_redacted_access_line() {
+        let events = request_with_capture(AccessLogSettings {
+            enabled: true,
+            format: AccessLogFormat::Combined,
+        })
+        .await;
+
+        let line = &events[0]["message"];
+        assert!(line.starts_with(&format!("- - {USER_ID} [")), "{line}");
+        assert!(line.contains(
+            "\"GET /api/orders?page=2&access_token=REDACTED&Password=REDACTED HTTP/1.1\" 200"
+        ));
+        assert!(line.ends_with("\"-\" \"curl/8.0\""));
+        assert!(!line.contains("s3cr3t"));
+    }
+
+    #[tokio::test]
+    async fn disabled_access_log_emits_nothing() {
+        let events = request_with_capture(AccessLogSettings::default()).await;
+
+        assert!(events.is_empty());
+    }
+}
@@ -0,0 +1,394 @@
+/// Body Capture Middleware
+///
+/// Opt-in debugging aid that logs request and response bodies under [`BODY_CAPTURE_LOG_TARGET`]
+/// for a sampled fraction of requests:
+/// - only paths starting with one of `routes` are eligible, and only while `enabled`;
+/// - `sample_rate` picks requests at random, `max_captures_per_minute` caps the volume;
+/// - JSON bodies are logged with `redact_fields` values replaced by `REDACTED` and truncated
+///   to `max_body_bytes`; non-JSON bodies, streamed bodies and bodies over
+///   [`MAX_BUFFERED_BODY_BYTES`] are never buffered and are logged as omitted.
+///
+/// Settings start from `settings.rustok.runtime.body_capture` and are swapped at runtime
+/// through [`BodyCaptureControl`] (`/api/admin/body-capture`). Mounted as the innermost
+/// layer in `app_router.rs::compose_application_router()`, after auth and rate limiting.
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
+
+use axum::{
+    body::{Body, Bytes, HttpBody},
+    extract::{Request, State},
+    http::{header::CONTENT_TYPE, HeaderMap, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use serde_json::Value;
+
+use crate::common::settings::BodyCaptureSettings;
+use crate::middleware::http_metrics::route_label;
+use crate::middleware::request_id::RequestId;
+
+pub const BODY_CAPTURE_LOG_TARGET: &str = "http.body_capture";
+
+/// Bodies larger than this, or without a known size, are passed through untouched.
+pub const MAX_BUFFERED_BODY_BYTES: usize = 1024 * 1024;
+
+const REDACTED: &str = "REDACTED";
+const CAPTURE_WINDOW: Duration = Duration::from_secs(60);
+
+#[derive(Debug)]
+struct CaptureWindow {
+    started_at: Instant,
+    captured: u32,
+}
+
+#[derive(Debug)]
+struct Inner {
+    settings: RwLock<BodyCaptureSettings>,
+    window: Mutex<CaptureWindow>,
+}
+
+/// Shared, runtime-swappable body capture settings plus the per-minute capture budget.
+#[derive(Debug, Clone)]
+pub struct BodyCaptureControl {
+    inner: Arc<Inner>,
+}
+
+impl BodyCaptureControl {
+    pub fn new(settings: BodyCaptureSettings) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                settings: RwLock::new(settings),
+                window: Mutex::new(CaptureWindow {
+                    started_at: Instant::now(),
+                    captured: 0,
+                }),
+            }),
+        }
+    }
+
+    pub fn settings(&self) -> BodyCaptureSettings {
+        self.inner
+            .settings
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+
+    pub fn update(&self, settings: BodyCaptureSettings) -> Result<(), String> {
+        validate_settings(&settings)?;
+        *self
+            .inner
+            .settings
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = settings;
+        Ok(())
+    }
+
+    /// Returns the settings to capture with when `path` is selected for capture.
+    fn sample(&self, path: &str) -> Option<BodyCaptureSettings> {
+        let settings = self.settings();
+        if !settings.enabled
+            || !settings
+                .routes
+                .iter()
+                .any(|prefix| path.starts_with(prefix.as_str()))
+            || !sampled(settings.sample_rate)
+        {
+            return None;
+        }
+
+        let mut window = self
+            .inner
+            .window
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if window.started_at.elapsed() >= CAPTURE_WINDOW {
+            window.started_at = Instant::now();
+            window.captured = 0;
+        }
+        if window.captured >= settings.max_captures_per_minute {
+            return None;
+        }
+        window.captured += 1;
+
+        Some(settings)
+    }
+}
+
+pub fn validate_settings(settings: &BodyCaptureSettings) -> Result<(), String> {
+    if !(0.0..=1.0).contains(&settings.sample_rate) {
+        return Err(format!(
+            "sample_rate must be between 0.0 and 1.0, got {}",
+            settings.sample_rate
+        ));
+    }
+    if settings
+        .routes
+        .iter()
+        .any(|prefix| !prefix.starts_with('/'))
+    {
+        return Err("routes must be path prefixes starting with '/'".to_string());
+    }
+    Ok(())
+}
+
+pub async fn capture(
+    State(control): State<BodyCaptureControl>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some(settings) = control.sample(request.uri().path()) else {
+        return next.run(request).await;
+    };
+
+    let method = request.method().to_string();
+    let route = route_label(&request);
+    let path = request.uri().path().to_string();
+    let request_id = request
+        .extensions()
+        .get::<RequestId>()
+        .map(|id| id.0.clone())
+        .unwrap_or_else(|| "-".to_string());
+
+    let (parts, body) = request.into_parts();
+    let (body, request_body) = match buffer(body, &parts.headers, &settings).await {
+        Ok(buffered) => buffered,
+        Err(error) => {
+            tracing::warn!(error = %error, "body capture failed to read request body");
+            return StatusCode::BAD_REQUEST.into_response();
+        }
+    };
+
+    let response = next.run(Request::from_parts(parts, body)).await;
+
+    let (parts, body) = response.into_parts();
+    let (body, response_body) = match buffer(body, &parts.headers, &settings).await {
+        Ok(buffered) => buffered,
+        Err(error) => {
+            tracing::warn!(error = %error, "body capture failed to read response body");
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+
+    tracing::info!(
+        target: BODY_CAPTURE_LOG_TARGET,
+        request_id = %request_id,
+        method = %method,
+        route = %route,
+        path = %path,
+        status = parts.status.as_u16(),
+        request_body = %request_body,
+        response_body = %response_body,
+        "captured request/response bodies"
+    );
+
+    Response::from_parts(parts, body)
+}
+
+/// Buffers `body` when it is small enough to be captured and renders it for the log.
+async fn buffer(
+    body: Body,
+    headers: &HeaderMap,
+    settings: &BodyCaptureSettings,
+) -> Result<(Body, String), axum::Error> {
+    let content_type = headers
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or("unknown content type")
+        .to_string();
+
+    match body.size_hint().exact() {
+        Some(0) => return Ok((body, String::new())),
+        Some(len) if len as usize <= MAX_BUFFERED_BODY_BYTES && is_json(&content_type) => {}
+        Some(len) => return Ok((body, format!("<{len} bytes of {content_type} omitted>"))),
+        None => return Ok((body, format!("<streamed {content_type} omitted>"))),
+    }
+
+    let bytes = axum::body::to_bytes(body, MAX_BUFFERED_BODY_BYTES).await?;
+    let rendered = render_json(&bytes, settings);
+    Ok((Body::from(bytes), rendered))
+}
+
+fn render_json(bytes: &Bytes, settings: &BodyCaptureSettings) -> String {
+    let Ok(mut value) = serde_json::from_slice::<Value>(bytes) else {
+        return format!("<{} bytes of invalid JSON omitted>", bytes.len());
+    };
+    redact(&mut value, &settings.redact_fields);
+    truncate(value.to_string(), settings.max_body_bytes)
+}
+
+fn redact(value: &mut Value, fields: &[String]) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if fields.iter().any(|field| key.eq_ignore_ascii_case(field)) {
+                    *value = Value::String(REDACTED.to_string());
+                } else {
+                    redact(value, fields);
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                redact(item, fields);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn truncate(mut text: String, max_bytes: usize) -> String {
+    if text.len() <= max_bytes {
+        return text;
+    }
+    let mut end = max_bytes;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    text.truncate(end);
+    text.push_str("…[truncated]");
+    text
+}
+
+fn is_json(content_type: &str) -> bool {
+    let mime = content_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+    mime == "application/json" || mime.ends_with("+json")
+}
+
+fn sampled(sample_rate: f64) -> bool {
+    if sample_rate <= 0.0 {
+        false
+    } else if sample_rate >= 1.0 {
+        true
+    } else {
+        rand::random_bool(sample_rate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use axum::{middleware::from_fn_with_state, routing::post, Json, Router};
+    use tower::ServiceExt;
+    use tracing::field::{Field, Visit};
+    use tracing_subscriber::layer::{Context, SubscriberExt};
+    use tracing_subscriber::Layer;
+
+    use super::*;
+
+    #[derive(Default)]
+    struct EventFields(HashMap<String, String>);
+
+    impl Visit for EventFields {
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            self.0
+                .insert(field.name().to_string(), format!("{value:?}"));
+        }
+    }
+
+    #[derive(Clone, Default)]
+    struct BodyCaptureLogs(Arc<Mutex<Vec<HashMap<String, String>>>>);
+
+    impl<S: tracing::Subscriber> Layer<S> for BodyCaptureLogs {
+        fn on_event(&self, event: &tracing::Event<'_>, _ctx: Context<'_, S>) {
+            if event.metadata().target() != BODY_CAPTURE_LOG_TARGET {
+                return;
+            }
+            let mut fields = EventFields::default();
+            event.record(&mut fields);
+            self.0.lock().unwrap().push(fields.0);
+        }
+    }
+
+    fn settings(sample_rate: f64) -> BodyCaptureSettings {
+        BodyCaptureSettings {
+            enabled: true,
+            sample_rate,
+            routes: vec!["/api/auth".to_string()],
+            ..BodyCaptureSettings::default()
+        }
+    }
+
+    async fn login_with_capture(settings: BodyCaptureSettings) -> Vec<HashMap<String, String>> {
+        let logs = BodyCaptureLogs::default();
+        let _guard =
+            tracing::subscriber::set_default(tracing_subscriber::registry().with(logs.clone()));
+
+        let app = Router::new()
+            .route(
+                "/api/auth/login",
+                post(|Json(body): Json<Value>| async move {
+                    Json(serde_json::json!({ "email": body["email"], "access_token": "jwt-abc" }))
+                }),
+            )
+            .layer(from_fn_with_state(
+                BodyCaptureControl::new(settings),
+                capture,
+            ));
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/auth/login")
+                    .header(CONTENT_TYPE, "application/json")
+                    .body(Body::from(
+                        r#"{"email":"ops@example.com","password":"hunter2"}"#,
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert!(String::from_utf8_lossy(&body).contains("jwt-abc"));
+
+        let events = logs.0.lock().unwrap().clone();
+        events
+    }
+
+    #[tokio::test]
+    async fn sampled_route_logs_redacted_bodies() {
+        let events = login_with_capture(settings(1.0)).await;
+
+        assert_eq!(events.len(), 1);
+        let event = &events[0];
+        assert_eq!(event["route"], "/api/auth/login");
+        assert_eq!(event["status"], "200");
+        assert!(event["request_body"].contains("ops@example.com"));
+        assert!(event["request_body"].contains(r#""password":"REDACTED""#));
+        assert!(!event["request_body"].contains("hunter2"));
+        assert!(event["response_body"].contains(r#""access_token":"REDACTED""#));
+        assert!(!event["response_body"].contains("jwt-abc"));
+    }
+
+    #[tokio::test]
+    async fn zero_sample_rate_logs_nothing() {
+        let events = login_with_capture(settings(0.0)).await;
+
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn captures_are_capped_per_minute_and_settings_validated() {
+        let control = BodyCaptureControl::new(BodyCaptureSettings {
+            max_captures_per_minute: 2,
+            ..settings(1.0)
+        });
+
+        assert!(control.sample("/api/auth/login").is_some());
+        assert!(control.sample("/api/orders").is_none());
+        assert!(control.sample("/api/auth/login").is_some());
+        assert!(control.sample("/api/auth/login").is_none());
+
+        assert!(control.update(settings(1.5)).is_err());
+        assert_eq!(control.settings().sample_rate, 1.0);
+    }
+}
@@ -0,0 +1,349 @@
+/// CORS Middleware
+///
+/// Applies the configured cross-origin policy (`settings.rustok.cors`) to `/api/*` routes:
+/// - preflight (`OPTIONS` + `Access-Control-Request-Method`) from an allowed origin is answered
+///   with `204` and the negotiated `Access-Control-Allow-*` headers;
+/// - preflight from a disallowed origin, or for a disallowed method, is rejected with `403`;
+/// - actual requests from an allowed origin get `Access-Control-Allow-Origin` on the response,
+///   requests from other origins pass through without CORS headers (the browser blocks them);
+/// - every `/api/*` response carries `Vary: Origin`, with or without an `Origin` header, so a
+///   shared cache never serves one origin's CORS headers (or their absence) to another.
+///
+/// Mounted globally in `app_router.rs::compose_application_router()` via
+/// `axum::middleware::from_fn_with_state`.
+use std::sync::Arc;
+
+use axum::{
+    extract::{Request, State},
+    http::{
+        header::{
+            ACCESS_CONTROL_ALLOW_CREDENTIALS, ACCESS_CONTROL_ALLOW_HEADERS,
+            ACCESS_CONTROL_ALLOW_METHODS, ACCESS_CONTROL_ALLOW_ORIGIN,
+            ACCESS_CONTROL_EXPOSE_HEADERS, ACCESS_CONTROL_MAX_AGE, ACCESS_CONTROL_REQUEST_METHOD,
+            ORIGIN, VARY,
+        },
+        HeaderMap, HeaderValue, Method, StatusCode,
+    },
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+use crate::common::settings::{CorsPreset, CorsSettings};
+use crate::middleware::request_id::REQUEST_ID_HEADER;
+
+const API_PREFIX: &str = "/api/";
+const DEV_LOCAL_HOSTS: &[&str] = &["localhost", "127.0.0.1", "[::1]"];
+
+/// Resolved CORS policy shared by the middleware.
+#[derive(Debug, Clone)]
+pub struct CorsPolicy {
+    preset: CorsPreset,
+    allowed_origins: Vec<String>,
+    allowed_methods: Vec<Method>,
+    allowed_headers: String,
+    allow_credentials: bool,
+    max_age_secs: u64,
+}
+
+impl CorsPolicy {
+    pub fn from_settings(settings: &CorsSettings) -> Self {
+        Self {
+            preset: settings.preset,
+            allowed_origins: settings
+                .allowed_origins
+                .iter()
+                .map(|origin| origin.trim().trim_end_matches('/').to_ascii_lowercase())
+                .filter(|origin| !origin.is_empty())
+                .collect(),
+            allowed_methods: settings
+                .allowed_methods
+                .iter()
+                .filter_map(|method| Method::from_bytes(method.trim().as_bytes()).ok())
+                .collect(),
+            allowed_headers: settings
+                .allowed_headers
+                .iter()
+                .map(|header| header.trim().to_ascii_lowercase())
+                .filter(|header| !header.is_empty())
+                .collect::<Vec<_>>()
+                .join(", "),
+            allow_credentials: settings.allow_credentials,
+            max_age_secs: settings.max_age_secs,
+        }
+    }
+
+    /// Dev preset: allows any local origin, credentials included.
+    pub fn dev_permissive() -> Self {
+        Self::from_settings(&CorsSettings {
+            preset: CorsPreset::DevPermissive,
+            allow_credentials: true,
+            ..CorsSettings::default()
+        })
+    }
+
+    pub fn is_origin_allowed(&self, origin: &str) -> bool {
+        let origin = origin.trim_end_matches('/').to_ascii_lowercase();
+        if self
+            .allowed_origins
+            .iter()
+            .any(|allowed| allowed == &origin)
+        {
+            return true;
+        }
+
+        self.preset == CorsPreset::DevPermissive && is_local_origin(&origin)
+    }
+
+    fn is_method_allowed(&self, method: &str) -> bool {
+        self.allowed_methods
+            .iter()
+            .any(|allowed| allowed.as_str().eq_ignore_ascii_case(method))
+    }
+
+    fn apply_origin_headers(&self, headers: &mut HeaderMap, origin: &HeaderValue) {
+        headers.insert(ACCESS_CONTROL_ALLOW_ORIGIN, origin.clone());
+        if self.allow_credentials {
+            headers.insert(
+                ACCESS_CONTROL_ALLOW_CREDENTIALS,
+                HeaderValue::from_static("true"),
+            );
+        }
+    }
+
+    fn preflight_response(&self, origin: &HeaderValue) -> Response {
+        let mut response = StatusCode::NO_CONTENT.into_response();
+        let headers = response.headers_mut();
+        self.apply_origin_headers(headers, origin);
+
+        let methods = self
+            .allowed_methods
+            .iter()
+            .map(Method::as_str)
+            .collect::<Vec<_>>()
+            .join(", ");
+        if let Ok(value) = HeaderValue::from_str(&methods) {
+            headers.insert(ACCESS_CONTROL_ALLOW_METHODS, value);
+        }
+        if let Ok(value) = HeaderValue::from_str(&self.allowed_headers) {
+            headers.insert(ACCESS_CONTROL_ALLOW_HEADERS, value);
+        }
+        headers.insert(ACCESS_CONTROL_MAX_AGE, HeaderValue::from(self.max_age_secs));
+        response
+    }
+}
+
+fn is_local_origin(origin: &str) -> bool {
+    let Some(authority) = origin
+        .strip_prefix("http://")
+        .or_else(|| origin.strip_prefix("https://"))
+    else {
+        return false;
+    };
+
+    let host = if authority.starts_with('[') {
+        authority
+            .split_once(']')
+            .map(|(host, _)| format!("{host}]"))
+            .unwrap_or_default()
+    } else {
+        authority.split(':').next().unwrap_or_default().to_string()
+    };
+
+    DEV_LOCAL_HOSTS.contains(&host.as_str())
+}
+
+fn vary_on_origin(mut response: Response) -> Response {
+    let headers = response.headers_mut();
+    let already_varies = headers
+        .get_all(VARY)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|name| {
+            let name = name.trim();
+            name == "*" || name.eq_ignore_ascii_case(ORIGIN.as_str())
+        });
+    if !already_varies {
+        headers.append(VARY, HeaderValue::from_static("origin"));
+    }
+    response
+}
+
+pub async fn cors(State(policy): State<Arc<CorsPolicy>>, request: Request, next: Next) -> Response {
+    if !request.uri().path().starts_with(API_PREFIX) {
+        return next.run(request).await;
+    }
+
+    let Some(origin) = request.headers().get(ORIGIN).cloned() else {
+        return vary_on_origin(next.run(request).await);
+    };
+
+    let origin_allowed = origin
+        .to_str()
+        .map(|value| policy.is_origin_allowed(value))
+        .unwrap_or(false);
+
+    let requested_method = (request.method() == Method::OPTIONS)
+        .then(|| request.headers().get(ACCESS_CONTROL_REQUEST_METHOD))
+        .flatten()
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+
+    if let Some(requested_method) = requested_method {
+        if !origin_allowed || !policy.is_method_allowed(&requested_method) {
+            tracing::debug!(
+                origin = ?origin,
+                method = %requested_method,
+                "Rejected CORS preflight"
+            );
+            return vary_on_origin(StatusCode::FORBIDDEN.into_response());
+        }
+        return vary_on_origin(policy.preflight_response(&origin));
+    }
+
+    let mut response = next.run(request).await;
+    if origin_allowed {
+        let headers = response.headers_mut();
+        policy.apply_origin_headers(headers, &origin);
+        // Lets the admin read the correlation id from cross-origin error responses.
+        headers.insert(
+            ACCESS_CONTROL_EXPOSE_HEADERS,
+            HeaderValue::from_static(REQUEST_ID_HEADER),
+        );
+    }
+    vary_on_origin(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, middleware::from_fn_with_state, routing::get, Router};
+    use tower::ServiceExt;
+
+    fn strict_policy() -> Arc<CorsPolicy> {
+        Arc::new(CorsPolicy::from_settings(&CorsSettings {
+            allowed_origins: vec!["https://admin.example.com".to_string()],
+            allow_credentials: true,
+            ..CorsSettings::default()
+        }))
+    }
+
+    fn app(policy: Arc<CorsPolicy>) -> Router {
+        Router::new()
+            .route(
+                "/api/graphql",
+                get(|| async { "ok" }).post(|| async { "ok" }),
+            )
+            .layer(from_fn_with_state(policy, cors))
+    }
+
+    fn preflight(origin: &str, method: &str) -> Request {
+        Request::builder()
+            .method(Method::OPTIONS)
+            .uri("/api/graphql")
+            .header(ORIGIN, origin)
+            .header(ACCESS_CONTROL_REQUEST_METHOD, method)
+            .body(Body::empty())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn preflight_from_allowed_origin_returns_cors_headers() {
+        let response = app(strict_policy())
+            .oneshot(preflight("https://admin.example.com", "POST"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        let headers = response.headers();
+        assert_eq!(
+            headers.get(ACCESS_CONTROL_ALLOW_ORIGIN).unwrap(),
+            "https://admin.example.com"
+        );
+        assert_eq!(
+            headers.get(ACCESS_CONTROL_ALLOW_CREDENTIALS).unwrap(),
+            "true"
+        );
+        assert!(headers
+            .get(ACCESS_CONTROL_ALLOW_METHODS)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|value| value.contains("POST")));
+        assert!(headers
+            .get(ACCESS_CONTROL_ALLOW_HEADERS)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|value| value.contains("authorization")));
+        assert_eq!(headers.get(ACCESS_CONTROL_MAX_AGE).unwrap(), "600");
+    }
+
+    #[tokio::test]
+    async fn preflight_from_disallowed_origin_is_rejected() {
+        let response = app(strict_policy())
+            .oneshot(preflight("https://evil.example.com", "POST"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        assert!(response
+            .headers()
+            .get(ACCESS_CONTROL_ALLOW_ORIGIN)
+            .is_none());
+        assert_eq!(response.headers().get(VARY).unwrap(), "origin");
+    }
+
+    #[tokio::test]
+    async fn simple_request_from_disallowed_origin_gets_no_cors_headers() {
+        let response = app(strict_policy())
+            .oneshot(
+                Request::builder()
+                    .uri("/api/graphql")
+                    .header(ORIGIN, "https://evil.example.com")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(response
+            .headers()
+            .get(ACCESS_CONTROL_ALLOW_ORIGIN)
+            .is_none());
+        assert_eq!(response.headers().get(VARY).unwrap(), "origin");
+    }
+
+    #[tokio::test]
+    async fn api_responses_vary_on_origin_once() {
+        let without_origin = app(strict_policy())
+            .oneshot(
+                Request::builder()
+                    .uri("/api/graphql")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(without_origin.headers().get(VARY).unwrap(), "origin");
+
+        let allowed = app(strict_policy())
+            .oneshot(
+                Request::builder()
+                    .uri("/api/graphql")
+                    .header(ORIGIN, "https://admin.example.com")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(allowed.headers().get_all(VARY).iter().count(), 1);
+    }
+
+    #[test]
+    fn dev_permissive_preset_allows_local_origins_only() {
+        let policy = CorsPolicy::dev_permissive();
+
+        assert!(policy.is_origin_allowed("http://localhost:8080"));
+        assert!(policy.is_origin_allowed("http://127.0.0.1:3000"));
+        assert!(!policy.is_origin_allowed("http://localhost.evil.com"));
+        assert!(!strict_policy().is_origin_allowed("http://localhost:8080"));
+    }
+}
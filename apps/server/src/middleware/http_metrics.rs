@@ -0,0 +1,177 @@
+/// HTTP Metrics Middleware
+///
+/// Records request count, in-flight gauge and latency histogram for every request
+/// through `rustok_telemetry::metrics`:
+/// - `route` is the matched route template (`/api/pages/{id}`), never the raw path,
+///   so ids in URLs cannot blow up label cardinality;
+/// - requests that match no route share the [`UNMATCHED_ROUTE`] label;
+/// - `status_class` is `1xx`..`5xx`;
+/// - the in-flight gauge is released by a drop guard, so a request whose future is
+///   dropped (client disconnect, timeout) does not stay in flight forever.
+///
+/// Mounted via `Router::layer` in `app_router.rs::compose_application_router()` so
+/// that `MatchedPath` is already resolved when it runs.
+use std::time::Instant;
+
+use axum::{
+    extract::{MatchedPath, Request},
+    http::StatusCode,
+    middleware::Next,
+    response::Response,
+};
+use rustok_telemetry::metrics::{http_request_finished, http_request_started, record_http_request};
+
+pub const UNMATCHED_ROUTE: &str = "unmatched";
+
+fn status_class(status: StatusCode) -> &'static str {
+    match status.as_u16() {
+        100..=199 => "1xx",
+        200..=299 => "2xx",
+        300..=399 => "3xx",
+        400..=499 => "4xx",
+        _ => "5xx",
+    }
+}
+
+/// Matched route template of `request`, or [`UNMATCHED_ROUTE`].
+pub fn route_label(request: &Request) -> String {
+    request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_string())
+        .unwrap_or_else(|| UNMATCHED_ROUTE.to_string())
+}
+
+/// Holds a request's in-flight slot until dropped.
+struct InFlight {
+    method: String,
+    route: String,
+}
+
+impl InFlight {
+    fn start(method: String, route: String) -> Self {
+        http_request_started(&method, &route);
+        Self { method, route }
+    }
+}
+
+impl Drop for InFlight {
+    fn drop(&mut self) {
+        http_request_finished(&self.method, &self.route);
+    }
+}
+
+pub async fn record(request: Request, next: Next) -> Response {
+    let in_flight = InFlight::start(request.method().as_str().to_string(), route_label(&request));
+    let started_at = Instant::now();
+    let response = next.run(request).await;
+    record_http_request(
+        &in_flight.method,
+        &in_flight.route,
+        status_class(response.status()),
+        started_at.elapsed().as_secs_f64(),
+    );
+
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, middleware::from_fn, routing::get, Router};
+    use rustok_telemetry::metrics::{
+        HTTP_SERVER_REQUESTS_IN_FLIGHT, HTTP_SERVER_REQUESTS_TOTAL,
+        HTTP_SERVER_REQUEST_DURATION_SECONDS,
+    };
+    use tower::ServiceExt;
+
+    const ROUTE: &str = "/api/metrics-test/{id}";
+
+    fn app() -> Router {
+        Router::new()
+            .route(ROUTE, get(|| async { "ok" }))
+            .layer(from_fn(record))
+    }
+
+    #[tokio::test]
+    async fn request_is_counted_under_its_route_template() {
+        let labels = ["GET", ROUTE, "2xx"];
+        let count_before = HTTP_SERVER_REQUESTS_TOTAL.with_label_values(&labels).get();
+        let samples_before = HTTP_SERVER_REQUEST_DURATION_SECONDS
+            .with_label_values(&labels)
+            .get_sample_count();
+
+        for id in ["42", "43"] {
+            let response = app()
+                .oneshot(
+                    Request::builder()
+                        .uri(format!("/api/metrics-test/{id}"))
+                        .body(Body::empty())
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+
+        assert_eq!(
+            HTTP_SERVER_REQUESTS_TOTAL.with_label_values(&labels).get(),
+            count_before + 2
+        );
+        assert_eq!(
+            HTTP_SERVER_REQUEST_DURATION_SECONDS
+                .with_label_values(&labels)
+                .get_sample_count(),
+            samples_before + 2
+        );
+        assert_eq!(
+            HTTP_SERVER_REQUESTS_IN_FLIGHT
+                .with_label_values(&["GET", ROUTE])
+                .get(),
+            0
+        );
+        assert_eq!(
+            HTTP_SERVER_REQUESTS_TOTAL
+                .with_label_values(&["GET", "/api/metrics-test/42", "2xx"])
+                .get(),
+            0
+        );
+    }
+
+    #[tokio::test]
+    async fn cancelled_request_is_no_longer_in_flight() {
+        const SLOW_ROUTE: &str = "/api/metrics-test-slow";
+        let in_flight = HTTP_SERVER_REQUESTS_IN_FLIGHT.with_label_values(&["GET", SLOW_ROUTE]);
+        let app = Router::new()
+            .route(
+                SLOW_ROUTE,
+                get(|| async {
+                    tokio::time::sleep(std::time::Duration::from_secs(60)).await;
+                    "ok"
+                }),
+            )
+            .layer(from_fn(record));
+
+        let request = tokio::spawn(
+            app.oneshot(
+                Request::builder()
+                    .uri(SLOW_ROUTE)
+                    .body(Body::empty())
+                    .unwrap(),
+            ),
+        );
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        assert_eq!(in_flight.get(), 1);
+
+        request.abort();
+        assert!(request.await.unwrap_err().is_cancelled());
+        assert_eq!(in_flight.get(), 0);
+    }
+
+    #[test]
+    fn status_codes_collapse_into_classes() {
+        assert_eq!(status_class(StatusCode::CREATED), "2xx");
+        assert_eq!(status_class(StatusCode::NOT_FOUND), "4xx");
+        assert_eq!(status_class(StatusCode::SERVICE_UNAVAILABLE), "5xx");
+    }
+}
@@ -1,7 +1,13 @@
+pub mod access_log;
 pub mod auth_context;
 pub mod block_rest_auth;
+pub mod body_capture;
 pub mod channel;
+pub mod cors;
+pub mod http_metrics;
 pub mod locale;
+pub mod panic_capture;
 pub mod rate_limit;
+pub mod request_id;
 pub mod security_headers;
 pub mod tenant;
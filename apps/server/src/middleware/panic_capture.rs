@@ -0,0 +1,159 @@
+/// Panic Capture Middleware
+///
+/// Turns a panicking request handler into a structured error instead of a bare unwind:
+/// - logs one `error` event under [`PANIC_LOG_TARGET`] with the panic message, request id,
+///   trace id, method, route template and path;
+/// - increments `rustok_http_server_panics_total{method, route}`;
+/// - answers `500` with a generic body that carries only the request id, so panic details
+///   never reach the client.
+///
+/// The default panic hook still prints the panic and backtrace; this layer only adds
+/// correlation. Mounted in `app_router.rs::compose_application_router()` inside
+/// `request_id::propagate`, `http_metrics::record` and `access_log::log`, so the `500` is
+/// counted and logged like any other response. It replaces Loco's `catch_panic`
+/// middleware, which is disabled in the server configs.
+use std::any::Any;
+use std::panic::AssertUnwindSafe;
+
+use axum::{
+    extract::Request,
+    http::StatusCode,
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Json,
+};
+use futures_util::FutureExt;
+use serde_json::json;
+
+use crate::middleware::http_metrics::route_label;
+use crate::middleware::request_id::RequestId;
+
+pub const PANIC_LOG_TARGET: &str = "http.panic";
+
+pub async fn capture(request: Request, next: Next) -> Response {
+    let method = request.method().to_string();
+    let route = route_label(&request);
+    let path = request.uri().path().to_string();
+    let request_id = request
+        .extensions()
+        .get::<RequestId>()
+        .map(|id| id.0.clone());
+
+    let payload = match AssertUnwindSafe(next.run(request)).catch_unwind().await {
+        Ok(response) => return response,
+        Err(payload) => payload,
+    };
+
+    let trace_id = rustok_telemetry::current_trace_id();
+    tracing::error!(
+        target: PANIC_LOG_TARGET,
+        request_id = request_id.as_deref().unwrap_or("-"),
+        trace_id = trace_id.as_deref().unwrap_or("-"),
+        method = %method,
+        route = %route,
+        path = %path,
+        panic = %panic_message(payload.as_ref()),
+        "request handler panicked"
+    );
+    rustok_telemetry::metrics::record_http_panic(&method, &route);
+
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        Json(json!({
+            "error": "internal_server_error",
+            "description": "Internal Server Error",
+            "request_id": request_id,
+        })),
+    )
+        .into_response()
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("non-string panic payload")
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{body::Body, middleware::from_fn, routing::get, Router};
+    use rustok_telemetry::metrics::HTTP_SERVER_PANICS_TOTAL;
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::common::log_capture::LogCapture;
+    use crate::middleware::request_id::{propagate, REQUEST_ID_HEADER};
+
+    const ROUTE: &str = "/api/panic-test/{id}";
+
+    async fn explode() -> &'static str {
+        panic!("inventory invariant violated")
+    }
+
+    #[tokio::test]
+    async fn handler_panic_is_logged_with_request_id() {
+        let logs = LogCapture::new(PANIC_LOG_TARGET);
+        let _guard = logs.install();
+        let panics_before = HTTP_SERVER_PANICS_TOTAL
+            .with_label_values(&["GET", ROUTE])
+            .get();
+
+        let app = Router::new()
+            .route(ROUTE, get(explode))
+            .route("/api/healthy", get(|| async { "ok" }))
+            .layer(from_fn(capture))
+            .layer(from_fn(propagate));
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri("/api/panic-test/7")
+                    .header(REQUEST_ID_HEADER, "req-panic-1")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(
+            response.headers().get(REQUEST_ID_HEADER).unwrap(),
+            "req-panic-1"
+        );
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+        assert!(body.contains("req-panic-1"));
+        assert!(!body.contains("inventory invariant violated"));
+
+        let events = logs.events();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0]["request_id"], "req-panic-1");
+        assert_eq!(events[0]["route"], ROUTE);
+        assert_eq!(events[0]["path"], "/api/panic-test/7");
+        assert_eq!(events[0]["panic"], "inventory invariant violated");
+        assert!(events[0].contains_key("trace_id"));
+        assert_eq!(
+            HTTP_SERVER_PANICS_TOTAL
+                .with_label_values(&["GET", ROUTE])
+                .get(),
+            panics_before + 1
+        );
+
+        let healthy = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/healthy")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(healthy.status(), StatusCode::OK);
+        assert_eq!(logs.events().len(), 1);
+    }
+}
@@ -0,0 +1,153 @@
+/// Request ID Middleware
+///
+/// Correlates client-visible errors with server logs:
+/// - reuses a well-formed incoming `X-Request-Id`, otherwise generates a UUID v4;
+/// - stores it as a [`RequestId`] request extension for handlers and downstream middleware;
+/// - runs the rest of the stack inside a `request` span carrying `request_id`;
+/// - echoes it back in the `X-Request-Id` response header so clients can surface it.
+///
+/// Mounted as the outermost layer in `app_router.rs::compose_application_router()`.
+use axum::{extract::Request, http::HeaderValue, middleware::Next, response::Response};
+use tracing::Instrument;
+use uuid::Uuid;
+
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Incoming ids longer than this are replaced with a generated one.
+const MAX_REQUEST_ID_LEN: usize = 128;
+
+/// Request-scoped correlation id, available via `Extension<RequestId>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestId(pub String);
+
+impl RequestId {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for RequestId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+fn is_valid_request_id(value: &str) -> bool {
+    !value.is_empty()
+        && value.len() <= MAX_REQUEST_ID_LEN
+        && value
+            .bytes()
+            .all(|byte| byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.' | b':'))
+}
+
+fn resolve_request_id(request: &Request) -> RequestId {
+    request
+        .headers()
+        .get(REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::trim)
+        .filter(|value| is_valid_request_id(value))
+        .map(|value| RequestId(value.to_string()))
+        .unwrap_or_else(|| RequestId(Uuid::new_v4().to_string()))
+}
+
+pub async fn propagate(mut request: Request, next: Next) -> Response {
+    let request_id = resolve_request_id(&request);
+    request.extensions_mut().insert(request_id.clone());
+
+    let span = tracing::info_span!(
+        "request",
+        request_id = %request_id,
+        method = %request.method(),
+        path = %request.uri().path()
+    );
+    let mut response = next.run(request).instrument(span).await;
+
+    if let Ok(value) = HeaderValue::from_str(request_id.as_str()) {
+        response.headers_mut().insert(REQUEST_ID_HEADER, value);
+    }
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, middleware::from_fn, routing::get, Extension, Router};
+    use tower::ServiceExt;
+
+    fn app() -> Router {
+        Router::new()
+            .route(
+                "/api/echo",
+                get(|Extension(request_id): Extension<RequestId>| async move { request_id.0 }),
+            )
+            .layer(from_fn(propagate))
+    }
+
+    async fn body_string(response: Response) -> String {
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        String::from_utf8(bytes.to_vec()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn provided_request_id_is_echoed() {
+        let response = app()
+            .oneshot(
+                Request::builder()
+                    .uri("/api/echo")
+                    .header("X-Request-Id", "client-req-42")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            response.headers().get(REQUEST_ID_HEADER).unwrap(),
+            "client-req-42"
+        );
+        assert_eq!(body_string(response).await, "client-req-42");
+    }
+
+    #[tokio::test]
+    async fn request_id_is_generated_when_absent() {
+        let response = app()
+            .oneshot(
+                Request::builder()
+                    .uri("/api/echo")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let header = response
+            .headers()
+            .get(REQUEST_ID_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string)
+            .expect("generated request id header");
+        assert!(Uuid::parse_str(&header).is_ok());
+        assert_eq!(body_string(response).await, header);
+    }
+
+    #[tokio::test]
+    async fn malformed_request_id_is_replaced() {
+        let response = app()
+            .oneshot(
+                Request::builder()
+                    .uri("/api/echo")
+                    .header("X-Request-Id", "bad id with spaces")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let header = response.headers().get(REQUEST_ID_HEADER).unwrap();
+        assert_ne!(header, "bad id with spaces");
+        assert!(Uuid::parse_str(header.to_str().unwrap()).is_ok());
+    }
+}
@@ -16,6 +16,7 @@ use crate::services::registry_governance::RegistryGovernanceService;
 use crate::services::release_backend::ReleaseDeploymentService;
 #[cfg(feature = "mod-seo")]
 use rustok_api::loco::transactional_event_bus_from_context;
+use rustok_outbox::WebhookHealthProbe;
 #[cfg(feature = "mod-seo")]
 use rustok_seo::SeoService;
 
@@ -52,6 +53,7 @@ impl StopHandle {
 static OUTBOX_RELAY_WORKER_INSTANCE_IDS: AtomicU64 = AtomicU64::new(1);
 static BUILD_WORKER_INSTANCE_IDS: AtomicU64 = AtomicU64::new(1);
 static REMOTE_EXECUTOR_REAPER_INSTANCE_IDS: AtomicU64 = AtomicU64::new(1);
+static WEBHOOK_HEALTH_WORKER_INSTANCE_IDS: AtomicU64 = AtomicU64::new(1);
 #[cfg(feature = "mod-seo")]
 static SEO_BULK_WORKER_INSTANCE_IDS: AtomicU64 = AtomicU64::new(1);
 
@@ -92,6 +94,17 @@ impl RemoteExecutorReaperHandle {
     }
 }
 
+pub struct WebhookHealthWorkerHandle {
+    instance_id: u64,
+    _handle: JoinHandle<()>,
+}
+
+impl WebhookHealthWorkerHandle {
+    pub fn instance_id(&self) -> u64 {
+        self.instance_id
+    }
+}
+
 #[cfg(feature = "mod-seo")]
 pub struct SeoBulkWorkerHandle {
     instance_id: u64,
@@ -122,6 +135,7 @@ pub async fn connect_runtime_workers(ctx: &AppContext) -> Result<()> {
         .map_err(|error| Error::Message(format!("Invalid rustok settings: {error}")))?;
     #[cfg(feature = "mod-seo")]
     let seo_bulk_worker_enabled = settings.runtime.background_workers.seo_bulk_enabled;
+    let webhook_health_enabled = settings.runtime.background_workers.webhook_health_enabled;
 
     if settings.runtime.is_registry_only() {
         tracing::info!("Skipping background workers for registry-only host mode");
@@ -175,6 +189,13 @@ pub async fn connect_runtime_workers(ctx: &AppContext) -> Result<()> {
         ));
     }
 
+    if webhook_health_enabled && !ctx.shared_store.contains::<WebhookHealthWorkerHandle>() {
+        ctx.shared_store.insert(spawn_webhook_health_worker_handle(
+            ctx.clone(),
+            stop_rx.clone(),
+        ));
+    }
+
     #[cfg(feature = "mod-seo")]
     if seo_bulk_worker_enabled && !ctx.shared_store.contains::<SeoBulkWorkerHandle>() {
         ctx.shared_store
@@ -218,6 +239,16 @@ fn spawn_remote_executor_reaper_handle(
     }
 }
 
+fn spawn_webhook_health_worker_handle(
+    ctx: AppContext,
+    stop_rx: tokio::sync::watch::Receiver<bool>,
+) -> WebhookHealthWorkerHandle {
+    WebhookHealthWorkerHandle {
+        instance_id: WEBHOOK_HEALTH_WORKER_INSTANCE_IDS.fetch_add(1, Ordering::Relaxed),
+        _handle: tokio::spawn(webhook_health_worker_loop(ctx, stop_rx)),
+    }
+}
+
 #[cfg(feature = "mod-seo")]
 fn spawn_seo_bulk_worker_handle(
     ctx: AppContext,
@@ -341,6 +372,33 @@ async fn remote_executor_reaper_loop(
     }
 }
 
+async fn webhook_health_worker_loop(
+    ctx: AppContext,
+    mut stop_rx: tokio::sync::watch::Receiver<bool>,
+) {
+    let probe = WebhookHealthProbe::new(ctx.db.clone());
+    let poll_interval = probe.interval();
+
+    loop {
+        if *stop_rx.borrow() {
+            tracing::info!("Webhook health worker received shutdown signal, exiting");
+            return;
+        }
+
+        if let Err(error) = probe.probe_once().await {
+            tracing::error!(error = %error, "Webhook health worker failed to probe subscriptions");
+        }
+
+        tokio::select! {
+            _ = tokio::time::sleep(poll_interval) => {}
+            _ = stop_rx.changed() => {
+                tracing::info!("Webhook health worker received shutdown signal, exiting");
+                return;
+            }
+        }
+    }
+}
+
 #[cfg(feature = "mod-seo")]
 async fn seo_bulk_worker_loop(ctx: AppContext, mut stop_rx: tokio::sync::watch::Receiver<bool>) {
     let event_bus = transactional_event_bus_from_context(&ctx);
@@ -5,6 +5,7 @@ use axum::Router as AxumRouter;
 use leptos::prelude::provide_context;
 use leptos_axum::handle_server_fns_with_context;
 use loco_rs::app::AppContext;
+use std::sync::Arc;
 
 #[cfg(feature = "embed-admin")]
 #[allow(unused_imports)]
@@ -15,6 +16,7 @@ use rustok_storefront as _;
 
 use crate::common::settings::RustokSettings;
 use crate::middleware;
+use crate::middleware::cors::CorsPolicy;
 use crate::middleware::rate_limit::rate_limit_for_paths;
 use crate::services::app_runtime::AppRuntimeBootstrap;
 
@@ -124,9 +126,15 @@ pub fn compose_application_router(
     runtime: AppRuntimeBootstrap,
     rustok_settings: &RustokSettings,
 ) -> AxumRouter {
+    let cors_policy = Arc::new(CorsPolicy::from_settings(&rustok_settings.cors));
+    let access_log_settings = Arc::new(rustok_settings.runtime.access_log.clone());
+
     if rustok_settings.runtime.is_registry_only() {
         return router
             .layer(Extension(runtime.registry))
+            .layer(axum_middleware::from_fn(
+                middleware::access_log::capture_identity,
+            ))
             // Rate limiting must be present to prevent resource exhaustion even
             // in registry-only mode.
             .layer(axum_middleware::from_fn_with_state(
@@ -145,7 +153,18 @@ pub fn compose_application_router(
             ))
             .layer(axum_middleware::from_fn(
                 middleware::security_headers::security_headers,
-            ));
+            ))
+            .layer(axum_middleware::from_fn_with_state(
+                cors_policy,
+                middleware::cors::cors,
+            ))
+            .layer(axum_middleware::from_fn(middleware::panic_capture::capture))
+            .layer(axum_middleware::from_fn_with_state(
+                access_log_settings,
+                middleware::access_log::log,
+            ))
+            .layer(axum_middleware::from_fn(middleware::http_metrics::record))
+            .layer(axum_middleware::from_fn(middleware::request_id::propagate));
     }
 
     let server_fn_ctx = ctx.clone();
@@ -180,6 +199,13 @@ pub fn compose_application_router(
     )
     .layer(Extension(runtime.registry))
     .layer(Extension(runtime.graphql_schema))
+    .layer(axum_middleware::from_fn_with_state(
+        runtime.body_capture,
+        middleware::body_capture::capture,
+    ))
+    .layer(axum_middleware::from_fn(
+        middleware::access_log::capture_identity,
+    ))
     .layer(axum_middleware::from_fn_with_state(
         runtime.rate_limit_state,
         rate_limit_for_paths,
@@ -203,6 +229,17 @@ pub fn compose_application_router(
     .layer(axum_middleware::from_fn(
         middleware::security_headers::security_headers,
     ))
+    .layer(axum_middleware::from_fn_with_state(
+        cors_policy,
+        middleware::cors::cors,
+    ))
+    .layer(axum_middleware::from_fn(middleware::panic_capture::capture))
+    .layer(axum_middleware::from_fn_with_state(
+        access_log_settings,
+        middleware::access_log::log,
+    ))
+    .layer(axum_middleware::from_fn(middleware::http_metrics::record))
+    .layer(axum_middleware::from_fn(middleware::request_id::propagate))
 }
 
 #[cfg(test)]
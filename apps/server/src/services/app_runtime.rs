@@ -9,6 +9,7 @@ use crate::auth::auth_config_from_ctx;
 use crate::common::settings::{RustokSettings, SharedRustokSettings};
 use crate::graphql::AppSchema;
 use crate::middleware;
+use crate::middleware::body_capture::BodyCaptureControl;
 use crate::middleware::rate_limit::{
     cleanup_task, PathRateLimitMiddlewareState, PathRateLimitPolicy, RateLimitConfig, RateLimiter,
     SharedApiRateLimiter, SharedAuthRateLimiter, SharedOAuthRateLimiter, SharedSearchRateLimiter,
@@ -34,6 +35,7 @@ pub struct AppRuntimeBootstrap {
     pub registry: ModuleRegistry,
     pub graphql_schema: Arc<AppSchema>,
     pub rate_limit_state: PathRateLimitMiddlewareState,
+    pub body_capture: BodyCaptureControl,
 }
 
 fn validate_compiled_surface_contract(
@@ -67,6 +69,9 @@ pub async fn bootstrap_app_runtime(
     ctx.shared_store
         .insert(SharedRustokSettings(Arc::new(settings.clone())));
 
+    let body_capture = BodyCaptureControl::new(settings.runtime.body_capture.clone());
+    ctx.shared_store.insert(body_capture.clone());
+
     init_marketplace_catalog(ctx);
 
     let manifest = PlatformCompositionService::active_manifest(&ctx.db)
@@ -113,6 +118,8 @@ pub async fn bootstrap_app_runtime(
                 ))
             })?;
         middleware::tenant::init_tenant_cache_infrastructure(ctx, &cache_service).await;
+        #[cfg(feature = "mod-content")]
+        crate::services::content_nodes::init_node_cache(ctx, &cache_service).await;
         init_content_orchestration(ctx);
 
         init_storage(ctx, settings).await?;
@@ -124,7 +131,7 @@ pub async fn bootstrap_app_runtime(
             tracing::info!("Workflow cron scheduler disabled by runtime.background_workers config");
         }
 
-        init_alloy_runtime(ctx);
+        init_alloy_runtime(ctx, &cache_service).await;
     }
 
     let graphql_schema = init_graphql_schema(ctx);
@@ -135,6 +142,7 @@ pub async fn bootstrap_app_runtime(
         registry,
         graphql_schema,
         rate_limit_state: rate_limits.combined_state,
+        body_capture,
     })
 }
 
@@ -167,10 +175,13 @@ fn init_marketplace_catalog(ctx: &AppContext) {
         .insert(SharedMarketplaceCatalogService(marketplace_catalog));
 }
 
-fn init_alloy_runtime(_ctx: &AppContext) {
+async fn init_alloy_runtime(_ctx: &AppContext, _cache_service: &CacheService) {
     #[cfg(feature = "mod-alloy")]
     {
-        alloy::init(_ctx);
+        let kv_backend = _cache_service
+            .backend("alloy-kv", std::time::Duration::from_secs(60 * 60), 10_000)
+            .await;
+        alloy::init(_ctx, kv_backend);
     }
 }
 
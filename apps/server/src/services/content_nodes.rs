@@ -0,0 +1,150 @@
+//! Server-side construction of content `NodeService`s.
+//!
+//! Every service built here shares one node cache, so the `node:{id}:` entries written by
+//! one request are dropped by a mutation made through any other.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use loco_rs::app::AppContext;
+use rustok_cache::CacheService;
+use rustok_content::services::NodeService;
+use rustok_core::CacheBackend;
+
+use crate::services::event_bus::transactional_event_bus_from_context;
+
+const NODE_CACHE_PREFIX: &str = "content-nodes";
+const NODE_CACHE_TTL: Duration = Duration::from_secs(300);
+const NODE_CACHE_MAX_CAPACITY: u64 = 10_000;
+
+#[derive(Clone)]
+pub struct SharedNodeCache(pub Arc<dyn CacheBackend>);
+
+pub async fn init_node_cache(ctx: &AppContext, cache_service: &CacheService) {
+    let cache = cache_service
+        .backend(NODE_CACHE_PREFIX, NODE_CACHE_TTL, NODE_CACHE_MAX_CAPACITY)
+        .await;
+    ctx.shared_store.insert(SharedNodeCache(cache));
+}
+
+/// A `NodeService` wired to the shared node cache, when [`init_node_cache`] has run.
+pub fn node_service_from_ctx(ctx: &AppContext) -> NodeService {
+    let service = NodeService::new(ctx.db.clone(), transactional_event_bus_from_context(ctx));
+    match ctx.shared_store.get::<SharedNodeCache>() {
+        Some(SharedNodeCache(cache)) => service.with_cache(cache),
+        None => service,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{node_service_from_ctx, SharedNodeCache};
+    use loco_rs::{
+        app::{AppContext, SharedStore},
+        cache,
+        environment::Environment,
+        storage::{self, Storage},
+        tests_cfg::config::test_config,
+    };
+    use rustok_content::entities::node::ContentStatus;
+    use rustok_content::services::node_cache_prefix;
+    use rustok_content::{ContentModule, CreateNodeInput, NodeTranslationInput, UpdateNodeInput};
+    use rustok_core::events::EventTransport;
+    use rustok_core::{
+        CacheBackend, InMemoryCacheBackend, MemoryTransport, MigrationSource, SecurityContext,
+    };
+    use sea_orm::{ConnectOptions, Database, DatabaseConnection};
+    use sea_orm_migration::SchemaManager;
+    use std::{sync::Arc, time::Duration};
+    use uuid::Uuid;
+
+    async fn content_db() -> DatabaseConnection {
+        let mut opts = ConnectOptions::new(format!(
+            "sqlite:file:server_content_nodes_{}?mode=memory&cache=shared",
+            Uuid::new_v4()
+        ));
+        opts.max_connections(5)
+            .min_connections(1)
+            .sqlx_logging(false);
+        let db = Database::connect(opts)
+            .await
+            .expect("in-memory sqlite db should connect");
+        let manager = SchemaManager::new(&db);
+        for migration in ContentModule.migrations() {
+            migration
+                .up(&manager)
+                .await
+                .expect("content migration should apply");
+        }
+        db
+    }
+
+    fn test_app_context(db: DatabaseConnection) -> AppContext {
+        AppContext {
+            environment: Environment::Test,
+            db,
+            queue_provider: None,
+            config: test_config(),
+            mailer: None,
+            storage: Storage::single(storage::drivers::mem::new()).into(),
+            cache: Arc::new(cache::Cache::new(cache::drivers::null::new())),
+            shared_store: Arc::new(SharedStore::default()),
+        }
+    }
+
+    #[tokio::test]
+    async fn services_from_context_share_the_node_cache() {
+        let ctx = test_app_context(content_db().await);
+        let transport: Arc<dyn EventTransport> = Arc::new(MemoryTransport::new());
+        ctx.shared_store.insert(transport);
+        let cache = Arc::new(InMemoryCacheBackend::new(Duration::from_secs(300), 100));
+        ctx.shared_store.insert(SharedNodeCache(cache.clone()));
+        let tenant_id = Uuid::new_v4();
+
+        let node = node_service_from_ctx(&ctx)
+            .create_node(
+                tenant_id,
+                SecurityContext::system(),
+                CreateNodeInput {
+                    kind: "post".to_string(),
+                    translations: vec![NodeTranslationInput {
+                        locale: "en".to_string(),
+                        title: Some("Cached post".to_string()),
+                        slug: Some("cached-post".to_string()),
+                        excerpt: None,
+                    }],
+                    bodies: Vec::new(),
+                    status: Some(ContentStatus::Draft),
+                    parent_id: None,
+                    author_id: None,
+                    category_id: None,
+                    position: None,
+                    depth: None,
+                    reply_count: None,
+                    metadata: serde_json::json!({}),
+                },
+            )
+            .await
+            .expect("node should be created");
+        let body_key = format!("{}body", node_cache_prefix(node.id));
+        cache
+            .set(body_key.clone(), b"rendered".to_vec())
+            .await
+            .expect("cache entry should be stored");
+
+        node_service_from_ctx(&ctx)
+            .update_node(
+                tenant_id,
+                node.id,
+                SecurityContext::system(),
+                UpdateNodeInput {
+                    status: Some(ContentStatus::Published),
+                    ..UpdateNodeInput::default()
+                },
+            )
+            .await
+            .expect("node should be updated");
+
+        assert_eq!(cache.get(&body_key).await.unwrap(), None);
+    }
+}
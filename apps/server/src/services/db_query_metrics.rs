@@ -0,0 +1,209 @@
+//! Database query metrics and slow-query logging.
+//!
+//! Installed on `ctx.db` through SeaORM's metric callback, so every statement executed
+//! through the shared connection is timed. Durations land in
+//! `rustok_database_query_duration_seconds{query_type, table}`; statements at or above
+//! the configured threshold are logged under [`SLOW_QUERY_LOG_TARGET`] with string and
+//! numeric literals replaced by `?`. Bound values are never logged.
+
+use std::time::Duration;
+
+use rustok_telemetry::metrics::{record_db_query_duration, record_db_query_error};
+use sea_orm::{metric::Info, DatabaseConnection};
+
+use crate::common::settings::RuntimeDatabaseSettings;
+
+pub const SLOW_QUERY_LOG_TARGET: &str = "db.slow_query";
+
+#[derive(Debug, Clone, Copy)]
+pub struct DbQueryMetrics {
+    slow_threshold: Option<Duration>,
+}
+
+impl DbQueryMetrics {
+    /// `None` records metrics only and never logs slow queries.
+    pub fn new(slow_threshold: Option<Duration>) -> Self {
+        Self { slow_threshold }
+    }
+
+    pub fn from_settings(settings: &RuntimeDatabaseSettings) -> Self {
+        Self::new(
+            (settings.slow_query_threshold_ms > 0)
+                .then(|| Duration::from_millis(settings.slow_query_threshold_ms)),
+        )
+    }
+
+    pub fn install(self, db: &mut DatabaseConnection) {
+        db.set_metric_callback(move |info| self.observe(info));
+    }
+
+    pub fn observe(&self, info: &Info<'_>) {
+        let sql = info.statement.sql.as_str();
+        let query_type = query_type(sql);
+        let table = query_table(sql);
+
+        record_db_query_duration(query_type, &table, info.elapsed.as_secs_f64());
+        if info.failed {
+            record_db_query_error(query_type, "execution_failed");
+        }
+
+        if self
+            .slow_threshold
+            .is_some_and(|threshold| info.elapsed >= threshold)
+        {
+            tracing::warn!(
+                target: SLOW_QUERY_LOG_TARGET,
+                elapsed_ms = info.elapsed.as_millis() as u64,
+                query_type,
+                table = %table,
+                failed = info.failed,
+                sql = %redact_sql(sql),
+                "slow database query"
+            );
+        }
+    }
+}
+
+fn query_type(sql: &str) -> &'static str {
+    let keyword = sql.split_whitespace().next().unwrap_or_default();
+    ["SELECT", "INSERT", "UPDATE", "DELETE", "WITH"]
+        .into_iter()
+        .find(|candidate| keyword.eq_ignore_ascii_case(candidate))
+        .unwrap_or("OTHER")
+}
+
+/// First table the statement reads from or writes to, without quotes or schema.
+fn query_table(sql: &str) -> String {
+    let tokens: Vec<&str> = sql.split_whitespace().collect();
+    let anchor = match query_type(sql) {
+        "INSERT" => "INTO",
+        "UPDATE" => "UPDATE",
+        _ => "FROM",
+    };
+
+    tokens
+        .iter()
+        .position(|token| token.eq_ignore_ascii_case(anchor))
+        .and_then(|index| tokens.get(index + 1))
+        .map(|table| {
+            let table = table.rsplit('.').next().unwrap_or(table);
+            table
+                .trim_matches(|c: char| c == '"' || c == '`' || c == '(' || c == ')' || c == ',')
+                .to_string()
+        })
+        .filter(|table| !table.is_empty())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// Replaces quoted string literals and bare numeric literals with `?`.
+fn redact_sql(sql: &str) -> String {
+    let mut output = String::with_capacity(sql.len());
+    let mut chars = sql.chars().peekable();
+    let mut previous: Option<char> = None;
+
+    while let Some(current) = chars.next() {
+        if current == '\'' {
+            while let Some(next) = chars.next() {
+                if next == '\'' {
+                    if chars.peek() == Some(&'\'') {
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+            }
+            output.push('?');
+            previous = Some('?');
+            continue;
+        }
+
+        let starts_literal = current.is_ascii_digit()
+            && !previous.is_some_and(|p| p.is_alphanumeric() || matches!(p, '_' | '$' | '"'));
+        if starts_literal {
+            while chars
+                .peek()
+                .is_some_and(|next| next.is_ascii_digit() || *next == '.')
+            {
+                chars.next();
+            }
+            output.push('?');
+            previous = Some('?');
+            continue;
+        }
+
+        output.push(current);
+        previous = Some(current);
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use rustok_telemetry::metrics::DATABASE_QUERY_DURATION_SECONDS;
+    use sea_orm::{ConnectionTrait, Database, DbBackend, Statement};
+
+    use super::*;
+    use crate::common::log_capture::LogCapture;
+
+    async fn connect(metrics: DbQueryMetrics) -> DatabaseConnection {
+        let mut db = Database::connect("sqlite::memory:").await.unwrap();
+        metrics.install(&mut db);
+        db
+    }
+
+    fn statement(sql: &str) -> Statement {
+        Statement::from_string(DbBackend::Sqlite, sql.to_string())
+    }
+
+    #[tokio::test]
+    async fn query_durations_are_recorded_per_table() {
+        let db = connect(DbQueryMetrics::new(None)).await;
+        db.execute(statement("CREATE TABLE db_metrics_probe (id INTEGER)"))
+            .await
+            .unwrap();
+
+        let histogram =
+            DATABASE_QUERY_DURATION_SECONDS.with_label_values(&["SELECT", "db_metrics_probe"]);
+        let before = histogram.get_sample_count();
+        db.query_one(statement("SELECT count(*) FROM db_metrics_probe"))
+            .await
+            .unwrap();
+
+        assert_eq!(histogram.get_sample_count(), before + 1);
+    }
+
+    #[tokio::test]
+    async fn slow_query_is_logged_without_literals() {
+        let capture = LogCapture::new(SLOW_QUERY_LOG_TARGET);
+        let _guard = capture.install();
+        let db = connect(DbQueryMetrics::new(Some(Duration::from_millis(5)))).await;
+
+        db.query_one(statement(
+            "WITH RECURSIVE counter(x) AS (SELECT 1 UNION ALL SELECT x + 1 FROM counter WHERE x < 3000000) \
+             SELECT count(*), 'owner@example.com' FROM counter",
+        ))
+        .await
+        .unwrap();
+
+        let logged = capture.field_values("sql");
+        assert_eq!(logged.len(), 1, "expected one slow query log: {logged:?}");
+        assert!(logged[0].contains("WITH RECURSIVE counter"));
+        assert!(logged[0].contains("x < ?"));
+        assert!(!logged[0].contains("3000000"));
+        assert!(!logged[0].contains("owner@example.com"));
+    }
+
+    #[test]
+    fn sql_literals_are_redacted_but_placeholders_kept() {
+        assert_eq!(
+            redact_sql("SELECT * FROM \"users2\" WHERE email = 'a''b' AND id = $12 LIMIT 10"),
+            "SELECT * FROM \"users2\" WHERE email = ? AND id = $12 LIMIT ?"
+        );
+        assert_eq!(
+            query_table("INSERT INTO \"public\".\"pages\" (id) VALUES ($1)"),
+            "pages"
+        );
+        assert_eq!(query_type("update pages SET title = $1"), "UPDATE");
+    }
+}
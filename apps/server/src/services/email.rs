@@ -10,9 +10,12 @@ pub struct SharedSmtpEmailService(pub Arc<rustok_email::SmtpEmailSender>);
 use async_trait::async_trait;
 use loco_rs::app::AppContext;
 use loco_rs::mailer::{Email, EmailSender};
-use rustok_email::{EmailError, RenderedEmail};
+use rustok_email::{
+    default_notification_templates, DisabledSmsSender, EmailError, NotificationChannels,
+    RenderedEmail,
+};
 
-use crate::common::settings::{EmailProvider, RustokSettings};
+use crate::common::settings::{EmailProvider, EmailSettings, RustokSettings};
 use crate::error::{Error, Result};
 
 #[derive(Debug, Clone)]
@@ -298,6 +301,47 @@ impl BuiltInAuthEmailSender for TemplatedSmtpMailerAdapter {
 
 // ── Factory ──────────────────────────────────────────────────────────────────
 
+fn smtp_email_config(email: &EmailSettings) -> rustok_email::EmailConfig {
+    rustok_email::EmailConfig {
+        enabled: email.enabled,
+        smtp: rustok_email::SmtpConfig {
+            host: email.smtp.host.clone(),
+            port: email.smtp.port,
+            username: email.smtp.username.clone(),
+            password: email.smtp.password.clone(),
+        },
+        from: email.from.clone(),
+        reset_base_url: email.reset_base_url.clone(),
+    }
+}
+
+/// Channels for `EmailModule`'s notification listener, inserted into the shared
+/// `ModuleRuntimeExtensions` at startup.
+///
+/// Only the `smtp` provider delivers notification emails: the `loco` mailer lives on
+/// `AppContext`, which does not exist yet when the extensions are built, so `loco` and
+/// `none` get the disabled sender (sends are logged and skipped). SMS is not configured
+/// by the server yet and is always disabled.
+pub fn notification_channels(settings: &RustokSettings) -> NotificationChannels {
+    let email = match settings.email.provider {
+        EmailProvider::Smtp => EmailService::from_config(&smtp_email_config(&settings.email))
+            .unwrap_or_else(|error| {
+                tracing::warn!(
+                    %error,
+                    "SMTP notification sender unavailable; notification emails disabled"
+                );
+                EmailService::Disabled
+            }),
+        EmailProvider::Loco | EmailProvider::None => EmailService::Disabled,
+    };
+
+    NotificationChannels {
+        email: Arc::new(email),
+        sms: Arc::new(DisabledSmsSender),
+        templates: Arc::new(default_notification_templates()),
+    }
+}
+
 /// Build a localized built-in auth email sender from `AppContext`.
 ///
 /// `locale` is used to render localized built-in auth templates for both `loco`
@@ -343,18 +387,8 @@ pub fn email_service_from_ctx(
                 )));
             }
 
-            let config = rustok_email::EmailConfig {
-                enabled: settings.email.enabled,
-                smtp: rustok_email::SmtpConfig {
-                    host: settings.email.smtp.host,
-                    port: settings.email.smtp.port,
-                    username: settings.email.smtp.username,
-                    password: settings.email.smtp.password,
-                },
-                from: settings.email.from,
-                reset_base_url: settings.email.reset_base_url,
-            };
-            let service = EmailService::from_config(&config).map_err(email_err)?;
+            let service = EmailService::from_config(&smtp_email_config(&settings.email))
+                .map_err(email_err)?;
             let EmailService::Smtp(sender) = service else {
                 return Ok(Box::new(DisabledBuiltInAuthEmailSender));
             };
@@ -4,7 +4,10 @@ pub mod app_runtime;
 pub mod auth_lifecycle;
 pub mod build_event_hub;
 pub mod build_executor;
+#[cfg(feature = "mod-content")]
+pub mod content_nodes;
 pub mod content_orchestration;
+pub mod db_query_metrics;
 pub mod effective_module_policy;
 pub mod email;
 pub mod event_bus;
@@ -33,6 +36,7 @@ pub mod registry_principal;
 pub mod release_backend;
 pub mod runtime_guardrails;
 pub mod settings_service;
+pub mod tenant_isolation;
 pub mod topic_field_service;
 pub mod user_field_service;
 
@@ -4,9 +4,57 @@ use rustok_core::{EventBus, ModuleEventListenerContext, ModuleRegistry, ModuleRu
 use rustok_index::IndexerRuntimeConfig;
 use rustok_telemetry::metrics;
 use sea_orm::DatabaseConnection;
+use std::sync::atomic::{AtomicU8, Ordering};
 use std::sync::Arc;
 
 use crate::common::settings::RustokSettings;
+use crate::services::email::notification_channels;
+
+/// Lifecycle state of the module event dispatcher task.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DispatcherState {
+    /// No module-owned listeners are registered, so no dispatcher task was spawned.
+    Idle,
+    Running,
+    Stopped,
+}
+
+/// Shared liveness marker for the module event dispatcher.
+///
+/// Stored in `shared_store` by [`spawn_module_event_dispatcher`] so readiness
+/// probes can tell whether the dispatcher task is still alive.
+#[derive(Debug, Clone)]
+pub struct DispatcherLiveness(Arc<AtomicU8>);
+
+impl DispatcherLiveness {
+    pub fn new(state: DispatcherState) -> Self {
+        let liveness = Self(Arc::new(AtomicU8::new(0)));
+        liveness.set(state);
+        liveness
+    }
+
+    pub fn set(&self, state: DispatcherState) {
+        let value = match state {
+            DispatcherState::Idle => 0,
+            DispatcherState::Running => 1,
+            DispatcherState::Stopped => 2,
+        };
+        self.0.store(value, Ordering::Release);
+    }
+
+    pub fn state(&self) -> DispatcherState {
+        match self.0.load(Ordering::Acquire) {
+            0 => DispatcherState::Idle,
+            1 => DispatcherState::Running,
+            _ => DispatcherState::Stopped,
+        }
+    }
+
+    /// `true` while the dispatcher is running or intentionally idle.
+    pub fn is_alive(&self) -> bool {
+        self.state() != DispatcherState::Stopped
+    }
+}
 
 pub fn spawn_module_event_dispatcher(
     ctx: &AppContext,
@@ -19,14 +67,20 @@ pub fn spawn_module_event_dispatcher(
     let handler_count = dispatcher.handler_count();
     if handler_count == 0 {
         tracing::info!("No module-owned event listeners registered in ModuleRegistry");
+        ctx.shared_store
+            .insert(DispatcherLiveness::new(DispatcherState::Idle));
         return;
     }
 
+    let liveness = DispatcherLiveness::new(DispatcherState::Running);
+    ctx.shared_store.insert(liveness.clone());
+
     let running = dispatcher.start();
     tokio::spawn(async move {
         if let Err(error) = running.join().await {
             tracing::error!("Module event dispatcher panicked: {:?}", error);
         }
+        liveness.set(DispatcherState::Stopped);
     });
 
     tracing::info!(handler_count, "Module event dispatcher initialized");
@@ -61,6 +115,7 @@ pub fn build_shared_runtime_extensions(
         settings.search.reindex.yield_every,
     );
     extensions.insert(indexer_runtime);
+    extensions.insert(notification_channels(settings));
     Arc::new(extensions)
 }
 
@@ -90,9 +145,13 @@ pub fn build_module_event_dispatcher(
 
 #[cfg(test)]
 mod tests {
-    use super::{build_module_event_dispatcher, build_shared_runtime_extensions};
+    use super::{
+        build_module_event_dispatcher, build_shared_runtime_extensions, DispatcherLiveness,
+        DispatcherState,
+    };
     use crate::common::settings::RustokSettings;
     use rustok_core::{EventBus, ModuleRegistry};
+    use rustok_email::{EmailModule, NotificationChannels};
     use rustok_index::IndexModule;
     use rustok_search::SearchModule;
     use sea_orm::Database;
@@ -116,4 +175,33 @@ mod tests {
         let expected = if cfg!(feature = "mod-workflow") { 5 } else { 4 };
         assert_eq!(dispatcher.handler_count(), expected);
     }
+
+    #[tokio::test]
+    async fn shared_extensions_register_the_email_notification_handler() {
+        let registry = ModuleRegistry::new().register(EmailModule);
+        let settings = RustokSettings::default();
+        let extensions = build_shared_runtime_extensions(&registry, &settings);
+        assert!(extensions.get::<NotificationChannels>().is_some());
+
+        let db = Database::connect("sqlite::memory:")
+            .await
+            .expect("in-memory sqlite should connect");
+        let dispatcher =
+            build_module_event_dispatcher(&registry, EventBus::default(), db, extensions.as_ref());
+
+        assert_eq!(dispatcher.handler_count(), 1);
+    }
+
+    #[test]
+    fn dispatcher_liveness_tracks_state_transitions() {
+        let liveness = DispatcherLiveness::new(DispatcherState::Running);
+        let observer = liveness.clone();
+        assert!(observer.is_alive());
+
+        liveness.set(DispatcherState::Stopped);
+
+        assert_eq!(observer.state(), DispatcherState::Stopped);
+        assert!(!observer.is_alive());
+        assert!(DispatcherLiveness::new(DispatcherState::Idle).is_alive());
+    }
 }
@@ -0,0 +1,124 @@
+//! Cross-tenant probes for the server's own tenant-scoped lookups, and the
+//! [`TenantIsolationCheck`] that runs them together with the module probes.
+
+use async_trait::async_trait;
+use loco_rs::app::AppContext;
+use rustok_core::security::{CrossTenantProbe, TenantIsolationCheck};
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter};
+use uuid::Uuid;
+
+use crate::models::users;
+
+/// Looks up every user of the owner tenant by email, scoped to the reader tenant.
+pub struct UserEmailLookupProbe;
+
+#[async_trait]
+impl CrossTenantProbe for UserEmailLookupProbe {
+    fn name(&self) -> &str {
+        "users.find_by_email"
+    }
+
+    async fn read_foreign(
+        &self,
+        db: &DatabaseConnection,
+        reader: Uuid,
+        owner: Uuid,
+    ) -> Result<usize, String> {
+        let owned = users::Entity::find()
+            .filter(users::Column::TenantId.eq(owner))
+            .all(db)
+            .await
+            .map_err(|err| err.to_string())?;
+        if owned.is_empty() {
+            return Err(format!("tenant {owner} has no seeded users"));
+        }
+
+        let mut leaked = 0;
+        for user in &owned {
+            let found = users::Entity::find_by_email(db, reader, &user.email)
+                .await
+                .map_err(|err| err.to_string())?;
+            if found.is_some_and(|found| found.id == user.id) {
+                leaked += 1;
+            }
+        }
+        Ok(leaked)
+    }
+}
+
+/// Isolation check between two seeded tenants, probing users and, with `mod-content`,
+/// content nodes.
+pub fn tenant_isolation_check(
+    ctx: &AppContext,
+    tenant_a: Uuid,
+    tenant_b: Uuid,
+) -> TenantIsolationCheck {
+    let check = TenantIsolationCheck::new(tenant_a, tenant_b).with_probe(UserEmailLookupProbe);
+
+    #[cfg(feature = "mod-content")]
+    let check = check.with_probe(rustok_content::services::NodeIsolationProbe::new(
+        crate::services::content_nodes::node_service_from_ctx(ctx),
+    ));
+    #[cfg(not(feature = "mod-content"))]
+    let _ = ctx;
+
+    check
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UserEmailLookupProbe;
+    use crate::models::{tenants, users};
+    use migration::Migrator;
+    use rustok_core::security::{
+        AsyncSecurityCheck, CrossTenantProbe, SecurityConfig, TenantIsolationCheck,
+    };
+    use rustok_test_utils::db::setup_test_db_with_migrations;
+    use sea_orm::ActiveModelTrait;
+
+    #[tokio::test]
+    async fn user_email_lookup_stays_inside_the_reader_tenant() {
+        let db = setup_test_db_with_migrations::<Migrator>().await;
+        let mut tenant_ids = Vec::new();
+        for slug in ["isolation-a", "isolation-b"] {
+            let tenant = tenants::ActiveModel::new(slug, slug)
+                .insert(&db)
+                .await
+                .expect("tenant should insert");
+            users::ActiveModel::new(tenant.id, "shared@example.com", "hash")
+                .insert(&db)
+                .await
+                .expect("user should insert");
+            tenant_ids.push(tenant.id);
+        }
+        let (tenant_a, tenant_b) = (tenant_ids[0], tenant_ids[1]);
+
+        assert_eq!(
+            UserEmailLookupProbe
+                .read_foreign(&db, tenant_a, tenant_b)
+                .await,
+            Ok(0)
+        );
+
+        let findings = TenantIsolationCheck::new(tenant_a, tenant_b)
+            .with_probe(UserEmailLookupProbe)
+            .execute(&SecurityConfig::default(), &db)
+            .await;
+        assert!(findings.is_empty(), "{findings:?}");
+    }
+
+    #[tokio::test]
+    async fn user_email_lookup_fails_for_a_tenant_without_users() {
+        let db = setup_test_db_with_migrations::<Migrator>().await;
+        let tenant = tenants::ActiveModel::new("isolation-empty", "isolation-empty")
+            .insert(&db)
+            .await
+            .expect("tenant should insert");
+
+        let result = UserEmailLookupProbe
+            .read_foreign(&db, uuid::Uuid::new_v4(), tenant.id)
+            .await;
+
+        assert!(result.unwrap_err().contains("no seeded users"));
+    }
+}
@@ -2,7 +2,11 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use uuid::Uuid;
 
-use crate::model::{Script, ScriptId, ScriptStatus, ScriptTrigger};
+use crate::bundle::ScriptBundle;
+use crate::model::{FieldChange, Script, ScriptId, ScriptStatus, ScriptTrigger};
+use crate::runner::ExecutionResult;
+use crate::storage::{ScriptFilter, ScriptQuery};
+use crate::utils::dynamic_to_json;
 
 // ============ Requests ============
 
@@ -15,6 +19,8 @@ pub struct CreateScriptRequest {
     #[serde(default)]
     pub permissions: Vec<String>,
     #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
     pub run_as_system: bool,
     pub tenant_id: Option<Uuid>,
 }
@@ -27,6 +33,7 @@ pub struct UpdateScriptRequest {
     pub trigger: Option<ScriptTrigger>,
     pub status: Option<ScriptStatus>,
     pub permissions: Option<Vec<String>>,
+    pub tags: Option<Vec<String>>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -43,6 +50,14 @@ pub struct EntityInput {
     pub data: HashMap<String, serde_json::Value>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct ImportScriptsRequest {
+    pub bundle: ScriptBundle,
+    /// Replace scripts that already exist under the same name.
+    #[serde(default)]
+    pub overwrite: bool,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct ListScriptsQuery {
     #[serde(default = "default_page")]
@@ -51,6 +66,14 @@ pub struct ListScriptsQuery {
     pub per_page: u32,
     #[serde(default)]
     pub status: Option<String>,
+    /// Case-insensitive substring of the script name.
+    #[serde(default)]
+    pub search: Option<String>,
+    #[serde(default)]
+    pub tag: Option<String>,
+    /// `event`, `cron`, `manual` or `api`.
+    #[serde(default)]
+    pub trigger_type: Option<String>,
 }
 
 fn default_page() -> u32 {
@@ -69,6 +92,32 @@ impl ListScriptsQuery {
     pub fn limit(&self) -> u64 {
         self.per_page.min(100) as u64
     }
+
+    pub fn script_query(&self) -> ScriptQuery {
+        let filter = ScriptFilter {
+            search: non_empty(&self.search),
+            tag: non_empty(&self.tag),
+            status: self.status.as_deref().and_then(ScriptStatus::parse),
+            trigger_type: non_empty(&self.trigger_type),
+            event: None,
+        };
+
+        if filter.search.is_none() && filter.tag.is_none() && filter.trigger_type.is_none() {
+            return match filter.status {
+                Some(status) => ScriptQuery::ByStatus(status),
+                None => ScriptQuery::All,
+            };
+        }
+        ScriptQuery::Filter(filter)
+    }
+}
+
+fn non_empty(value: &Option<String>) -> Option<String> {
+    value
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
 }
 
 // ============ Responses ============
@@ -81,6 +130,7 @@ pub struct ScriptResponse {
     pub code: String,
     pub trigger: ScriptTrigger,
     pub status: ScriptStatus,
+    pub tags: Vec<String>,
     pub version: u32,
     pub error_count: u32,
     pub created_at: String,
@@ -96,6 +146,7 @@ impl From<Script> for ScriptResponse {
             code: s.code,
             trigger: s.trigger,
             status: s.status,
+            tags: s.tags,
             version: s.version,
             error_count: s.error_count,
             created_at: s.created_at.to_rfc3339(),
@@ -111,9 +162,36 @@ pub struct RunScriptResponse {
     pub duration_ms: i64,
     pub error: Option<String>,
     pub changes: Option<HashMap<String, serde_json::Value>>,
+    /// Dry-run diff of the input entity: `{field: {from, to}}`.
+    pub changed: HashMap<String, FieldDiff>,
     pub return_value: serde_json::Value,
 }
 
+#[derive(Debug, Serialize)]
+pub struct FieldDiff {
+    pub from: serde_json::Value,
+    pub to: serde_json::Value,
+}
+
+impl From<FieldChange> for FieldDiff {
+    fn from(change: FieldChange) -> Self {
+        Self {
+            from: dynamic_to_json(change.from),
+            to: dynamic_to_json(change.to),
+        }
+    }
+}
+
+impl FieldDiff {
+    pub fn from_result(result: &ExecutionResult) -> HashMap<String, Self> {
+        result
+            .entity_diff()
+            .into_iter()
+            .map(|(field, change)| (field, change.into()))
+            .collect()
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct ListScriptsResponse {
     pub scripts: Vec<ScriptResponse>,
@@ -11,9 +11,9 @@ use tracing::{info, instrument};
 use uuid::Uuid;
 
 use crate::error::ScriptError;
-use crate::model::{EntityProxy, ScriptStatus};
+use crate::model::EntityProxy;
 use crate::runner::ScriptOrchestrator;
-use crate::storage::{ScriptQuery, ScriptRegistry};
+use crate::storage::ScriptRegistry;
 use crate::utils::{dynamic_to_json, json_to_dynamic};
 
 use super::dto::*;
@@ -32,6 +32,7 @@ impl IntoResponse for ApiError {
             "not_found" => StatusCode::NOT_FOUND,
             "validation" => StatusCode::BAD_REQUEST,
             "conflict" => StatusCode::CONFLICT,
+            "rate_limited" => StatusCode::TOO_MANY_REQUESTS,
             _ => StatusCode::INTERNAL_SERVER_ERROR,
         };
         (status, Json(self)).into_response()
@@ -57,6 +58,18 @@ impl From<ScriptError> for ApiError {
                 error: format!("Invalid status: {msg}"),
                 code: "validation".to_string(),
             },
+            ScriptError::InvalidBundle(msg) => ApiError {
+                error: format!("Invalid bundle: {msg}"),
+                code: "validation".to_string(),
+            },
+            ScriptError::ForbiddenCall { .. } => ApiError {
+                error: e.to_string(),
+                code: "validation".to_string(),
+            },
+            ScriptError::RateLimited(_) => ApiError {
+                error: e.to_string(),
+                code: "rate_limited".to_string(),
+            },
             _ => ApiError {
                 error: e.to_string(),
                 code: "internal".to_string(),
@@ -72,10 +85,7 @@ pub async fn list_scripts<S: ScriptRegistry>(
     State(state): State<Arc<AppState<S>>>,
     Query(query): Query<ListScriptsQuery>,
 ) -> ApiResult<Json<ListScriptsResponse>> {
-    let script_query = match query.status.as_deref().and_then(ScriptStatus::parse) {
-        Some(status) => ScriptQuery::ByStatus(status),
-        None => ScriptQuery::All,
-    };
+    let script_query = query.script_query();
 
     let offset = query.offset();
     let limit = query.limit();
@@ -124,6 +134,7 @@ pub async fn create_script<S: ScriptRegistry>(
     }
     script.description = req.description;
     script.permissions = req.permissions;
+    script.tags = req.tags;
     script.run_as_system = req.run_as_system;
 
     let saved = state.registry.save(script).await.map_err(ApiError::from)?;
@@ -160,6 +171,9 @@ pub async fn update_script<S: ScriptRegistry>(
     if let Some(perms) = req.permissions {
         script.permissions = perms;
     }
+    if let Some(tags) = req.tags {
+        script.tags = tags;
+    }
 
     let saved = state.registry.save(script).await.map_err(ApiError::from)?;
 
@@ -214,6 +228,7 @@ pub async fn run_script<S: ScriptRegistry>(
         .await
         .map_err(ApiError::from)?;
 
+    let changed = FieldDiff::from_result(&result);
     let (success, error, changes, return_value) = match &result.outcome {
         crate::runner::ExecutionOutcome::Success {
             return_value,
@@ -227,7 +242,7 @@ pub async fn run_script<S: ScriptRegistry>(
                 .map(dynamic_to_json)
                 .unwrap_or(serde_json::Value::Null),
         ),
-        crate::runner::ExecutionOutcome::Aborted { reason } => (
+        crate::runner::ExecutionOutcome::Aborted { reason, .. } => (
             false,
             Some(reason.to_string()),
             None,
@@ -254,6 +269,7 @@ pub async fn run_script<S: ScriptRegistry>(
         duration_ms: result.duration_ms(),
         error,
         changes,
+        changed,
         return_value,
     }))
 }
@@ -292,6 +308,7 @@ pub async fn run_script_by_name<S: ScriptRegistry>(
         .await
         .map_err(ApiError::from)?;
 
+    let changed = FieldDiff::from_result(&result);
     let (success, error, changes, return_value) = match &result.outcome {
         crate::runner::ExecutionOutcome::Success {
             return_value,
@@ -305,7 +322,7 @@ pub async fn run_script_by_name<S: ScriptRegistry>(
                 .map(dynamic_to_json)
                 .unwrap_or(serde_json::Value::Null),
         ),
-        crate::runner::ExecutionOutcome::Aborted { reason } => (
+        crate::runner::ExecutionOutcome::Aborted { reason, .. } => (
             false,
             Some(reason.to_string()),
             None,
@@ -325,6 +342,7 @@ pub async fn run_script_by_name<S: ScriptRegistry>(
         duration_ms: result.duration_ms(),
         error,
         changes,
+        changed,
         return_value,
     }))
 }
@@ -0,0 +1,341 @@
+use parking_lot::Mutex;
+use rustok_core::CircuitState;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
+use tracing::warn;
+
+/// Per-host circuit breaker settings for outbound script calls.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HostCircuitBreakerConfig {
+    /// Share of failed calls in the window that opens the breaker (0.0 - 1.0).
+    pub failure_ratio: f64,
+    /// Calls the window must contain before the ratio is evaluated.
+    pub min_calls: usize,
+    /// Number of most recent calls considered.
+    pub window_size: usize,
+    /// How long the breaker stays open before letting a probe call through.
+    pub open_duration: Duration,
+}
+
+impl Default for HostCircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_ratio: 0.5,
+            min_calls: 10,
+            window_size: 20,
+            open_duration: Duration::from_secs(30),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CircuitOpen {
+    pub host: String,
+    pub retry_after: Duration,
+}
+
+impl std::fmt::Display for CircuitOpen {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "circuit breaker open for host {}: retry in {}s",
+            self.host,
+            self.retry_after.as_secs().max(1)
+        )
+    }
+}
+
+struct HostBreaker {
+    state: CircuitState,
+    outcomes: VecDeque<bool>,
+    opened_at: Option<Instant>,
+    probe_in_flight: bool,
+}
+
+impl HostBreaker {
+    fn new() -> Self {
+        Self {
+            state: CircuitState::Closed,
+            outcomes: VecDeque::new(),
+            opened_at: None,
+            probe_in_flight: false,
+        }
+    }
+
+    fn failures(&self) -> usize {
+        self.outcomes.iter().filter(|ok| !**ok).count()
+    }
+}
+
+static REGISTRY: OnceLock<CircuitBreakerRegistry> = OnceLock::new();
+
+/// Circuit breakers keyed by host, shared by every script execution in the process.
+pub struct CircuitBreakerRegistry {
+    config: HostCircuitBreakerConfig,
+    hosts: Mutex<HashMap<String, Arc<Mutex<HostBreaker>>>>,
+}
+
+impl CircuitBreakerRegistry {
+    pub fn new(config: HostCircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            hosts: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Configures the process-wide registry; only the first call before [`Self::global`]
+    /// takes effect. Returns whether `config` was installed.
+    pub fn init(config: HostCircuitBreakerConfig) -> bool {
+        match REGISTRY.get() {
+            Some(registry) => {
+                if registry.config != config {
+                    warn!(
+                        target: "alloy::script",
+                        "Circuit breaker registry already initialised; ignoring new config"
+                    );
+                }
+                false
+            }
+            None => REGISTRY.set(CircuitBreakerRegistry::new(config)).is_ok(),
+        }
+    }
+
+    /// Process-wide registry used by the `http_*` bridge functions; falls back to the
+    /// default config when [`Self::init`] has not run.
+    pub fn global() -> &'static CircuitBreakerRegistry {
+        REGISTRY.get_or_init(|| CircuitBreakerRegistry::new(HostCircuitBreakerConfig::default()))
+    }
+
+    pub fn config(&self) -> &HostCircuitBreakerConfig {
+        &self.config
+    }
+
+    fn breaker(&self, host: &str) -> Arc<Mutex<HostBreaker>> {
+        Arc::clone(
+            self.hosts
+                .lock()
+                .entry(host.to_string())
+                .or_insert_with(|| Arc::new(Mutex::new(HostBreaker::new()))),
+        )
+    }
+
+    pub fn acquire(&self, host: &str) -> Result<(), CircuitOpen> {
+        self.acquire_at(host, Instant::now())
+    }
+
+    /// Admits a call unless the breaker is open; once `open_duration` has passed a single
+    /// probe is let through (half-open) and its outcome decides whether the breaker closes.
+    pub fn acquire_at(&self, host: &str, now: Instant) -> Result<(), CircuitOpen> {
+        let breaker = self.breaker(host);
+        let mut breaker = breaker.lock();
+
+        match breaker.state {
+            CircuitState::Closed => Ok(()),
+            CircuitState::Open => {
+                let opened_at = breaker.opened_at.unwrap_or(now);
+                let elapsed = now.saturating_duration_since(opened_at);
+                if elapsed < self.config.open_duration {
+                    record_rejected(host);
+                    return Err(CircuitOpen {
+                        host: host.to_string(),
+                        retry_after: self.config.open_duration - elapsed,
+                    });
+                }
+
+                transition(host, &mut breaker, CircuitState::HalfOpen);
+                breaker.probe_in_flight = true;
+                Ok(())
+            }
+            CircuitState::HalfOpen if breaker.probe_in_flight => {
+                record_rejected(host);
+                Err(CircuitOpen {
+                    host: host.to_string(),
+                    retry_after: Duration::ZERO,
+                })
+            }
+            CircuitState::HalfOpen => {
+                breaker.probe_in_flight = true;
+                Ok(())
+            }
+        }
+    }
+
+    pub fn record(&self, host: &str, success: bool) {
+        self.record_at(host, success, Instant::now());
+    }
+
+    pub fn record_at(&self, host: &str, success: bool, now: Instant) {
+        let breaker = self.breaker(host);
+        let mut breaker = breaker.lock();
+        rustok_telemetry::metrics::record_circuit_breaker_call(
+            &service_label(host),
+            if success { "success" } else { "failure" },
+        );
+
+        if breaker.state == CircuitState::HalfOpen {
+            breaker.probe_in_flight = false;
+            breaker.outcomes.clear();
+            if success {
+                breaker.opened_at = None;
+                transition(host, &mut breaker, CircuitState::Closed);
+            } else {
+                breaker.opened_at = Some(now);
+                transition(host, &mut breaker, CircuitState::Open);
+            }
+            return;
+        }
+
+        breaker.outcomes.push_back(success);
+        while breaker.outcomes.len() > self.config.window_size.max(1) {
+            breaker.outcomes.pop_front();
+        }
+
+        let calls = breaker.outcomes.len();
+        let failures = breaker.failures();
+        rustok_telemetry::metrics::update_circuit_breaker_failures(
+            &service_label(host),
+            failures as i64,
+        );
+
+        if breaker.state == CircuitState::Closed
+            && calls >= self.config.min_calls.max(1)
+            && failures as f64 / calls as f64 >= self.config.failure_ratio
+        {
+            warn!(
+                target: "alloy::script",
+                host,
+                failures,
+                calls,
+                "Opening circuit breaker for outbound host"
+            );
+            breaker.opened_at = Some(now);
+            transition(host, &mut breaker, CircuitState::Open);
+        }
+    }
+
+    pub fn state(&self, host: &str) -> CircuitState {
+        self.hosts
+            .lock()
+            .get(host)
+            .map(|breaker| breaker.lock().state)
+            .unwrap_or(CircuitState::Closed)
+    }
+
+    /// Current state of every host seen so far, sorted by host.
+    pub fn states(&self) -> Vec<(String, CircuitState)> {
+        let mut states = self
+            .hosts
+            .lock()
+            .iter()
+            .map(|(host, breaker)| (host.clone(), breaker.lock().state))
+            .collect::<Vec<_>>();
+        states.sort_by(|left, right| left.0.cmp(&right.0));
+        states
+    }
+}
+
+fn service_label(host: &str) -> String {
+    format!("alloy_http:{host}")
+}
+
+fn record_rejected(host: &str) {
+    rustok_telemetry::metrics::record_circuit_breaker_call(&service_label(host), "rejected");
+}
+
+fn transition(host: &str, breaker: &mut HostBreaker, to: CircuitState) {
+    let from = breaker.state;
+    breaker.state = to;
+
+    let service = service_label(host);
+    rustok_telemetry::metrics::record_circuit_breaker_transition(
+        &service,
+        from.as_str(),
+        to.as_str(),
+    );
+    rustok_telemetry::metrics::update_circuit_breaker_state(&service, to.as_u8() as i64);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registry() -> CircuitBreakerRegistry {
+        CircuitBreakerRegistry::new(HostCircuitBreakerConfig {
+            failure_ratio: 0.5,
+            min_calls: 4,
+            window_size: 10,
+            open_duration: Duration::from_secs(30),
+        })
+    }
+
+    #[test]
+    fn repeated_failures_open_the_breaker() {
+        let registry = registry();
+        let now = Instant::now();
+
+        registry.record_at("api.example.com", true, now);
+        for _ in 0..3 {
+            assert!(registry.acquire_at("api.example.com", now).is_ok());
+            registry.record_at("api.example.com", false, now);
+        }
+
+        assert_eq!(registry.state("api.example.com"), CircuitState::Open);
+        let rejected = registry
+            .acquire_at("api.example.com", now + Duration::from_secs(1))
+            .unwrap_err();
+        assert_eq!(rejected.host, "api.example.com");
+        assert_eq!(rejected.retry_after, Duration::from_secs(29));
+        assert_eq!(registry.state("other.example.com"), CircuitState::Closed);
+    }
+
+    #[test]
+    fn successful_probe_after_open_window_closes_the_breaker() {
+        let registry = registry();
+        let now = Instant::now();
+        for _ in 0..4 {
+            registry.record_at("api.example.com", false, now);
+        }
+        assert_eq!(registry.state("api.example.com"), CircuitState::Open);
+
+        let probe_at = now + Duration::from_secs(31);
+        assert!(registry.acquire_at("api.example.com", probe_at).is_ok());
+        assert_eq!(registry.state("api.example.com"), CircuitState::HalfOpen);
+        assert!(registry.acquire_at("api.example.com", probe_at).is_err());
+
+        registry.record_at("api.example.com", true, probe_at);
+
+        assert_eq!(registry.state("api.example.com"), CircuitState::Closed);
+        assert!(registry.acquire_at("api.example.com", probe_at).is_ok());
+    }
+
+    #[test]
+    fn failed_probe_reopens_the_breaker() {
+        let registry = registry();
+        let now = Instant::now();
+        for _ in 0..4 {
+            registry.record_at("api.example.com", false, now);
+        }
+
+        let probe_at = now + Duration::from_secs(31);
+        assert!(registry.acquire_at("api.example.com", probe_at).is_ok());
+        registry.record_at("api.example.com", false, probe_at);
+
+        assert_eq!(registry.state("api.example.com"), CircuitState::Open);
+        assert!(registry
+            .acquire_at("api.example.com", probe_at + Duration::from_secs(1))
+            .is_err());
+    }
+
+    #[test]
+    fn global_registry_keeps_its_first_config() {
+        let installed = CircuitBreakerRegistry::global().config().clone();
+        let other = HostCircuitBreakerConfig {
+            min_calls: installed.min_calls + 1,
+            ..installed.clone()
+        };
+
+        assert!(!CircuitBreakerRegistry::init(other));
+        assert_eq!(CircuitBreakerRegistry::global().config(), &installed);
+    }
+}
@@ -1,9 +1,17 @@
-use rhai::{Dynamic, Engine, Map};
+use rhai::{Dynamic, Engine, EvalAltResult, Map};
+use std::cell::Cell;
 use std::collections::HashMap;
 use tracing::{debug, warn};
 
+use super::circuit_breaker::CircuitBreakerRegistry;
+use super::rate_limit::check_outbound_budget;
+use crate::context::ExecutionPhase;
+use crate::error::ScriptError;
 use crate::utils::json_to_dynamic;
 
+/// Names registered by [`register_http`].
+pub(crate) const HTTP_FUNCTIONS: [&str; 3] = ["http_get", "http_post", "http_request"];
+
 pub fn register_http(engine: &mut Engine) {
     engine.register_fn("http_get", http_get);
     engine.register_fn("http_get", http_get_with_headers);
@@ -12,13 +20,92 @@ pub fn register_http(engine: &mut Engine) {
     engine.register_fn("http_request", http_request);
 }
 
-fn run_blocking<F, T>(f: F) -> T
+pub(super) fn run_blocking<F, T>(f: F) -> T
 where
     F: std::future::Future<Output = T>,
 {
     tokio::task::block_in_place(|| tokio::runtime::Handle::current().block_on(f))
 }
 
+/// Prefix the engine maps back to [`ScriptError::RateLimited`].
+pub(crate) const RATE_LIMITED_PREFIX: &str = "RATE_LIMITED:";
+
+/// Prefix the engine maps back to [`ScriptError::ForbiddenCall`].
+pub(crate) const FORBIDDEN_CALL_PREFIX: &str = "FORBIDDEN_CALL:";
+
+type HttpResult = Result<Map, Box<EvalAltResult>>;
+
+thread_local! {
+    // Phase of the script evaluating on this thread. The engine also rejects scripts that
+    // name an `http_*` function, but a name built at run time (`Fn("http_" + "post")`)
+    // only shows up here.
+    static EXECUTING_PHASE: Cell<Option<ExecutionPhase>> = const { Cell::new(None) };
+}
+
+/// Restores the previous phase when the script evaluation ends.
+pub(crate) struct PhaseScopeGuard {
+    previous: Option<ExecutionPhase>,
+}
+
+impl Drop for PhaseScopeGuard {
+    fn drop(&mut self) {
+        EXECUTING_PHASE.with(|phase| phase.set(self.previous));
+    }
+}
+
+/// Binds `http_*` calls made on this thread to `phase`; outside any scope they are allowed.
+pub(crate) fn enter_phase_scope(phase: ExecutionPhase) -> PhaseScopeGuard {
+    let previous = EXECUTING_PHASE.with(|current| current.replace(Some(phase)));
+    PhaseScopeGuard { previous }
+}
+
+/// Admits an outbound call: `name` must be allowed in the executing phase, and the call
+/// spends one unit of the executing tenant's budget. Either failure terminates the script
+/// instead of returning an error map.
+fn outbound_call(name: &str) -> Result<(), Box<EvalAltResult>> {
+    if let Some(phase) = EXECUTING_PHASE
+        .with(Cell::get)
+        .filter(|phase| !phase.allows_external_services())
+    {
+        warn!(
+            target: "alloy::script",
+            phase = phase.as_str(),
+            call = name,
+            "Blocked external-service call"
+        );
+        return Err(format!("{FORBIDDEN_CALL_PREFIX} {name}").into());
+    }
+
+    check_outbound_budget().map_err(|err| match err {
+        ScriptError::RateLimited(message) => format!("{RATE_LIMITED_PREFIX} {message}").into(),
+        other => other.to_string().into(),
+    })
+}
+
+fn request_host(url: &str) -> Option<String> {
+    reqwest::Url::parse(url)
+        .ok()?
+        .host_str()
+        .map(|host| host.to_ascii_lowercase())
+}
+
+/// Runs an outbound call behind the per-host circuit breaker. Transport errors and 5xx
+/// responses count as failures; while the breaker is open the call is not sent at all.
+fn call_host<F>(url: &str, request: F) -> Result<(u16, serde_json::Value), String>
+where
+    F: std::future::Future<Output = Result<(u16, serde_json::Value), String>>,
+{
+    let Some(host) = request_host(url) else {
+        return run_blocking(request);
+    };
+    let breakers = CircuitBreakerRegistry::global();
+    breakers.acquire(&host).map_err(|open| open.to_string())?;
+
+    let result = run_blocking(request);
+    breakers.record(&host, matches!(&result, Ok((status, _)) if *status < 500));
+    result
+}
+
 fn build_http_response(status: u16, body: serde_json::Value) -> Map {
     let mut result = Map::new();
     result.insert("status".into(), Dynamic::from(status as i64));
@@ -27,18 +114,19 @@ fn build_http_response(status: u16, body: serde_json::Value) -> Map {
     result
 }
 
-fn http_get(url: &str) -> Map {
+fn http_get(url: &str) -> HttpResult {
     http_get_with_headers(url, Map::new())
 }
 
-fn http_get_with_headers(url: &str, headers: Map) -> Map {
+fn http_get_with_headers(url: &str, headers: Map) -> HttpResult {
+    outbound_call("http_get")?;
     let url = url.to_string();
     let url_log = url.clone();
     let headers = extract_headers(headers);
 
     debug!(target: "alloy::script", "HTTP GET {}", url);
 
-    let result = run_blocking(async move {
+    let result = call_host(&url_log, async move {
         let client = reqwest::Client::new();
         let mut request = client.get(&url);
 
@@ -60,23 +148,24 @@ fn http_get_with_headers(url: &str, headers: Map) -> Map {
     });
 
     match result {
-        Ok((status, body)) => build_http_response(status, body),
+        Ok((status, body)) => Ok(build_http_response(status, body)),
         Err(err) => {
             warn!(target: "alloy::script", "HTTP GET {} failed: {}", url_log, err);
             let mut map = Map::new();
             map.insert("status".into(), Dynamic::from(0_i64));
             map.insert("ok".into(), Dynamic::from(false));
             map.insert("error".into(), Dynamic::from(err));
-            map
+            Ok(map)
         }
     }
 }
 
-fn http_post_json(url: &str, body: Dynamic) -> Map {
+fn http_post_json(url: &str, body: Dynamic) -> HttpResult {
     http_post_json_with_headers(url, body, Map::new())
 }
 
-fn http_post_json_with_headers(url: &str, body: Dynamic, headers: Map) -> Map {
+fn http_post_json_with_headers(url: &str, body: Dynamic, headers: Map) -> HttpResult {
+    outbound_call("http_post")?;
     let url = url.to_string();
     let url_log = url.clone();
     let headers = extract_headers(headers);
@@ -84,7 +173,7 @@ fn http_post_json_with_headers(url: &str, body: Dynamic, headers: Map) -> Map {
 
     debug!(target: "alloy::script", "HTTP POST {}", url);
 
-    let result = run_blocking(async move {
+    let result = call_host(&url_log, async move {
         let client = reqwest::Client::new();
         let mut request = client
             .post(&url)
@@ -109,19 +198,20 @@ fn http_post_json_with_headers(url: &str, body: Dynamic, headers: Map) -> Map {
     });
 
     match result {
-        Ok((status, resp_body)) => build_http_response(status, resp_body),
+        Ok((status, resp_body)) => Ok(build_http_response(status, resp_body)),
         Err(err) => {
             warn!(target: "alloy::script", "HTTP POST {} failed: {}", url_log, err);
             let mut map = Map::new();
             map.insert("status".into(), Dynamic::from(0_i64));
             map.insert("ok".into(), Dynamic::from(false));
             map.insert("error".into(), Dynamic::from(err));
-            map
+            Ok(map)
         }
     }
 }
 
-fn http_request(method: &str, url: &str, body: Dynamic, headers: Map) -> Map {
+fn http_request(method: &str, url: &str, body: Dynamic, headers: Map) -> HttpResult {
+    outbound_call("http_request")?;
     let method = method.to_uppercase();
     let url = url.to_string();
     let method_log = method.clone();
@@ -131,7 +221,7 @@ fn http_request(method: &str, url: &str, body: Dynamic, headers: Map) -> Map {
 
     debug!(target: "alloy::script", "HTTP {} {}", method, url);
 
-    let result = run_blocking(async move {
+    let result = call_host(&url_log, async move {
         let client = reqwest::Client::new();
         let req_method =
             reqwest::Method::from_bytes(method.as_bytes()).unwrap_or(reqwest::Method::GET);
@@ -159,14 +249,14 @@ fn http_request(method: &str, url: &str, body: Dynamic, headers: Map) -> Map {
     });
 
     match result {
-        Ok((status, resp_body)) => build_http_response(status, resp_body),
+        Ok((status, resp_body)) => Ok(build_http_response(status, resp_body)),
         Err(err) => {
             warn!(target: "alloy::script", "HTTP {} {} failed: {}", method_log, url_log, err);
             let mut map = Map::new();
             map.insert("status".into(), Dynamic::from(0_i64));
             map.insert("ok".into(), Dynamic::from(false));
             map.insert("error".into(), Dynamic::from(err));
-            map
+            Ok(map)
         }
     }
 }
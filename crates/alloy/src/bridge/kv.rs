@@ -0,0 +1,308 @@
+use parking_lot::Mutex;
+use rhai::{Dynamic, Engine, EvalAltResult};
+use rustok_core::CacheBackend;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use super::http::run_blocking;
+use crate::error::{ScriptError, ScriptResult};
+use crate::utils::{dynamic_to_json, json_to_dynamic};
+
+/// Limits that keep the script KV store a scratchpad rather than a database.
+#[derive(Debug, Clone)]
+pub struct ScriptKvLimits {
+    /// Live keys one script may hold per tenant.
+    pub max_keys: usize,
+    pub max_key_len: usize,
+    /// Size of the JSON-encoded value.
+    pub max_value_bytes: usize,
+    /// Longer TTLs are clamped to this value.
+    pub max_ttl: Duration,
+}
+
+impl Default for ScriptKvLimits {
+    fn default() -> Self {
+        Self {
+            max_keys: 100,
+            max_key_len: 128,
+            max_value_bytes: 16 * 1024,
+            max_ttl: Duration::from_secs(24 * 60 * 60),
+        }
+    }
+}
+
+/// Temporary key-value storage for scripts, scoped by tenant and script name.
+pub struct ScriptKvStore {
+    backend: Arc<dyn CacheBackend>,
+    limits: ScriptKvLimits,
+    /// Expiry of every key written through this store, used to enforce `max_keys`.
+    keys: Mutex<HashMap<(String, String), HashMap<String, Instant>>>,
+}
+
+impl ScriptKvStore {
+    pub fn new(backend: Arc<dyn CacheBackend>) -> Self {
+        Self::with_limits(backend, ScriptKvLimits::default())
+    }
+
+    pub fn with_limits(backend: Arc<dyn CacheBackend>, limits: ScriptKvLimits) -> Self {
+        Self {
+            backend,
+            limits,
+            keys: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn limits(&self) -> &ScriptKvLimits {
+        &self.limits
+    }
+
+    pub async fn get(
+        &self,
+        tenant_id: &str,
+        script_name: &str,
+        key: &str,
+    ) -> ScriptResult<Option<serde_json::Value>> {
+        self.validate_key(key)?;
+
+        let payload = self
+            .backend
+            .get(&Self::cache_key(tenant_id, script_name, key))
+            .await
+            .map_err(|err| ScriptError::Storage(err.to_string()))?;
+
+        payload
+            .map(|bytes| {
+                serde_json::from_slice(&bytes).map_err(|err| ScriptError::Storage(err.to_string()))
+            })
+            .transpose()
+    }
+
+    pub async fn set(
+        &self,
+        tenant_id: &str,
+        script_name: &str,
+        key: &str,
+        value: serde_json::Value,
+        ttl: Duration,
+    ) -> ScriptResult<()> {
+        self.validate_key(key)?;
+        if ttl.is_zero() {
+            return Err(ScriptError::Runtime("kv_set: ttl must be positive".into()));
+        }
+
+        let payload =
+            serde_json::to_vec(&value).map_err(|err| ScriptError::Runtime(err.to_string()))?;
+        if payload.len() > self.limits.max_value_bytes {
+            return Err(ScriptError::Runtime(format!(
+                "kv_set: value is {} bytes, limit is {}",
+                payload.len(),
+                self.limits.max_value_bytes
+            )));
+        }
+
+        let ttl = ttl.min(self.limits.max_ttl);
+        self.reserve_key(tenant_id, script_name, key, Instant::now() + ttl)?;
+
+        self.backend
+            .set_with_ttl(Self::cache_key(tenant_id, script_name, key), payload, ttl)
+            .await
+            .map_err(|err| ScriptError::Storage(err.to_string()))
+    }
+
+    fn validate_key(&self, key: &str) -> ScriptResult<()> {
+        if key.is_empty() || key.len() > self.limits.max_key_len {
+            return Err(ScriptError::Runtime(format!(
+                "kv: key must be 1..={} bytes",
+                self.limits.max_key_len
+            )));
+        }
+        Ok(())
+    }
+
+    fn reserve_key(
+        &self,
+        tenant_id: &str,
+        script_name: &str,
+        key: &str,
+        expires_at: Instant,
+    ) -> ScriptResult<()> {
+        let now = Instant::now();
+        let mut scopes = self.keys.lock();
+        let keys = scopes
+            .entry((tenant_id.to_string(), script_name.to_string()))
+            .or_default();
+        keys.retain(|_, expiry| *expiry > now);
+
+        if !keys.contains_key(key) && keys.len() >= self.limits.max_keys {
+            return Err(ScriptError::Runtime(format!(
+                "kv_set: script already holds {} keys",
+                self.limits.max_keys
+            )));
+        }
+
+        keys.insert(key.to_string(), expires_at);
+        Ok(())
+    }
+
+    /// Length-prefixes the tenant and script so names containing `:` cannot collide.
+    fn cache_key(tenant_id: &str, script_name: &str, key: &str) -> String {
+        format!(
+            "alloy:kv:{}:{tenant_id}:{}:{script_name}:{key}",
+            tenant_id.len(),
+            script_name.len()
+        )
+    }
+}
+
+struct KvScope {
+    store: Arc<ScriptKvStore>,
+    tenant_id: String,
+    script_name: String,
+}
+
+thread_local! {
+    static KV_SCOPE: RefCell<Option<KvScope>> = const { RefCell::new(None) };
+}
+
+/// Restores the previous KV scope when the script evaluation ends.
+pub(crate) struct KvScopeGuard {
+    previous: Option<KvScope>,
+}
+
+impl Drop for KvScopeGuard {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        KV_SCOPE.with(|scope| *scope.borrow_mut() = previous);
+    }
+}
+
+/// Binds `kv_get`/`kv_set` calls made on this thread to the executing script.
+pub(crate) fn enter_kv_scope(
+    store: Option<Arc<ScriptKvStore>>,
+    tenant_id: Option<&str>,
+    script_name: &str,
+) -> KvScopeGuard {
+    let next = store.map(|store| KvScope {
+        store,
+        tenant_id: tenant_id.unwrap_or_default().to_string(),
+        script_name: script_name.to_string(),
+    });
+    let previous = KV_SCOPE.with(|scope| std::mem::replace(&mut *scope.borrow_mut(), next));
+    KvScopeGuard { previous }
+}
+
+pub fn register_kv(engine: &mut Engine) {
+    engine.register_fn("kv_get", kv_get);
+    engine.register_fn("kv_set", kv_set);
+}
+
+fn with_kv_scope<T>(
+    f: impl FnOnce(&ScriptKvStore, &str, &str) -> ScriptResult<T>,
+) -> Result<T, Box<EvalAltResult>> {
+    KV_SCOPE
+        .with(|scope| match scope.borrow().as_ref() {
+            Some(scope) => f(&scope.store, &scope.tenant_id, &scope.script_name),
+            None => Err(ScriptError::Runtime("kv store is not configured".into())),
+        })
+        .map_err(|err| err.to_string().into())
+}
+
+fn kv_get(key: &str) -> Result<Dynamic, Box<EvalAltResult>> {
+    let value = with_kv_scope(|store, tenant_id, script_name| {
+        run_blocking(store.get(tenant_id, script_name, key))
+    })?;
+    Ok(value.map(json_to_dynamic).unwrap_or(Dynamic::UNIT))
+}
+
+fn kv_set(key: &str, value: Dynamic, ttl_secs: i64) -> Result<(), Box<EvalAltResult>> {
+    let ttl = Duration::from_secs(ttl_secs.max(0) as u64);
+    let value = dynamic_to_json(value);
+    with_kv_scope(|store, tenant_id, script_name| {
+        run_blocking(store.set(tenant_id, script_name, key, value, ttl))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{create_default_engine, ExecutionContext, ExecutionPhase};
+    use rustok_core::InMemoryCacheBackend;
+
+    fn store(limits: ScriptKvLimits) -> Arc<ScriptKvStore> {
+        let backend = Arc::new(InMemoryCacheBackend::new(Duration::from_secs(60), 1_000));
+        Arc::new(ScriptKvStore::with_limits(backend, limits))
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn value_set_before_is_visible_after_until_ttl_expires() {
+        let engine = create_default_engine().with_kv_store(store(ScriptKvLimits::default()));
+        let before = ExecutionContext::new(ExecutionPhase::Before).with_tenant("tenant-a");
+        let after = ExecutionContext::new(ExecutionPhase::After).with_tenant("tenant-a");
+
+        let stored = engine
+            .execute("discount", r#"kv_set("discount", 15, 1)"#, &before)
+            .unwrap();
+        assert!(stored.is_unit());
+
+        let read = engine
+            .execute("discount", r#"kv_get("discount")"#, &after)
+            .unwrap();
+        assert_eq!(read.as_int().unwrap(), 15);
+
+        let other_tenant = ExecutionContext::new(ExecutionPhase::After).with_tenant("tenant-b");
+        let isolated = engine
+            .execute("discount", r#"kv_get("discount")"#, &other_tenant)
+            .unwrap();
+        assert!(isolated.is_unit());
+
+        tokio::time::sleep(Duration::from_millis(1_100)).await;
+
+        let expired = engine
+            .execute("discount", r#"kv_get("discount")"#, &after)
+            .unwrap();
+        assert!(expired.is_unit());
+    }
+
+    #[tokio::test]
+    async fn key_count_and_value_size_are_limited() {
+        let store = store(ScriptKvLimits {
+            max_keys: 2,
+            max_value_bytes: 16,
+            ..ScriptKvLimits::default()
+        });
+        let ttl = Duration::from_secs(60);
+
+        store.set("t", "s", "a", 1.into(), ttl).await.unwrap();
+        store.set("t", "s", "b", 2.into(), ttl).await.unwrap();
+        store.set("t", "s", "a", 3.into(), ttl).await.unwrap();
+        assert!(store.set("t", "s", "c", 4.into(), ttl).await.is_err());
+        assert!(store.set("t", "other", "c", 4.into(), ttl).await.is_ok());
+
+        let oversized = serde_json::Value::String("x".repeat(32));
+        assert!(store.set("t", "s", "a", oversized, ttl).await.is_err());
+        assert_eq!(
+            store.get("t", "s", "a").await.unwrap(),
+            Some(serde_json::json!(3))
+        );
+    }
+
+    #[tokio::test]
+    async fn names_containing_separators_do_not_collide() {
+        let store = store(ScriptKvLimits::default());
+        let ttl = Duration::from_secs(60);
+
+        store.set("a:b", "c", "k", 1.into(), ttl).await.unwrap();
+        store.set("a", "b:c", "k", 2.into(), ttl).await.unwrap();
+
+        assert_eq!(
+            store.get("a:b", "c", "k").await.unwrap(),
+            Some(serde_json::json!(1))
+        );
+        assert_eq!(
+            store.get("a", "b:c", "k").await.unwrap(),
+            Some(serde_json::json!(2))
+        );
+    }
+}
@@ -1,11 +1,25 @@
+mod circuit_breaker;
 mod http;
+mod kv;
+mod rate_limit;
 mod utils;
 
 use crate::context::ExecutionPhase;
 use email_address::EmailAddress;
 use rhai::Engine;
 
-pub use utils::register_utils;
+pub use circuit_breaker::{CircuitBreakerRegistry, CircuitOpen, HostCircuitBreakerConfig};
+pub use kv::{register_kv, ScriptKvLimits, ScriptKvStore};
+pub use rate_limit::OutboundRateLimiter;
+pub use utils::{register_json_helpers, register_utils};
+
+pub(crate) use http::{enter_phase_scope, FORBIDDEN_CALL_PREFIX, RATE_LIMITED_PREFIX};
+pub(crate) use kv::enter_kv_scope;
+pub(crate) use rate_limit::enter_outbound_scope;
+
+/// Functions only registered for phases that may call external services; rejected by
+/// [`ScriptEngine::validate`](crate::ScriptEngine::validate).
+pub const EXTERNAL_SERVICE_FUNCTIONS: &[&str] = &http::HTTP_FUNCTIONS;
 
 fn validate_email_address(email: &str) -> bool {
     EmailAddress::is_valid(email)
@@ -16,6 +30,8 @@ pub struct Bridge;
 impl Bridge {
     pub fn register_for_phase(engine: &mut Engine, phase: ExecutionPhase) {
         register_utils(engine);
+        register_json_helpers(engine);
+        register_kv(engine);
 
         match phase {
             ExecutionPhase::Before => {
@@ -57,6 +73,11 @@ impl Bridge {
         });
     }
 
+    /// Outbound circuit breaker state per host, for health and admin views.
+    pub fn circuit_breaker_states() -> Vec<(String, rustok_core::CircuitState)> {
+        CircuitBreakerRegistry::global().states()
+    }
+
     fn register_db_services(_engine: &mut Engine) {}
 
     fn register_external_services(engine: &mut Engine) {
@@ -0,0 +1,211 @@
+use parking_lot::Mutex;
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use std::time::Instant;
+
+use crate::engine::OutboundRateLimit;
+use crate::error::{ScriptError, ScriptResult};
+
+/// Sliding-window budget of outbound bridge calls, tracked per tenant.
+///
+/// Tenants without a call in the last `window` are dropped at most once per `window`,
+/// so the map only holds tenants that are actually making calls.
+pub struct OutboundRateLimiter {
+    limit: OutboundRateLimit,
+    state: Mutex<LimiterState>,
+}
+
+#[derive(Default)]
+struct LimiterState {
+    tenants: HashMap<String, VecDeque<Instant>>,
+    last_sweep: Option<Instant>,
+}
+
+impl LimiterState {
+    fn sweep_idle(&mut self, now: Instant, window: std::time::Duration) {
+        if self
+            .last_sweep
+            .is_some_and(|at| now.saturating_duration_since(at) < window)
+        {
+            return;
+        }
+        self.tenants.retain(|_, calls| {
+            calls
+                .back()
+                .is_some_and(|at| now.saturating_duration_since(*at) < window)
+        });
+        self.last_sweep = Some(now);
+    }
+}
+
+impl OutboundRateLimiter {
+    pub fn new(limit: OutboundRateLimit) -> Self {
+        Self {
+            limit,
+            state: Mutex::new(LimiterState::default()),
+        }
+    }
+
+    pub fn limit(&self) -> OutboundRateLimit {
+        self.limit
+    }
+
+    pub fn check(&self, tenant_id: &str) -> ScriptResult<()> {
+        self.check_at(tenant_id, Instant::now())
+    }
+
+    /// Spends one call from the tenant's budget, or fails once `max_calls` were made
+    /// within the last `window`.
+    pub fn check_at(&self, tenant_id: &str, now: Instant) -> ScriptResult<()> {
+        let mut state = self.state.lock();
+        state.sweep_idle(now, self.limit.window);
+        let calls = state.tenants.entry(tenant_id.to_string()).or_default();
+
+        while calls
+            .front()
+            .is_some_and(|at| now.saturating_duration_since(*at) >= self.limit.window)
+        {
+            calls.pop_front();
+        }
+
+        if calls.len() >= self.limit.max_calls as usize {
+            return Err(ScriptError::RateLimited(format!(
+                "tenant {} made {} outbound calls in the last {}s",
+                tenant_id,
+                calls.len(),
+                self.limit.window.as_secs()
+            )));
+        }
+
+        calls.push_back(now);
+        Ok(())
+    }
+
+    #[cfg(test)]
+    fn tracked_tenants(&self) -> usize {
+        self.state.lock().tenants.len()
+    }
+}
+
+struct OutboundScope {
+    limiter: Arc<OutboundRateLimiter>,
+    tenant_id: String,
+}
+
+thread_local! {
+    // Rhai evaluates synchronously and `http_*` block in place, so the executing
+    // script's tenant is visible to bridge functions on the same thread.
+    static OUTBOUND_SCOPE: RefCell<Option<OutboundScope>> = const { RefCell::new(None) };
+}
+
+/// Restores the previous outbound scope when the script evaluation ends.
+pub(crate) struct OutboundScopeGuard {
+    previous: Option<OutboundScope>,
+}
+
+impl Drop for OutboundScopeGuard {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        OUTBOUND_SCOPE.with(|scope| *scope.borrow_mut() = previous);
+    }
+}
+
+/// Binds outbound calls made on this thread to `tenant_id` (executions without a tenant
+/// share one budget).
+pub(crate) fn enter_outbound_scope(
+    limiter: Option<Arc<OutboundRateLimiter>>,
+    tenant_id: Option<&str>,
+) -> OutboundScopeGuard {
+    let next = limiter.map(|limiter| OutboundScope {
+        limiter,
+        tenant_id: tenant_id.unwrap_or_default().to_string(),
+    });
+    let previous = OUTBOUND_SCOPE.with(|scope| std::mem::replace(&mut *scope.borrow_mut(), next));
+    OutboundScopeGuard { previous }
+}
+
+pub(crate) fn check_outbound_budget() -> ScriptResult<()> {
+    OUTBOUND_SCOPE.with(|scope| match scope.borrow().as_ref() {
+        Some(scope) => scope.limiter.check(&scope.tenant_id),
+        None => Ok(()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn limiter(max_calls: u32) -> OutboundRateLimiter {
+        OutboundRateLimiter::new(OutboundRateLimit {
+            max_calls,
+            window: Duration::from_secs(10),
+        })
+    }
+
+    #[test]
+    fn call_over_budget_is_rejected_until_window_passes() {
+        let limiter = limiter(3);
+        let now = Instant::now();
+
+        for offset in 0..3 {
+            assert!(limiter
+                .check_at("tenant-a", now + Duration::from_secs(offset))
+                .is_ok());
+        }
+        let rejected = limiter.check_at("tenant-a", now + Duration::from_secs(5));
+        assert!(matches!(rejected, Err(ScriptError::RateLimited(_))));
+        assert!(limiter.check_at("tenant-b", now).is_ok());
+
+        assert!(limiter
+            .check_at("tenant-a", now + Duration::from_secs(10))
+            .is_ok());
+        assert!(limiter
+            .check_at("tenant-a", now + Duration::from_secs(10))
+            .is_err());
+        assert!(limiter
+            .check_at("tenant-a", now + Duration::from_secs(12))
+            .is_ok());
+    }
+
+    #[test]
+    fn idle_tenants_are_dropped_after_a_window() {
+        let limiter = limiter(3);
+        let now = Instant::now();
+
+        for tenant in 0..100 {
+            assert!(limiter.check_at(&format!("tenant-{tenant}"), now).is_ok());
+        }
+        assert_eq!(limiter.tracked_tenants(), 100);
+
+        assert!(limiter
+            .check_at("tenant-a", now + Duration::from_secs(5))
+            .is_ok());
+        assert_eq!(limiter.tracked_tenants(), 101);
+
+        assert!(limiter
+            .check_at("tenant-b", now + Duration::from_secs(11))
+            .is_ok());
+        assert_eq!(limiter.tracked_tenants(), 2);
+    }
+
+    #[test]
+    fn bridge_budget_follows_the_executing_tenant() {
+        let limiter = Arc::new(limiter(1));
+
+        {
+            let _guard = enter_outbound_scope(Some(Arc::clone(&limiter)), Some("tenant-a"));
+            assert!(check_outbound_budget().is_ok());
+            assert!(matches!(
+                check_outbound_budget(),
+                Err(ScriptError::RateLimited(_))
+            ));
+
+            let _nested = enter_outbound_scope(Some(Arc::clone(&limiter)), Some("tenant-b"));
+            assert!(check_outbound_budget().is_ok());
+        }
+
+        assert!(check_outbound_budget().is_ok());
+    }
+}
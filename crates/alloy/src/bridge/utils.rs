@@ -1,6 +1,8 @@
-use rhai::{Dynamic, Engine, EvalAltResult, Position};
+use rhai::{Dynamic, Engine, EvalAltResult, Map, Position};
 use tracing::{error, info, warn};
 
+use crate::utils::{dynamic_to_json, json_to_dynamic};
+
 pub fn register_utils(engine: &mut Engine) {
     engine.register_fn("log", log_info);
     engine.register_fn("log_warn", log_warn);
@@ -20,12 +22,19 @@ pub fn register_utils(engine: &mut Engine) {
     engine.register_fn("now_unix", now_unix);
 
     engine.register_fn("abort", abort_script);
+    engine.register_fn("abort_with", abort_script_with_code);
 
     engine.register_fn("format_money", format_money);
     engine.register_fn("is_empty", is_empty);
     engine.register_fn("coalesce", coalesce);
 }
 
+pub fn register_json_helpers(engine: &mut Engine) {
+    engine.register_fn("json_parse", json_parse);
+    engine.register_fn("json_stringify", json_stringify);
+    engine.register_fn("json_get", json_get);
+}
+
 fn log_info(message: &str) {
     info!(target: "alloy::script", "{}", message);
 }
@@ -53,6 +62,13 @@ fn abort_script(message: &str) -> Result<Dynamic, Box<EvalAltResult>> {
     )))
 }
 
+fn abort_script_with_code(code: &str, message: &str) -> Result<Dynamic, Box<EvalAltResult>> {
+    Err(Box::new(EvalAltResult::ErrorRuntime(
+        format!("ABORT[{}]:{}", code, message).into(),
+        Position::NONE,
+    )))
+}
+
 fn format_money(amount: i64) -> String {
     let s = amount.abs().to_string();
     let mut result = String::new();
@@ -91,3 +107,47 @@ fn coalesce(value: Dynamic, default: Dynamic) -> Dynamic {
         value
     }
 }
+
+fn json_parse(input: &str) -> Result<Map, Box<EvalAltResult>> {
+    let value: serde_json::Value = serde_json::from_str(input).map_err(|err| {
+        Box::new(EvalAltResult::ErrorRuntime(
+            format!("json_parse: {}", err).into(),
+            Position::NONE,
+        ))
+    })?;
+
+    json_to_dynamic(value).try_cast::<Map>().ok_or_else(|| {
+        Box::new(EvalAltResult::ErrorRuntime(
+            "json_parse: expected a JSON object".into(),
+            Position::NONE,
+        ))
+    })
+}
+
+fn json_stringify(value: Dynamic) -> String {
+    dynamic_to_json(value).to_string()
+}
+
+/// Looks up a dotted path such as `"order.items.0.sku"`; numeric segments index arrays.
+/// Returns `()` when any segment is missing.
+fn json_get(map: Map, path: &str) -> Dynamic {
+    let mut current = Dynamic::from(map);
+    for segment in path.split('.') {
+        let next = if let Some(map) = current.clone().try_cast::<Map>() {
+            map.get(segment).cloned()
+        } else if let Some(array) = current.clone().try_cast::<rhai::Array>() {
+            segment
+                .parse::<usize>()
+                .ok()
+                .and_then(|index| array.get(index).cloned())
+        } else {
+            None
+        };
+
+        match next {
+            Some(value) => current = value,
+            None => return Dynamic::UNIT,
+        }
+    }
+    current
+}
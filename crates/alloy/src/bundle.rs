@@ -0,0 +1,233 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use uuid::Uuid;
+
+use crate::engine::ScriptEngine;
+use crate::error::{ScriptError, ScriptResult};
+use crate::model::{Script, ScriptStatus, ScriptTrigger};
+use crate::storage::{ScriptQuery, ScriptRegistry};
+
+pub const SCRIPT_BUNDLE_VERSION: u32 = 1;
+
+/// Portable set of scripts for moving them between environments.
+///
+/// Ids, tenant, author and error counters stay behind: they only make sense in the
+/// environment the scripts were exported from.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScriptBundle {
+    pub version: u32,
+    pub exported_at: DateTime<Utc>,
+    pub scripts: Vec<BundledScript>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundledScript {
+    pub name: String,
+    pub description: Option<String>,
+    pub code: String,
+    pub trigger: ScriptTrigger,
+    pub status: ScriptStatus,
+    #[serde(default)]
+    pub run_as_system: bool,
+    #[serde(default)]
+    pub permissions: Vec<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+impl From<Script> for BundledScript {
+    fn from(script: Script) -> Self {
+        Self {
+            name: script.name,
+            description: script.description,
+            code: script.code,
+            trigger: script.trigger,
+            status: script.status,
+            run_as_system: script.run_as_system,
+            permissions: script.permissions,
+            tags: script.tags,
+        }
+    }
+}
+
+impl BundledScript {
+    fn apply_to(self, script: &mut Script) {
+        script.description = self.description;
+        script.code = self.code;
+        script.trigger = self.trigger;
+        script.status = self.status;
+        script.run_as_system = self.run_as_system;
+        script.permissions = self.permissions;
+        script.tags = self.tags;
+        script.updated_at = Utc::now();
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ImportReport {
+    pub created: Vec<String>,
+    pub updated: Vec<String>,
+    /// Already present and left untouched because `overwrite` was off.
+    pub skipped: Vec<String>,
+}
+
+pub async fn export_scripts<R: ScriptRegistry + ?Sized>(
+    registry: &R,
+    query: ScriptQuery,
+) -> ScriptResult<ScriptBundle> {
+    let scripts = registry.find(query).await?;
+
+    Ok(ScriptBundle {
+        version: SCRIPT_BUNDLE_VERSION,
+        exported_at: Utc::now(),
+        scripts: scripts.into_iter().map(BundledScript::from).collect(),
+    })
+}
+
+/// Creates or updates the bundled scripts in `tenant_id`.
+///
+/// Every script is compiled before anything is written, so a bundle with a broken script
+/// is rejected as a whole.
+pub async fn import_scripts<R: ScriptRegistry + ?Sized>(
+    registry: &R,
+    engine: &ScriptEngine,
+    tenant_id: Uuid,
+    bundle: ScriptBundle,
+    overwrite: bool,
+) -> ScriptResult<ImportReport> {
+    if bundle.version != SCRIPT_BUNDLE_VERSION {
+        return Err(ScriptError::InvalidBundle(format!(
+            "unsupported bundle version {}",
+            bundle.version
+        )));
+    }
+
+    let mut names = HashSet::new();
+    for script in &bundle.scripts {
+        if !names.insert(script.name.as_str()) {
+            return Err(ScriptError::InvalidBundle(format!(
+                "script '{}' appears more than once",
+                script.name
+            )));
+        }
+
+        engine.check_compiles(&script.code).map_err(|error| {
+            ScriptError::Compilation(format!("script '{}': {error}", script.name))
+        })?;
+    }
+
+    let mut report = ImportReport::default();
+    for bundled in bundle.scripts {
+        let name = bundled.name.clone();
+        match registry.get_by_name(&name).await {
+            Ok(_) if !overwrite => report.skipped.push(name),
+            Ok(mut existing) => {
+                bundled.apply_to(&mut existing);
+                registry.save(existing).await?;
+                engine.invalidate(&name);
+                report.updated.push(name);
+            }
+            Err(ScriptError::NotFound { .. }) => {
+                let mut script = Script::new(name.clone(), String::new(), ScriptTrigger::Manual);
+                script.tenant_id = tenant_id;
+                bundled.apply_to(&mut script);
+                registry.save(script).await?;
+                report.created.push(name);
+            }
+            Err(error) => return Err(error),
+        }
+    }
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::create_default_engine;
+    use crate::model::EventType;
+    use crate::storage::InMemoryStorage;
+
+    async fn source() -> InMemoryStorage {
+        let storage = InMemoryStorage::new();
+        let mut hook = Script::new(
+            "normalize_order",
+            r#"entity["total"] = entity["total"] * 2;"#,
+            ScriptTrigger::Event {
+                entity_type: "order".into(),
+                event: EventType::BeforeCreate,
+            },
+        );
+        hook.activate();
+        hook.tags = vec!["orders".into()];
+        hook.permissions = vec!["orders:update".into()];
+        hook.author_id = Some("admin-1".into());
+        storage.save(hook).await.unwrap();
+
+        let mut report = Script::new("weekly_report", "42", ScriptTrigger::Manual);
+        report.description = Some("Weekly sales".into());
+        storage.save(report).await.unwrap();
+        storage
+    }
+
+    #[tokio::test]
+    async fn export_then_import_round_trips_scripts() {
+        let engine = create_default_engine();
+        let bundle = export_scripts(&source().await, ScriptQuery::All)
+            .await
+            .unwrap();
+        let bundle: ScriptBundle =
+            serde_json::from_str(&serde_json::to_string(&bundle).unwrap()).unwrap();
+
+        let target = InMemoryStorage::new();
+        target
+            .save(Script::new("weekly_report", "1", ScriptTrigger::Manual))
+            .await
+            .unwrap();
+
+        let tenant_id = Uuid::new_v4();
+        let skipped = import_scripts(&target, &engine, tenant_id, bundle.clone(), false)
+            .await
+            .unwrap();
+        assert_eq!(skipped.created, ["normalize_order"]);
+        assert_eq!(skipped.skipped, ["weekly_report"]);
+        assert_eq!(target.get_by_name("weekly_report").await.unwrap().code, "1");
+
+        let report = import_scripts(&target, &engine, tenant_id, bundle, true)
+            .await
+            .unwrap();
+        assert_eq!(report.updated, ["normalize_order", "weekly_report"]);
+
+        let hook = target.get_by_name("normalize_order").await.unwrap();
+        assert_eq!(hook.tenant_id, tenant_id);
+        assert_eq!(hook.status, ScriptStatus::Active);
+        assert_eq!(hook.tags, ["orders"]);
+        assert_eq!(hook.permissions, ["orders:update"]);
+        assert_eq!(hook.author_id, None);
+        assert!(matches!(hook.trigger, ScriptTrigger::Event { .. }));
+
+        let report = target.get_by_name("weekly_report").await.unwrap();
+        assert_eq!(report.code, "42");
+        assert_eq!(report.description.as_deref(), Some("Weekly sales"));
+    }
+
+    #[tokio::test]
+    async fn import_rejects_bundle_with_script_that_fails_to_compile() {
+        let engine = create_default_engine();
+        let mut bundle = export_scripts(&source().await, ScriptQuery::All)
+            .await
+            .unwrap();
+        bundle.scripts[1].code = "let x = ;".into();
+
+        let target = InMemoryStorage::new();
+        let error = import_scripts(&target, &engine, Uuid::new_v4(), bundle, true)
+            .await
+            .unwrap_err();
+
+        assert!(
+            matches!(error, ScriptError::Compilation(ref message) if message.contains("weekly_report"))
+        );
+        assert!(target.find(ScriptQuery::All).await.unwrap().is_empty());
+    }
+}
@@ -13,6 +13,25 @@ pub enum ExecutionPhase {
     Scheduled,
 }
 
+impl ExecutionPhase {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Before => "before",
+            Self::After => "after",
+            Self::OnCommit => "on_commit",
+            Self::Manual => "manual",
+            Self::Scheduled => "scheduled",
+        }
+    }
+
+    /// Phases that get the external-service bridge (`http_*`); scripts in other phases
+    /// fail with [`ScriptError::ForbiddenCall`](crate::ScriptError::ForbiddenCall) when
+    /// they reference it, or when a call reaches it through a name built at run time.
+    pub fn allows_external_services(&self) -> bool {
+        matches!(self, Self::OnCommit | Self::Manual | Self::Scheduled)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ExecutionContext {
     pub execution_id: Uuid,
@@ -96,7 +115,10 @@ impl ExecutionContext {
 
         if let Some(ref proxy) = self.entity_proxy {
             match self.phase {
-                ExecutionPhase::Before => scope.push("entity", proxy.clone()),
+                // Manual runs double as dry runs of Before scripts, so they may write too.
+                ExecutionPhase::Before | ExecutionPhase::Manual => {
+                    scope.push("entity", proxy.clone())
+                }
                 _ => scope.push_constant("entity", proxy.clone()),
             };
         }
@@ -13,9 +13,11 @@ use uuid::Uuid;
 
 use crate::{
     api::{
-        CreateScriptRequest, EntityInput, ListScriptsQuery, ListScriptsResponse, RunScriptRequest,
-        RunScriptResponse, ScriptResponse, UpdateScriptRequest,
+        CreateScriptRequest, EntityInput, FieldDiff, ImportScriptsRequest, ListScriptsQuery,
+        ListScriptsResponse, RunScriptRequest, RunScriptResponse, ScriptResponse,
+        UpdateScriptRequest,
     },
+    bundle::{ImportReport, ScriptBundle},
     model::{EntityProxy, Script, ScriptStatus},
     runner::ExecutionOutcome,
     utils::{dynamic_to_json, json_to_dynamic},
@@ -27,7 +29,9 @@ fn script_error(error: ScriptError) -> Error {
         ScriptError::NotFound { .. } => Error::NotFound,
         ScriptError::Compilation(message)
         | ScriptError::InvalidTrigger(message)
-        | ScriptError::InvalidStatus(message) => Error::BadRequest(message),
+        | ScriptError::InvalidStatus(message)
+        | ScriptError::InvalidBundle(message) => Error::BadRequest(message),
+        forbidden @ ScriptError::ForbiddenCall { .. } => Error::BadRequest(forbidden.to_string()),
         other => Error::Message(other.to_string()),
     }
 }
@@ -48,14 +52,9 @@ pub async fn list_scripts(
     Query(query): Query<ListScriptsQuery>,
 ) -> Result<Json<ListScriptsResponse>> {
     let runtime = crate::runtime::scoped_runtime(&ctx, tenant.id);
-    let script_query = match query.status.as_deref().and_then(ScriptStatus::parse) {
-        Some(status) => crate::storage::ScriptQuery::ByStatus(status),
-        None => crate::storage::ScriptQuery::All,
-    };
-
     let page = runtime
         .storage
-        .find_paginated(script_query, query.offset(), query.limit())
+        .find_paginated(query.script_query(), query.offset(), query.limit())
         .await
         .map_err(script_error)?;
 
@@ -97,6 +96,7 @@ pub async fn create_script(
     script.tenant_id = req.tenant_id.unwrap_or(tenant.id);
     script.description = req.description;
     script.permissions = req.permissions;
+    script.tags = req.tags;
     script.run_as_system = req.run_as_system;
 
     let saved = runtime.storage.save(script).await.map_err(script_error)?;
@@ -132,6 +132,9 @@ pub async fn update_script(
     if let Some(permissions) = req.permissions {
         script.permissions = permissions;
     }
+    if let Some(tags) = req.tags {
+        script.tags = tags;
+    }
 
     let saved = runtime.storage.save(script).await.map_err(script_error)?;
     Ok(Json(saved.into()))
@@ -236,8 +239,39 @@ pub async fn validate_script(
     }
 }
 
+pub async fn export_scripts(
+    State(ctx): State<AppContext>,
+    tenant: TenantContext,
+    Query(query): Query<ListScriptsQuery>,
+) -> Result<Json<ScriptBundle>> {
+    let runtime = crate::runtime::scoped_runtime(&ctx, tenant.id);
+    let bundle = crate::bundle::export_scripts(runtime.storage.as_ref(), query.script_query())
+        .await
+        .map_err(script_error)?;
+    Ok(Json(bundle))
+}
+
+pub async fn import_scripts(
+    State(ctx): State<AppContext>,
+    tenant: TenantContext,
+    Json(req): Json<ImportScriptsRequest>,
+) -> Result<Json<ImportReport>> {
+    let runtime = crate::runtime::scoped_runtime(&ctx, tenant.id);
+    let report = crate::bundle::import_scripts(
+        runtime.storage.as_ref(),
+        &runtime.engine,
+        tenant.id,
+        req.bundle,
+        req.overwrite,
+    )
+    .await
+    .map_err(script_error)?;
+    Ok(Json(report))
+}
+
 fn run_response(result: crate::ExecutionResult) -> RunScriptResponse {
     let duration_ms = result.duration_ms();
+    let changed = FieldDiff::from_result(&result);
     let (success, error, changes, return_value) = match result.outcome {
         ExecutionOutcome::Success {
             return_value,
@@ -255,7 +289,7 @@ fn run_response(result: crate::ExecutionResult) -> RunScriptResponse {
                 .map(dynamic_to_json)
                 .unwrap_or(serde_json::Value::Null),
         ),
-        ExecutionOutcome::Aborted { reason } => {
+        ExecutionOutcome::Aborted { reason, .. } => {
             (false, Some(reason), None, serde_json::Value::Null)
         }
         ExecutionOutcome::Failed { ref error } => (
@@ -272,6 +306,7 @@ fn run_response(result: crate::ExecutionResult) -> RunScriptResponse {
         duration_ms,
         error,
         changes,
+        changed,
         return_value,
     }
 }
@@ -306,6 +341,8 @@ pub fn routes() -> Routes {
         .prefix("api/alloy")
         .add("/scripts", get(list_scripts).post(create_script))
         .add("/scripts/validate", post(validate_script))
+        .add("/scripts/export", get(export_scripts))
+        .add("/scripts/import", post(import_scripts))
         .add(
             "/scripts/{id}",
             get(get_script).put(update_script).delete(delete_script),
@@ -1,5 +1,23 @@
 use std::time::Duration;
 
+use crate::bridge::HostCircuitBreakerConfig;
+
+/// Лимит исходящих HTTP-вызовов скриптов одного tenant за скользящее окно
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutboundRateLimit {
+    pub max_calls: u32,
+    pub window: Duration,
+}
+
+impl Default for OutboundRateLimit {
+    fn default() -> Self {
+        Self {
+            max_calls: 60,
+            window: Duration::from_secs(60),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct EngineConfig {
     /// Максимум операций на один запуск
@@ -8,6 +26,14 @@ pub struct EngineConfig {
     /// Таймаут выполнения
     pub timeout: Duration,
 
+    /// Жёсткий лимит wall-clock времени (`None` — без ограничения); в отличие от
+    /// `max_operations` срабатывает и во время долгих native-вызовов
+    pub max_duration: Option<Duration>,
+
+    /// Максимум одновременных потоков `max_duration`, включая потоки скриптов, брошенных
+    /// после дедлайна и ещё ждущих возврата native-вызова
+    pub max_script_threads: usize,
+
     /// Максимум глубины вызова функций
     pub max_call_depth: usize,
 
@@ -19,6 +45,13 @@ pub struct EngineConfig {
 
     /// Максимум глубины вложенных объектов
     pub max_map_depth: usize,
+
+    /// Лимит исходящих HTTP-вызовов на tenant (`None` — без ограничения)
+    pub outbound_rate_limit: Option<OutboundRateLimit>,
+
+    /// Настройки per-host circuit breaker для исходящих вызовов; реестр общий на процесс,
+    /// поэтому действует конфиг первого созданного engine
+    pub circuit_breaker: HostCircuitBreakerConfig,
 }
 
 impl Default for EngineConfig {
@@ -26,10 +59,14 @@ impl Default for EngineConfig {
         Self {
             max_operations: 50_000,
             timeout: Duration::from_millis(100),
+            max_duration: None,
+            max_script_threads: 64,
             max_call_depth: 16,
             max_string_size: 64 * 1024,
             max_array_size: 10_000,
             max_map_depth: 16,
+            outbound_rate_limit: Some(OutboundRateLimit::default()),
+            circuit_breaker: HostCircuitBreakerConfig::default(),
         }
     }
 }
@@ -1,5 +1,5 @@
 mod config;
 mod runtime;
 
-pub use config::EngineConfig;
+pub use config::{EngineConfig, OutboundRateLimit};
 pub use runtime::ScriptEngine;
@@ -1,40 +1,104 @@
 use parking_lot::RwLock;
-use rhai::{Dynamic, Engine, EvalAltResult, RhaiNativeFunc, Scope, AST};
+use rhai::{
+    ASTNode, Dynamic, Engine, EvalAltResult, Expr, FnCallExpr, FnPtr, RhaiNativeFunc, Scope, Stmt,
+    AST,
+};
+use std::cell::Cell;
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
-use crate::context::ExecutionContext;
+use crate::bridge::{
+    enter_kv_scope, enter_outbound_scope, enter_phase_scope, CircuitBreakerRegistry,
+    OutboundRateLimiter, ScriptKvStore, EXTERNAL_SERVICE_FUNCTIONS, FORBIDDEN_CALL_PREFIX,
+    RATE_LIMITED_PREFIX,
+};
+use crate::context::{ExecutionContext, ExecutionPhase};
 use crate::error::{ScriptError, ScriptResult};
+use crate::model::EntityProxy;
 
 use super::config::EngineConfig;
 
+thread_local! {
+    // Set only on threads spawned for `max_duration`, so a script that outlives its
+    // deadline is terminated by the progress callback once its native call returns.
+    static DEADLINE: Cell<Option<Instant>> = const { Cell::new(None) };
+}
+
+/// Termination token of a script stopped by [`DEADLINE`].
+#[derive(Debug, Clone, Copy)]
+struct DeadlineExceeded;
+
+/// Holds one of the engine's `max_script_threads` slots until the script thread exits.
+struct ScriptThreadSlot(Arc<AtomicUsize>);
+
+impl Drop for ScriptThreadSlot {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
 pub struct CompiledScript {
+    name: String,
     ast: AST,
     source_hash: u64,
+    /// First external-service function the script references, if any.
+    external_call: Option<String>,
 }
 
 pub struct ScriptEngine {
-    engine: Engine,
+    engine: Arc<Engine>,
     config: EngineConfig,
     cache: RwLock<HashMap<String, Arc<CompiledScript>>>,
+    outbound_limiter: Option<Arc<OutboundRateLimiter>>,
+    kv_store: Option<Arc<ScriptKvStore>>,
+    script_threads: Arc<AtomicUsize>,
 }
 
 impl ScriptEngine {
     pub fn new(config: EngineConfig) -> Self {
+        Self::new_with_setup(config, |_| {})
+    }
+
+    /// Like [`ScriptEngine::new`], with `setup` registering functions and types on the
+    /// Rhai engine before any script can run.
+    pub fn new_with_setup(config: EngineConfig, setup: impl FnOnce(&mut Engine)) -> Self {
         let mut engine = Engine::new();
 
         engine.set_allow_looping(true);
         engine.set_allow_shadowing(true);
         engine.set_strict_variables(true);
+        engine.on_progress(|_| {
+            DEADLINE
+                .with(Cell::get)
+                .filter(|deadline| Instant::now() >= *deadline)
+                .map(|_| Dynamic::from(DeadlineExceeded))
+        });
+        setup(&mut engine);
+        CircuitBreakerRegistry::init(config.circuit_breaker.clone());
+
+        let outbound_limiter = config
+            .outbound_rate_limit
+            .map(|limit| Arc::new(OutboundRateLimiter::new(limit)));
 
         Self {
-            engine,
+            engine: Arc::new(engine),
             config,
             cache: RwLock::new(HashMap::new()),
+            outbound_limiter,
+            kv_store: None,
+            script_threads: Arc::new(AtomicUsize::new(0)),
         }
     }
 
+    /// Backs the `kv_get`/`kv_set` bridge functions; without a store they fail.
+    pub fn with_kv_store(mut self, store: Arc<ScriptKvStore>) -> Self {
+        self.kv_store = Some(store);
+        self
+    }
+
     fn compute_hash(source: &str) -> u64 {
         use std::collections::hash_map::DefaultHasher;
         use std::hash::{Hash, Hasher};
@@ -48,19 +112,39 @@ impl ScriptEngine {
         &mut self,
         name: &str,
         func: impl RhaiNativeFunc<A, N, X, R, F> + Send + Sync + 'static,
-    ) where
+    ) -> ScriptResult<()>
+    where
         A: 'static,
         R: 'static + Clone + Send + Sync,
     {
-        self.engine.register_fn(name, func);
+        self.engine_mut()
+            .ok_or_else(Self::engine_busy)?
+            .register_fn(name, func);
+        Ok(())
     }
 
-    pub fn register_type<T: Clone + Send + Sync + 'static>(&mut self, name: &str) {
-        self.engine.register_type_with_name::<T>(name);
+    pub fn register_type<T: Clone + Send + Sync + 'static>(
+        &mut self,
+        name: &str,
+    ) -> ScriptResult<()> {
+        self.engine_mut()
+            .ok_or_else(Self::engine_busy)?
+            .register_type_with_name::<T>(name);
+        Ok(())
     }
 
-    pub fn engine_mut(&mut self) -> &mut Engine {
-        &mut self.engine
+    /// `None` while a script abandoned after `max_duration` is still running in the
+    /// background and shares the engine; register functions and types before executing
+    /// scripts, or through [`ScriptEngine::new_with_setup`].
+    pub fn engine_mut(&mut self) -> Option<&mut Engine> {
+        Arc::get_mut(&mut self.engine)
+    }
+
+    fn engine_busy() -> ScriptError {
+        ScriptError::Runtime(
+            "Script engine cannot be configured while a timed-out script is still running"
+                .to_string(),
+        )
     }
 
     pub fn compile(
@@ -85,7 +169,12 @@ impl ScriptEngine {
             .compile_with_scope(scope, source)
             .map_err(|e| ScriptError::Compilation(e.to_string()))?;
 
-        let compiled = Arc::new(CompiledScript { ast, source_hash });
+        let compiled = Arc::new(CompiledScript {
+            name: name.to_string(),
+            external_call: find_forbidden_call(&ast),
+            ast,
+            source_hash,
+        });
 
         let mut cache = self.cache.write();
         cache.insert(name.to_string(), Arc::clone(&compiled));
@@ -93,6 +182,41 @@ impl ScriptEngine {
         Ok(compiled)
     }
 
+    /// Compiles `source` without caching or executing it and rejects calls to
+    /// [`EXTERNAL_SERVICE_FUNCTIONS`], directly or through `Fn("...")` pointers.
+    ///
+    /// Every binding a script can see at run time (`entity`, `entity_before`, `params`,
+    /// `USER_ID`, ...) is in scope, so strict-variable checks match execution.
+    pub fn validate(&self, name: &str, source: &str) -> ScriptResult<()> {
+        let ast = self.compile_uncached(source)?;
+
+        match find_forbidden_call(&ast) {
+            Some(call) => {
+                tracing::debug!(script = name, call = %call, "Script references a forbidden call");
+                Err(ScriptError::ForbiddenCall { name: call })
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Compiles `source` in the full execution scope without caching it, so a script is
+    /// checked the way it will run; external-service calls are not rejected here.
+    pub fn check_compiles(&self, source: &str) -> ScriptResult<()> {
+        self.compile_uncached(source).map(|_| ())
+    }
+
+    fn compile_uncached(&self, source: &str) -> ScriptResult<AST> {
+        let scope = ExecutionContext::new(ExecutionPhase::Manual)
+            .with_user("")
+            .with_tenant("")
+            .with_entity_proxy(EntityProxy::empty("entity"))
+            .with_entity_before_proxy(EntityProxy::empty("entity"))
+            .to_scope();
+        self.engine
+            .compile_with_scope(&scope, source)
+            .map_err(|e| ScriptError::Compilation(e.to_string()))
+    }
+
     pub fn invalidate(&self, name: &str) {
         let mut cache = self.cache.write();
         cache.remove(name);
@@ -109,9 +233,26 @@ impl ScriptEngine {
         source: &str,
         ctx: &ExecutionContext,
     ) -> ScriptResult<Dynamic> {
+        self.execute_with_scope(name, source, ctx)
+            .map(|(value, _)| value)
+    }
+
+    /// Like [`ScriptEngine::execute`], but also returns the scope as the script left it.
+    ///
+    /// The scope starts as [`ExecutionContext::to_scope`]. An entity attached with
+    /// [`ExecutionContext::with_entity_proxy`] is bound as writable `entity` in the `Before`
+    /// and `Manual` phases, so writes such as `entity.status = "approved"` can be read back
+    /// from the returned scope (the proxy shares state with the one in `ctx`). Top-level
+    /// `let` bindings of the script are kept as well.
+    pub fn execute_with_scope(
+        &self,
+        name: &str,
+        source: &str,
+        ctx: &ExecutionContext,
+    ) -> ScriptResult<(Dynamic, Scope<'static>)> {
         let mut scope = ctx.to_scope();
         let compiled = self.compile(name, source, &mut scope)?;
-        self.execute_compiled_with_timeout(&compiled, scope)
+        self.execute_compiled_with_timeout(&compiled, scope, ctx)
     }
 
     pub fn execute_compiled(
@@ -120,18 +261,42 @@ impl ScriptEngine {
         ctx: &ExecutionContext,
     ) -> ScriptResult<Dynamic> {
         let scope = ctx.to_scope();
-        self.execute_compiled_with_timeout(compiled, scope)
+        self.execute_compiled_with_timeout(compiled, scope, ctx)
+            .map(|(value, _)| value)
     }
 
     fn execute_compiled_with_timeout(
         &self,
         compiled: &CompiledScript,
-        mut scope: Scope,
-    ) -> ScriptResult<Dynamic> {
+        mut scope: Scope<'static>,
+        ctx: &ExecutionContext,
+    ) -> ScriptResult<(Dynamic, Scope<'static>)> {
+        // The engine may carry bridges of other phases; only the executing phase decides.
+        // Names built at run time are caught by the bridge itself (see `enter_phase_scope`).
+        if let Some(call) = compiled.external_call.as_ref() {
+            if !ctx.phase.allows_external_services() {
+                tracing::warn!(
+                    script = %compiled.name,
+                    phase = ctx.phase.as_str(),
+                    call = %call,
+                    "Script calls an external service outside of an external-service phase"
+                );
+                return Err(ScriptError::ForbiddenCall { name: call.clone() });
+            }
+        }
+
+        if let Some(max_duration) = self.config.max_duration {
+            return self.execute_with_deadline(compiled, scope, ctx, max_duration);
+        }
+
         let timeout = self.config.timeout;
         let max_ops = self.config.max_operations;
         let start = Instant::now();
 
+        let tenant_id = ctx.tenant_id.as_deref();
+        let _phase = enter_phase_scope(ctx.phase);
+        let _outbound = enter_outbound_scope(self.outbound_limiter.clone(), tenant_id);
+        let _kv = enter_kv_scope(self.kv_store.clone(), tenant_id, &compiled.name);
         let result = self
             .engine
             .eval_ast_with_scope::<Dynamic>(&mut scope, &compiled.ast);
@@ -145,10 +310,108 @@ impl ScriptEngine {
             );
         }
 
-        result.map_err(|e| Self::convert_error(*e, max_ops))
+        result
+            .map(|value| (value, scope))
+            .map_err(|e| Self::convert_error(*e, max_ops))
+    }
+
+    /// Evaluates on a dedicated thread and stops waiting once `max_duration` has passed,
+    /// even if the script is blocked inside a native function.
+    ///
+    /// Rust cannot kill a thread, so a script blocked in a native call keeps its thread
+    /// until the call returns and the progress callback stops it. Such abandoned threads
+    /// count against `max_script_threads` together with the running ones; once the limit is
+    /// reached, executions fail fast instead of spawning more threads.
+    fn execute_with_deadline(
+        &self,
+        compiled: &CompiledScript,
+        mut scope: Scope<'static>,
+        ctx: &ExecutionContext,
+        max_duration: Duration,
+    ) -> ScriptResult<(Dynamic, Scope<'static>)> {
+        let engine = Arc::clone(&self.engine);
+        let ast = compiled.ast.clone();
+        let script_name = compiled.name.clone();
+        let tenant_id = ctx.tenant_id.clone();
+        let phase = ctx.phase;
+        let outbound_limiter = self.outbound_limiter.clone();
+        let kv_store = self.kv_store.clone();
+        // `http_*` and `kv_*` bridges block on the caller's Tokio runtime.
+        let runtime = tokio::runtime::Handle::try_current().ok();
+        let (sender, receiver) = mpsc::sync_channel(1);
+        let start = Instant::now();
+
+        let max_threads = self.config.max_script_threads;
+        if self.script_threads.fetch_add(1, Ordering::SeqCst) >= max_threads {
+            self.script_threads.fetch_sub(1, Ordering::SeqCst);
+            tracing::warn!(
+                script = %compiled.name,
+                max_threads,
+                "Script thread limit reached, refusing to start script"
+            );
+            return Err(ScriptError::Runtime(format!(
+                "Too many script threads still running (limit {max_threads})"
+            )));
+        }
+        let slot = ScriptThreadSlot(Arc::clone(&self.script_threads));
+
+        std::thread::Builder::new()
+            .name(format!("alloy-script-{}", compiled.name))
+            .spawn(move || {
+                // Locals drop in reverse order: the engine clone goes before the slot does.
+                let _slot = slot;
+                let engine = engine;
+                let _runtime = runtime.as_ref().map(|handle| handle.enter());
+                let tenant_id = tenant_id.as_deref();
+                let _phase = enter_phase_scope(phase);
+                let _outbound = enter_outbound_scope(outbound_limiter, tenant_id);
+                let _kv = enter_kv_scope(kv_store, tenant_id, &script_name);
+                DEADLINE.with(|deadline| deadline.set(Some(start + max_duration)));
+
+                let result = engine.eval_ast_with_scope::<Dynamic>(&mut scope, &ast);
+                // The receiver is gone once the caller has given up on this script.
+                let _ = sender.send((result, scope));
+            })
+            .map_err(|e| ScriptError::Runtime(format!("Failed to spawn script thread: {e}")))?;
+
+        let (result, scope) = match receiver.recv_timeout(max_duration) {
+            Ok(outcome) => outcome,
+            Err(RecvTimeoutError::Timeout) => {
+                return Err(Self::deadline_error(&compiled.name, start, max_duration))
+            }
+            Err(RecvTimeoutError::Disconnected) => {
+                return Err(ScriptError::Runtime("Script thread panicked".to_string()))
+            }
+        };
+
+        result.map(|value| (value, scope)).map_err(|e| match *e {
+            EvalAltResult::ErrorTerminated(ref token, _) if token.is::<DeadlineExceeded>() => {
+                Self::deadline_error(&compiled.name, start, max_duration)
+            }
+            other => Self::convert_error(other, self.config.max_operations),
+        })
+    }
+
+    fn deadline_error(name: &str, start: Instant, max_duration: Duration) -> ScriptError {
+        let elapsed_ms = start.elapsed().as_millis() as u64;
+        let limit_ms = max_duration.as_millis() as u64;
+        tracing::warn!(
+            script = name,
+            elapsed_ms,
+            limit_ms,
+            "Script exceeded max_duration and was abandoned"
+        );
+        ScriptError::Timeout {
+            limit_ms,
+            elapsed_ms,
+        }
     }
 
     fn convert_error(err: EvalAltResult, op_limit: u64) -> ScriptError {
+        if let Some(name) = forbidden_call(&err) {
+            return ScriptError::ForbiddenCall { name };
+        }
+
         match err {
             EvalAltResult::ErrorTerminated(reason, _) => ScriptError::Aborted(reason.to_string()),
             EvalAltResult::ErrorTooManyOperations(_) => {
@@ -158,6 +421,16 @@ impl ScriptEngine {
                 let msg_str = msg.to_string();
                 if msg_str.starts_with("ABORT:") {
                     ScriptError::Aborted(msg_str.trim_start_matches("ABORT:").trim().to_string())
+                } else if let Some((code, message)) = msg_str
+                    .strip_prefix("ABORT[")
+                    .and_then(|rest| rest.split_once("]:"))
+                {
+                    ScriptError::AbortedWithCode {
+                        code: code.to_string(),
+                        message: message.trim().to_string(),
+                    }
+                } else if let Some(reason) = msg_str.strip_prefix(RATE_LIMITED_PREFIX) {
+                    ScriptError::RateLimited(reason.trim().to_string())
                 } else {
                     ScriptError::Runtime(msg_str)
                 }
@@ -170,3 +443,58 @@ impl ScriptEngine {
         &self.config
     }
 }
+
+/// Call the bridge refused at run time, also when it was made inside a script function.
+fn forbidden_call(err: &EvalAltResult) -> Option<String> {
+    match err {
+        EvalAltResult::ErrorRuntime(msg, _) => msg
+            .to_string()
+            .strip_prefix(FORBIDDEN_CALL_PREFIX)
+            .map(|name| name.trim().to_string()),
+        EvalAltResult::ErrorInFunctionCall(_, _, inner, _) => forbidden_call(inner),
+        _ => None,
+    }
+}
+
+fn find_forbidden_call(ast: &AST) -> Option<String> {
+    let mut forbidden = None;
+    // The walk stops as soon as the callback returns `false`.
+    let walked_all = ast.walk(&mut |path: &[ASTNode]| {
+        forbidden = path
+            .last()
+            .and_then(referenced_function)
+            .filter(|name| EXTERNAL_SERVICE_FUNCTIONS.contains(&name.as_str()));
+        forbidden.is_none()
+    });
+    if walked_all {
+        None
+    } else {
+        forbidden
+    }
+}
+
+/// Function a node calls or points to.
+fn referenced_function(node: &ASTNode) -> Option<String> {
+    match node {
+        ASTNode::Expr(Expr::FnCall(call, _) | Expr::MethodCall(call, _)) => {
+            Some(called_function(call))
+        }
+        // A call used as a statement, e.g. `http_get(url);`.
+        ASTNode::Stmt(Stmt::FnCall(call, _)) => Some(called_function(call)),
+        // The optimizer folds `Fn("...")` with a literal name into a constant.
+        ASTNode::Expr(Expr::DynamicConstant(value, _)) => value
+            .as_ref()
+            .clone()
+            .try_cast::<FnPtr>()
+            .map(|pointer| pointer.fn_name().to_string()),
+        _ => None,
+    }
+}
+
+fn called_function(call: &FnCallExpr) -> String {
+    match (call.name.as_str(), call.args.first()) {
+        // `Fn("http_get")` builds a pointer that `call(...)` would dispatch later.
+        ("Fn", Some(Expr::StringConstant(target, _))) => target.to_string(),
+        (name, _) => name.to_string(),
+    }
+}
@@ -11,8 +11,12 @@ pub enum ScriptError {
     #[error("Script aborted: {0}")]
     Aborted(String),
 
-    #[error("Timeout: script exceeded {limit_ms}ms")]
-    Timeout { limit_ms: u64 },
+    /// `abort_with(code, message)`: an abort callers can match on by `code`.
+    #[error("Script aborted [{code}]: {message}")]
+    AbortedWithCode { code: String, message: String },
+
+    #[error("Timeout: script exceeded {limit_ms}ms (stopped after {elapsed_ms}ms)")]
+    Timeout { limit_ms: u64, elapsed_ms: u64 },
 
     #[error("Operation limit exceeded: {limit} operations")]
     OperationLimit { limit: u64 },
@@ -31,6 +35,18 @@ pub enum ScriptError {
 
     #[error("Invalid status: {0}")]
     InvalidStatus(String),
+
+    #[error("Invalid script bundle: {0}")]
+    InvalidBundle(String),
+
+    #[error("Invalid entity proxy: {0}")]
+    InvalidProxy(String),
+
+    #[error("Forbidden call: {name} is not allowed in this script")]
+    ForbiddenCall { name: String },
+
+    #[error("Outbound rate limit exceeded: {0}")]
+    RateLimited(String),
 }
 
 pub type ScriptResult<T> = Result<T, ScriptError>;
@@ -2,4 +2,4 @@ pub mod migration;
 pub mod storage;
 
 pub use migration::ScriptExecutionsMigration;
-pub use storage::{ExecutionLogEntry, SeaOrmExecutionLog};
+pub use storage::{ExecutionLogEntry, ExecutionLogFilter, SeaOrmExecutionLog};
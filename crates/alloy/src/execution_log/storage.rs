@@ -45,6 +45,23 @@ pub struct ExecutionLogEntry {
     pub created_at: DateTime<Utc>,
 }
 
+/// Narrows [`SeaOrmExecutionLog::query`]; unset fields match every record.
+#[derive(Debug, Clone, Default)]
+pub struct ExecutionLogFilter {
+    pub script_id: Option<ScriptId>,
+    pub phase: Option<ExecutionPhase>,
+    /// Stored outcome: `"success"`, `"aborted"` or `"failed"`.
+    pub status: Option<String>,
+    pub tenant_id: Option<Uuid>,
+    /// Inclusive lower bound on the start time.
+    pub from: Option<DateTime<Utc>>,
+    /// Exclusive upper bound on the start time.
+    pub to: Option<DateTime<Utc>>,
+    pub limit: Option<u64>,
+    /// Only applied together with `limit`.
+    pub offset: u64,
+}
+
 #[derive(Clone)]
 pub struct SeaOrmExecutionLog {
     db: DatabaseConnection,
@@ -62,7 +79,7 @@ impl SeaOrmExecutionLog {
             ExecutionOutcome::Failed { .. } => "failed",
         };
         let error_str = match &result.outcome {
-            ExecutionOutcome::Aborted { reason } => Some(reason.clone()),
+            ExecutionOutcome::Aborted { reason, .. } => Some(reason.clone()),
             ExecutionOutcome::Failed { error } => Some(error.to_string()),
             ExecutionOutcome::Success { .. } => None,
         };
@@ -100,7 +117,7 @@ impl SeaOrmExecutionLog {
             ExecutionOutcome::Failed { .. } => "failed",
         };
         let error_str = match &result.outcome {
-            ExecutionOutcome::Aborted { reason } => Some(reason.clone()),
+            ExecutionOutcome::Aborted { reason, .. } => Some(reason.clone()),
             ExecutionOutcome::Failed { error } => Some(error.to_string()),
             ExecutionOutcome::Success { .. } => None,
         };
@@ -152,6 +169,42 @@ impl SeaOrmExecutionLog {
 
         Ok(models.into_iter().map(model_to_entry).collect())
     }
+
+    /// Records matching `filter`, newest first.
+    pub async fn query(&self, filter: ExecutionLogFilter) -> ScriptResult<Vec<ExecutionLogEntry>> {
+        let mut select = Entity::find();
+        if let Some(script_id) = filter.script_id {
+            select = select.filter(Column::ScriptId.eq(script_id));
+        }
+        if let Some(phase) = filter.phase {
+            select = select.filter(Column::Phase.eq(phase_to_str(phase)));
+        }
+        if let Some(status) = filter.status {
+            select = select.filter(Column::Outcome.eq(status));
+        }
+        if let Some(tenant_id) = filter.tenant_id {
+            select = select.filter(Column::TenantId.eq(tenant_id));
+        }
+        if let Some(from) = filter.from {
+            select = select.filter(Column::CreatedAt.gte(from));
+        }
+        if let Some(to) = filter.to {
+            select = select.filter(Column::CreatedAt.lt(to));
+        }
+
+        let mut select = select.order_by_desc(Column::CreatedAt);
+        // SQLite rejects OFFSET without LIMIT, so paging needs both.
+        if let Some(limit) = filter.limit {
+            select = select.limit(limit).offset(filter.offset);
+        }
+
+        let models = select
+            .all(&self.db)
+            .await
+            .map_err(|err| ScriptError::Storage(err.to_string()))?;
+
+        Ok(models.into_iter().map(model_to_entry).collect())
+    }
 }
 
 fn phase_to_str(phase: ExecutionPhase) -> String {
@@ -188,3 +241,122 @@ fn model_to_entry(model: Model) -> ExecutionLogEntry {
         created_at: model.created_at,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+    use sea_orm_migration::{MigrationTrait, SchemaManager};
+
+    use super::*;
+    use crate::execution_log::ScriptExecutionsMigration;
+
+    async fn log() -> SeaOrmExecutionLog {
+        let db = sea_orm::Database::connect("sqlite::memory:").await.unwrap();
+        ScriptExecutionsMigration
+            .up(&SchemaManager::new(&db))
+            .await
+            .unwrap();
+        SeaOrmExecutionLog::new(db)
+    }
+
+    fn result(
+        script_id: ScriptId,
+        started_at: DateTime<Utc>,
+        outcome: ExecutionOutcome,
+    ) -> ExecutionResult {
+        ExecutionResult {
+            script_id,
+            script_name: "sync_order".to_string(),
+            execution_id: Uuid::new_v4(),
+            phase: ExecutionPhase::OnCommit,
+            started_at,
+            finished_at: started_at,
+            outcome,
+            entity: None,
+        }
+    }
+
+    fn aborted() -> ExecutionOutcome {
+        ExecutionOutcome::Aborted {
+            reason: "order is locked".to_string(),
+            code: None,
+        }
+    }
+
+    fn success() -> ExecutionOutcome {
+        ExecutionOutcome::Success {
+            return_value: None,
+            entity_changes: Default::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_query_filters_by_status_newest_first() {
+        let log = log().await;
+        let script_id = Uuid::new_v4();
+        let now = Utc::now();
+        for (minutes, outcome) in [(3, success()), (2, aborted()), (1, aborted())] {
+            log.record(&result(
+                script_id,
+                now - Duration::minutes(minutes),
+                outcome,
+            ))
+            .await
+            .unwrap();
+        }
+        log.record(&result(Uuid::new_v4(), now, aborted()))
+            .await
+            .unwrap();
+
+        let aborted = log
+            .query(ExecutionLogFilter {
+                script_id: Some(script_id),
+                status: Some("aborted".to_string()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(aborted.len(), 2);
+        assert!(aborted[0].created_at > aborted[1].created_at);
+        assert!(aborted.iter().all(|entry| entry.script_id == script_id));
+
+        let second_page = log
+            .query(ExecutionLogFilter {
+                script_id: Some(script_id),
+                limit: Some(1),
+                offset: 1,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(second_page.len(), 1);
+        assert_eq!(second_page[0].id, aborted[1].id);
+    }
+
+    #[tokio::test]
+    async fn test_query_filters_by_time_window() {
+        let log = log().await;
+        let script_id = Uuid::new_v4();
+        let now = Utc::now();
+        let mut ids = Vec::new();
+        for hours in [1, 5, 30] {
+            let result = result(script_id, now - Duration::hours(hours), success());
+            log.record(&result).await.unwrap();
+            ids.push(result.execution_id);
+        }
+
+        let window = log
+            .query(ExecutionLogFilter {
+                from: Some(now - Duration::hours(24)),
+                to: Some(now - Duration::hours(2)),
+                phase: Some(ExecutionPhase::OnCommit),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(window.len(), 1);
+        assert_eq!(window[0].id, ids[1]);
+    }
+}
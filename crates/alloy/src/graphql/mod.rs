@@ -2,27 +2,32 @@ mod mutation;
 mod query;
 mod types;
 
-use async_graphql::{Context, FieldError, Result};
+use async_graphql::{Context, ErrorExtensions, Result};
 use loco_rs::app::AppContext;
-use rustok_api::{graphql::GraphQLError, has_any_effective_permission, AuthContext, TenantContext};
-use rustok_core::{permissions::Action, Permission, Resource};
+use rustok_api::{require_permission, AuthContext, TenantContext};
+use rustok_core::{permissions::Action, Resource};
+
+use crate::ScriptError;
 
 pub use mutation::AlloyMutation;
 pub use query::AlloyQuery;
 pub use types::*;
 
-pub(crate) async fn require_admin(ctx: &Context<'_>) -> Result<AuthContext> {
-    let auth = ctx
-        .data::<AuthContext>()
-        .map_err(|_| <FieldError as GraphQLError>::unauthenticated())?
-        .clone();
-
-    let required = Permission::new(Resource::Scripts, Action::Manage);
-    if !has_any_effective_permission(&auth.permissions, &[required]) {
-        return Err(<FieldError as GraphQLError>::permission_denied("Forbidden"));
+/// `abort_with` codes are exposed as `extensions.code` so clients can branch on them.
+impl ErrorExtensions for ScriptError {
+    fn extend(&self) -> async_graphql::Error {
+        let error = async_graphql::Error::new(self.to_string());
+        match self {
+            ScriptError::AbortedWithCode { code, .. } => {
+                error.extend_with(|_, extensions| extensions.set("code", code.as_str()))
+            }
+            _ => error,
+        }
     }
+}
 
-    Ok(auth)
+pub(crate) async fn require_admin(ctx: &Context<'_>) -> Result<AuthContext> {
+    require_permission!(ctx, Resource::Scripts, Action::Manage)
 }
 
 pub(crate) fn runtime_from_graphql_ctx(
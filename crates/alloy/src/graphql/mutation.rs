@@ -1,14 +1,14 @@
 use std::collections::HashMap;
 
-use async_graphql::{Context, Json, Object, Result};
+use async_graphql::{Context, ErrorExtensions, Json, Object, Result};
 use chrono::Utc;
 use uuid::Uuid;
 
 use crate::{
     model::{Script, ScriptStatus},
     runner::ExecutionOutcome,
-    utils::{dynamic_to_json, json_to_dynamic, validate_cron_expression},
-    ScriptRegistry,
+    utils::{dynamic_to_json, json_to_dynamic, validate_cron_expression, validate_timezone},
+    ScriptError, ScriptRegistry,
 };
 
 use super::{
@@ -21,6 +21,9 @@ fn validate_cron_trigger(trigger: &ScriptTriggerInput) -> Result<()> {
         validate_cron_expression(&cron.expression).map_err(|error| {
             async_graphql::Error::new(format!("Invalid cron expression: {error}"))
         })?;
+        if let Some(timezone) = &cron.timezone {
+            validate_timezone(timezone).map_err(async_graphql::Error::new)?;
+        }
     }
 
     Ok(())
@@ -55,6 +58,7 @@ impl AlloyMutation {
         script.description = input.description;
         script.run_as_system = input.run_as_system;
         script.permissions = input.permissions;
+        script.tags = input.tags;
         script.author_id = input.author_id;
         if let Some(status) = input.status {
             script.status = status.into();
@@ -75,7 +79,7 @@ impl AlloyMutation {
         id: Uuid,
         input: UpdateScriptInput,
     ) -> Result<GqlScript> {
-        require_admin(ctx).await?;
+        let auth = require_admin(ctx).await?;
         let runtime = runtime_from_graphql_ctx(ctx)?;
         let mut script = runtime
             .storage
@@ -114,6 +118,9 @@ impl AlloyMutation {
         if let Some(permissions) = input.permissions {
             script.permissions = permissions;
         }
+        if let Some(tags) = input.tags {
+            script.tags = tags;
+        }
         if input.clear_author_id {
             script.author_id = None;
         } else if let Some(author_id) = input.author_id {
@@ -122,7 +129,7 @@ impl AlloyMutation {
 
         let saved = runtime
             .storage
-            .save(script)
+            .update(script, Some(auth.user_id.to_string()))
             .await
             .map_err(|error| async_graphql::Error::new(error.to_string()))?;
 
@@ -169,7 +176,7 @@ impl AlloyMutation {
             .orchestrator
             .run_manual(&input.script_name, params, user_id.clone())
             .await
-            .map_err(|error| async_graphql::Error::new(error.to_string()))?;
+            .map_err(|error| error.extend())?;
 
         let tenant_id = ctx
             .data::<rustok_api::TenantContext>()
@@ -196,7 +203,25 @@ impl AlloyMutation {
                         .collect(),
                 )),
             ),
-            ExecutionOutcome::Aborted { ref reason } => (false, Some(reason.clone()), None, None),
+            // A coded abort becomes a GraphQL error so clients can branch on `extensions.code`;
+            // `extensions.executionId` points at the logged run.
+            ExecutionOutcome::Aborted {
+                ref reason,
+                code: Some(ref code),
+            } => {
+                return Err(ScriptError::AbortedWithCode {
+                    code: code.clone(),
+                    message: reason.clone(),
+                }
+                .extend()
+                .extend_with(|_, extensions| {
+                    extensions.set("executionId", result.execution_id.to_string())
+                }));
+            }
+            ExecutionOutcome::Aborted {
+                ref reason,
+                code: None,
+            } => (false, Some(reason.clone()), None, None),
             ExecutionOutcome::Failed { ref error } => (false, Some(error.to_string()), None, None),
         };
 
@@ -1,13 +1,17 @@
 use async_graphql::{Context, Object, Result};
 use rustok_api::graphql::{PageInfo, PaginationInput};
+use rustok_api::TenantContext;
 use rustok_telemetry::metrics;
 use uuid::Uuid;
 
-use crate::{storage::ScriptQuery, ScriptRegistry};
+use crate::{
+    storage::{ScriptFilter, ScriptQuery},
+    ExecutionLogFilter, ScriptRegistry,
+};
 
 use super::{
-    require_admin, runtime_from_graphql_ctx, GqlEventType, GqlScript, GqlScriptConnection,
-    GqlScriptStatus,
+    require_admin, runtime_from_graphql_ctx, ExecutionLogFilterInput, GqlEventType,
+    GqlExecutionLogEntry, GqlScript, GqlScriptConnection, ScriptFilterInput,
 };
 
 #[derive(Default)]
@@ -18,16 +22,19 @@ impl AlloyQuery {
     async fn scripts(
         &self,
         ctx: &Context<'_>,
-        status: Option<GqlScriptStatus>,
+        #[graphql(default)] filter: ScriptFilterInput,
         #[graphql(default)] pagination: PaginationInput,
     ) -> Result<GqlScriptConnection> {
         require_admin(ctx).await?;
         let state = runtime_from_graphql_ctx(ctx)?;
         let requested_limit = pagination.requested_limit();
-        let query = match status {
-            Some(status) => ScriptQuery::ByStatus(status.into()),
-            None => ScriptQuery::All,
-        };
+        let query = ScriptQuery::Filter(ScriptFilter {
+            search: filter.search,
+            tag: filter.tag,
+            status: filter.status.map(Into::into),
+            trigger_type: filter.trigger_type,
+            event: filter.event.map(Into::into),
+        });
 
         let (offset, limit) = pagination.normalize()?;
         let page = state
@@ -115,4 +122,46 @@ impl AlloyQuery {
 
         Ok(scripts)
     }
+    /// Script runs of the current tenant, newest first.
+    async fn execution_log(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(default)] filter: ExecutionLogFilterInput,
+        limit: Option<i32>,
+        offset: Option<i32>,
+    ) -> Result<Vec<GqlExecutionLogEntry>> {
+        require_admin(ctx).await?;
+        let state = runtime_from_graphql_ctx(ctx)?;
+        let tenant_id = ctx.data::<TenantContext>().map(|tenant| tenant.id).ok();
+        let requested_limit = limit.map(|value| value.max(0) as u64);
+        let limit = limit.unwrap_or(50).clamp(1, 100) as u64;
+        let entries = state
+            .execution_log
+            .query(ExecutionLogFilter {
+                script_id: filter.script_id,
+                phase: filter.phase.map(Into::into),
+                status: filter.status,
+                tenant_id,
+                from: filter.from,
+                to: filter.to,
+                limit: Some(limit),
+                offset: offset.unwrap_or(0).max(0) as u64,
+            })
+            .await
+            .map_err(|error| async_graphql::Error::new(error.to_string()))?;
+
+        let entries = entries
+            .into_iter()
+            .map(GqlExecutionLogEntry::from)
+            .collect::<Vec<_>>();
+        metrics::record_read_path_budget(
+            "graphql",
+            "alloy.execution_log",
+            requested_limit,
+            limit,
+            entries.len(),
+        );
+
+        Ok(entries)
+    }
 }
@@ -3,6 +3,8 @@ use chrono::{DateTime, Utc};
 use rustok_api::graphql::PageInfo;
 use uuid::Uuid;
 
+use crate::context::ExecutionPhase;
+use crate::execution_log::ExecutionLogEntry;
 use crate::model::{EventType, HttpMethod, Script, ScriptStatus, ScriptTrigger};
 
 #[derive(Enum, Copy, Clone, Eq, PartialEq)]
@@ -119,6 +121,7 @@ pub struct EventTrigger {
 #[derive(SimpleObject)]
 pub struct CronTrigger {
     pub expression: String,
+    pub timezone: String,
 }
 
 #[derive(SimpleObject)]
@@ -147,7 +150,13 @@ impl From<ScriptTrigger> for GqlScriptTrigger {
                 entity_type,
                 event: event.into(),
             }),
-            ScriptTrigger::Cron { expression } => Self::Cron(CronTrigger { expression }),
+            ScriptTrigger::Cron {
+                expression,
+                timezone,
+            } => Self::Cron(CronTrigger {
+                expression,
+                timezone,
+            }),
             ScriptTrigger::Manual => Self::Manual(ManualTrigger { placeholder: true }),
             ScriptTrigger::Api { path, method } => Self::Api(ApiTrigger {
                 path,
@@ -169,6 +178,7 @@ pub struct GqlScript {
     pub version: u32,
     pub run_as_system: bool,
     pub permissions: Vec<String>,
+    pub tags: Vec<String>,
     pub author_id: Option<String>,
     pub error_count: u32,
     pub last_error_at: Option<DateTime<Utc>>,
@@ -189,6 +199,7 @@ impl From<Script> for GqlScript {
             version: script.version,
             run_as_system: script.run_as_system,
             permissions: script.permissions,
+            tags: script.tags,
             author_id: script.author_id,
             error_count: script.error_count,
             last_error_at: script.last_error_at,
@@ -208,12 +219,95 @@ pub struct GqlExecutionResult {
     pub changes: Option<async_graphql::Json<serde_json::Value>>,
 }
 
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+#[graphql(rename_items = "SCREAMING_SNAKE_CASE")]
+pub enum GqlExecutionPhase {
+    Before,
+    After,
+    OnCommit,
+    Manual,
+    Scheduled,
+}
+
+impl From<ExecutionPhase> for GqlExecutionPhase {
+    fn from(phase: ExecutionPhase) -> Self {
+        match phase {
+            ExecutionPhase::Before => Self::Before,
+            ExecutionPhase::After => Self::After,
+            ExecutionPhase::OnCommit => Self::OnCommit,
+            ExecutionPhase::Manual => Self::Manual,
+            ExecutionPhase::Scheduled => Self::Scheduled,
+        }
+    }
+}
+
+impl From<GqlExecutionPhase> for ExecutionPhase {
+    fn from(phase: GqlExecutionPhase) -> Self {
+        match phase {
+            GqlExecutionPhase::Before => Self::Before,
+            GqlExecutionPhase::After => Self::After,
+            GqlExecutionPhase::OnCommit => Self::OnCommit,
+            GqlExecutionPhase::Manual => Self::Manual,
+            GqlExecutionPhase::Scheduled => Self::Scheduled,
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct GqlExecutionLogEntry {
+    pub id: Uuid,
+    pub script_id: Uuid,
+    pub script_name: String,
+    pub phase: GqlExecutionPhase,
+    pub status: String,
+    pub duration_ms: i64,
+    pub error: Option<String>,
+    pub user_id: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<ExecutionLogEntry> for GqlExecutionLogEntry {
+    fn from(entry: ExecutionLogEntry) -> Self {
+        Self {
+            id: entry.id,
+            script_id: entry.script_id,
+            script_name: entry.script_name,
+            phase: entry.phase.into(),
+            status: entry.outcome,
+            duration_ms: entry.duration_ms,
+            error: entry.error,
+            user_id: entry.user_id,
+            created_at: entry.created_at,
+        }
+    }
+}
+
 #[derive(SimpleObject)]
 pub struct GqlScriptConnection {
     pub items: Vec<GqlScript>,
     pub page_info: PageInfo,
 }
 
+#[derive(InputObject, Default)]
+pub struct ScriptFilterInput {
+    pub status: Option<GqlScriptStatus>,
+    pub search: Option<String>,
+    pub tag: Option<String>,
+    pub trigger_type: Option<String>,
+    pub event: Option<GqlEventType>,
+}
+
+#[derive(InputObject, Default)]
+pub struct ExecutionLogFilterInput {
+    pub script_id: Option<Uuid>,
+    pub phase: Option<GqlExecutionPhase>,
+    /// `success`, `aborted` or `failed`.
+    pub status: Option<String>,
+    pub from: Option<DateTime<Utc>>,
+    /// Exclusive.
+    pub to: Option<DateTime<Utc>>,
+}
+
 #[derive(InputObject)]
 pub struct EventTriggerInput {
     pub entity_type: String,
@@ -223,6 +317,8 @@ pub struct EventTriggerInput {
 #[derive(InputObject)]
 pub struct CronTriggerInput {
     pub expression: String,
+    /// IANA zone such as `Europe/Berlin`; UTC when omitted.
+    pub timezone: Option<String>,
 }
 
 #[derive(InputObject)]
@@ -246,8 +342,12 @@ impl From<ScriptTriggerInput> for ScriptTrigger {
                 entity_type: event.entity_type,
                 event: event.event.into(),
             },
-            ScriptTriggerInput::Cron(cron) => ScriptTrigger::Cron {
-                expression: cron.expression,
+            ScriptTriggerInput::Cron(cron) => match cron.timezone {
+                Some(timezone) => ScriptTrigger::Cron {
+                    expression: cron.expression,
+                    timezone,
+                },
+                None => ScriptTrigger::cron(cron.expression),
             },
             ScriptTriggerInput::Api(api) => ScriptTrigger::Api {
                 path: api.path,
@@ -269,6 +369,8 @@ pub struct CreateScriptInput {
     pub run_as_system: bool,
     #[graphql(default)]
     pub permissions: Vec<String>,
+    #[graphql(default)]
+    pub tags: Vec<String>,
     pub author_id: Option<String>,
 }
 
@@ -281,6 +383,7 @@ pub struct UpdateScriptInput {
     pub status: Option<GqlScriptStatus>,
     pub run_as_system: Option<bool>,
     pub permissions: Option<Vec<String>>,
+    pub tags: Option<Vec<String>>,
     pub author_id: Option<String>,
     #[graphql(default)]
     pub clear_author_id: bool,
@@ -38,7 +38,7 @@ impl<S: ScriptRegistry> HookExecutor<S> {
             .run_before(entity_type, event, proxy, user_id)
             .await
         {
-            HookOutcome::Continue { changes } => Ok(BeforeHookResult::Continue(changes)),
+            HookOutcome::Continue { changes, .. } => Ok(BeforeHookResult::Continue(changes)),
             HookOutcome::Rejected { reason } => Ok(BeforeHookResult::Rejected(reason)),
             HookOutcome::Error { error } => Err(error),
         }
@@ -98,8 +98,8 @@ mod tests {
                 .await;
 
             match before {
-                crate::runner::HookOutcome::Continue { changes } => {
-                    if !changes.is_empty() {
+                crate::runner::HookOutcome::Continue { changes, changed } => {
+                    if changed {
                         deal.apply_changes(changes);
                     }
                 }
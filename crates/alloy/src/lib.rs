@@ -6,6 +6,7 @@ use sea_orm_migration::MigrationTrait;
 
 pub mod api;
 pub mod bridge;
+pub mod bundle;
 pub mod context;
 pub mod controllers;
 pub mod engine;
@@ -23,25 +24,32 @@ pub mod storage;
 pub mod utils;
 
 pub use api::{create_router, AppState};
-pub use bridge::Bridge;
+pub use bridge::{Bridge, ScriptKvLimits, ScriptKvStore};
+pub use bundle::{export_scripts, import_scripts, BundledScript, ImportReport, ScriptBundle};
 pub use context::{ExecutionContext, ExecutionPhase};
 pub use controllers::routes;
-pub use engine::{EngineConfig, ScriptEngine};
+pub use engine::{EngineConfig, OutboundRateLimit, ScriptEngine};
 pub use error::{ScriptError, ScriptResult};
-pub use execution_log::{ExecutionLogEntry, ScriptExecutionsMigration, SeaOrmExecutionLog};
+pub use execution_log::{
+    ExecutionLogEntry, ExecutionLogFilter, ScriptExecutionsMigration, SeaOrmExecutionLog,
+};
 pub use graphql::{AlloyMutation, AlloyQuery};
 pub use integration::{BeforeHookResult, HookExecutor, ScriptableEntity};
-pub use migration::ScriptsMigration;
+pub use migration::{ScriptVersionsMigration, ScriptsMigration};
 pub use model::{
-    register_entity_proxy, EntityProxy, EventType, HttpMethod, Script, ScriptId, ScriptStatus,
-    ScriptTrigger,
+    register_entity_proxies, register_entity_proxy, EntityPath, EntityProxy, EventType, HttpMethod,
+    ProxyDef, Script, ScriptId, ScriptStatus, ScriptTrigger,
 };
 pub use runner::{
-    ExecutionOutcome, ExecutionResult, HookOutcome, ScriptExecutor, ScriptOrchestrator,
+    DefaultFailureHandler, ExecutionOutcome, ExecutionResult, HookOutcome, RetryPolicy,
+    ScriptExecutor, ScriptFailure, ScriptFailureHandler, ScriptOrchestrator,
 };
 pub use runtime::{init, runtime_from_ctx, scoped_runtime, AlloyRuntime, SharedAlloyRuntime};
 pub use scheduler::{ScheduledJob, Scheduler};
-pub use storage::{InMemoryStorage, ScriptPage, ScriptQuery, ScriptRegistry, SeaOrmStorage};
+pub use storage::{
+    InMemoryStorage, ScriptFilter, ScriptPage, ScriptQuery, ScriptRegistry, ScriptVersion,
+    SeaOrmStorage,
+};
 
 pub struct AlloyModule;
 
@@ -51,22 +59,19 @@ pub fn create_default_engine() -> ScriptEngine {
 }
 
 pub fn create_engine_with_config(config: engine::EngineConfig) -> ScriptEngine {
-    let mut engine = ScriptEngine::new(config);
-
-    bridge::register_utils(engine.engine_mut());
-    register_entity_proxy(engine.engine_mut());
-
-    engine
+    ScriptEngine::new_with_setup(config, |engine| {
+        bridge::register_utils(engine);
+        bridge::register_kv(engine);
+        register_entity_proxy(engine);
+    })
 }
 
 pub fn create_engine_for_phase(phase: context::ExecutionPhase) -> ScriptEngine {
     let config = EngineConfig::default();
-    let mut engine = ScriptEngine::new(config);
-
-    Bridge::register_for_phase(engine.engine_mut(), phase);
-    register_entity_proxy(engine.engine_mut());
-
-    engine
+    ScriptEngine::new_with_setup(config, |engine| {
+        Bridge::register_for_phase(engine, phase);
+        register_entity_proxy(engine);
+    })
 }
 
 pub fn create_orchestrator<R: ScriptRegistry>(
@@ -156,6 +161,50 @@ mod tests {
         assert!(matches!(result, Err(ScriptError::Aborted(_))));
     }
 
+    #[test]
+    fn test_abort_with_code() {
+        let engine = create_default_engine();
+        let ctx = ExecutionContext::new(ExecutionPhase::Before);
+
+        let result = engine.execute(
+            "test_abort_with",
+            r#"abort_with("VALIDATION_FAILED", "Deal amount too small")"#,
+            &ctx,
+        );
+
+        match result {
+            Err(ScriptError::AbortedWithCode { code, message }) => {
+                assert_eq!(code, "VALIDATION_FAILED");
+                assert_eq!(message, "Deal amount too small");
+            }
+            other => panic!("expected a coded abort, got {other:?}"),
+        }
+
+        let plain = engine.execute("test_abort", r#"abort("Deal amount too small")"#, &ctx);
+        assert!(matches!(
+            plain,
+            Err(ScriptError::Aborted(ref reason)) if reason == "Deal amount too small"
+        ));
+    }
+
+    #[test]
+    fn test_abort_code_is_a_graphql_extension() {
+        use async_graphql::{ErrorExtensions, Value};
+
+        let coded = ScriptError::AbortedWithCode {
+            code: "VALIDATION_FAILED".to_string(),
+            message: "Deal amount too small".to_string(),
+        }
+        .extend();
+        let plain = ScriptError::Aborted("Deal amount too small".to_string()).extend();
+
+        assert_eq!(
+            coded.extensions.as_ref().and_then(|ext| ext.get("code")),
+            Some(&Value::from("VALIDATION_FAILED"))
+        );
+        assert!(plain.extensions.is_none());
+    }
+
     #[test]
     fn test_entity_access() {
         let engine = create_default_engine();
@@ -183,14 +232,156 @@ mod tests {
         assert_eq!(result.as_int().unwrap(), 50000);
     }
 
+    #[test]
+    fn test_execute_with_scope_returns_entity_writes() {
+        let engine = create_default_engine();
+
+        let mut deal: std::collections::HashMap<String, Dynamic> = std::collections::HashMap::new();
+        deal.insert("status".to_string(), Dynamic::from("draft"));
+
+        let entity = EntityProxy::new("1", "deal", deal);
+        let ctx = ExecutionContext::new(ExecutionPhase::Before).with_entity_proxy(entity);
+
+        let (_, scope) = engine
+            .execute_with_scope(
+                "test_approve",
+                r#"
+                entity.status = "approved";
+                let reviewed = true;
+            "#,
+                &ctx,
+            )
+            .unwrap();
+
+        let entity = scope.get_value::<EntityProxy>("entity").unwrap();
+        assert_eq!(entity.get("status").into_string().unwrap(), "approved");
+        assert!(scope.get_value::<bool>("reviewed").unwrap());
+    }
+
+    #[test]
+    fn test_validate_accepts_valid_script() {
+        let engine = create_default_engine();
+
+        let result = engine.validate(
+            "valid",
+            r#"
+                if entity["amount"] > 10000 {
+                    log("Big deal: " + entity["name"]);
+                }
+                params
+            "#,
+        );
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_syntax_error() {
+        let engine = create_default_engine();
+
+        let result = engine.validate("broken", "let x = ;");
+
+        assert!(matches!(result, Err(ScriptError::Compilation(_))));
+    }
+
+    #[test]
+    fn test_validate_rejects_external_service_call() {
+        let engine = create_default_engine();
+
+        let direct = engine.validate("direct", r#"http_get("https://example.com")"#);
+        let pointer = engine.validate(
+            "pointer",
+            r#"let f = Fn("http_post"); f.call("https://example.com", #{})"#,
+        );
+
+        assert!(matches!(
+            direct,
+            Err(ScriptError::ForbiddenCall { ref name }) if name == "http_get"
+        ));
+        assert!(matches!(
+            pointer,
+            Err(ScriptError::ForbiddenCall { ref name }) if name == "http_post"
+        ));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_external_calls_follow_execution_phase() {
+        // An engine with every bridge registered, reused across phases.
+        let engine = create_engine_for_phase(ExecutionPhase::Manual);
+        let script = r#"http_post("http://127.0.0.1:9/hook", #{ "event": "created" })"#;
+
+        let before = engine.execute(
+            "notify",
+            script,
+            &ExecutionContext::new(ExecutionPhase::Before),
+        );
+        assert!(matches!(
+            before,
+            Err(ScriptError::ForbiddenCall { ref name }) if name == "http_post"
+        ));
+
+        let on_commit = engine
+            .execute(
+                "notify",
+                script,
+                &ExecutionContext::new(ExecutionPhase::OnCommit),
+            )
+            .unwrap();
+        let response = on_commit.try_cast::<rhai::Map>().unwrap();
+        assert!(response.contains_key("status"));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_dynamically_named_external_calls_follow_execution_phase() {
+        let engine = create_engine_for_phase(ExecutionPhase::Manual);
+        let scripts = [
+            r#"Fn("http_" + "post").call("http://127.0.0.1:9/hook", #{})"#,
+            r#"
+                let name = "";
+                for part in ["http_", "post"] { name += part; }
+                Fn(name).call("http://127.0.0.1:9/hook", #{})
+            "#,
+            r#"
+                fn notify(name) { Fn(name).call("http://127.0.0.1:9/hook", #{}) }
+                let name = "http_";
+                name += "post";
+                notify(name)
+            "#,
+        ];
+
+        for (index, script) in scripts.iter().enumerate() {
+            let name = format!("dynamic-{index}");
+            let before = engine.execute(
+                &name,
+                script,
+                &ExecutionContext::new(ExecutionPhase::Before),
+            );
+            assert!(
+                matches!(before, Err(ScriptError::ForbiddenCall { ref name }) if name == "http_post"),
+                "script {index}: {before:?}"
+            );
+
+            let on_commit = engine
+                .execute(
+                    &name,
+                    script,
+                    &ExecutionContext::new(ExecutionPhase::OnCommit),
+                )
+                .unwrap();
+            assert!(on_commit
+                .try_cast::<rhai::Map>()
+                .unwrap()
+                .contains_key("status"));
+        }
+    }
+
     #[test]
     fn test_operation_limit() {
         let config = EngineConfig {
             max_operations: 100,
             ..Default::default()
         };
-        let mut engine = ScriptEngine::new(config);
-        bridge::register_utils(engine.engine_mut());
+        let engine = ScriptEngine::new_with_setup(config, bridge::register_utils);
 
         let ctx = ExecutionContext::new(ExecutionPhase::Manual);
 
@@ -209,6 +400,68 @@ mod tests {
         assert!(matches!(result, Err(ScriptError::OperationLimit { .. })));
     }
 
+    #[test]
+    fn test_max_duration_stops_slow_native_call() {
+        let config = EngineConfig {
+            max_duration: Some(std::time::Duration::from_millis(50)),
+            ..Default::default()
+        };
+        let mut engine = ScriptEngine::new(config);
+        engine
+            .register_fn("slow_call", || {
+                std::thread::sleep(std::time::Duration::from_millis(500));
+                1_i64
+            })
+            .unwrap();
+
+        let ctx = ExecutionContext::new(ExecutionPhase::Manual);
+        let started = std::time::Instant::now();
+
+        let result = engine.execute("test_slow", "slow_call()", &ctx);
+
+        assert!(matches!(
+            result,
+            Err(ScriptError::Timeout { limit_ms: 50, .. })
+        ));
+        assert!(started.elapsed() < std::time::Duration::from_millis(500));
+    }
+
+    #[test]
+    fn test_abandoned_script_threads_are_bounded() {
+        let config = EngineConfig {
+            max_duration: Some(std::time::Duration::from_millis(20)),
+            max_script_threads: 1,
+            ..Default::default()
+        };
+        let mut engine = ScriptEngine::new(config);
+        engine
+            .register_fn("slow_call", || {
+                std::thread::sleep(std::time::Duration::from_millis(300));
+                1_i64
+            })
+            .unwrap();
+        let ctx = ExecutionContext::new(ExecutionPhase::Manual);
+
+        let first = engine.execute("test_slow", "slow_call()", &ctx);
+        assert!(matches!(first, Err(ScriptError::Timeout { .. })));
+
+        let second = engine.execute("test_slow", "slow_call()", &ctx);
+        assert!(matches!(second, Err(ScriptError::Runtime(ref msg)) if msg.contains("Too many")));
+        assert!(engine.engine_mut().is_none());
+        assert!(engine.register_fn("late_call", || 2_i64).is_err());
+
+        std::thread::sleep(std::time::Duration::from_millis(400));
+        assert!(engine.engine_mut().is_some());
+        assert_eq!(
+            engine
+                .execute("test_fast", "40 + 2", &ctx)
+                .unwrap()
+                .as_int()
+                .unwrap(),
+            42
+        );
+    }
+
     #[test]
     fn test_cache_invalidation() {
         let engine = create_default_engine();
@@ -258,6 +511,44 @@ mod tests {
         assert!(result.as_bool().unwrap());
     }
 
+    #[test]
+    fn test_json_helpers_round_trip_nested_object() {
+        let engine = create_engine_for_phase(ExecutionPhase::Before);
+        let ctx = ExecutionContext::new(ExecutionPhase::Before);
+
+        let result = engine
+            .execute(
+                "test_json_round_trip",
+                r#"
+                let payload = json_parse(`{"order":{"id":7,"items":[{"sku":"A-1","qty":2}]}}`);
+                let again = json_parse(json_stringify(payload));
+                [json_get(again, "order.items.0.sku"), json_get(again, "order.id")]
+            "#,
+                &ctx,
+            )
+            .unwrap();
+
+        let values = result.into_array().unwrap();
+        assert_eq!(values[0].clone().into_string().unwrap(), "A-1");
+        assert_eq!(values[1].as_int().unwrap(), 7);
+    }
+
+    #[test]
+    fn test_json_get_missing_key_returns_unit() {
+        let engine = create_engine_for_phase(ExecutionPhase::OnCommit);
+        let ctx = ExecutionContext::new(ExecutionPhase::OnCommit);
+
+        let result = engine
+            .execute(
+                "test_json_missing",
+                r#"json_get(json_parse(`{"order":{"id":7}}`), "order.customer.email")"#,
+                &ctx,
+            )
+            .unwrap();
+
+        assert!(result.is_unit());
+    }
+
     #[test]
     fn test_validation_helpers() {
         let engine = create_engine_for_phase(ExecutionPhase::Before);
@@ -317,6 +608,139 @@ mod tests {
         assert!(entity.has_changes());
     }
 
+    fn node_with_metadata() -> EntityProxy {
+        let mut seo = rhai::Map::new();
+        seo.insert("title".into(), Dynamic::from("Spring sale"));
+        let mut metadata = rhai::Map::new();
+        metadata.insert("seo".into(), Dynamic::from_map(seo));
+
+        EntityProxy::new(
+            "1",
+            "node",
+            std::collections::HashMap::from([(
+                "metadata".to_string(),
+                Dynamic::from_map(metadata),
+            )]),
+        )
+    }
+
+    #[test]
+    fn test_entity_reads_nested_fields() {
+        let engine = create_default_engine();
+        let ctx =
+            ExecutionContext::new(ExecutionPhase::Before).with_entity_proxy(node_with_metadata());
+
+        let title = engine
+            .execute("nested_read", "entity.metadata.seo.title", &ctx)
+            .unwrap();
+        let missing = engine
+            .execute(
+                "nested_missing",
+                "entity.metadata.og.title == () && entity.metadata.og == ()",
+                &ctx,
+            )
+            .unwrap();
+
+        assert_eq!(title.into_string().unwrap(), "Spring sale");
+        assert!(missing.as_bool().unwrap());
+        assert!(engine
+            .execute("nested_missing_value", "entity.metadata.og.title", &ctx)
+            .unwrap()
+            .is_unit());
+    }
+
+    #[test]
+    fn test_entity_writes_create_nested_maps() {
+        let engine = create_default_engine();
+        let ctx =
+            ExecutionContext::new(ExecutionPhase::Before).with_entity_proxy(node_with_metadata());
+
+        let written = engine
+            .execute(
+                "nested_write",
+                r#"entity.metadata.og.image = "/img/sale.png";"#,
+                &ctx,
+            )
+            .unwrap();
+        assert!(written.is_unit());
+
+        let entity = ctx.entity_proxy.as_ref().unwrap();
+        assert!(entity.is_changed("metadata"));
+        assert_eq!(
+            entity
+                .get_path(&["metadata", "og", "image"])
+                .into_string()
+                .unwrap(),
+            "/img/sale.png"
+        );
+        assert_eq!(
+            entity
+                .get_path(&["metadata", "seo", "title"])
+                .into_string()
+                .unwrap(),
+            "Spring sale"
+        );
+    }
+
+    #[test]
+    fn test_register_entity_proxies_exposes_every_type() {
+        const PROXIES: &[ProxyDef] = &[
+            ProxyDef::new("deal", &["amount", "status"]),
+            ProxyDef::new("contact", &["email", "status"]),
+        ];
+        let mut engine = rhai::Engine::new();
+        register_entity_proxies(&mut engine, PROXIES).unwrap();
+
+        let deal = EntityProxy::new(
+            "1",
+            "deal",
+            std::collections::HashMap::from([("amount".to_string(), Dynamic::from(500_i64))]),
+        );
+        let contact = EntityProxy::new(
+            "2",
+            "contact",
+            std::collections::HashMap::from([(
+                "email".to_string(),
+                Dynamic::from("a@example.com"),
+            )]),
+        );
+        let mut scope = rhai::Scope::new();
+        scope.push("deal", deal.clone());
+        scope.push("contact", contact.clone());
+
+        let summary: String = engine
+            .eval_with_scope(
+                &mut scope,
+                r#"
+                deal.status = "won";
+                contact.status = "customer";
+                contact.email + " " + deal.amount
+            "#,
+            )
+            .unwrap();
+
+        assert_eq!(summary, "a@example.com 500");
+        assert_eq!(deal.get("status").into_string().unwrap(), "won");
+        assert_eq!(contact.get("status").into_string().unwrap(), "customer");
+    }
+
+    #[test]
+    fn test_register_entity_proxies_rejects_collisions() {
+        let mut engine = rhai::Engine::new();
+
+        let shadowing = register_entity_proxies(&mut engine, &[ProxyDef::new("deal", &["type"])]);
+        let duplicate_type = register_entity_proxies(
+            &mut engine,
+            &[
+                ProxyDef::new("deal", &["amount"]),
+                ProxyDef::new("deal", &["stage"]),
+            ],
+        );
+
+        assert!(matches!(shadowing, Err(ScriptError::InvalidProxy(_))));
+        assert!(matches!(duplicate_type, Err(ScriptError::InvalidProxy(_))));
+    }
+
     #[tokio::test]
     async fn test_orchestrator_integration() {
         let storage = Arc::new(InMemoryStorage::new());
@@ -347,13 +771,249 @@ mod tests {
             .await;
 
         match outcome {
-            HookOutcome::Continue { changes } => {
+            HookOutcome::Continue { changes, .. } => {
                 assert!(changes.contains_key("processed"));
             }
             _ => panic!("Expected Continue outcome"),
         }
     }
 
+    #[tokio::test]
+    async fn test_due_scripts_returns_cron_scripts_due_in_window() {
+        use chrono::TimeZone;
+
+        let storage = Arc::new(InMemoryStorage::new());
+        let orchestrator = create_orchestrator(storage.clone());
+
+        for (name, expression) in [("every_5_min", "0 */5 * * * *"), ("hourly", "0 0 * * * *")] {
+            let mut script = Script::new(name, "1", ScriptTrigger::cron(expression));
+            script.activate();
+            storage.save(script).await.unwrap();
+        }
+
+        let since = chrono::Utc.with_ymd_and_hms(2026, 3, 1, 10, 2, 0).unwrap();
+        let due = orchestrator
+            .due_scripts(since, since + chrono::Duration::minutes(5))
+            .await
+            .unwrap();
+
+        let names: Vec<_> = due.iter().map(|script| script.name.as_str()).collect();
+        assert_eq!(names, vec!["every_5_min"]);
+    }
+
+    #[tokio::test]
+    async fn test_before_script_mutation_is_returned() {
+        let storage = Arc::new(InMemoryStorage::new());
+        let orchestrator = create_orchestrator(storage.clone());
+
+        let mut script = Script::new(
+            "normalize_email",
+            r#"entity["email"] = entity["email"].to_lower();"#,
+            ScriptTrigger::Event {
+                entity_type: "user".into(),
+                event: EventType::BeforeUpdate,
+            },
+        );
+        script.activate();
+        storage.save(script).await.unwrap();
+
+        let user = |email: &str| {
+            let data: std::collections::HashMap<String, Dynamic> = std::collections::HashMap::from(
+                [("email".to_string(), Dynamic::from(email.to_string()))],
+            );
+            EntityProxy::new("user-1", "user", data)
+        };
+
+        let outcome = orchestrator
+            .run_before(
+                "user",
+                EventType::BeforeUpdate,
+                user("John@Example.COM"),
+                None,
+            )
+            .await;
+        match outcome {
+            HookOutcome::Continue { changes, changed } => {
+                assert!(changed);
+                assert_eq!(
+                    changes["email"].clone().into_string().unwrap(),
+                    "john@example.com"
+                );
+            }
+            _ => panic!("Expected Continue outcome"),
+        }
+
+        let outcome = orchestrator
+            .run_before(
+                "user",
+                EventType::BeforeUpdate,
+                user("john@example.com"),
+                None,
+            )
+            .await;
+        assert!(matches!(
+            outcome,
+            HookOutcome::Continue { changed: false, .. }
+        ));
+
+        let script = storage.get_by_name("normalize_email").await.unwrap();
+        let executor = ScriptExecutor::new(Arc::new(create_default_engine()), storage.clone());
+        let ctx = ExecutionContext::new(ExecutionPhase::Before);
+        let result = executor
+            .execute(&script, &ctx, Some(user("Jane@Example.com")))
+            .await;
+        let entity = result.entity.expect("entity is returned with the result");
+        assert_eq!(
+            entity.get("email").into_string().unwrap(),
+            "jane@example.com"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_reports_field_diff() {
+        let storage = Arc::new(InMemoryStorage::new());
+        let orchestrator = create_orchestrator(storage.clone());
+        for (name, code) in [
+            (
+                "approve_order",
+                r#"entity["status"] = "approved"; entity["discount"] = 10;"#,
+            ),
+            (
+                "touch_order",
+                r#"entity["amount"] = 1000; entity["amount"]"#,
+            ),
+        ] {
+            let mut script = Script::new(name, code, ScriptTrigger::Manual);
+            script.activate();
+            storage.save(script).await.unwrap();
+        }
+
+        let order = || {
+            let data: std::collections::HashMap<String, Dynamic> =
+                std::collections::HashMap::from([
+                    ("status".to_string(), Dynamic::from("pending".to_string())),
+                    ("amount".to_string(), Dynamic::from(1000_i64)),
+                ]);
+            Some(EntityProxy::new("order-1", "order", data))
+        };
+
+        let result = orchestrator
+            .run_manual_with_entity("approve_order", Default::default(), order(), None)
+            .await
+            .unwrap();
+        let changed = serde_json::to_value(api::FieldDiff::from_result(&result)).unwrap();
+        assert_eq!(
+            changed,
+            serde_json::json!({
+                "status": { "from": "pending", "to": "approved" },
+                "discount": { "from": null, "to": 10 }
+            })
+        );
+
+        let result = orchestrator
+            .run_manual_with_entity("touch_order", Default::default(), order(), None)
+            .await
+            .unwrap();
+        assert!(result.is_success());
+        assert!(api::FieldDiff::from_result(&result).is_empty());
+    }
+
+    async fn on_commit_fixture(
+        code: &str,
+        engine: ScriptEngine,
+    ) -> (
+        ScriptOrchestrator<InMemoryStorage>,
+        Arc<SeaOrmExecutionLog>,
+        Script,
+    ) {
+        use sea_orm_migration::prelude::SchemaManager;
+
+        let db = sea_orm::Database::connect("sqlite::memory:").await.unwrap();
+        ScriptExecutionsMigration
+            .up(&SchemaManager::new(&db))
+            .await
+            .unwrap();
+        let log = Arc::new(SeaOrmExecutionLog::new(db));
+
+        let storage = Arc::new(InMemoryStorage::new());
+        let mut script = Script::new(
+            "sync_order",
+            code,
+            ScriptTrigger::Event {
+                entity_type: "order".into(),
+                event: EventType::OnCommit,
+            },
+        );
+        script.activate();
+        storage.save(script.clone()).await.unwrap();
+
+        let orchestrator = create_orchestrator_with_engine(Arc::new(engine), storage)
+            .with_retry_policy(RetryPolicy::new(3, std::time::Duration::from_millis(1)))
+            .with_execution_log(log.clone());
+        (orchestrator, log, script)
+    }
+
+    fn order_entity() -> EntityProxy {
+        EntityProxy::new("order-1", "order", std::collections::HashMap::new())
+    }
+
+    #[tokio::test]
+    async fn test_on_commit_retries_until_success() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mut engine = create_default_engine();
+        let counter = calls.clone();
+        engine
+            .register_fn(
+                "flaky_sync",
+                move || -> Result<i64, Box<rhai::EvalAltResult>> {
+                    if counter.fetch_add(1, Ordering::SeqCst) < 2 {
+                        Err("upstream unavailable".into())
+                    } else {
+                        Ok(1)
+                    }
+                },
+            )
+            .unwrap();
+        let (orchestrator, log, script) = on_commit_fixture("flaky_sync()", engine).await;
+
+        let results = orchestrator
+            .run_on_commit("order", order_entity(), None)
+            .await;
+
+        assert_eq!(results.len(), 1);
+        assert!(results[0].is_success());
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        let mut outcomes: Vec<_> = log
+            .list_for_script(script.id, 10)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|entry| entry.outcome)
+            .collect();
+        outcomes.sort();
+        assert_eq!(outcomes, vec!["failed", "failed", "success"]);
+    }
+
+    #[tokio::test]
+    async fn test_on_commit_abort_is_not_retried() {
+        let (orchestrator, log, script) =
+            on_commit_fixture(r#"abort("order is locked")"#, create_default_engine()).await;
+
+        let results = orchestrator
+            .run_on_commit("order", order_entity(), None)
+            .await;
+
+        assert!(matches!(
+            results[0].outcome,
+            ExecutionOutcome::Aborted { ref reason, .. } if reason == "order is locked"
+        ));
+        let entries = log.list_for_script(script.id, 10).await.unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].outcome, "aborted");
+    }
+
     #[test]
     fn module_metadata() {
         let module = AlloyModule;
@@ -118,3 +118,71 @@ enum Scripts {
     CreatedAt,
     UpdatedAt,
 }
+
+#[derive(DeriveMigrationName)]
+pub struct ScriptVersionsMigration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for ScriptVersionsMigration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ScriptVersions::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ScriptVersions::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(ScriptVersions::ScriptId).uuid().not_null())
+                    .col(ColumnDef::new(ScriptVersions::TenantId).uuid().not_null())
+                    .col(ColumnDef::new(ScriptVersions::Version).integer().not_null())
+                    .col(ColumnDef::new(ScriptVersions::Code).text().not_null())
+                    .col(
+                        ColumnDef::new(ScriptVersions::Status)
+                            .string_len(32)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(ScriptVersions::UserId).string_len(255))
+                    .col(
+                        ColumnDef::new(ScriptVersions::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_script_versions_script_version")
+                    .table(ScriptVersions::Table)
+                    .col(ScriptVersions::ScriptId)
+                    .col(ScriptVersions::Version)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ScriptVersions::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ScriptVersions {
+    Table,
+    Id,
+    ScriptId,
+    TenantId,
+    Version,
+    Code,
+    Status,
+    UserId,
+    CreatedAt,
+}
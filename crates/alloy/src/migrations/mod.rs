@@ -1,5 +1,7 @@
 mod m20260302_000001_create_scripts;
 mod m20260302_000002_create_script_executions;
+mod m20261016_000003_add_script_tags;
+mod m20261016_000004_create_script_versions;
 
 use sea_orm_migration::MigrationTrait;
 
@@ -7,5 +9,7 @@ pub fn migrations() -> Vec<Box<dyn MigrationTrait>> {
     vec![
         Box::new(m20260302_000001_create_scripts::Migration),
         Box::new(m20260302_000002_create_script_executions::Migration),
+        Box::new(m20261016_000003_add_script_tags::Migration),
+        Box::new(m20261016_000004_create_script_versions::Migration),
     ]
 }
@@ -2,6 +2,8 @@ mod proxy;
 mod script;
 mod trigger;
 
-pub use proxy::{register_entity_proxy, EntityProxy};
+pub use proxy::{
+    register_entity_proxies, register_entity_proxy, EntityPath, EntityProxy, FieldChange, ProxyDef,
+};
 pub use script::{Script, ScriptId, ScriptStatus};
 pub use trigger::{EventType, HttpMethod, ScriptTrigger};
@@ -1,8 +1,17 @@
 use parking_lot::RwLock;
-use rhai::{CustomType, Dynamic, TypeBuilder};
-use std::collections::HashMap;
+use rhai::{CustomType, Dynamic, Map, TypeBuilder};
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
+use crate::error::{ScriptError, ScriptResult};
+
+/// Value of a field before and after a script run.
+#[derive(Debug, Clone)]
+pub struct FieldChange {
+    pub from: Dynamic,
+    pub to: Dynamic,
+}
+
 #[derive(Debug, Clone)]
 pub struct EntityProxy {
     id: String,
@@ -59,6 +68,48 @@ impl EntityProxy {
         state.changes.insert(field.to_string(), value);
     }
 
+    /// Value at `path` inside nested maps (`["metadata", "seo", "title"]`); unit when any
+    /// key along the way is missing or not a map.
+    pub fn get_path<S: AsRef<str>>(&self, path: &[S]) -> Dynamic {
+        self.lookup(path).unwrap_or(Dynamic::UNIT)
+    }
+
+    /// Sets the value at `path`, creating (or replacing non-map values with) intermediate
+    /// maps; the change is recorded on the top-level field.
+    pub fn set_path<S: AsRef<str>>(&mut self, path: &[S], value: Dynamic) {
+        let Some((field, rest)) = path.split_first() else {
+            return;
+        };
+        let root = if rest.is_empty() {
+            value
+        } else {
+            let mut root = self.get(field.as_ref());
+            insert_nested(&mut root, rest, value);
+            root
+        };
+        self.set(field.as_ref(), root);
+    }
+
+    fn lookup<S: AsRef<str>>(&self, path: &[S]) -> Option<Dynamic> {
+        let (field, rest) = path.split_first()?;
+        let state = self.state.read();
+        let mut value = state
+            .changes
+            .get(field.as_ref())
+            .or_else(|| state.data.get(field.as_ref()))?
+            .clone();
+        drop(state);
+        for key in rest {
+            let next = value.read_lock::<Map>()?.get(key.as_ref())?.clone();
+            value = next;
+        }
+        Some(value)
+    }
+
+    fn shares_state(&self, other: &EntityProxy) -> bool {
+        Arc::ptr_eq(&self.state, &other.state)
+    }
+
     pub fn is_changed(&self, field: &str) -> bool {
         let state = self.state.read();
         state.changes.contains_key(field)
@@ -69,6 +120,35 @@ impl EntityProxy {
         state.changes.clone()
     }
 
+    /// Changes that differ from the original data: writing back an identical value
+    /// (e.g. lowercasing an already lowercase email) is not a change.
+    pub fn effective_changes(&self) -> HashMap<String, Dynamic> {
+        let state = self.state.read();
+        state
+            .changes
+            .iter()
+            .filter(|(field, value)| {
+                state
+                    .data
+                    .get(*field)
+                    .is_none_or(|original| !same_value(original, value))
+            })
+            .map(|(field, value)| (field.clone(), value.clone()))
+            .collect()
+    }
+
+    /// Field-level diff between the input data and the entity as scripts left it.
+    pub fn diff(&self) -> HashMap<String, FieldChange> {
+        let original = self.original();
+        self.effective_changes()
+            .into_iter()
+            .map(|(field, to)| {
+                let from = original.get(&field).cloned().unwrap_or(Dynamic::UNIT);
+                (field, FieldChange { from, to })
+            })
+            .collect()
+    }
+
     pub fn take_changes(&self) -> HashMap<String, Dynamic> {
         let mut state = self.state.write();
         std::mem::take(&mut state.changes)
@@ -107,6 +187,97 @@ impl EntityProxy {
     }
 }
 
+fn insert_nested<S: AsRef<str>>(target: &mut Dynamic, path: &[S], value: Dynamic) {
+    let Some((key, rest)) = path.split_first() else {
+        *target = value;
+        return;
+    };
+    if !target.is_map() {
+        *target = Dynamic::from_map(Map::new());
+    }
+    let mut map = target
+        .write_lock::<Map>()
+        .expect("target was just made a map");
+    let child = map.entry(key.as_ref().into()).or_insert(Dynamic::UNIT);
+    insert_nested(child, rest, value);
+}
+
+fn same_value(left: &Dynamic, right: &Dynamic) -> bool {
+    left.type_name() == right.type_name() && left.to_string() == right.to_string()
+}
+
+/// Map-valued field of an [`EntityProxy`] as scripts see it, or a missing key inside one.
+///
+/// Indexing walks nested maps, so `entity.metadata.seo.title` reads through the proxy and
+/// yields unit below a missing key. Assignments like `entity.metadata.seo.title = "x"` go
+/// back through [`EntityProxy::set_path`], creating `seo` when `metadata` exists: a key
+/// missing right under an existing map is itself a handle, which compares equal to `()`.
+/// `to_map()` returns the plain value.
+#[derive(Debug, Clone)]
+pub struct EntityPath {
+    entity: EntityProxy,
+    path: Vec<String>,
+}
+
+impl EntityPath {
+    pub fn path(&self) -> &[String] {
+        &self.path
+    }
+
+    /// Current value at this path; unit when missing.
+    pub fn value(&self) -> Dynamic {
+        self.entity.get_path(&self.path)
+    }
+
+    fn child(&self, key: &str) -> Vec<String> {
+        let mut path = self.path.clone();
+        path.push(key.to_string());
+        path
+    }
+
+    fn index(&self, key: &str) -> Dynamic {
+        let path = self.child(key);
+        match self.entity.lookup(&path) {
+            Some(value) => wrap(&self.entity, path, value),
+            // Keep a handle under an existing map so `a.new_key.b = x` can create `new_key`.
+            None if self.entity.lookup(&self.path).is_some() => Dynamic::from(EntityPath {
+                entity: self.entity.clone(),
+                path,
+            }),
+            None => Dynamic::UNIT,
+        }
+    }
+
+    fn is_missing(&self) -> bool {
+        self.entity.lookup(&self.path).is_none()
+    }
+}
+
+/// Whether `value` is the handle for `path` that Rhai hands back after a nested write.
+fn is_write_back(entity: &EntityProxy, path: &[String], value: &Dynamic) -> bool {
+    value
+        .read_lock::<EntityPath>()
+        .is_some_and(|other| other.entity.shares_state(entity) && other.path == path)
+}
+
+/// Wraps map values in an [`EntityPath`] so nested access stays tied to the entity.
+fn wrap(entity: &EntityProxy, path: Vec<String>, value: Dynamic) -> Dynamic {
+    if value.is_map() {
+        Dynamic::from(EntityPath {
+            entity: entity.clone(),
+            path,
+        })
+    } else {
+        value
+    }
+}
+
+/// Plain value to store: handles are resolved so changes never hold one.
+fn resolve(value: Dynamic) -> Dynamic {
+    let resolved = value.read_lock::<EntityPath>().map(|handle| handle.value());
+    resolved.unwrap_or(value)
+}
+
 impl CustomType for EntityProxy {
     fn build(mut builder: TypeBuilder<Self>) {
         builder
@@ -115,9 +286,13 @@ impl CustomType for EntityProxy {
             .with_get("type", |entity: &mut EntityProxy| {
                 entity.entity_type.clone()
             })
-            .with_indexer_get(|entity: &mut EntityProxy, key: &str| entity.get(key))
+            .with_indexer_get(|entity: &mut EntityProxy, key: &str| {
+                wrap(entity, vec![key.to_string()], entity.get(key))
+            })
             .with_indexer_set(|entity: &mut EntityProxy, key: &str, val: Dynamic| {
-                entity.set(key, val);
+                if !is_write_back(entity, &[key.to_string()], &val) {
+                    entity.set(key, resolve(val));
+                }
             })
             .with_fn("is_changed", |entity: &mut EntityProxy, field: &str| {
                 entity.is_changed(field)
@@ -129,6 +304,96 @@ impl CustomType for EntityProxy {
     }
 }
 
+impl CustomType for EntityPath {
+    fn build(mut builder: TypeBuilder<Self>) {
+        builder
+            .with_name("EntityPath")
+            .with_indexer_get(|handle: &mut EntityPath, key: &str| handle.index(key))
+            .with_indexer_set(|handle: &mut EntityPath, key: &str, val: Dynamic| {
+                let path = handle.child(key);
+                if !is_write_back(&handle.entity, &path, &val) {
+                    handle.entity.set_path(&path, resolve(val));
+                }
+            })
+            .with_fn("to_map", |handle: &mut EntityPath| handle.value())
+            .with_fn("==", |handle: &mut EntityPath, _: ()| handle.is_missing())
+            .with_fn("!=", |handle: &mut EntityPath, _: ()| !handle.is_missing())
+            .with_fn("==", |_: (), handle: EntityPath| handle.is_missing())
+            .with_fn("!=", |_: (), handle: EntityPath| !handle.is_missing());
+    }
+}
+
 pub fn register_entity_proxy(engine: &mut rhai::Engine) {
     engine.build_type::<EntityProxy>();
+    engine.build_type::<EntityPath>();
+}
+
+/// Properties and methods of `Entity` itself; declared fields may not shadow them.
+const RESERVED_FIELDS: &[&str] = &["id", "type", "is_changed", "has_changes", "snapshot"];
+
+/// Script-visible fields of one entity type, e.g.
+/// `ProxyDef::new("deal", &["amount", "stage"])`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProxyDef {
+    pub entity_type: &'static str,
+    pub fields: &'static [&'static str],
+}
+
+impl ProxyDef {
+    pub const fn new(entity_type: &'static str, fields: &'static [&'static str]) -> Self {
+        Self {
+            entity_type,
+            fields,
+        }
+    }
+}
+
+/// Registers the entity proxy types plus a property getter and setter for every declared
+/// field, so a module can list all its entities in one `&[ProxyDef]`.
+///
+/// Every entity is an `Entity` in Rhai, so a field several types share (`status`) gets a
+/// single accessor. Declaring a type twice, a field twice within a type, or a field that
+/// shadows a built-in `Entity` property fails before anything is registered.
+pub fn register_entity_proxies(engine: &mut rhai::Engine, defs: &[ProxyDef]) -> ScriptResult<()> {
+    let mut entity_types = HashSet::new();
+    let mut fields = Vec::new();
+    for def in defs {
+        if !entity_types.insert(def.entity_type) {
+            return Err(ScriptError::InvalidProxy(format!(
+                "entity type '{}' is declared twice",
+                def.entity_type
+            )));
+        }
+        let mut own_fields = HashSet::new();
+        for &field in def.fields {
+            if RESERVED_FIELDS.contains(&field) {
+                return Err(ScriptError::InvalidProxy(format!(
+                    "field '{}.{field}' shadows a built-in Entity property",
+                    def.entity_type
+                )));
+            }
+            if !own_fields.insert(field) {
+                return Err(ScriptError::InvalidProxy(format!(
+                    "field '{}.{field}' is declared twice",
+                    def.entity_type
+                )));
+            }
+            if !fields.contains(&field) {
+                fields.push(field);
+            }
+        }
+    }
+
+    register_entity_proxy(engine);
+    for field in fields {
+        engine.register_get(field, move |entity: &mut EntityProxy| {
+            wrap(entity, vec![field.to_string()], entity.get(field))
+        });
+        engine.register_set(field, move |entity: &mut EntityProxy, val: Dynamic| {
+            if !is_write_back(entity, &[field.to_string()], &val) {
+                entity.set(field, resolve(val));
+            }
+        });
+    }
+    Ok(())
 }
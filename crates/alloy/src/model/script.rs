@@ -30,6 +30,9 @@ pub struct Script {
     pub version: u32,
     pub run_as_system: bool,
     pub permissions: Vec<String>,
+    /// Free-form labels used to group scripts in the admin.
+    #[serde(default)]
+    pub tags: Vec<String>,
     pub author_id: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -51,6 +54,7 @@ impl Script {
             version: 1,
             run_as_system: false,
             permissions: Vec::new(),
+            tags: Vec::new(),
             author_id: None,
             created_at: now,
             updated_at: now,
@@ -1,6 +1,11 @@
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use cron::Schedule;
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
+use crate::error::{ScriptError, ScriptResult};
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum ScriptTrigger {
@@ -10,6 +15,9 @@ pub enum ScriptTrigger {
     },
     Cron {
         expression: String,
+        /// IANA zone the expression is evaluated in, e.g. `Europe/Berlin`.
+        #[serde(default = "default_timezone")]
+        timezone: String,
     },
     Manual,
     Api {
@@ -18,6 +26,59 @@ pub enum ScriptTrigger {
     },
 }
 
+impl ScriptTrigger {
+    /// Trigger type as stored in the `trigger_type` column.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Event { .. } => "event",
+            Self::Cron { .. } => "cron",
+            Self::Manual => "manual",
+            Self::Api { .. } => "api",
+        }
+    }
+
+    /// `Cron` trigger evaluated in UTC.
+    pub fn cron(expression: impl Into<String>) -> Self {
+        Self::Cron {
+            expression: expression.into(),
+            timezone: default_timezone(),
+        }
+    }
+
+    /// Parsed schedule of a `Cron` trigger (six fields, seconds first) and the zone it is
+    /// evaluated in; `None` for other triggers.
+    pub fn schedule(&self) -> ScriptResult<Option<(Schedule, Tz)>> {
+        match self {
+            Self::Cron {
+                expression,
+                timezone,
+            } => {
+                let schedule = Schedule::from_str(expression)
+                    .map_err(|err| ScriptError::InvalidTrigger(format!("Invalid cron: {err}")))?;
+                let timezone = timezone.parse::<Tz>().map_err(|_| {
+                    ScriptError::InvalidTrigger(format!("Unknown timezone: {timezone}"))
+                })?;
+                Ok(Some((schedule, timezone)))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// First fire time strictly after `after`; `None` for non-cron triggers, invalid
+    /// expressions or zones, and schedules with no future occurrence.
+    pub fn next_fire_time(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let (schedule, timezone) = self.schedule().ok()??;
+        schedule
+            .after(&after.with_timezone(&timezone))
+            .next()
+            .map(|next| next.with_timezone(&Utc))
+    }
+}
+
+fn default_timezone() -> String {
+    "UTC".to_string()
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum EventType {
@@ -103,3 +164,79 @@ impl FromStr for HttpMethod {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn cron(expression: &str) -> ScriptTrigger {
+        ScriptTrigger::cron(expression)
+    }
+
+    #[test]
+    fn test_next_fire_time_follows_five_minute_schedule() {
+        let trigger = cron("0 */5 * * * *");
+        let start = Utc.with_ymd_and_hms(2026, 3, 1, 10, 2, 30).unwrap();
+
+        let first = trigger.next_fire_time(start).unwrap();
+        let second = trigger.next_fire_time(first).unwrap();
+
+        assert_eq!(first, Utc.with_ymd_and_hms(2026, 3, 1, 10, 5, 0).unwrap());
+        assert_eq!(second - first, chrono::Duration::minutes(5));
+        assert_eq!(ScriptTrigger::Manual.next_fire_time(start), None);
+    }
+
+    #[test]
+    fn test_invalid_cron_is_a_parse_error() {
+        let trigger = cron("every five minutes");
+
+        assert!(matches!(
+            trigger.schedule(),
+            Err(ScriptError::InvalidTrigger(_))
+        ));
+        assert_eq!(trigger.next_fire_time(Utc::now()), None);
+    }
+
+    #[test]
+    fn test_cron_is_evaluated_in_its_timezone() {
+        let trigger = ScriptTrigger::Cron {
+            expression: "0 0 9 * * *".to_string(),
+            timezone: "Europe/Berlin".to_string(),
+        };
+        let winter = Utc.with_ymd_and_hms(2026, 1, 10, 0, 0, 0).unwrap();
+        let summer = Utc.with_ymd_and_hms(2026, 7, 10, 0, 0, 0).unwrap();
+
+        assert_eq!(
+            trigger.next_fire_time(winter),
+            Some(Utc.with_ymd_and_hms(2026, 1, 10, 8, 0, 0).unwrap())
+        );
+        assert_eq!(
+            trigger.next_fire_time(summer),
+            Some(Utc.with_ymd_and_hms(2026, 7, 10, 7, 0, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_unknown_timezone_is_a_parse_error() {
+        let trigger = ScriptTrigger::Cron {
+            expression: "0 */5 * * * *".to_string(),
+            timezone: "Mars/Olympus".to_string(),
+        };
+
+        assert!(matches!(
+            trigger.schedule(),
+            Err(ScriptError::InvalidTrigger(_))
+        ));
+    }
+
+    #[test]
+    fn test_stored_cron_without_timezone_defaults_to_utc() {
+        let trigger: ScriptTrigger = serde_json::from_value(
+            serde_json::json!({ "type": "cron", "expression": "0 0 * * * *" }),
+        )
+        .unwrap();
+
+        assert_eq!(trigger, ScriptTrigger::cron("0 0 * * * *"));
+    }
+}
@@ -79,6 +79,7 @@ impl<R: ScriptRegistry> ScriptExecutor<R> {
                         depth: ctx.call_depth,
                     },
                 },
+                entity,
             };
         }
 
@@ -102,7 +103,7 @@ impl<R: ScriptRegistry> ScriptExecutor<R> {
                 let entity_changes = ctx_with_entity
                     .entity_proxy
                     .as_ref()
-                    .map(EntityProxy::changes)
+                    .map(EntityProxy::effective_changes)
                     .unwrap_or_else(HashMap::new);
 
                 debug!(
@@ -122,7 +123,19 @@ impl<R: ScriptRegistry> ScriptExecutor<R> {
                     reason = %reason,
                     "Script aborted"
                 );
-                ExecutionOutcome::Aborted { reason }
+                ExecutionOutcome::Aborted { reason, code: None }
+            }
+            Err(ScriptError::AbortedWithCode { code, message }) => {
+                debug!(
+                    script.id = %script.id,
+                    code = %code,
+                    reason = %message,
+                    "Script aborted"
+                );
+                ExecutionOutcome::Aborted {
+                    reason: message,
+                    code: Some(code),
+                }
             }
             Err(error) => {
                 warn!(
@@ -153,6 +166,7 @@ impl<R: ScriptRegistry> ScriptExecutor<R> {
             started_at,
             finished_at: Utc::now(),
             outcome,
+            entity: ctx_with_entity.entity_proxy,
         }
     }
 }
@@ -0,0 +1,180 @@
+use async_trait::async_trait;
+use rustok_core::{DomainEvent, EventEnvelope, EventTransport};
+use std::sync::Arc;
+use tracing::{error, warn};
+use uuid::Uuid;
+
+use crate::context::ExecutionPhase;
+use crate::error::ScriptError;
+use crate::model::{EventType, ScriptId};
+
+const MAX_ERROR_LEN: usize = 2000;
+
+/// A hook script that failed while running for a real operation.
+#[derive(Debug, Clone)]
+pub struct ScriptFailure {
+    pub script_id: ScriptId,
+    pub script_name: String,
+    pub phase: ExecutionPhase,
+    pub event: Option<EventType>,
+    pub error: ScriptError,
+}
+
+impl ScriptFailure {
+    pub fn to_domain_event(&self) -> DomainEvent {
+        DomainEvent::ScriptFailed {
+            script_id: self.script_id,
+            script_name: self.script_name.clone(),
+            phase: self.phase.as_str().to_string(),
+            event: self.event.map(|event| event.as_str().to_string()),
+            error: self.error.to_string().chars().take(MAX_ERROR_LEN).collect(),
+        }
+    }
+}
+
+/// Called by the orchestrator for every failed hook script; manual and dry runs are not
+/// reported.
+#[async_trait]
+pub trait ScriptFailureHandler: Send + Sync {
+    async fn on_failure(&self, failure: &ScriptFailure);
+}
+
+/// Logs the failure, counts it in module error metrics and, when an event transport is
+/// configured, publishes `DomainEvent::ScriptFailed` for notifier handlers.
+#[derive(Default)]
+pub struct DefaultFailureHandler {
+    events: Option<(Uuid, Arc<dyn EventTransport>)>,
+}
+
+impl DefaultFailureHandler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_events(tenant_id: Uuid, transport: Arc<dyn EventTransport>) -> Self {
+        Self {
+            events: Some((tenant_id, transport)),
+        }
+    }
+}
+
+#[async_trait]
+impl ScriptFailureHandler for DefaultFailureHandler {
+    async fn on_failure(&self, failure: &ScriptFailure) {
+        error!(
+            target: "alloy::script",
+            script_id = %failure.script_id,
+            script_name = %failure.script_name,
+            phase = failure.phase.as_str(),
+            event = failure.event.map(|event| event.as_str()),
+            error = %failure.error,
+            "Hook script failed"
+        );
+        rustok_telemetry::metrics::record_module_error("alloy", "script_failed", "error");
+
+        let Some((tenant_id, transport)) = &self.events else {
+            return;
+        };
+        let envelope = EventEnvelope::new(*tenant_id, None, failure.to_domain_event());
+        if let Err(err) = transport.publish(envelope).await {
+            warn!(
+                target: "alloy::script",
+                script_id = %failure.script_id,
+                error = %err,
+                "Failed to publish script.failed event"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::create_orchestrator;
+    use crate::model::{EntityProxy, Script, ScriptTrigger};
+    use crate::runner::HookOutcome;
+    use crate::storage::{InMemoryStorage, ScriptRegistry};
+    use parking_lot::Mutex;
+    use rustok_core::MemoryTransport;
+    use std::collections::HashMap;
+
+    #[derive(Default)]
+    struct RecordingHandler(Mutex<Vec<ScriptFailure>>);
+
+    #[async_trait]
+    impl ScriptFailureHandler for RecordingHandler {
+        async fn on_failure(&self, failure: &ScriptFailure) {
+            self.0.lock().push(failure.clone());
+        }
+    }
+
+    async fn storage_with_failing_script() -> (Arc<InMemoryStorage>, ScriptId) {
+        let storage = Arc::new(InMemoryStorage::new());
+        let mut script = Script::new(
+            "broken_hook",
+            r#"throw "boom";"#,
+            ScriptTrigger::Event {
+                entity_type: "order".into(),
+                event: EventType::BeforeCreate,
+            },
+        );
+        script.activate();
+        let script = storage.save(script).await.unwrap();
+        (storage, script.id)
+    }
+
+    fn order() -> EntityProxy {
+        EntityProxy::new("order-1", "order", HashMap::new())
+    }
+
+    #[tokio::test]
+    async fn failing_hook_invokes_the_failure_callback() {
+        let (storage, script_id) = storage_with_failing_script().await;
+        let handler = Arc::new(RecordingHandler::default());
+        let orchestrator = create_orchestrator(storage).with_failure_handler(handler.clone());
+
+        let outcome = orchestrator
+            .run_before("order", EventType::BeforeCreate, order(), None)
+            .await;
+        assert!(matches!(outcome, HookOutcome::Error { .. }));
+
+        let failures = handler.0.lock();
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].script_id, script_id);
+        assert_eq!(failures[0].phase, ExecutionPhase::Before);
+        assert_eq!(failures[0].event, Some(EventType::BeforeCreate));
+        assert!(failures[0].error.to_string().contains("boom"));
+    }
+
+    #[tokio::test]
+    async fn default_handler_emits_script_failed_event() {
+        let (storage, script_id) = storage_with_failing_script().await;
+        let transport = Arc::new(MemoryTransport::new());
+        let mut events = transport.subscribe();
+        let tenant_id = Uuid::new_v4();
+        let orchestrator = create_orchestrator(storage).with_failure_handler(Arc::new(
+            DefaultFailureHandler::with_events(tenant_id, transport.clone()),
+        ));
+
+        orchestrator
+            .run_before("order", EventType::BeforeCreate, order(), None)
+            .await;
+
+        let envelope = events.try_recv().expect("script.failed is published");
+        assert_eq!(envelope.tenant_id, tenant_id);
+        assert_eq!(envelope.event_type, "script.failed");
+        match envelope.event {
+            DomainEvent::ScriptFailed {
+                script_id: failed_id,
+                phase,
+                event,
+                ..
+            } => {
+                assert_eq!(failed_id, script_id);
+                assert_eq!(phase, "before");
+                assert_eq!(event.as_deref(), Some("before_create"));
+            }
+            other => panic!("unexpected event: {other:?}"),
+        }
+    }
+}
@@ -1,7 +1,11 @@
 mod executor;
+mod failure;
 mod orchestrator;
 mod result;
+mod retry;
 
 pub use executor::ScriptExecutor;
+pub use failure::{DefaultFailureHandler, ScriptFailure, ScriptFailureHandler};
 pub use orchestrator::ScriptOrchestrator;
 pub use result::{ExecutionOutcome, ExecutionResult, HookOutcome, PhaseResult};
+pub use retry::RetryPolicy;
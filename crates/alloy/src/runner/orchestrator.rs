@@ -1,20 +1,28 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use chrono::{DateTime, Utc};
 use rhai::Dynamic;
+use uuid::Uuid;
 
 use crate::context::{ExecutionContext, ExecutionPhase};
 use crate::engine::ScriptEngine;
 use crate::error::{ScriptError, ScriptResult};
+use crate::execution_log::SeaOrmExecutionLog;
 use crate::model::{EntityProxy, EventType, Script};
 use crate::storage::{ScriptQuery, ScriptRegistry};
 
 use super::executor::ScriptExecutor;
+use super::failure::{DefaultFailureHandler, ScriptFailure, ScriptFailureHandler};
 use super::result::{ExecutionOutcome, ExecutionResult, HookOutcome, PhaseResult};
+use super::retry::RetryPolicy;
 
 pub struct ScriptOrchestrator<R: ScriptRegistry> {
     executor: ScriptExecutor<R>,
     registry: Arc<R>,
+    failure_handler: Arc<dyn ScriptFailureHandler>,
+    retry_policy: RetryPolicy,
+    execution_log: Option<Arc<SeaOrmExecutionLog>>,
 }
 
 impl<R: ScriptRegistry> ScriptOrchestrator<R> {
@@ -22,9 +30,30 @@ impl<R: ScriptRegistry> ScriptOrchestrator<R> {
         Self {
             executor: ScriptExecutor::new(engine, Arc::clone(&registry)),
             registry,
+            failure_handler: Arc::new(DefaultFailureHandler::new()),
+            retry_policy: RetryPolicy::default(),
+            execution_log: None,
         }
     }
 
+    /// Retries for failed `OnCommit` hooks; without one each hook runs once.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Records every `OnCommit` attempt, retries included.
+    pub fn with_execution_log(mut self, log: Arc<SeaOrmExecutionLog>) -> Self {
+        self.execution_log = Some(log);
+        self
+    }
+
+    /// Replaces the callback notified when a hook script fails during a real operation.
+    pub fn with_failure_handler(mut self, handler: Arc<dyn ScriptFailureHandler>) -> Self {
+        self.failure_handler = handler;
+        self
+    }
+
     pub async fn run_before(
         &self,
         entity_type: &str,
@@ -40,6 +69,7 @@ impl<R: ScriptRegistry> ScriptOrchestrator<R> {
         if scripts.is_empty() {
             return HookOutcome::Continue {
                 changes: HashMap::new(),
+                changed: false,
             };
         }
 
@@ -57,13 +87,15 @@ impl<R: ScriptRegistry> ScriptOrchestrator<R> {
                 .execute(&script, &ctx, Some(current_entity.clone()))
                 .await;
 
-            if let ExecutionOutcome::Aborted { ref reason } = result.outcome {
+            if let ExecutionOutcome::Aborted { ref reason, .. } = result.outcome {
                 return HookOutcome::Rejected {
                     reason: reason.clone(),
                 };
             }
 
             if let ExecutionOutcome::Failed { ref error } = result.outcome {
+                self.report_failure(&script, ExecutionPhase::Before, Some(event), error)
+                    .await;
                 return HookOutcome::Error {
                     error: error.clone(),
                 };
@@ -84,6 +116,7 @@ impl<R: ScriptRegistry> ScriptOrchestrator<R> {
         phase_result.merge_changes();
 
         HookOutcome::Continue {
+            changed: !phase_result.entity_changes.is_empty(),
             changes: phase_result.entity_changes,
         }
     }
@@ -104,6 +137,7 @@ impl<R: ScriptRegistry> ScriptOrchestrator<R> {
         if scripts.is_empty() {
             return HookOutcome::Continue {
                 changes: HashMap::new(),
+                changed: false,
             };
         }
 
@@ -125,13 +159,15 @@ impl<R: ScriptRegistry> ScriptOrchestrator<R> {
                 .execute(&script, &ctx, Some(entity.clone()))
                 .await;
 
-            if let ExecutionOutcome::Aborted { ref reason } = result.outcome {
+            if let ExecutionOutcome::Aborted { ref reason, .. } = result.outcome {
                 return HookOutcome::Rejected {
                     reason: reason.clone(),
                 };
             }
 
             if let ExecutionOutcome::Failed { ref error } = result.outcome {
+                self.report_failure(&script, ExecutionPhase::After, Some(event), error)
+                    .await;
                 return HookOutcome::Error {
                     error: error.clone(),
                 };
@@ -143,6 +179,7 @@ impl<R: ScriptRegistry> ScriptOrchestrator<R> {
         phase_result.merge_changes();
 
         HookOutcome::Continue {
+            changed: !phase_result.entity_changes.is_empty(),
             changes: phase_result.entity_changes,
         }
     }
@@ -169,16 +206,41 @@ impl<R: ScriptRegistry> ScriptOrchestrator<R> {
 
         let mut results = Vec::new();
         for script in scripts {
-            let result = self
-                .executor
-                .execute(&script, &ctx, Some(entity.clone()))
+            let result = self.run_with_retry(&script, &ctx, &entity).await;
+            if let ExecutionOutcome::Failed { ref error } = result.outcome {
+                self.report_failure(
+                    &script,
+                    ExecutionPhase::OnCommit,
+                    Some(EventType::OnCommit),
+                    error,
+                )
                 .await;
+            }
             results.push(result);
         }
 
         results
     }
 
+    /// Active cron scripts with a fire time in `(since, now]`, i.e. those that became due
+    /// since the previous check at `since`.
+    pub async fn due_scripts(
+        &self,
+        since: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> ScriptResult<Vec<Script>> {
+        let scripts = self.registry.find(ScriptQuery::Scheduled).await?;
+        Ok(scripts
+            .into_iter()
+            .filter(|script| {
+                script
+                    .trigger
+                    .next_fire_time(since)
+                    .is_some_and(|next| next <= now)
+            })
+            .collect())
+    }
+
     pub async fn run_manual(
         &self,
         script_name: &str,
@@ -244,6 +306,77 @@ impl<R: ScriptRegistry> ScriptOrchestrator<R> {
         Ok(self.executor.execute(&script, &ctx, None).await)
     }
 
+    /// Runs `script` until it succeeds, aborts or uses up the retry policy; returns the
+    /// last attempt. Each attempt gets its own execution id.
+    async fn run_with_retry(
+        &self,
+        script: &Script,
+        ctx: &ExecutionContext,
+        entity: &EntityProxy,
+    ) -> ExecutionResult {
+        let mut attempt = 1;
+        loop {
+            let mut attempt_ctx = ctx.clone();
+            if attempt > 1 {
+                attempt_ctx.execution_id = Uuid::new_v4();
+            }
+            let result = self
+                .executor
+                .execute(script, &attempt_ctx, Some(entity.clone()))
+                .await;
+
+            if let Some(log) = &self.execution_log {
+                let tenant_id = ctx
+                    .tenant_id
+                    .as_deref()
+                    .and_then(|tenant| Uuid::parse_str(tenant).ok());
+                if let Err(error) = log
+                    .record_with_context(&result, ctx.user_id.clone(), tenant_id)
+                    .await
+                {
+                    tracing::warn!(script = %script.name, %error, "Failed to record execution");
+                }
+            }
+
+            let ExecutionOutcome::Failed { ref error } = result.outcome else {
+                return result;
+            };
+            if attempt >= self.retry_policy.max_attempts {
+                return result;
+            }
+
+            attempt += 1;
+            let delay = self.retry_policy.delay_before(attempt);
+            tracing::warn!(
+                script = %script.name,
+                %error,
+                attempt,
+                max_attempts = self.retry_policy.max_attempts,
+                delay_ms = delay.as_millis() as u64,
+                "OnCommit script failed, retrying"
+            );
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    async fn report_failure(
+        &self,
+        script: &Script,
+        phase: ExecutionPhase,
+        event: Option<EventType>,
+        error: &ScriptError,
+    ) {
+        self.failure_handler
+            .on_failure(&ScriptFailure {
+                script_id: script.id,
+                script_name: script.name.clone(),
+                phase,
+                event,
+                error: error.clone(),
+            })
+            .await;
+    }
+
     async fn find_scripts(&self, entity_type: &str, event: EventType) -> ScriptResult<Vec<Script>> {
         self.registry
             .find(ScriptQuery::ByEvent {
@@ -5,7 +5,7 @@ use uuid::Uuid;
 
 use crate::context::ExecutionPhase;
 use crate::error::ScriptError;
-use crate::model::ScriptId;
+use crate::model::{EntityProxy, FieldChange, ScriptId};
 
 #[derive(Debug, Clone)]
 pub struct ExecutionResult {
@@ -16,6 +16,8 @@ pub struct ExecutionResult {
     pub started_at: DateTime<Utc>,
     pub finished_at: DateTime<Utc>,
     pub outcome: ExecutionOutcome,
+    /// Entity as the script left it; Before-phase writes are visible here.
+    pub entity: Option<EntityProxy>,
 }
 
 #[derive(Debug, Clone)]
@@ -26,6 +28,8 @@ pub enum ExecutionOutcome {
     },
     Aborted {
         reason: String,
+        /// Set when the script called `abort_with(code, message)`.
+        code: Option<String>,
     },
     Failed {
         error: ScriptError,
@@ -44,6 +48,14 @@ impl ExecutionResult {
     pub fn duration_ms(&self) -> i64 {
         (self.finished_at - self.started_at).num_milliseconds()
     }
+
+    /// Fields a successful run changed on its entity; empty for aborted or failed runs.
+    pub fn entity_diff(&self) -> HashMap<String, FieldChange> {
+        match (&self.outcome, &self.entity) {
+            (ExecutionOutcome::Success { .. }, Some(entity)) => entity.diff(),
+            _ => HashMap::new(),
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -64,7 +76,7 @@ impl PhaseResult {
 
     pub fn has_abort(&self) -> Option<&str> {
         for result in &self.results {
-            if let ExecutionOutcome::Aborted { reason } = &result.outcome {
+            if let ExecutionOutcome::Aborted { reason, .. } = &result.outcome {
                 return Some(reason);
             }
         }
@@ -90,7 +102,15 @@ impl PhaseResult {
 
 #[derive(Debug)]
 pub enum HookOutcome {
-    Continue { changes: HashMap<String, Dynamic> },
-    Rejected { reason: String },
-    Error { error: ScriptError },
+    /// `changed` tells the caller whether `changes` must be applied to the entity.
+    Continue {
+        changes: HashMap<String, Dynamic>,
+        changed: bool,
+    },
+    Rejected {
+        reason: String,
+    },
+    Error {
+        error: ScriptError,
+    },
 }
@@ -0,0 +1,63 @@
+use std::time::Duration;
+
+/// How often a failed `OnCommit` hook is re-run. Aborted scripts are never retried.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// Total runs including the first; `1` disables retries.
+    pub max_attempts: u32,
+    /// Delay before the first retry.
+    pub backoff: Duration,
+    /// Factor applied to the delay after every retry.
+    pub backoff_multiplier: f64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::no_retry()
+    }
+}
+
+impl RetryPolicy {
+    pub fn new(max_attempts: u32, backoff: Duration) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            backoff,
+            backoff_multiplier: 2.0,
+        }
+    }
+
+    pub fn no_retry() -> Self {
+        Self::new(1, Duration::ZERO)
+    }
+
+    pub fn with_backoff_multiplier(mut self, multiplier: f64) -> Self {
+        self.backoff_multiplier = multiplier.max(1.0);
+        self
+    }
+
+    /// Delay before `attempt` (1-based); the first attempt runs immediately.
+    pub fn delay_before(&self, attempt: u32) -> Duration {
+        if attempt <= 1 {
+            return Duration::ZERO;
+        }
+        let factor = self
+            .backoff_multiplier
+            .powi(attempt.saturating_sub(2) as i32);
+        self.backoff.mul_f64(factor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delay_grows_by_multiplier() {
+        let policy = RetryPolicy::new(4, Duration::from_millis(100)).with_backoff_multiplier(3.0);
+
+        assert_eq!(policy.delay_before(1), Duration::ZERO);
+        assert_eq!(policy.delay_before(2), Duration::from_millis(100));
+        assert_eq!(policy.delay_before(3), Duration::from_millis(300));
+        assert_eq!(policy.delay_before(4), Duration::from_millis(900));
+    }
+}
@@ -1,11 +1,12 @@
 use std::sync::Arc;
 
 use loco_rs::app::AppContext;
+use rustok_core::{CacheBackend, EventTransport};
 use uuid::Uuid;
 
 use crate::{
-    create_default_engine, Scheduler, ScriptEngine, ScriptExecutor, ScriptOrchestrator,
-    SeaOrmExecutionLog, SeaOrmStorage,
+    create_default_engine, DefaultFailureHandler, Scheduler, ScriptEngine, ScriptExecutor,
+    ScriptKvStore, ScriptOrchestrator, SeaOrmExecutionLog, SeaOrmStorage,
 };
 
 #[derive(Clone)]
@@ -13,6 +14,8 @@ pub struct AlloyRuntime {
     pub engine: Arc<ScriptEngine>,
     pub storage: Arc<SeaOrmStorage>,
     pub execution_log: Arc<SeaOrmExecutionLog>,
+    /// Transport for `script.failed` events; failures are only logged without it.
+    pub events: Option<Arc<dyn EventTransport>>,
 }
 
 #[derive(Clone)]
@@ -29,10 +32,15 @@ pub struct SharedAlloyRuntime(pub Arc<AlloyRuntime>);
 impl AlloyRuntime {
     pub fn scoped(&self, tenant_id: Uuid) -> ScopedAlloyRuntime {
         let storage = Arc::new(self.storage.for_tenant(tenant_id));
-        let orchestrator = Arc::new(ScriptOrchestrator::new(
-            self.engine.clone(),
-            storage.clone(),
-        ));
+        let failure_handler = match &self.events {
+            Some(transport) => DefaultFailureHandler::with_events(tenant_id, transport.clone()),
+            None => DefaultFailureHandler::new(),
+        };
+        let orchestrator = Arc::new(
+            ScriptOrchestrator::new(self.engine.clone(), storage.clone())
+                .with_failure_handler(Arc::new(failure_handler))
+                .with_execution_log(self.execution_log.clone()),
+        );
 
         ScopedAlloyRuntime {
             engine: self.engine.clone(),
@@ -43,12 +51,15 @@ impl AlloyRuntime {
     }
 }
 
-pub fn init(ctx: &AppContext) -> Arc<AlloyRuntime> {
+/// Builds the runtime once per app; `kv_backend` stores `kv_set` values, so pass the app's
+/// shared cache to make them visible across server instances.
+pub fn init(ctx: &AppContext, kv_backend: Arc<dyn CacheBackend>) -> Arc<AlloyRuntime> {
     if let Some(shared) = ctx.shared_store.get::<SharedAlloyRuntime>() {
         return shared.0.clone();
     }
 
-    let engine = Arc::new(create_default_engine());
+    let engine =
+        Arc::new(create_default_engine().with_kv_store(Arc::new(ScriptKvStore::new(kv_backend))));
     let storage = Arc::new(SeaOrmStorage::new(ctx.db.clone()));
     let execution_log = Arc::new(SeaOrmExecutionLog::new(ctx.db.clone()));
 
@@ -65,6 +76,7 @@ pub fn init(ctx: &AppContext) -> Arc<AlloyRuntime> {
         engine,
         storage,
         execution_log,
+        events: ctx.shared_store.get::<Arc<dyn EventTransport>>(),
     });
 
     ctx.shared_store.insert(SharedAlloyRuntime(runtime.clone()));
@@ -1,9 +1,7 @@
 use std::collections::HashMap;
-use std::str::FromStr;
 use std::sync::Arc;
 
 use chrono::Utc;
-use cron::Schedule;
 use tokio::sync::RwLock;
 use tokio::time::{interval, Duration};
 use tracing::{error, info, warn};
@@ -38,7 +36,7 @@ impl<S: ScriptRegistry + 'static> Scheduler<S> {
         jobs.clear();
 
         for script in scripts {
-            if let ScriptTrigger::Cron { expression } = &script.trigger {
+            if let ScriptTrigger::Cron { expression, .. } = &script.trigger {
                 match self.create_job(&script, expression) {
                     Ok(job) => {
                         info!("Loaded cron job: {} ({})", script.name, expression);
@@ -136,7 +134,7 @@ impl<S: ScriptRegistry + 'static> Scheduler<S> {
             crate::runner::ExecutionOutcome::Failed { error } => {
                 error!("Scheduled script {} failed: {}", script.name, error);
             }
-            crate::runner::ExecutionOutcome::Aborted { reason } => {
+            crate::runner::ExecutionOutcome::Aborted { reason, .. } => {
                 warn!("Scheduled script {} aborted: {}", script.name, reason);
             }
             crate::runner::ExecutionOutcome::Success { .. } => {
@@ -162,21 +160,18 @@ impl<S: ScriptRegistry + 'static> Scheduler<S> {
             job.running = false;
             job.last_run = Some(Utc::now());
 
-            if let Ok(schedule) = Schedule::from_str(&job.cron_expression) {
-                if let Some(next) = schedule.upcoming(Utc).next() {
-                    job.next_run = next;
-                }
+            if let Some(next) = script.trigger.next_fire_time(Utc::now()) {
+                job.next_run = next;
             }
         }
     }
 
     fn create_job(&self, script: &Script, cron_expr: &str) -> Result<ScheduledJob, String> {
-        let schedule =
-            Schedule::from_str(cron_expr).map_err(|err| format!("Invalid cron: {err}"))?;
+        script.trigger.schedule().map_err(|err| err.to_string())?;
 
-        let next_run = schedule
-            .upcoming(Utc)
-            .next()
+        let next_run = script
+            .trigger
+            .next_fire_time(Utc::now())
             .ok_or_else(|| "No upcoming schedule".to_string())?;
 
         Ok(ScheduledJob {
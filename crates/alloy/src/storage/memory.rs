@@ -8,6 +8,12 @@ use super::traits::{ScriptPage, ScriptQuery, ScriptRegistry};
 use crate::error::{ScriptError, ScriptResult};
 use crate::model::{Script, ScriptId, ScriptStatus, ScriptTrigger};
 
+/// Script registry kept in process memory.
+///
+/// Every operation holds the lock for its whole duration, so a query observes either all
+/// or none of a concurrent `save`, and results are ordered by name like [`SeaOrmStorage`].
+///
+/// [`SeaOrmStorage`]: super::SeaOrmStorage
 #[derive(Clone)]
 pub struct InMemoryStorage {
     scripts: Arc<RwLock<HashMap<ScriptId, Script>>>,
@@ -32,7 +38,7 @@ impl ScriptRegistry for InMemoryStorage {
     async fn find(&self, query: ScriptQuery) -> ScriptResult<Vec<Script>> {
         let guard = self.scripts.read().await;
 
-        let result = match query {
+        let mut result: Vec<Script> = match query {
             ScriptQuery::ById(id) => guard.get(&id).cloned().into_iter().collect(),
             ScriptQuery::ByName(name) => guard
                 .values()
@@ -76,9 +82,16 @@ impl ScriptRegistry for InMemoryStorage {
                 .filter(|script| script.status == status)
                 .cloned()
                 .collect(),
+            ScriptQuery::Filter(filter) => guard
+                .values()
+                .filter(|script| filter.matches(script))
+                .cloned()
+                .collect(),
             ScriptQuery::All => guard.values().cloned().collect(),
         };
+        drop(guard);
 
+        result.sort_by(|left, right| left.name.cmp(&right.name).then(left.id.cmp(&right.id)));
         Ok(result)
     }
 
@@ -119,8 +132,10 @@ impl ScriptRegistry for InMemoryStorage {
     async fn save(&self, mut script: Script) -> ScriptResult<Script> {
         let mut guard = self.scripts.write().await;
 
-        if guard.contains_key(&script.id) {
-            script.version += 1;
+        // Bump from the stored copy so concurrent saves of a stale script still get
+        // distinct versions.
+        if let Some(stored) = guard.get(&script.id) {
+            script.version = stored.version + 1;
             script.updated_at = chrono::Utc::now();
         }
 
@@ -169,3 +184,168 @@ impl ScriptRegistry for InMemoryStorage {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::EventType;
+    use crate::storage::ScriptFilter;
+
+    fn hook(name: String) -> Script {
+        let mut script = Script::new(
+            name,
+            "entity",
+            ScriptTrigger::Event {
+                entity_type: "order".into(),
+                event: EventType::BeforeCreate,
+            },
+        );
+        script.activate();
+        script
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn concurrent_saves_and_queries_stay_consistent() {
+        let storage = Arc::new(InMemoryStorage::new());
+        let shared = storage.save(hook("shared".into())).await.unwrap();
+
+        let mut tasks = Vec::new();
+        for task in 0..32 {
+            let storage = Arc::clone(&storage);
+            let shared = shared.clone();
+            tasks.push(tokio::spawn(async move {
+                for round in 0..10 {
+                    storage
+                        .save(hook(format!("script_{task}_{round}")))
+                        .await
+                        .unwrap();
+                    storage.save(shared.clone()).await.unwrap();
+
+                    let page = storage
+                        .find_paginated(ScriptQuery::All, 0, 1_000)
+                        .await
+                        .unwrap();
+                    assert_eq!(page.total as usize, page.items.len());
+                    assert!(page
+                        .items
+                        .windows(2)
+                        .all(|pair| pair[0].name <= pair[1].name));
+                }
+            }));
+        }
+        for task in tasks {
+            task.await.unwrap();
+        }
+
+        let all = storage.find(ScriptQuery::All).await.unwrap();
+        assert_eq!(all.len(), 32 * 10 + 1);
+        let by_event = storage
+            .find(ScriptQuery::ByEvent {
+                entity_type: "order".into(),
+                event: EventType::BeforeCreate,
+            })
+            .await
+            .unwrap();
+        assert_eq!(by_event.len(), all.len());
+        assert_eq!(
+            storage.get(shared.id).await.unwrap().version,
+            shared.version + 32 * 10
+        );
+    }
+
+    #[tokio::test]
+    async fn filter_by_status_and_tag_returns_only_matches() {
+        let storage = InMemoryStorage::new();
+        let tagged = |name: &str, tags: &[&str], active: bool| {
+            let mut script = Script::new(name, "1", ScriptTrigger::Manual);
+            script.tags = tags.iter().map(|tag| tag.to_string()).collect();
+            if active {
+                script.activate();
+            }
+            script
+        };
+        for script in [
+            tagged("billing_sync", &["billing"], true),
+            tagged("billing_report", &["billing", "reports"], true),
+            tagged("billing_draft", &["billing"], false),
+            tagged("welcome_email", &["marketing"], true),
+        ] {
+            storage.save(script).await.unwrap();
+        }
+        storage.save(hook("billing_hook".into())).await.unwrap();
+
+        let filter = ScriptFilter {
+            status: Some(ScriptStatus::Active),
+            tag: Some("billing".into()),
+            ..ScriptFilter::default()
+        };
+        let page = storage
+            .find_paginated(ScriptQuery::Filter(filter.clone()), 0, 10)
+            .await
+            .unwrap();
+        let names: Vec<_> = page.items.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(page.total, 2);
+        assert_eq!(names, ["billing_report", "billing_sync"]);
+
+        let first = storage
+            .find_paginated(ScriptQuery::Filter(filter), 0, 1)
+            .await
+            .unwrap();
+        assert_eq!(first.total, 2);
+        assert_eq!(first.items[0].name, "billing_report");
+
+        let manual_named_billing = storage
+            .find(ScriptQuery::Filter(ScriptFilter {
+                search: Some("BILLING".into()),
+                trigger_type: Some("manual".into()),
+                ..ScriptFilter::default()
+            }))
+            .await
+            .unwrap();
+        assert_eq!(manual_named_billing.len(), 3);
+    }
+    #[tokio::test]
+    async fn filter_by_event_and_status_pages_with_full_total() {
+        let storage = InMemoryStorage::new();
+        let event_hook = |name: &str, event: EventType, active: bool| {
+            let mut script = Script::new(
+                name,
+                "1",
+                ScriptTrigger::Event {
+                    entity_type: "order".into(),
+                    event,
+                },
+            );
+            if active {
+                script.activate();
+            }
+            script
+        };
+        for script in [
+            event_hook("notify_a", EventType::AfterCreate, true),
+            event_hook("notify_b", EventType::AfterCreate, true),
+            event_hook("notify_c", EventType::AfterCreate, true),
+            event_hook("notify_draft", EventType::AfterCreate, false),
+            event_hook("validate", EventType::BeforeCreate, true),
+        ] {
+            storage.save(script).await.unwrap();
+        }
+
+        let page = storage
+            .find_paginated(
+                ScriptQuery::Filter(ScriptFilter {
+                    status: Some(ScriptStatus::Active),
+                    event: Some(EventType::AfterCreate),
+                    ..ScriptFilter::default()
+                }),
+                1,
+                1,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(page.total, 3);
+        assert_eq!(page.items.len(), 1);
+        assert_eq!(page.items[0].name, "notify_b");
+    }
+}
@@ -1,7 +1,9 @@
 mod memory;
 mod sea_orm;
 mod traits;
+mod versions;
 
 pub use memory::InMemoryStorage;
 pub use sea_orm::{Entity as ScriptsEntity, SeaOrmStorage};
-pub use traits::{ScriptPage, ScriptQuery, ScriptRegistry};
+pub use traits::{ScriptFilter, ScriptPage, ScriptQuery, ScriptRegistry};
+pub use versions::ScriptVersion;
@@ -3,13 +3,15 @@ use sea_orm::entity::prelude::*;
 use sea_orm::sea_query::Expr;
 use sea_orm::{
     ActiveModelTrait, ActiveValue, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter,
-    QueryOrder, QuerySelect,
+    QueryOrder, QuerySelect, TransactionTrait,
 };
 
 use crate::error::{ScriptError, ScriptResult};
 use crate::model::{EventType, HttpMethod, Script, ScriptId, ScriptStatus, ScriptTrigger};
 use crate::storage::{ScriptPage, ScriptQuery, ScriptRegistry};
 
+use super::versions;
+
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
 #[sea_orm(table_name = "scripts")]
 pub struct Model {
@@ -25,6 +27,7 @@ pub struct Model {
     pub version: i32,
     pub run_as_system: bool,
     pub permissions: Json,
+    pub tags: Json,
     pub author_id: Option<String>,
     pub error_count: i32,
     pub last_error_at: Option<DateTime<Utc>>,
@@ -39,8 +42,8 @@ impl ActiveModelBehavior for ActiveModel {}
 
 #[derive(Clone)]
 pub struct SeaOrmStorage {
-    db: DatabaseConnection,
-    tenant_id: Option<Uuid>,
+    pub(super) db: DatabaseConnection,
+    pub(super) tenant_id: Option<Uuid>,
 }
 
 impl SeaOrmStorage {
@@ -65,6 +68,94 @@ impl SeaOrmStorage {
         }
     }
 
+    /// Saves like [`ScriptRegistry::save`], recording `user_id` as the editor on the
+    /// snapshot of the version being replaced.
+    pub async fn update(&self, script: Script, user_id: Option<String>) -> ScriptResult<Script> {
+        self.save_as(script, user_id).await
+    }
+
+    async fn save_as(&self, mut script: Script, user_id: Option<String>) -> ScriptResult<Script> {
+        let now = Utc::now();
+        let (trigger_type, trigger_config) = Self::trigger_to_parts(&script.trigger);
+        let permissions_json = Self::strings_to_json(&script.permissions);
+        let tags_json = Self::strings_to_json(&script.tags);
+
+        if let Some(existing) = Entity::find_by_id(script.id)
+            .one(&self.db)
+            .await
+            .map_err(|err| ScriptError::Storage(err.to_string()))?
+        {
+            script.version = script.version.max(1) + 1;
+            script.updated_at = now;
+
+            let txn = self
+                .db
+                .begin()
+                .await
+                .map_err(|err| ScriptError::Storage(err.to_string()))?;
+            versions::snapshot(&existing, user_id)
+                .insert(&txn)
+                .await
+                .map_err(|err| ScriptError::Storage(err.to_string()))?;
+
+            let mut active: ActiveModel = existing.into();
+            active.name = ActiveValue::Set(script.name.clone());
+            active.description = ActiveValue::Set(script.description.clone());
+            active.code = ActiveValue::Set(script.code.clone());
+            active.trigger_type = ActiveValue::Set(trigger_type);
+            active.trigger_config = ActiveValue::Set(trigger_config);
+            active.status = ActiveValue::Set(script.status.as_str().to_string());
+            active.version = ActiveValue::Set(script.version as i32);
+            active.run_as_system = ActiveValue::Set(script.run_as_system);
+            active.permissions = ActiveValue::Set(permissions_json);
+            active.tags = ActiveValue::Set(tags_json);
+            active.author_id = ActiveValue::Set(script.author_id.clone());
+            active.error_count = ActiveValue::Set(script.error_count as i32);
+            active.last_error_at = ActiveValue::Set(script.last_error_at);
+            active.updated_at = ActiveValue::Set(script.updated_at);
+
+            let updated = active
+                .update(&txn)
+                .await
+                .map_err(|err| ScriptError::Storage(err.to_string()))?;
+            txn.commit()
+                .await
+                .map_err(|err| ScriptError::Storage(err.to_string()))?;
+            return Self::model_to_script(updated);
+        }
+
+        script.version = script.version.max(1);
+        script.created_at = now;
+        script.updated_at = now;
+
+        let model = ActiveModel {
+            id: ActiveValue::Set(script.id),
+            tenant_id: ActiveValue::Set(script.tenant_id),
+            name: ActiveValue::Set(script.name.clone()),
+            description: ActiveValue::Set(script.description.clone()),
+            code: ActiveValue::Set(script.code.clone()),
+            trigger_type: ActiveValue::Set(trigger_type),
+            trigger_config: ActiveValue::Set(trigger_config),
+            status: ActiveValue::Set(script.status.as_str().to_string()),
+            version: ActiveValue::Set(script.version as i32),
+            run_as_system: ActiveValue::Set(script.run_as_system),
+            permissions: ActiveValue::Set(permissions_json),
+            tags: ActiveValue::Set(tags_json),
+            author_id: ActiveValue::Set(script.author_id.clone()),
+            error_count: ActiveValue::Set(script.error_count as i32),
+            last_error_at: ActiveValue::Set(script.last_error_at),
+            created_at: ActiveValue::Set(script.created_at),
+            updated_at: ActiveValue::Set(script.updated_at),
+        };
+
+        let inserted = model
+            .insert(&self.db)
+            .await
+            .map_err(|err| ScriptError::Storage(err.to_string()))?;
+
+        Self::model_to_script(inserted)
+    }
+
     fn trigger_to_parts(trigger: &ScriptTrigger) -> (String, serde_json::Value) {
         match trigger {
             ScriptTrigger::Event { entity_type, event } => (
@@ -74,9 +165,12 @@ impl SeaOrmStorage {
                     "event": event.as_str(),
                 }),
             ),
-            ScriptTrigger::Cron { expression } => (
+            ScriptTrigger::Cron {
+                expression,
+                timezone,
+            } => (
                 "cron".to_string(),
-                serde_json::json!({ "expression": expression }),
+                serde_json::json!({ "expression": expression, "timezone": timezone }),
             ),
             ScriptTrigger::Manual => ("manual".to_string(), serde_json::json!({})),
             ScriptTrigger::Api { path, method } => (
@@ -114,7 +208,16 @@ impl SeaOrmStorage {
                     .and_then(|value| value.as_str())
                     .unwrap_or_default()
                     .to_string();
-                Ok(ScriptTrigger::Cron { expression })
+                match trigger_config
+                    .get("timezone")
+                    .and_then(|value| value.as_str())
+                {
+                    Some(timezone) => Ok(ScriptTrigger::Cron {
+                        expression,
+                        timezone: timezone.to_string(),
+                    }),
+                    None => Ok(ScriptTrigger::cron(expression)),
+                }
             }
             "manual" => Ok(ScriptTrigger::Manual),
             "api" => {
@@ -149,16 +252,8 @@ impl SeaOrmStorage {
     fn model_to_script(model: Model) -> ScriptResult<Script> {
         let trigger = Self::trigger_from_parts(&model.trigger_type, &model.trigger_config)?;
         let status = Self::status_from_str(&model.status)?;
-        let permissions = model
-            .permissions
-            .as_array()
-            .map(|values| {
-                values
-                    .iter()
-                    .filter_map(|value| value.as_str().map(|s| s.to_string()))
-                    .collect()
-            })
-            .unwrap_or_default();
+        let permissions = Self::strings_from_json(&model.permissions);
+        let tags = Self::strings_from_json(&model.tags);
 
         Ok(Script {
             id: model.id,
@@ -171,6 +266,7 @@ impl SeaOrmStorage {
             version: model.version.max(1) as u32,
             run_as_system: model.run_as_system,
             permissions,
+            tags,
             author_id: model.author_id,
             created_at: model.created_at,
             updated_at: model.updated_at,
@@ -179,9 +275,21 @@ impl SeaOrmStorage {
         })
     }
 
-    fn permissions_to_json(permissions: &[String]) -> serde_json::Value {
+    fn strings_from_json(value: &serde_json::Value) -> Vec<String> {
+        value
+            .as_array()
+            .map(|values| {
+                values
+                    .iter()
+                    .filter_map(|value| value.as_str().map(|s| s.to_string()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn strings_to_json(values: &[String]) -> serde_json::Value {
         serde_json::Value::Array(
-            permissions
+            values
                 .iter()
                 .map(|value| serde_json::Value::String(value.clone()))
                 .collect(),
@@ -218,6 +326,33 @@ impl SeaOrmStorage {
                 .filter(Column::TriggerType.eq("cron"))
                 .filter(Column::Status.eq(ScriptStatus::Active.as_str())),
             ScriptQuery::ByStatus(status) => select.filter(Column::Status.eq(status.as_str())),
+            ScriptQuery::Filter(filter) => {
+                let mut select = select;
+                if let Some(search) = filter.search {
+                    select = select.filter(Expr::cust_with_values(
+                        "name ILIKE $1 ESCAPE '\\'",
+                        [format!("%{}%", escape_like(&search))],
+                    ));
+                }
+                if let Some(tag) = filter.tag {
+                    select = select.filter(Expr::cust_with_values(
+                        "tags @> jsonb_build_array($1::text)",
+                        [tag],
+                    ));
+                }
+                if let Some(status) = filter.status {
+                    select = select.filter(Column::Status.eq(status.as_str()));
+                }
+                if let Some(trigger_type) = filter.trigger_type {
+                    select = select.filter(Column::TriggerType.eq(trigger_type));
+                }
+                if let Some(event) = filter.event {
+                    select = select.filter(Column::TriggerType.eq("event")).filter(
+                        Expr::cust_with_values("trigger_config->>'event' = $1", [event.as_str()]),
+                    );
+                }
+                select
+            }
             ScriptQuery::All => select,
         };
 
@@ -229,6 +364,13 @@ impl SeaOrmStorage {
     }
 }
 
+fn escape_like(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
 #[async_trait::async_trait]
 impl ScriptRegistry for SeaOrmStorage {
     async fn find(&self, query: ScriptQuery) -> ScriptResult<Vec<Script>> {
@@ -299,70 +441,8 @@ impl ScriptRegistry for SeaOrmStorage {
         Self::model_to_script(model)
     }
 
-    async fn save(&self, mut script: Script) -> ScriptResult<Script> {
-        let now = Utc::now();
-        let (trigger_type, trigger_config) = Self::trigger_to_parts(&script.trigger);
-        let permissions_json = Self::permissions_to_json(&script.permissions);
-
-        if let Some(existing) = Entity::find_by_id(script.id)
-            .one(&self.db)
-            .await
-            .map_err(|err| ScriptError::Storage(err.to_string()))?
-        {
-            script.version = script.version.max(1) + 1;
-            script.updated_at = now;
-
-            let mut active: ActiveModel = existing.into();
-            active.name = ActiveValue::Set(script.name.clone());
-            active.description = ActiveValue::Set(script.description.clone());
-            active.code = ActiveValue::Set(script.code.clone());
-            active.trigger_type = ActiveValue::Set(trigger_type);
-            active.trigger_config = ActiveValue::Set(trigger_config);
-            active.status = ActiveValue::Set(script.status.as_str().to_string());
-            active.version = ActiveValue::Set(script.version as i32);
-            active.run_as_system = ActiveValue::Set(script.run_as_system);
-            active.permissions = ActiveValue::Set(permissions_json);
-            active.author_id = ActiveValue::Set(script.author_id.clone());
-            active.error_count = ActiveValue::Set(script.error_count as i32);
-            active.last_error_at = ActiveValue::Set(script.last_error_at);
-            active.updated_at = ActiveValue::Set(script.updated_at);
-
-            let updated = active
-                .update(&self.db)
-                .await
-                .map_err(|err| ScriptError::Storage(err.to_string()))?;
-            return Self::model_to_script(updated);
-        }
-
-        script.version = script.version.max(1);
-        script.created_at = now;
-        script.updated_at = now;
-
-        let model = ActiveModel {
-            id: ActiveValue::Set(script.id),
-            tenant_id: ActiveValue::Set(script.tenant_id),
-            name: ActiveValue::Set(script.name.clone()),
-            description: ActiveValue::Set(script.description.clone()),
-            code: ActiveValue::Set(script.code.clone()),
-            trigger_type: ActiveValue::Set(trigger_type),
-            trigger_config: ActiveValue::Set(trigger_config),
-            status: ActiveValue::Set(script.status.as_str().to_string()),
-            version: ActiveValue::Set(script.version as i32),
-            run_as_system: ActiveValue::Set(script.run_as_system),
-            permissions: ActiveValue::Set(permissions_json),
-            author_id: ActiveValue::Set(script.author_id.clone()),
-            error_count: ActiveValue::Set(script.error_count as i32),
-            last_error_at: ActiveValue::Set(script.last_error_at),
-            created_at: ActiveValue::Set(script.created_at),
-            updated_at: ActiveValue::Set(script.updated_at),
-        };
-
-        let inserted = model
-            .insert(&self.db)
-            .await
-            .map_err(|err| ScriptError::Storage(err.to_string()))?;
-
-        Self::model_to_script(inserted)
+    async fn save(&self, script: Script) -> ScriptResult<Script> {
+        self.save_as(script, None).await
     }
 
     async fn delete(&self, id: ScriptId) -> ScriptResult<()> {
@@ -1,7 +1,7 @@
 use async_trait::async_trait;
 
 use crate::error::ScriptResult;
-use crate::model::{EventType, Script, ScriptId, ScriptStatus};
+use crate::model::{EventType, Script, ScriptId, ScriptStatus, ScriptTrigger};
 
 #[derive(Clone)]
 pub enum ScriptQuery {
@@ -14,9 +14,45 @@ pub enum ScriptQuery {
     ByApiPath(String),
     Scheduled,
     ByStatus(ScriptStatus),
+    Filter(ScriptFilter),
     All,
 }
 
+/// Admin listing filter; every criterion that is set must match.
+#[derive(Clone, Debug, Default)]
+pub struct ScriptFilter {
+    /// Case-insensitive substring of the script name.
+    pub search: Option<String>,
+    pub tag: Option<String>,
+    pub status: Option<ScriptStatus>,
+    /// One of `event`, `cron`, `manual`, `api`.
+    pub trigger_type: Option<String>,
+    /// Event hooks for this event, on any entity type.
+    pub event: Option<EventType>,
+}
+
+impl ScriptFilter {
+    pub fn matches(&self, script: &Script) -> bool {
+        let search = self.search.as_deref().map(str::to_lowercase);
+        search.is_none_or(|search| script.name.to_lowercase().contains(&search))
+            && self
+                .tag
+                .as_deref()
+                .is_none_or(|tag| script.tags.iter().any(|t| t == tag))
+            && self.status.is_none_or(|status| script.status == status)
+            && self
+                .trigger_type
+                .as_deref()
+                .is_none_or(|kind| script.trigger.kind() == kind)
+            && self.event.is_none_or(|event| {
+                matches!(
+                    script.trigger,
+                    ScriptTrigger::Event { event: hook_event, .. } if hook_event == event
+                )
+            })
+    }
+}
+
 pub struct ScriptPage {
     pub items: Vec<Script>,
     pub total: u64,
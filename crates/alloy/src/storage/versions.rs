@@ -0,0 +1,183 @@
+use chrono::{DateTime, Utc};
+use sea_orm::entity::prelude::*;
+use sea_orm::{ActiveValue, QueryOrder};
+
+use crate::error::{ScriptError, ScriptResult};
+use crate::model::{Script, ScriptId, ScriptStatus};
+
+use super::sea_orm::Model as ScriptModel;
+use super::{ScriptRegistry, SeaOrmStorage};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "script_versions")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub script_id: Uuid,
+    pub tenant_id: Uuid,
+    pub version: i32,
+    pub code: String,
+    pub status: String,
+    pub user_id: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+/// Source and status of a script as they were before an edit overwrote them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScriptVersion {
+    pub id: Uuid,
+    pub script_id: ScriptId,
+    /// `Script::version` the snapshot was taken from.
+    pub version: u32,
+    pub code: String,
+    pub status: ScriptStatus,
+    /// Editor whose change replaced this version.
+    pub user_id: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ScriptVersion {
+    /// Puts this version's source and status back on `script`; save it to apply.
+    pub fn restore(&self, script: &mut Script) {
+        script.code = self.code.clone();
+        script.status = self.status;
+    }
+
+    fn from_model(model: Model) -> ScriptResult<Self> {
+        let status = ScriptStatus::parse(&model.status)
+            .ok_or_else(|| ScriptError::InvalidStatus(model.status.clone()))?;
+        Ok(Self {
+            id: model.id,
+            script_id: model.script_id,
+            version: model.version.max(1) as u32,
+            code: model.code,
+            status,
+            user_id: model.user_id,
+            created_at: model.created_at,
+        })
+    }
+}
+
+pub(super) fn snapshot(previous: &ScriptModel, user_id: Option<String>) -> ActiveModel {
+    ActiveModel {
+        id: ActiveValue::Set(Uuid::new_v4()),
+        script_id: ActiveValue::Set(previous.id),
+        tenant_id: ActiveValue::Set(previous.tenant_id),
+        version: ActiveValue::Set(previous.version),
+        code: ActiveValue::Set(previous.code.clone()),
+        status: ActiveValue::Set(previous.status.clone()),
+        user_id: ActiveValue::Set(user_id),
+        created_at: ActiveValue::Set(Utc::now()),
+    }
+}
+
+impl SeaOrmStorage {
+    /// Earlier versions of the script, newest first.
+    pub async fn versions(&self, script_id: ScriptId) -> ScriptResult<Vec<ScriptVersion>> {
+        let mut query = Entity::find().filter(Column::ScriptId.eq(script_id));
+        if let Some(tid) = self.tenant_id {
+            query = query.filter(Column::TenantId.eq(tid));
+        }
+        let models = query
+            .order_by_desc(Column::Version)
+            .all(&self.db)
+            .await
+            .map_err(|err| ScriptError::Storage(err.to_string()))?;
+
+        models.into_iter().map(ScriptVersion::from_model).collect()
+    }
+
+    /// Restores the snapshot of `version`; the replaced source is itself kept as a version.
+    pub async fn restore_version(
+        &self,
+        script_id: ScriptId,
+        version: u32,
+        user_id: Option<String>,
+    ) -> ScriptResult<Script> {
+        let mut script = self.get(script_id).await?;
+        let snapshot = self
+            .versions(script_id)
+            .await?
+            .into_iter()
+            .find(|snapshot| snapshot.version == version)
+            .ok_or_else(|| ScriptError::NotFound {
+                name: format!("{} v{version}", script.name),
+            })?;
+
+        snapshot.restore(&mut script);
+        self.update(script, user_id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::Database;
+    use sea_orm_migration::{MigrationTrait, SchemaManager};
+
+    use super::*;
+    use crate::model::ScriptTrigger;
+    use crate::ScriptVersionsMigration;
+
+    async fn storage() -> SeaOrmStorage {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        let manager = SchemaManager::new(&db);
+        for migration in crate::migrations::migrations() {
+            migration.up(&manager).await.unwrap();
+        }
+        SeaOrmStorage::new(db)
+    }
+
+    #[tokio::test]
+    async fn test_versions_migration_up_and_down() {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        let manager = SchemaManager::new(&db);
+
+        ScriptVersionsMigration.up(&manager).await.unwrap();
+        assert!(manager.has_table("script_versions").await.unwrap());
+
+        ScriptVersionsMigration.down(&manager).await.unwrap();
+        assert!(!manager.has_table("script_versions").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_each_edit_snapshots_previous_source() {
+        let storage = storage().await;
+        let mut script = Script::new("validate_deal", "true", ScriptTrigger::Manual);
+        script.activate();
+        let mut script = storage.save(script).await.unwrap();
+
+        script.code = "amount > 0".to_string();
+        let mut script = storage
+            .update(script, Some("alice".to_string()))
+            .await
+            .unwrap();
+        script.code = r#"abort("all deals rejected")"#.to_string();
+        let script = storage
+            .update(script, Some("bob".to_string()))
+            .await
+            .unwrap();
+
+        let versions = storage.versions(script.id).await.unwrap();
+        assert_eq!(versions.len(), 2);
+        assert_eq!(versions[0].code, "amount > 0");
+        assert_eq!(versions[0].user_id.as_deref(), Some("bob"));
+        assert_eq!(versions[1].code, "true");
+        assert_eq!(versions[1].status, ScriptStatus::Active);
+        assert_eq!(versions[1].user_id.as_deref(), Some("alice"));
+
+        let restored = storage
+            .restore_version(script.id, versions[0].version, Some("carol".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(restored.code, "amount > 0");
+        assert_eq!(
+            storage.versions(script.id).await.unwrap()[0].code,
+            r#"abort("all deals rejected")"#
+        );
+    }
+}
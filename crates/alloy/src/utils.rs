@@ -9,6 +9,13 @@ pub fn validate_cron_expression(expression: &str) -> Result<(), String> {
         .map_err(|err| err.to_string())
 }
 
+pub fn validate_timezone(timezone: &str) -> Result<(), String> {
+    timezone
+        .parse::<chrono_tz::Tz>()
+        .map(|_| ())
+        .map_err(|_| format!("unknown timezone: {timezone}"))
+}
+
 pub fn json_to_dynamic(v: serde_json::Value) -> Dynamic {
     match v {
         serde_json::Value::Null => Dynamic::UNIT,
@@ -10,6 +10,10 @@ pub enum FormError {
 
     #[error("Submit error: {0}")]
     Submit(String),
+
+    /// Server rejected the submit because the record changed since it was loaded (HTTP 409).
+    #[error("Version conflict: server has version {server_version}")]
+    Conflict { server_version: i32 },
 }
 
 impl FormError {
@@ -27,4 +31,8 @@ impl FormError {
     pub fn submit(msg: impl Into<String>) -> Self {
         Self::Submit(msg.into())
     }
+
+    pub fn conflict(server_version: i32) -> Self {
+        Self::Conflict { server_version }
+    }
 }
@@ -1,6 +1,8 @@
+use crate::error::FormError;
 use crate::validator::Validator;
 use leptos::prelude::*;
 use std::collections::HashMap;
+use std::future::Future;
 
 #[derive(Clone)]
 pub struct FormContext {
@@ -9,6 +11,7 @@ pub struct FormContext {
     field_errors: RwSignal<HashMap<String, String>>,
     form_error: RwSignal<Option<String>>,
     is_submitting: RwSignal<bool>,
+    conflict: RwSignal<Option<i32>>,
 }
 
 impl FormContext {
@@ -19,6 +22,7 @@ impl FormContext {
             field_errors: RwSignal::new(HashMap::new()),
             form_error: RwSignal::new(None),
             is_submitting: RwSignal::new(false),
+            conflict: RwSignal::new(None),
         }
     }
 
@@ -96,6 +100,13 @@ impl FormContext {
         self.field_errors.with(|errors| errors.get(name).cloned())
     }
 
+    /// Reactive view of a field's error, for the `error` prop of `iu_leptos::Input`.
+    pub fn field_error(&self, name: impl Into<String>) -> Signal<Option<String>> {
+        let form = self.clone();
+        let name = name.into();
+        Signal::derive(move || form.get_field_error(&name))
+    }
+
     pub fn set_form_error(&self, error: Option<String>) {
         self.form_error.set(error);
     }
@@ -112,6 +123,47 @@ impl FormContext {
         self.is_submitting.set(submitting);
     }
 
+    /// Server version of the record when the last submit hit an optimistic-locking
+    /// conflict; the UI uses it to offer "reload latest".
+    pub fn conflict(&self) -> ReadSignal<Option<i32>> {
+        self.conflict.read_only()
+    }
+
+    pub fn set_conflict(&self, server_version: Option<i32>) {
+        self.conflict.set(server_version);
+    }
+
+    /// Validates the form and runs `handler` with the current values.
+    ///
+    /// `FormError::Conflict` from the handler is routed into [`Self::conflict`],
+    /// any other error into the form-level error.
+    pub async fn submit<F, Fut>(&self, handler: F) -> Result<(), FormError>
+    where
+        F: FnOnce(HashMap<String, String>) -> Fut,
+        Fut: Future<Output = Result<(), FormError>>,
+    {
+        if let Err(errors) = self.validate_all() {
+            return Err(FormError::validation(errors.join(", ")));
+        }
+
+        self.form_error.set(None);
+        self.conflict.set(None);
+        self.is_submitting.set(true);
+
+        let result = handler(self.fields.get_untracked()).await;
+
+        self.is_submitting.set(false);
+        match &result {
+            Err(FormError::Conflict { server_version }) => {
+                self.conflict.set(Some(*server_version));
+            }
+            Err(err) => self.form_error.set(Some(err.to_string())),
+            Ok(()) => {}
+        }
+
+        result
+    }
+
     pub fn reset(&self) {
         self.fields.update(|fields| {
             for value in fields.values_mut() {
@@ -121,6 +173,7 @@ impl FormContext {
         self.field_errors.update(|errors| errors.clear());
         self.form_error.set(None);
         self.is_submitting.set(false);
+        self.conflict.set(None);
     }
 }
 
@@ -129,3 +182,58 @@ impl Default for FormContext {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::pin::pin;
+    use std::task::{Context, Poll, Waker};
+
+    fn run_ready<T>(future: impl Future<Output = T>) -> T {
+        match pin!(future).poll(&mut Context::from_waker(Waker::noop())) {
+            Poll::Ready(value) => value,
+            Poll::Pending => panic!("submit handler should complete immediately"),
+        }
+    }
+
+    #[test]
+    fn conflicting_submit_sets_conflict_with_server_version() {
+        let form = FormContext::new();
+        form.set_value("title", "Draft".to_string());
+
+        let result = run_ready(form.submit(|values| async move {
+            assert_eq!(values.get("title").map(String::as_str), Some("Draft"));
+            Err(FormError::conflict(7))
+        }));
+
+        assert_eq!(result, Err(FormError::Conflict { server_version: 7 }));
+        assert_eq!(form.conflict().get_untracked(), Some(7));
+        assert_eq!(form.form_error.get_untracked(), None);
+        assert!(!form.is_submitting.get_untracked());
+    }
+
+    #[test]
+    fn successful_submit_clears_previous_conflict() {
+        let form = FormContext::new();
+        form.set_conflict(Some(3));
+
+        let result = run_ready(form.submit(|_| async { Ok(()) }));
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(form.conflict().get_untracked(), None);
+    }
+
+    #[test]
+    fn field_error_signal_follows_validation() {
+        let form = FormContext::new();
+        form.set_validator("email", Validator::new().required());
+        let error = form.field_error("email");
+
+        assert!(form.validate_field("email").is_err());
+        assert!(error.get_untracked().is_some());
+
+        form.set_value("email", "user@example.com".to_string());
+        assert!(form.validate_field("email").is_ok());
+        assert_eq!(error.get_untracked(), None);
+    }
+}
@@ -0,0 +1,424 @@
+// Client-side кэш результатов GraphQL queries
+// Ограничен по числу записей: при переполнении вытесняется least-recently-used запись,
+// следующий доступ к ней снова идёт в сеть. Записи старше TTL считаются промахом.
+
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::future::Future;
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+use web_time::Instant;
+
+pub const DEFAULT_QUERY_CACHE_CAPACITY: usize = 128;
+pub const DEFAULT_QUERY_CACHE_TTL: Duration = Duration::from_secs(5 * 60);
+
+struct CacheEntry {
+    value: Value,
+    last_used: u64,
+    expires_at: Instant,
+}
+
+struct LruEntries {
+    capacity: usize,
+    ttl: Duration,
+    clock: u64,
+    entries: HashMap<String, CacheEntry>,
+}
+
+impl LruEntries {
+    fn remove_expired(&mut self) {
+        let now = Instant::now();
+        self.entries.retain(|_, entry| entry.expires_at > now);
+    }
+
+    fn tick(&mut self) -> u64 {
+        self.clock += 1;
+        self.clock
+    }
+
+    fn evict_least_recently_used(&mut self) {
+        let oldest = self
+            .entries
+            .iter()
+            .min_by_key(|(_, entry)| entry.last_used)
+            .map(|(key, _)| key.clone());
+
+        if let Some(key) = oldest {
+            self.entries.remove(&key);
+        }
+    }
+}
+
+/// LRU-кэш данных GraphQL queries, разделяемый hooks через Leptos context.
+///
+/// Подключается через [`provide_query_cache`]; без него `use_query` работает без кэша.
+#[derive(Clone)]
+pub struct QueryCache {
+    inner: Arc<Mutex<LruEntries>>,
+}
+
+impl Default for QueryCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_QUERY_CACHE_CAPACITY)
+    }
+}
+
+impl QueryCache {
+    /// Создаёт кэш, хранящий не более `capacity` записей (минимум одну)
+    /// в течение [`DEFAULT_QUERY_CACHE_TTL`].
+    pub fn new(capacity: usize) -> Self {
+        Self::with_ttl(capacity, DEFAULT_QUERY_CACHE_TTL)
+    }
+
+    /// Как [`QueryCache::new`], но записи живут `ttl` с момента сохранения.
+    pub fn with_ttl(capacity: usize, ttl: Duration) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(LruEntries {
+                capacity: capacity.max(1),
+                ttl,
+                clock: 0,
+                entries: HashMap::new(),
+            })),
+        }
+    }
+
+    /// Ключ записи: query, variables, tenant, locale и сессия — всё, что влияет на ответ сервера.
+    ///
+    /// `session` — bearer token запроса. В ключ попадает только его отпечаток, поэтому два
+    /// пользователя одного tenant не получают закэшированные ответы друг друга.
+    pub fn key<V: Serialize>(
+        query: &str,
+        variables: Option<&V>,
+        tenant: Option<&str>,
+        locale: Option<&str>,
+        session: Option<&str>,
+    ) -> String {
+        let variables = variables
+            .and_then(|variables| serde_json::to_string(variables).ok())
+            .unwrap_or_default();
+
+        format!(
+            "{}{}\u{1f}{}",
+            Self::query_prefix(query, tenant, session),
+            locale.unwrap_or_default(),
+            variables
+        )
+    }
+
+    /// Общее начало ключей `query` при любых locale и variables — для
+    /// [`QueryCache::invalidate_prefix`] после mutation.
+    pub fn query_prefix(query: &str, tenant: Option<&str>, session: Option<&str>) -> String {
+        let session = session
+            .map(|token| {
+                let mut hasher = DefaultHasher::new();
+                token.hash(&mut hasher);
+                format!("{:016x}", hasher.finish())
+            })
+            .unwrap_or_default();
+
+        format!(
+            "{}\u{1f}{}\u{1f}{}\u{1f}",
+            tenant.unwrap_or_default(),
+            session,
+            query
+        )
+    }
+
+    fn lock(&self) -> MutexGuard<'_, LruEntries> {
+        self.inner
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Возвращает закэшированные данные и помечает запись как недавно использованную;
+    /// просроченная запись удаляется и считается промахом.
+    pub fn get(&self, key: &str) -> Option<Value> {
+        let mut entries = self.lock();
+        let now = entries.tick();
+        match entries.entries.get_mut(key) {
+            Some(entry) if entry.expires_at > Instant::now() => {
+                entry.last_used = now;
+                Some(entry.value.clone())
+            }
+            Some(_) => {
+                entries.entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Сохраняет данные; при превышении лимита вытесняет least-recently-used запись.
+    pub fn insert(&self, key: impl Into<String>, value: Value) {
+        let mut entries = self.lock();
+        let last_used = entries.tick();
+        let expires_at = Instant::now() + entries.ttl;
+        entries.entries.insert(
+            key.into(),
+            CacheEntry {
+                value,
+                last_used,
+                expires_at,
+            },
+        );
+        entries.remove_expired();
+
+        while entries.entries.len() > entries.capacity {
+            entries.evict_least_recently_used();
+        }
+    }
+
+    /// Отдаёт запись `key`, а при промахе (или `force`) вызывает `fetch` и кэширует успешный
+    /// ответ. На этом построен `use_query`; ошибки не кэшируются.
+    pub async fn get_or_fetch<E, F, Fut>(
+        &self,
+        key: String,
+        force: bool,
+        fetch: F,
+    ) -> Result<Value, E>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<Value, E>>,
+    {
+        if !force {
+            if let Some(value) = self.get(&key) {
+                return Ok(value);
+            }
+        }
+
+        let value = fetch().await?;
+        self.insert(key, value.clone());
+        Ok(value)
+    }
+
+    pub fn invalidate(&self, key: &str) {
+        self.lock().entries.remove(key);
+    }
+
+    /// Удаляет все записи, ключ которых начинается с `prefix` (см. [`QueryCache::query_prefix`]).
+    pub fn invalidate_prefix(&self, prefix: &str) {
+        self.lock()
+            .entries
+            .retain(|key, _| !key.starts_with(prefix));
+    }
+
+    pub fn clear(&self) {
+        self.lock().entries.clear();
+    }
+
+    pub fn contains(&self, key: &str) -> bool {
+        self.lock()
+            .entries
+            .get(key)
+            .is_some_and(|entry| entry.expires_at > Instant::now())
+    }
+
+    pub fn len(&self) -> usize {
+        let mut entries = self.lock();
+        entries.remove_expired();
+        entries.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.lock().capacity
+    }
+}
+
+/// Регистрирует [`QueryCache`] с заданным лимитом записей в текущем Leptos context.
+pub fn provide_query_cache(capacity: usize) -> QueryCache {
+    let cache = QueryCache::new(capacity);
+    leptos::prelude::provide_context(cache.clone());
+    cache
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    /// Запрос через [`QueryCache::get_or_fetch`]; каждый поход в "сеть" пишется в `fetches`.
+    async fn access(cache: &QueryCache, key: &str, fetches: &mut Vec<String>) -> Value {
+        cache
+            .get_or_fetch(key.to_string(), false, || {
+                fetches.push(key.to_string());
+                async move { Ok::<_, ()>(json!({ "key": key })) }
+            })
+            .await
+            .expect("fetch succeeds")
+    }
+
+    #[test]
+    fn key_depends_on_variables_tenant_and_locale() {
+        let query = "query Users($first: Int) { users(first: $first) { id } }";
+        let base = QueryCache::key(
+            query,
+            Some(&json!({ "first": 10 })),
+            Some("acme"),
+            None,
+            None,
+        );
+
+        assert_ne!(
+            base,
+            QueryCache::key(
+                query,
+                Some(&json!({ "first": 20 })),
+                Some("acme"),
+                None,
+                None
+            )
+        );
+        assert_ne!(
+            base,
+            QueryCache::key(
+                query,
+                Some(&json!({ "first": 10 })),
+                Some("other"),
+                None,
+                None
+            )
+        );
+        assert_ne!(
+            base,
+            QueryCache::key(
+                query,
+                Some(&json!({ "first": 10 })),
+                Some("acme"),
+                Some("ru"),
+                None
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn users_of_one_tenant_do_not_share_entries() {
+        let query = "query Users { users { id } }";
+        let cache = QueryCache::new(8);
+        let mut fetches = Vec::new();
+        let alice = QueryCache::key::<Value>(query, None, Some("acme"), None, Some("alice-token"));
+        let bob = QueryCache::key::<Value>(query, None, Some("acme"), None, Some("bob-token"));
+
+        access(&cache, &alice, &mut fetches).await;
+        access(&cache, &bob, &mut fetches).await;
+        access(&cache, &alice, &mut fetches).await;
+
+        assert_ne!(alice, bob);
+        assert!(!alice.contains("alice-token"));
+        assert_eq!(fetches, vec![alice, bob]);
+    }
+
+    #[tokio::test]
+    async fn exceeding_capacity_evicts_least_recently_used_entry() {
+        let cache = QueryCache::new(2);
+        let mut fetches = Vec::new();
+
+        access(&cache, "a", &mut fetches).await;
+        access(&cache, "b", &mut fetches).await;
+        access(&cache, "c", &mut fetches).await;
+
+        assert_eq!(cache.len(), 2);
+        assert!(!cache.contains("a"));
+        assert!(cache.contains("b"));
+        assert!(cache.contains("c"));
+    }
+
+    #[tokio::test]
+    async fn recently_read_entry_survives_eviction() {
+        let cache = QueryCache::new(2);
+        let mut fetches = Vec::new();
+
+        access(&cache, "a", &mut fetches).await;
+        access(&cache, "b", &mut fetches).await;
+        access(&cache, "a", &mut fetches).await;
+        access(&cache, "c", &mut fetches).await;
+
+        assert!(cache.contains("a"));
+        assert!(!cache.contains("b"));
+        assert_eq!(fetches, vec!["a", "b", "c"]);
+    }
+
+    #[tokio::test]
+    async fn evicted_entry_is_refetched_on_next_access() {
+        let cache = QueryCache::new(2);
+        let mut fetches = Vec::new();
+
+        access(&cache, "a", &mut fetches).await;
+        access(&cache, "b", &mut fetches).await;
+        access(&cache, "c", &mut fetches).await;
+        let value = access(&cache, "a", &mut fetches).await;
+
+        assert_eq!(value, json!({ "key": "a" }));
+        assert_eq!(fetches, vec!["a", "b", "c", "a"]);
+        assert!(!cache.contains("b"));
+    }
+
+    #[tokio::test]
+    async fn forced_fetch_refreshes_entry_and_errors_are_not_cached() {
+        let cache = QueryCache::new(2);
+        cache.insert("a", json!({ "version": 1 }));
+
+        let refreshed = cache
+            .get_or_fetch("a".to_string(), true, || async {
+                Ok::<_, ()>(json!({ "version": 2 }))
+            })
+            .await;
+        let failed = cache
+            .get_or_fetch("b".to_string(), false, || async { Err("offline") })
+            .await;
+
+        assert_eq!(refreshed, Ok(json!({ "version": 2 })));
+        assert_eq!(cache.get("a"), Some(json!({ "version": 2 })));
+        assert_eq!(failed, Err("offline"));
+        assert!(!cache.contains("b"));
+    }
+
+    #[test]
+    fn expired_entries_are_misses() {
+        let cache = QueryCache::with_ttl(8, Duration::from_millis(20));
+        cache.insert("a", json!(1));
+        assert_eq!(cache.get("a"), Some(json!(1)));
+
+        std::thread::sleep(Duration::from_millis(40));
+
+        assert!(!cache.contains("a"));
+        assert_eq!(cache.get("a"), None);
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn prefix_invalidation_drops_every_page_of_a_query() {
+        let users = "query Users($first: Int) { users(first: $first) { id } }";
+        let roles = "query Roles { roles { id } }";
+        let cache = QueryCache::new(8);
+        let page = |first: i64, locale: &str| {
+            QueryCache::key(
+                users,
+                Some(&json!({ "first": first })),
+                Some("acme"),
+                Some(locale),
+                Some("token"),
+            )
+        };
+        let roles_key = QueryCache::key::<Value>(roles, None, Some("acme"), None, Some("token"));
+        cache.insert(page(10, "en"), json!({}));
+        cache.insert(page(20, "ru"), json!({}));
+        cache.insert(roles_key.clone(), json!({}));
+
+        cache.invalidate_prefix(&QueryCache::query_prefix(
+            users,
+            Some("acme"),
+            Some("token"),
+        ));
+
+        assert!(!cache.contains(&page(10, "en")));
+        assert!(!cache.contains(&page(20, "ru")));
+        assert!(cache.contains(&roles_key));
+    }
+}
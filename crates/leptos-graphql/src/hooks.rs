@@ -8,7 +8,7 @@ use serde::{de::DeserializeOwned, Serialize};
 use serde_json::Value;
 use std::sync::Arc;
 
-use crate::{execute, GraphqlHttpError, GraphqlRequest};
+use crate::{execute, GraphqlHttpError, GraphqlRequest, QueryCache};
 
 fn get_locale() -> Option<String> {
     use_context::<UiRouteContext>()
@@ -34,6 +34,9 @@ impl<T> QueryResult<T> {
 
 /// Hook для выполнения GraphQL query с reactive state
 ///
+/// Если в context есть [`QueryCache`] (см. [`crate::provide_query_cache`]), повторный
+/// mount отдаёт закэшированные данные без запроса; `refetch()` всегда идёт в сеть.
+///
 /// # Example
 /// ```rust
 /// use leptos_graphql::use_query;
@@ -73,9 +76,35 @@ where
     let (loading, set_loading) = signal(true);
     let (refetch_trigger, set_refetch_trigger) = signal(0u32);
 
+    let cache = use_context::<QueryCache>();
+
     Effect::new(move |_| {
         // Trigger refetch when refetch_trigger changes
-        let _ = refetch_trigger.get();
+        let force_refetch = refetch_trigger.get() > 0;
+        let locale = get_locale();
+        let cache_key = cache.as_ref().map(|_| {
+            QueryCache::key(
+                &query,
+                variables.as_ref(),
+                tenant.as_deref(),
+                locale.as_deref(),
+                token.as_deref(),
+            )
+        });
+
+        if !force_refetch {
+            let cached = cache
+                .as_ref()
+                .zip(cache_key.as_deref())
+                .and_then(|(cache, key)| cache.get(key))
+                .and_then(|value| serde_json::from_value::<T>(value).ok());
+            if let Some(cached) = cached {
+                set_data.set(Some(cached));
+                set_error.set(None);
+                set_loading.set(false);
+                return;
+            }
+        }
 
         set_loading.set(true);
         set_error.set(None);
@@ -85,11 +114,22 @@ where
         let variables = variables.clone();
         let token = token.clone();
         let tenant = tenant.clone();
+        let cache = cache.clone();
 
         spawn_local(async move {
             let request = GraphqlRequest::new(query, variables);
+            let endpoint = &endpoint;
+            let fetch = move || execute::<V, Value>(endpoint, request, token, tenant, locale);
 
-            match execute::<V, T>(&endpoint, request, token, tenant, get_locale()).await {
+            let result = match cache.as_ref().zip(cache_key) {
+                Some((cache, key)) => cache.get_or_fetch(key, force_refetch, fetch).await,
+                None => fetch().await,
+            }
+            .and_then(|value| {
+                serde_json::from_value::<T>(value).map_err(|_| GraphqlHttpError::Network)
+            });
+
+            match result {
                 Ok(response) => {
                     set_data.set(Some(response));
                     set_loading.set(false);
@@ -1,9 +1,13 @@
+pub mod cache;
 pub mod hooks;
 
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use serde_json::Value;
 use std::str::FromStr;
 
+pub use cache::{
+    provide_query_cache, QueryCache, DEFAULT_QUERY_CACHE_CAPACITY, DEFAULT_QUERY_CACHE_TTL,
+};
 pub use hooks::{use_lazy_query, use_mutation, use_query, MutationResult, QueryResult};
 
 pub const GRAPHQL_ENDPOINT: &str = "/api/graphql";
@@ -1,4 +1,4 @@
-use leptos::children::Children;
+use leptos::children::{Children, ViewFn};
 use leptos::prelude::*;
 
 #[component]
@@ -72,3 +72,138 @@ pub fn CardFooter(#[prop(optional, into)] class: String, children: Children) ->
         </div>
     }
 }
+
+/// Which slot an [`AsyncCard`] renders.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CardState {
+    Loading,
+    Error(String),
+    Empty,
+    Ready,
+}
+
+impl CardState {
+    /// `None` is still loading; an `Ok` value for which `is_empty` holds is `Empty`.
+    pub fn from_result<T, E: std::fmt::Display>(
+        result: Option<&Result<T, E>>,
+        is_empty: impl FnOnce(&T) -> bool,
+    ) -> Self {
+        match result {
+            None => Self::Loading,
+            Some(Err(error)) => Self::Error(error.to_string()),
+            Some(Ok(value)) if is_empty(value) => Self::Empty,
+            Some(Ok(_)) => Self::Ready,
+        }
+    }
+}
+
+/// A [`Card`] that renders exactly one of its `loading`, `error`, `empty` or `content` slots
+/// for the current `state`. The first three fall back to a skeleton, the error message and
+/// `empty_text` when not given.
+#[component]
+pub fn AsyncCard(
+    #[prop(into)] state: Signal<CardState>,
+    #[prop(into)] content: ViewFn,
+    #[prop(optional, into)] loading: Option<ViewFn>,
+    #[prop(optional, into)] error: Option<Callback<String, AnyView>>,
+    #[prop(optional, into)] empty: Option<ViewFn>,
+    #[prop(optional, into)] empty_text: String,
+    #[prop(optional, into)] title: String,
+    #[prop(optional, into)] class: String,
+) -> impl IntoView {
+    let header = (!title.is_empty()).then(|| {
+        view! {
+            <CardHeader>
+                <CardTitle>{title}</CardTitle>
+            </CardHeader>
+        }
+    });
+
+    let body = move || match state.get() {
+        CardState::Loading => match &loading {
+            Some(loading) => loading.run(),
+            None => view! { <div class="h-24 animate-pulse rounded-lg bg-muted"></div> }.into_any(),
+        },
+        CardState::Error(message) => match error {
+            Some(error) => error.run(message),
+            None => view! { <p class="text-sm text-destructive">{message}</p> }.into_any(),
+        },
+        CardState::Empty => match &empty {
+            Some(empty) => empty.run(),
+            None => view! { <p class="text-sm text-muted-foreground">{empty_text.clone()}</p> }
+                .into_any(),
+        },
+        CardState::Ready => content.run(),
+    };
+
+    view! {
+        <Card class=class>
+            {header}
+            <CardContent>{body}</CardContent>
+        </Card>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SLOTS: [&str; 4] = ["slot-loading", "slot-error", "slot-empty", "slot-content"];
+
+    fn render(state: CardState) -> String {
+        Owner::new().with(|| {
+            view! {
+                <AsyncCard
+                    state=Signal::derive(move || state.clone())
+                    loading=|| view! { <span>"slot-loading"</span> }
+                    error=|message: String| view! { <span>"slot-error: " {message}</span> }.into_any()
+                    empty=|| view! { <span>"slot-empty"</span> }
+                    content=|| view! { <span>"slot-content"</span> }
+                />
+            }
+            .to_html()
+        })
+    }
+
+    #[test]
+    fn each_state_renders_only_its_slot() {
+        let cases = [
+            (CardState::Loading, "slot-loading"),
+            (CardState::Error("boom".to_string()), "slot-error"),
+            (CardState::Empty, "slot-empty"),
+            (CardState::Ready, "slot-content"),
+        ];
+
+        for (state, expected) in cases {
+            let html = render(state);
+            for slot in SLOTS {
+                assert_eq!(html.contains(slot), slot == expected, "{slot} in {html}");
+            }
+        }
+        assert!(render(CardState::Error("boom".to_string())).contains("boom"));
+    }
+
+    #[test]
+    fn from_result_maps_resource_states() {
+        let loaded: Result<Vec<u8>, String> = Ok(vec![1]);
+        let empty: Result<Vec<u8>, String> = Ok(Vec::new());
+        let failed: Result<Vec<u8>, String> = Err("offline".to_string());
+
+        assert_eq!(
+            CardState::from_result::<Vec<u8>, String>(None, Vec::is_empty),
+            CardState::Loading
+        );
+        assert_eq!(
+            CardState::from_result(Some(&loaded), Vec::is_empty),
+            CardState::Ready
+        );
+        assert_eq!(
+            CardState::from_result(Some(&empty), Vec::is_empty),
+            CardState::Empty
+        );
+        assert_eq!(
+            CardState::from_result(Some(&failed), Vec::is_empty),
+            CardState::Error("offline".to_string())
+        );
+    }
+}
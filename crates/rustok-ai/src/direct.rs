@@ -235,7 +235,7 @@ impl DirectTaskHandler for AlloyScriptAssistHandler {
                             .map(|(key, value)| (key, dynamic_to_json(value)))
                             .collect::<serde_json::Map<String, Value>>(),
                     }),
-                    alloy::ExecutionOutcome::Aborted { reason } => json!({
+                    alloy::ExecutionOutcome::Aborted { reason, .. } => json!({
                         "operation": input.operation.slug(),
                         "script_id": script.id,
                         "script_name": script.name,
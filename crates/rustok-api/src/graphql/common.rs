@@ -136,3 +136,32 @@ pub fn resolve_graphql_locale(ctx: &Context<'_>, requested: Option<&str>) -> Str
         })
         .unwrap_or_else(|| PLATFORM_FALLBACK_LOCALE.to_string())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn cursor_round_trips(offset in any::<i64>()) {
+            prop_assert_eq!(decode_cursor(&encode_cursor(offset)), Some(offset));
+        }
+
+        #[test]
+        fn decoding_arbitrary_input_never_panics(
+            bytes in proptest::collection::vec(any::<u8>(), 0..64)
+        ) {
+            let input = String::from_utf8_lossy(&bytes);
+            if let Some(offset) = decode_cursor(&input) {
+                prop_assert_eq!(decode_cursor(&encode_cursor(offset)), Some(offset));
+            }
+        }
+
+        #[test]
+        fn non_numeric_payload_is_rejected(payload in "[a-zA-Z_ ]{1,32}") {
+            use base64::{engine::general_purpose::STANDARD, Engine};
+            prop_assert_eq!(decode_cursor(&STANDARD.encode(payload)), None);
+        }
+    }
+}
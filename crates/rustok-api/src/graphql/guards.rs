@@ -0,0 +1,115 @@
+use async_graphql::{Context, FieldError, Result};
+use rustok_core::{permissions::Action, Permission, Resource};
+
+use super::GraphQLError;
+use crate::context::{has_effective_permission, AuthContext};
+
+/// Resolves the caller's [`AuthContext`] and checks that it grants `action` on `resource`;
+/// `Manage` on the resource grants every action. Fails with `UNAUTHENTICATED` or
+/// `PERMISSION_DENIED`.
+pub fn require_permission(
+    ctx: &Context<'_>,
+    resource: Resource,
+    action: Action,
+) -> Result<AuthContext> {
+    let auth = ctx
+        .data::<AuthContext>()
+        .map_err(|_| <FieldError as GraphQLError>::unauthenticated())?;
+
+    let required = Permission::new(resource, action);
+    if !has_effective_permission(&auth.permissions, &required) {
+        return Err(<FieldError as GraphQLError>::permission_denied(&format!(
+            "Permission denied: {required} required"
+        )));
+    }
+
+    Ok(auth.clone())
+}
+
+/// `require_permission!(ctx, Resource::Scripts, Action::Manage)?` — shorthand for
+/// [`require_permission`](crate::graphql::require_permission).
+#[macro_export]
+macro_rules! require_permission {
+    ($ctx:expr, $resource:expr, $action:expr) => {
+        $crate::graphql::require_permission($ctx, $resource, $action)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use async_graphql::{EmptyMutation, EmptySubscription, Object, Request, Schema, Value};
+    use uuid::Uuid;
+
+    use super::*;
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn publish_page(&self, ctx: &Context<'_>) -> Result<bool> {
+            crate::require_permission!(ctx, Resource::Pages, Action::Publish)?;
+            Ok(true)
+        }
+    }
+
+    fn auth(permissions: Vec<Permission>) -> AuthContext {
+        AuthContext {
+            user_id: Uuid::new_v4(),
+            session_id: Uuid::new_v4(),
+            tenant_id: Uuid::new_v4(),
+            permissions,
+            client_id: None,
+            scopes: vec![],
+            grant_type: "direct".to_string(),
+        }
+    }
+
+    async fn run(auth: Option<AuthContext>) -> async_graphql::Response {
+        let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+        let request = Request::new("{ publishPage }");
+        schema
+            .execute(match auth {
+                Some(auth) => request.data(auth),
+                None => request,
+            })
+            .await
+    }
+
+    fn error_code(response: &async_graphql::Response) -> Option<Value> {
+        response.errors[0]
+            .extensions
+            .as_ref()
+            .and_then(|extensions| extensions.get("code"))
+            .cloned()
+    }
+
+    #[tokio::test]
+    async fn permitted_action_is_allowed() {
+        let publish = Permission::new(Resource::Pages, Action::Publish);
+        for permissions in [vec![publish], vec![Permission::PAGES_MANAGE]] {
+            let response = run(Some(auth(permissions))).await;
+            assert!(response.errors.is_empty(), "{:?}", response.errors);
+        }
+    }
+
+    #[tokio::test]
+    async fn unpermitted_action_is_denied() {
+        let response = run(Some(auth(vec![Permission::PAGES_READ]))).await;
+
+        assert_eq!(
+            error_code(&response),
+            Some(Value::from("PERMISSION_DENIED"))
+        );
+        assert_eq!(
+            response.errors[0].message,
+            "Permission denied: pages:publish required"
+        );
+    }
+
+    #[tokio::test]
+    async fn missing_auth_context_is_unauthenticated() {
+        let response = run(None).await;
+
+        assert_eq!(error_code(&response), Some(Value::from("UNAUTHENTICATED")));
+    }
+}
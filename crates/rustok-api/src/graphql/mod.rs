@@ -1,8 +1,10 @@
 mod common;
 mod errors;
+mod guards;
 
 pub use common::{
     decode_cursor, encode_cursor, require_module_enabled, resolve_graphql_locale, PageInfo,
     PaginationInput,
 };
 pub use errors::{ErrorCode, GraphQLError};
+pub use guards::require_permission;
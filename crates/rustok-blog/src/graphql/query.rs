@@ -4,6 +4,7 @@ use rustok_api::{
     AuthContext, RequestContext, TenantContext,
 };
 use rustok_channel::ChannelService;
+use rustok_content::SlugLookup;
 use rustok_core::SecurityContext;
 use rustok_outbox::TransactionalEventBus;
 use rustok_profiles::{
@@ -103,8 +104,8 @@ impl BlogQuery {
         let locale = resolve_graphql_locale(ctx, locale.as_deref());
 
         let service = PostService::new(db.clone(), event_bus.clone());
-        let post = service
-            .get_post_by_slug_with_locale_fallback(
+        let lookup = service
+            .get_post_by_slug_or_redirect_with_locale_fallback(
                 tenant_id,
                 auth_context_to_security(ctx),
                 &locale,
@@ -113,6 +114,22 @@ impl BlogQuery {
             )
             .await
             .map_err(|err| async_graphql::Error::new(err.to_string()))?;
+        // GraphQL has no 301: a renamed post is served from its current slug, which the client
+        // can read back from `slug` to update its URL.
+        let post = match lookup {
+            Some(SlugLookup::Found(post)) => Some(*post),
+            Some(SlugLookup::Redirect(target)) => service
+                .get_post_by_slug_with_locale_fallback(
+                    tenant_id,
+                    auth_context_to_security(ctx),
+                    &locale,
+                    &target.to_slug,
+                    Some(tenant.default_locale.as_str()),
+                )
+                .await
+                .map_err(|err| async_graphql::Error::new(err.to_string()))?,
+            None => None,
+        };
 
         if let Some(post) = post.filter(|post| {
             is_post_visible_for_request(
@@ -17,8 +17,9 @@ struct PostTranslationUpsertInput {
 }
 
 use rustok_content::{
-    available_locales_from, normalize_locale_code, resolve_by_locale_with_fallback,
-    PLATFORM_FALLBACK_LOCALE,
+    available_locales_from, find_slug_redirect_entry, normalize_locale_code, record_slug_change,
+    resolve_by_locale_with_fallback, SlugLookup, SlugRedirectTarget, PLATFORM_FALLBACK_LOCALE,
+    SLUG_REDIRECT_STATUS,
 };
 use rustok_core::{prepare_content_payload, Action, Resource, SecurityContext};
 use rustok_events::DomainEvent;
@@ -37,6 +38,10 @@ use crate::services::rbac::{
 use crate::services::tag::{find_post_ids_by_tag, load_post_tags_map, sync_post_tags_in_tx};
 use crate::state_machine::BlogPostStatus;
 
+const POST_SLUG_REDIRECT_KIND: &str = "blog_post";
+/// Post slugs are shared by every locale, so their redirects are recorded under this one.
+const POST_SLUG_REDIRECT_LOCALE: &str = "*";
+
 pub struct PostService {
     db: DatabaseConnection,
     event_bus: TransactionalEventBus,
@@ -263,6 +268,20 @@ impl PostService {
             }
             self.ensure_slug_unique_in_tx(&txn, tenant_id, &normalized, Some(post_id))
                 .await?;
+            if normalized != post.slug {
+                record_slug_change(
+                    &txn,
+                    tenant_id,
+                    POST_SLUG_REDIRECT_KIND,
+                    post_id,
+                    POST_SLUG_REDIRECT_LOCALE,
+                    Some(post.slug.as_str()),
+                    &normalized,
+                    now.into(),
+                )
+                .await
+                .map_err(BlogError::from)?;
+            }
             post_active.slug = Set(normalized);
         }
         if input.category_id.is_some() {
@@ -571,6 +590,67 @@ impl PostService {
         .map(Some)
     }
 
+    /// Like [`Self::get_post_by_slug_with_locale_fallback`], but answers a slug the post was
+    /// renamed away from with a redirect to its current slug.
+    #[instrument(skip(self))]
+    pub async fn get_post_by_slug_or_redirect_with_locale_fallback(
+        &self,
+        tenant_id: Uuid,
+        security: SecurityContext,
+        locale: &str,
+        slug: &str,
+        fallback_locale: Option<&str>,
+    ) -> BlogResult<Option<SlugLookup<PostResponse>>> {
+        if let Some(post) = self
+            .get_post_by_slug_with_locale_fallback(
+                tenant_id,
+                security.clone(),
+                locale,
+                slug,
+                fallback_locale,
+            )
+            .await?
+        {
+            return Ok(Some(SlugLookup::Found(Box::new(post))));
+        }
+
+        let slug = normalize_slug(slug);
+        let Some(redirect) = find_slug_redirect_entry(
+            &self.db,
+            tenant_id,
+            POST_SLUG_REDIRECT_KIND,
+            POST_SLUG_REDIRECT_LOCALE,
+            &slug,
+        )
+        .await
+        .map_err(BlogError::from)?
+        else {
+            return Ok(None);
+        };
+        let Some(post) = blog_post::Entity::find_by_id(redirect.node_id)
+            .filter(blog_post::Column::TenantId.eq(tenant_id))
+            .one(&self.db)
+            .await
+            .map_err(BlogError::from)?
+        else {
+            return Ok(None);
+        };
+        if post.slug == slug
+            || (storage_to_status(&post.status)? != BlogPostStatus::Published
+                && !can_read_non_public_posts(&security))
+        {
+            return Ok(None);
+        }
+
+        Ok(Some(SlugLookup::Redirect(SlugRedirectTarget {
+            node_id: post.id,
+            locale: normalize_locale(locale)?,
+            from_slug: slug,
+            to_slug: post.slug,
+            status: SLUG_REDIRECT_STATUS,
+        })))
+    }
+
     #[instrument(skip(self, security))]
     pub async fn list_posts(
         &self,
@@ -69,6 +69,12 @@ pub struct UpdateVariantInput {
         message = "Shipping profile slug must be max 100 characters"
     ))]
     pub shipping_profile_slug: Option<String>,
+    #[validate(length(max = 255, message = "Option value must be max 255 characters"))]
+    pub option1: Option<String>,
+    #[validate(length(max = 255, message = "Option value must be max 255 characters"))]
+    pub option2: Option<String>,
+    #[validate(length(max = 255, message = "Option value must be max 255 characters"))]
+    pub option3: Option<String>,
     #[validate(nested)]
     pub prices: Option<Vec<PriceInput>>,
     pub inventory_quantity: Option<i32>,
@@ -10,6 +10,7 @@ pub mod product_option;
 pub mod product_option_translation;
 pub mod product_option_value;
 pub mod product_option_value_translation;
+pub mod product_price_history;
 pub mod product_translation;
 pub mod product_variant;
 pub mod region;
@@ -34,6 +35,7 @@ pub use product_option::Entity as ProductOption;
 pub use product_option_translation::Entity as ProductOptionTranslation;
 pub use product_option_value::Entity as ProductOptionValue;
 pub use product_option_value_translation::Entity as ProductOptionValueTranslation;
+pub use product_price_history::Entity as ProductPriceHistory;
 pub use product_translation::Entity as ProductTranslation;
 pub use product_variant::Entity as ProductVariant;
 pub use region::Entity as Region;
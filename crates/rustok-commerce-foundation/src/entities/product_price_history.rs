@@ -0,0 +1,74 @@
+use rust_decimal::Decimal;
+use sea_orm::entity::prelude::*;
+use sea_orm::Set;
+use serde::{Deserialize, Serialize};
+
+/// One change of a stored variant price. Rows are kept after the variant is removed so
+/// reporting over past orders stays possible.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "product_price_history")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub tenant_id: Uuid,
+    pub product_id: Uuid,
+    pub variant_id: Uuid,
+    pub price_id: Uuid,
+    pub currency_code: String,
+    pub price_list_id: Option<Uuid>,
+    pub channel_id: Option<Uuid>,
+    pub channel_slug: Option<String>,
+    pub region_id: Option<Uuid>,
+    pub min_quantity: Option<i32>,
+    pub max_quantity: Option<i32>,
+    pub old_amount: Decimal,
+    pub new_amount: Decimal,
+    pub old_compare_at_amount: Option<Decimal>,
+    pub new_compare_at_amount: Option<Decimal>,
+    pub changed_by: Option<Uuid>,
+    pub changed_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl ActiveModel {
+    /// History row for `previous` being replaced by `current`, or `None` when neither the
+    /// amount nor the compare-at amount changed.
+    pub fn for_change(
+        tenant_id: Uuid,
+        product_id: Uuid,
+        changed_by: Option<Uuid>,
+        previous: &super::price::Model,
+        current: &super::price::Model,
+    ) -> Option<Self> {
+        if previous.amount == current.amount
+            && previous.compare_at_amount == current.compare_at_amount
+        {
+            return None;
+        }
+
+        Some(Self {
+            id: Set(rustok_core::generate_id()),
+            tenant_id: Set(tenant_id),
+            product_id: Set(product_id),
+            variant_id: Set(current.variant_id),
+            price_id: Set(current.id),
+            currency_code: Set(current.currency_code.clone()),
+            price_list_id: Set(current.price_list_id),
+            channel_id: Set(current.channel_id),
+            channel_slug: Set(current.channel_slug.clone()),
+            region_id: Set(current.region_id),
+            min_quantity: Set(current.min_quantity),
+            max_quantity: Set(current.max_quantity),
+            old_amount: Set(previous.amount),
+            new_amount: Set(current.amount),
+            old_compare_at_amount: Set(previous.compare_at_amount),
+            new_compare_at_amount: Set(current.compare_at_amount),
+            changed_by: Set(changed_by),
+            changed_at: Set(chrono::Utc::now().into()),
+        })
+    }
+}
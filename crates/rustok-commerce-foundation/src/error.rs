@@ -32,6 +32,9 @@ pub enum CommerceError {
     #[error("Invalid option combination")]
     InvalidOptionCombination,
 
+    #[error("Duplicate variant options: {0}")]
+    DuplicateVariantOptions(String),
+
     #[error("Validation error: {0}")]
     Validation(String),
 
@@ -113,6 +116,13 @@ impl From<CommerceError> for RichError {
                     .with_user_message("The selected product options are not available")
                     .with_error_code("INVALID_OPTIONS")
             }
+            CommerceError::DuplicateVariantOptions(options) => RichError::new(
+                ErrorKind::Conflict,
+                format!("A variant with options '{}' already exists", options),
+            )
+            .with_user_message("A variant with these options already exists")
+            .with_field("options", options)
+            .with_error_code("DUPLICATE_VARIANT_OPTIONS"),
             CommerceError::Validation(msg) => {
                 RichError::new(ErrorKind::Validation, msg).with_user_message("Invalid input data")
             }
@@ -25,8 +25,8 @@ pub use rustok_inventory::InventoryService;
 pub use rustok_order::OrderService;
 pub use rustok_payment::PaymentService;
 pub use rustok_pricing::{
-    PriceAdjustmentKind, PriceAdjustmentPreview, PriceResolutionContext, PricingService,
-    ResolvedPrice,
+    PriceAdjustmentKind, PriceAdjustmentPreview, PriceHistoryEntry, PriceResolutionContext,
+    PricingService, ResolvedPrice,
 };
 pub use rustok_product::CatalogService;
 pub use rustok_region::RegionService;
@@ -4,11 +4,12 @@
 
 use rust_decimal::Decimal;
 use rustok_commerce::dto::{
-    CreateProductInput, CreateVariantInput, PriceInput, ProductTranslationInput, UpdateProductInput,
+    CreateOrderInput, CreateOrderLineItemInput, CreateProductInput, CreateVariantInput, PriceInput,
+    ProductTranslationInput, UpdateProductInput, UpdateVariantInput,
 };
 use rustok_commerce::entities;
 use rustok_commerce::entities::product::ProductStatus;
-use rustok_commerce::services::CatalogService;
+use rustok_commerce::services::{CatalogService, OrderService};
 use rustok_commerce::CommerceError;
 use rustok_core::field_schema::FieldType;
 use rustok_test_utils::{db::setup_test_db, helpers::unique_slug, mock_transactional_event_bus};
@@ -1270,3 +1271,236 @@ async fn test_multiple_variants_different_prices() {
     assert!(cheap.is_some());
     assert!(premium.is_some());
 }
+
+// =============================================================================
+// Variant Management Tests
+// =============================================================================
+
+fn variant_input(option1: &str, option2: Option<&str>) -> CreateVariantInput {
+    CreateVariantInput {
+        sku: Some(format!(
+            "SKU-{}",
+            Uuid::new_v4().to_string().split('-').next().unwrap()
+        )),
+        barcode: None,
+        shipping_profile_slug: None,
+        option1: Some(option1.to_string()),
+        option2: option2.map(str::to_string),
+        option3: None,
+        prices: vec![PriceInput {
+            currency_code: "USD".to_string(),
+            channel_id: None,
+            channel_slug: None,
+            amount: Decimal::from_str("59.99").unwrap(),
+            compare_at_amount: None,
+        }],
+        inventory_quantity: 3,
+        inventory_policy: "deny".to_string(),
+        weight: None,
+        weight_unit: None,
+    }
+}
+
+#[tokio::test]
+async fn test_variants_with_identical_options_are_rejected() {
+    let (_db, service) = setup().await;
+    let tenant_id = Uuid::new_v4();
+    let actor_id = Uuid::new_v4();
+
+    let mut input = create_test_product_input();
+    input.variants = vec![
+        variant_input("Red", Some("M")),
+        variant_input("red", Some(" M ")),
+    ];
+    let result = service.create_product(tenant_id, actor_id, input).await;
+    assert!(matches!(
+        result,
+        Err(CommerceError::DuplicateVariantOptions(_))
+    ));
+
+    let product = service
+        .create_product(tenant_id, actor_id, create_test_product_input())
+        .await
+        .unwrap();
+    let result = service
+        .add_variant(
+            tenant_id,
+            actor_id,
+            product.id,
+            variant_input("Default", None),
+        )
+        .await;
+    assert!(matches!(
+        result,
+        Err(CommerceError::DuplicateVariantOptions(_))
+    ));
+
+    let product = service
+        .add_variant(
+            tenant_id,
+            actor_id,
+            product.id,
+            variant_input("Large", None),
+        )
+        .await
+        .unwrap();
+    assert_eq!(product.variants.len(), 2);
+    let large = product
+        .variants
+        .iter()
+        .find(|variant| variant.option1.as_deref() == Some("Large"))
+        .expect("added variant should be listed");
+    assert_eq!(large.position, 1);
+    assert_eq!(large.inventory_quantity, 3);
+}
+
+#[tokio::test]
+async fn test_update_and_delete_variant() {
+    let (_db, service) = setup().await;
+    let tenant_id = Uuid::new_v4();
+    let actor_id = Uuid::new_v4();
+
+    let product = service
+        .create_product(tenant_id, actor_id, create_test_product_input())
+        .await
+        .unwrap();
+    let default_id = product.variants[0].id;
+    let product = service
+        .add_variant(
+            tenant_id,
+            actor_id,
+            product.id,
+            variant_input("Large", None),
+        )
+        .await
+        .unwrap();
+    let large_id = product
+        .variants
+        .iter()
+        .find(|variant| variant.id != default_id)
+        .unwrap()
+        .id;
+
+    let update = UpdateVariantInput {
+        sku: None,
+        barcode: None,
+        shipping_profile_slug: None,
+        option1: Some("Default".to_string()),
+        option2: None,
+        option3: None,
+        prices: None,
+        inventory_quantity: None,
+        inventory_policy: None,
+        weight: None,
+        weight_unit: None,
+    };
+    let result = service
+        .update_variant(tenant_id, actor_id, large_id, update.clone())
+        .await;
+    assert!(matches!(
+        result,
+        Err(CommerceError::DuplicateVariantOptions(_))
+    ));
+
+    let product = service
+        .update_variant(
+            tenant_id,
+            actor_id,
+            large_id,
+            UpdateVariantInput {
+                option1: Some("XL".to_string()),
+                prices: Some(vec![PriceInput {
+                    currency_code: "USD".to_string(),
+                    channel_id: None,
+                    channel_slug: None,
+                    amount: Decimal::from_str("64.99").unwrap(),
+                    compare_at_amount: None,
+                }]),
+                ..update
+            },
+        )
+        .await
+        .unwrap();
+    let xl = product
+        .variants
+        .iter()
+        .find(|variant| variant.id == large_id)
+        .unwrap();
+    assert_eq!(xl.title, "XL");
+    assert_eq!(xl.prices.len(), 1);
+    assert_eq!(xl.prices[0].amount, Decimal::from_str("64.99").unwrap());
+
+    service
+        .delete_variant(tenant_id, actor_id, large_id)
+        .await
+        .unwrap();
+    let product = service.get_product(tenant_id, product.id).await.unwrap();
+    assert_eq!(product.variants.len(), 1);
+
+    let result = service
+        .delete_variant(tenant_id, actor_id, default_id)
+        .await;
+    assert!(matches!(result, Err(CommerceError::NoVariants)));
+}
+
+#[tokio::test]
+async fn test_order_line_item_references_variant() {
+    let (db, service) = setup().await;
+    let tenant_id = Uuid::new_v4();
+    let actor_id = Uuid::new_v4();
+
+    let product = service
+        .create_product(tenant_id, actor_id, create_test_product_input())
+        .await
+        .unwrap();
+    let product = service
+        .add_variant(
+            tenant_id,
+            actor_id,
+            product.id,
+            variant_input("Large", None),
+        )
+        .await
+        .unwrap();
+    let variant = product
+        .variants
+        .iter()
+        .find(|variant| variant.option1.as_deref() == Some("Large"))
+        .unwrap();
+
+    let orders = OrderService::new(db, mock_transactional_event_bus());
+    let order = orders
+        .create_order(
+            tenant_id,
+            actor_id,
+            CreateOrderInput {
+                customer_id: None,
+                currency_code: "USD".to_string(),
+                shipping_total: Decimal::ZERO,
+                line_items: vec![CreateOrderLineItemInput {
+                    product_id: Some(product.id),
+                    variant_id: Some(variant.id),
+                    shipping_profile_slug: "default".to_string(),
+                    seller_id: None,
+                    sku: variant.sku.clone(),
+                    title: variant.title.clone(),
+                    quantity: 2,
+                    unit_price: variant.prices[0].amount,
+                    metadata: serde_json::json!({}),
+                }],
+                adjustments: Vec::new(),
+                tax_lines: Vec::new(),
+                metadata: serde_json::json!({}),
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(order.line_items.len(), 1);
+    assert_eq!(order.line_items[0].variant_id, Some(variant.id));
+    assert_eq!(order.line_items[0].product_id, Some(product.id));
+    assert_eq!(
+        order.line_items[0].total_price,
+        Decimal::from_str("119.98").unwrap()
+    );
+}
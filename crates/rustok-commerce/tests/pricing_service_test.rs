@@ -3728,3 +3728,114 @@ async fn test_list_active_price_lists_filters_by_channel_scope() {
     assert!(mobile_lists.iter().any(|list| list.id == global_id));
     assert!(!mobile_lists.iter().any(|list| list.id == scoped_id));
 }
+
+// =============================================================================
+// Price History Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_price_history_records_each_update() {
+    let (_db, service, catalog) = setup().await;
+    let tenant_id = Uuid::new_v4();
+    let actor_id = Uuid::new_v4();
+    let (product_id, variant_id) = create_test_product(&catalog, tenant_id).await;
+
+    service
+        .set_price(tenant_id, actor_id, variant_id, "USD", dec!(89.99), None)
+        .await
+        .unwrap();
+    service
+        .set_price(tenant_id, actor_id, variant_id, "USD", dec!(89.99), None)
+        .await
+        .unwrap();
+    service
+        .set_price(
+            tenant_id,
+            actor_id,
+            variant_id,
+            "USD",
+            dec!(79.99),
+            Some(dec!(99.99)),
+        )
+        .await
+        .unwrap();
+
+    let history = service.price_history(tenant_id, product_id).await.unwrap();
+    assert_eq!(history.len(), 2, "unchanged writes are not recorded");
+    assert_eq!(history[0].variant_id, variant_id);
+    assert_eq!(history[0].old_amount, dec!(99.99));
+    assert_eq!(history[0].new_amount, dec!(89.99));
+    assert_eq!(history[1].old_amount, dec!(89.99));
+    assert_eq!(history[1].new_amount, dec!(79.99));
+    assert_eq!(history[1].new_compare_at_amount, Some(dec!(99.99)));
+    assert_eq!(history[1].changed_by, Some(actor_id));
+
+    assert!(service
+        .price_history(Uuid::new_v4(), product_id)
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn test_scheduled_price_change_resolves_by_timestamp() {
+    let (_db, service, catalog) = setup().await;
+    let tenant_id = Uuid::new_v4();
+    let actor_id = Uuid::new_v4();
+    let (_product_id, variant_id) = create_test_product(&catalog, tenant_id).await;
+    let now = chrono::Utc::now();
+    let effective_at = now + chrono::Duration::hours(1);
+    let context = rustok_commerce::services::PriceResolutionContext {
+        currency_code: "USD".to_string(),
+        region_id: None,
+        price_list_id: None,
+        channel_id: None,
+        channel_slug: None,
+        quantity: Some(1),
+    };
+
+    service
+        .schedule_price_change(
+            tenant_id,
+            actor_id,
+            variant_id,
+            "USD",
+            dec!(120.00),
+            None,
+            effective_at,
+        )
+        .await
+        .unwrap();
+
+    for (at, expected) in [
+        (now, dec!(99.99)),
+        (effective_at + chrono::Duration::minutes(1), dec!(120.00)),
+    ] {
+        assert_eq!(
+            service
+                .effective_price_at(tenant_id, variant_id, "USD", at)
+                .await
+                .unwrap(),
+            Some(expected)
+        );
+        let resolved = service
+            .resolve_variant_price_at(tenant_id, variant_id, context.clone(), at)
+            .await
+            .unwrap()
+            .expect("base price resolves");
+        assert_eq!(resolved.amount, expected);
+    }
+
+    let current = service
+        .resolve_variant_price(tenant_id, variant_id, context)
+        .await
+        .unwrap()
+        .expect("base price resolves");
+    assert_eq!(current.amount, dec!(99.99));
+    assert!(matches!(
+        service
+            .schedule_price_change(tenant_id, actor_id, variant_id, "USD", dec!(1), None, now)
+            .await,
+        Err(CommerceError::Validation(_))
+    ));
+}
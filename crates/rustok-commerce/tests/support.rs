@@ -6,9 +6,10 @@ use rustok_channel::entities::{channel, channel_module_binding};
 use rustok_commerce::entities::{
     inventory_item, inventory_level, price, price_list, price_list_translation, product,
     product_image, product_image_translation, product_option, product_option_translation,
-    product_option_value, product_option_value_translation, product_translation, product_variant,
-    region, region_country_tax_policy, region_translation, reservation_item, shipping_profile,
-    shipping_profile_translation, stock_location, stock_location_translation, variant_translation,
+    product_option_value, product_option_value_translation, product_price_history,
+    product_translation, product_variant, region, region_country_tax_policy, region_translation,
+    reservation_item, shipping_profile, shipping_profile_translation, stock_location,
+    stock_location_translation, variant_translation,
 };
 use rustok_customer::entities::customer;
 use rustok_fulfillment::entities::{
@@ -305,6 +306,12 @@ pub async fn ensure_commerce_schema(db: &DatabaseConnection) {
         schema.create_table_from_entity(price_list_translation::Entity),
     )
     .await;
+    create_entity_table(
+        db,
+        &builder,
+        schema.create_table_from_entity(product_price_history::Entity),
+    )
+    .await;
     ensure_field_definition_tables(db).await;
     create_entity_table(
         db,
@@ -62,6 +62,22 @@ pub struct CategoryListItem {
     pub created_at: DateTime<Utc>,
 }
 
+/// A category with its descendants, children ordered by `position`.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CategoryTreeNode {
+    pub id: Uuid,
+    pub locale: String,
+    pub effective_locale: String,
+    pub name: String,
+    pub slug: String,
+    pub parent_id: Option<Uuid>,
+    pub position: i32,
+    pub depth: u32,
+    pub settings: serde_json::Value,
+    #[schema(no_recursion)]
+    pub children: Vec<CategoryTreeNode>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default, ToSchema, IntoParams)]
 pub struct ListCategoriesFilter {
     pub locale: Option<String>,
@@ -5,8 +5,8 @@ pub mod validation;
 pub mod validation_helpers;
 
 pub use category::{
-    CategoryListItem, CategoryResponse, CreateCategoryInput, ListCategoriesFilter,
-    UpdateCategoryInput,
+    CategoryListItem, CategoryResponse, CategoryTreeNode, CreateCategoryInput,
+    ListCategoriesFilter, UpdateCategoryInput,
 };
 pub use node::*;
 pub use tag::{CreateTagInput, ListTagsFilter, TagListItem, TagResponse, UpdateTagInput};
@@ -157,3 +157,50 @@ pub struct NodeListItem {
     pub created_at: String,
     pub published_at: Option<String>,
 }
+
+/// Per-locale translation coverage of every node in a tenant.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct LocalizationReport {
+    /// Enabled tenant locales, default locale first.
+    pub locales: Vec<String>,
+    pub nodes: Vec<NodeLocalizationStatus>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct NodeLocalizationStatus {
+    pub node_id: Uuid,
+    pub kind: String,
+    pub locales: Vec<LocaleCompleteness>,
+    /// Enabled locales without a translation row.
+    pub missing_translations: Vec<String>,
+    /// Enabled locales without a non-empty body.
+    pub missing_bodies: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct LocaleCompleteness {
+    pub locale: String,
+    pub has_translation: bool,
+    pub has_body: bool,
+}
+
+/// HTTP status callers should answer with when a slug resolves to a redirect.
+pub const SLUG_REDIRECT_STATUS: u16 = 301;
+
+/// Where an old slug of a node (or a page, or a blog post) points to now.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SlugRedirectTarget {
+    pub node_id: Uuid,
+    pub locale: String,
+    pub from_slug: String,
+    pub to_slug: String,
+    pub status: u16,
+}
+
+/// Outcome of a slug lookup that follows renames; pages and blog posts reuse it with their
+/// own response types.
+#[derive(Debug, Clone)]
+pub enum SlugLookup<T = NodeResponse> {
+    Found(Box<T>),
+    Redirect(SlugRedirectTarget),
+}
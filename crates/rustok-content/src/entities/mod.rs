@@ -6,6 +6,7 @@ pub mod node;
 pub mod node_translation;
 pub mod orchestration_audit_log;
 pub mod orchestration_operation;
+pub mod slug_redirect;
 pub mod url_alias;
 
 pub use body::Entity as Body;
@@ -16,4 +17,5 @@ pub use node::Entity as Node;
 pub use node_translation::Entity as NodeTranslation;
 pub use orchestration_audit_log::Entity as OrchestrationAuditLog;
 pub use orchestration_operation::Entity as OrchestrationOperation;
+pub use slug_redirect::Entity as SlugRedirect;
 pub use url_alias::Entity as UrlAlias;
@@ -0,0 +1,21 @@
+use sea_orm::entity::prelude::*;
+
+/// Old slug of a `kind` item (node, page, blog post) in one locale, kept so links to it can be
+/// redirected after a rename. `node_id` is the id of that item.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "slug_redirects")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub tenant_id: Uuid,
+    pub kind: String,
+    pub node_id: Uuid,
+    pub locale: String,
+    pub old_slug: String,
+    pub created_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -21,6 +21,9 @@ pub enum ContentError {
     #[error("Category not found: {0}")]
     CategoryNotFound(Uuid),
 
+    #[error("Category hierarchy contains a cycle through {0}")]
+    CategoryCycle(Uuid),
+
     #[error("Translation not found for node {node_id} and locale {locale}")]
     TranslationNotFound { node_id: Uuid, locale: String },
 
@@ -64,6 +67,13 @@ impl From<ContentError> for RichError {
                     .with_field("category_id", id.to_string())
                     .with_error_code("CATEGORY_NOT_FOUND")
             }
+            ContentError::CategoryCycle(id) => RichError::new(
+                ErrorKind::Internal,
+                format!("Category hierarchy contains a cycle through {}", id),
+            )
+            .with_user_message("The category tree is corrupted")
+            .with_field("category_id", id.to_string())
+            .with_error_code("CATEGORY_CYCLE"),
             ContentError::TranslationNotFound { node_id, locale } => RichError::new(
                 ErrorKind::NotFound,
                 format!(
@@ -162,6 +172,7 @@ impl ContentError {
             ContentError::Core(_) => "core",
             ContentError::NodeNotFound(_) => "not_found",
             ContentError::CategoryNotFound(_) => "not_found",
+            ContentError::CategoryCycle(_) => "integrity",
             ContentError::TranslationNotFound { .. } => "not_found",
             ContentError::DuplicateSlug { .. } => "conflict",
             ContentError::ConcurrentModification { .. } => "conflict",
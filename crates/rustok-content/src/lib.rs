@@ -16,7 +16,8 @@ mod state_machine_proptest;
 
 pub use dto::*;
 pub use entities::{
-    Body, CanonicalUrl, Category, CategoryTranslation, Node, NodeTranslation, UrlAlias,
+    Body, CanonicalUrl, Category, CategoryTranslation, Node, NodeTranslation, SlugRedirect,
+    UrlAlias,
 };
 pub use error::{ContentError, ContentResult};
 pub use locale::{
@@ -25,10 +26,11 @@ pub use locale::{
 };
 pub use rustok_core::PLATFORM_FALLBACK_LOCALE;
 pub use services::{
-    CanonicalUrlMutation, CanonicalUrlService, CategoryService, ContentOrchestrationBridge,
-    ContentOrchestrationService, DemotePostToTopicInput, DemotePostToTopicOutput, MergeTopicsInput,
-    MergeTopicsOutput, OrchestrationResult, PromoteTopicToPostInput, PromoteTopicToPostOutput,
-    ResolvedContentRoute, RetiredCanonicalTarget, SplitTopicInput, SplitTopicOutput,
+    find_slug_redirect_entry, record_slug_change, CanonicalUrlMutation, CanonicalUrlService,
+    CategoryService, ContentOrchestrationBridge, ContentOrchestrationService,
+    DemotePostToTopicInput, DemotePostToTopicOutput, MergeTopicsInput, MergeTopicsOutput,
+    OrchestrationResult, PromoteTopicToPostInput, PromoteTopicToPostOutput, ResolvedContentRoute,
+    RetiredCanonicalTarget, SplitTopicInput, SplitTopicOutput,
 };
 pub use state_machine::{Archived, ContentNode, Draft, Published, ToContentStatus};
 
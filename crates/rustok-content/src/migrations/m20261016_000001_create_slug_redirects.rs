@@ -0,0 +1,90 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SlugRedirects::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SlugRedirects::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(SlugRedirects::TenantId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(SlugRedirects::Kind)
+                            .string_len(64)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(SlugRedirects::NodeId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(SlugRedirects::Locale)
+                            .string_len(32)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SlugRedirects::OldSlug)
+                            .string_len(255)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SlugRedirects::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_slug_redirects_old_slug")
+                    .table(SlugRedirects::Table)
+                    .col(SlugRedirects::TenantId)
+                    .col(SlugRedirects::Kind)
+                    .col(SlugRedirects::Locale)
+                    .col(SlugRedirects::OldSlug)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_slug_redirects_node")
+                    .table(SlugRedirects::Table)
+                    .col(SlugRedirects::NodeId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SlugRedirects::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum SlugRedirects {
+    Table,
+    Id,
+    TenantId,
+    Kind,
+    NodeId,
+    Locale,
+    OldSlug,
+    CreatedAt,
+}
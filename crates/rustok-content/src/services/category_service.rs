@@ -1,16 +1,17 @@
 use chrono::Utc;
 use sea_orm::{
     ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, EntityTrait, ModelTrait,
-    PaginatorTrait, QueryFilter, QueryOrder,
+    PaginatorTrait, QueryFilter, QueryOrder, TransactionTrait,
 };
+use std::collections::{HashMap, HashSet};
 use tracing::instrument;
 use uuid::Uuid;
 
 use rustok_core::{SecurityContext, PLATFORM_FALLBACK_LOCALE};
 
 use crate::dto::category::{
-    CategoryListItem, CategoryResponse, CreateCategoryInput, ListCategoriesFilter,
-    UpdateCategoryInput,
+    CategoryListItem, CategoryResponse, CategoryTreeNode, CreateCategoryInput,
+    ListCategoriesFilter, UpdateCategoryInput,
 };
 use crate::entities::{category, category_translation};
 use crate::error::{ContentError, ContentResult};
@@ -243,6 +244,172 @@ impl CategoryService {
 
         Ok((items, total))
     }
+
+    /// Puts `ordered_ids` first among the children of `parent_id`, in the given order, and
+    /// renumbers all siblings as `0..n`. Siblings missing from `ordered_ids` keep their
+    /// relative order after the listed ones.
+    #[instrument(skip(self, _security, ordered_ids))]
+    pub async fn reorder_categories(
+        &self,
+        tenant_id: Uuid,
+        _security: SecurityContext,
+        parent_id: Option<Uuid>,
+        ordered_ids: &[Uuid],
+    ) -> ContentResult<()> {
+        let txn = self.db.begin().await?;
+
+        let parent_filter = match parent_id {
+            Some(parent_id) => category::Column::ParentId.eq(parent_id),
+            None => category::Column::ParentId.is_null(),
+        };
+        let siblings = category::Entity::find()
+            .filter(category::Column::TenantId.eq(tenant_id))
+            .filter(parent_filter)
+            .order_by_asc(category::Column::Position)
+            .order_by_asc(category::Column::CreatedAt)
+            .all(&txn)
+            .await?;
+
+        let mut seen = HashSet::new();
+        for id in ordered_ids {
+            if !seen.insert(*id) {
+                return Err(ContentError::validation(format!(
+                    "Category {id} is listed more than once"
+                )));
+            }
+            if !siblings.iter().any(|sibling| sibling.id == *id) {
+                return Err(ContentError::validation(format!(
+                    "Category {id} is not a child of the given parent"
+                )));
+            }
+        }
+
+        let (mut listed, rest): (Vec<_>, Vec<_>) = siblings
+            .into_iter()
+            .partition(|sibling| seen.contains(&sibling.id));
+        listed.sort_by_key(|sibling| ordered_ids.iter().position(|id| *id == sibling.id));
+
+        let now = Utc::now();
+        for (position, sibling) in listed.into_iter().chain(rest).enumerate() {
+            let position = position as i32;
+            if sibling.position == position {
+                continue;
+            }
+            let mut active: category::ActiveModel = sibling.into();
+            active.position = Set(position);
+            active.updated_at = Set(now.into());
+            active.update(&txn).await?;
+        }
+
+        txn.commit().await?;
+        Ok(())
+    }
+
+    /// Returns the tenant's categories as a forest rooted at categories without a parent.
+    ///
+    /// Roots are at depth 0; descendants deeper than `max_depth` are left out. A parent
+    /// chain that loops back on itself fails with [`ContentError::CategoryCycle`] instead
+    /// of being walked forever.
+    #[instrument(skip(self))]
+    pub async fn list_category_tree(
+        &self,
+        tenant_id: Uuid,
+        locale: &str,
+        max_depth: Option<u32>,
+    ) -> ContentResult<Vec<CategoryTreeNode>> {
+        let cats = category::Entity::find()
+            .filter(category::Column::TenantId.eq(tenant_id))
+            .order_by_asc(category::Column::Position)
+            .order_by_asc(category::Column::CreatedAt)
+            .all(&self.db)
+            .await?;
+        ensure_acyclic(&cats)?;
+
+        let translations = category_translation::Entity::find()
+            .filter(category_translation::Column::TenantId.eq(tenant_id))
+            .all(&self.db)
+            .await?;
+
+        let mut children: HashMap<Option<Uuid>, Vec<category::Model>> = HashMap::new();
+        for cat in cats {
+            children.entry(cat.parent_id).or_default().push(cat);
+        }
+
+        Ok(build_tree(
+            &mut children,
+            &translations,
+            None,
+            0,
+            max_depth,
+            locale,
+        ))
+    }
+}
+
+/// Walks every category's parent chain and reports the first one that revisits a category.
+fn ensure_acyclic(cats: &[category::Model]) -> ContentResult<()> {
+    let parents: HashMap<Uuid, Option<Uuid>> =
+        cats.iter().map(|cat| (cat.id, cat.parent_id)).collect();
+
+    for cat in cats {
+        let mut seen = HashSet::from([cat.id]);
+        let mut current = cat.parent_id;
+        while let Some(id) = current {
+            if !seen.insert(id) {
+                return Err(ContentError::CategoryCycle(id));
+            }
+            current = parents.get(&id).copied().flatten();
+        }
+    }
+
+    Ok(())
+}
+
+fn build_tree(
+    children: &mut HashMap<Option<Uuid>, Vec<category::Model>>,
+    translations: &[category_translation::Model],
+    parent_id: Option<Uuid>,
+    depth: u32,
+    max_depth: Option<u32>,
+    locale: &str,
+) -> Vec<CategoryTreeNode> {
+    let level = children.remove(&parent_id).unwrap_or_default();
+
+    level
+        .into_iter()
+        .map(|cat| {
+            let trs: Vec<&category_translation::Model> = translations
+                .iter()
+                .filter(|t| t.category_id == cat.id)
+                .collect();
+            let (tr, effective_locale) = resolve_translation(&trs, locale);
+            let descendants = if max_depth.is_none_or(|max| depth < max) {
+                build_tree(
+                    children,
+                    translations,
+                    Some(cat.id),
+                    depth + 1,
+                    max_depth,
+                    locale,
+                )
+            } else {
+                Vec::new()
+            };
+
+            CategoryTreeNode {
+                id: cat.id,
+                locale: locale.to_string(),
+                effective_locale,
+                name: tr.map(|t| t.name.clone()).unwrap_or_default(),
+                slug: tr.map(|t| t.slug.clone()).unwrap_or_default(),
+                parent_id: cat.parent_id,
+                position: cat.position,
+                depth,
+                settings: cat.settings,
+                children: descendants,
+            }
+        })
+        .collect()
 }
 
 fn resolve_translation<'a>(
@@ -290,3 +457,222 @@ fn to_response(
         updated_at: cat.updated_at.into(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::{ConnectOptions, ConnectionTrait, Database, DbBackend, Statement};
+
+    use super::*;
+
+    async fn test_db() -> DatabaseConnection {
+        let db_url = format!(
+            "sqlite:file:category_service_{}?mode=memory&cache=shared",
+            Uuid::new_v4()
+        );
+        let mut opts = ConnectOptions::new(db_url);
+        opts.max_connections(5)
+            .min_connections(1)
+            .sqlx_logging(false);
+        let db = Database::connect(opts)
+            .await
+            .expect("failed to connect sqlite db");
+
+        db.execute(Statement::from_string(
+            DbBackend::Sqlite,
+            "CREATE TABLE categories (
+                id TEXT PRIMARY KEY,
+                tenant_id TEXT NOT NULL,
+                parent_id TEXT NULL,
+                position INTEGER NOT NULL,
+                depth INTEGER NOT NULL,
+                node_count INTEGER NOT NULL,
+                settings TEXT NOT NULL,
+                created_at TEXT NOT NULL,
+                updated_at TEXT NOT NULL
+            )"
+            .to_string(),
+        ))
+        .await
+        .expect("create categories table");
+
+        db.execute(Statement::from_string(
+            DbBackend::Sqlite,
+            "CREATE TABLE category_translations (
+                id TEXT PRIMARY KEY,
+                category_id TEXT NOT NULL,
+                tenant_id TEXT NOT NULL,
+                locale TEXT NOT NULL,
+                name TEXT NOT NULL,
+                slug TEXT NOT NULL,
+                description TEXT NULL
+            )"
+            .to_string(),
+        ))
+        .await
+        .expect("create category_translations table");
+
+        db
+    }
+
+    async fn create(
+        service: &CategoryService,
+        tenant_id: Uuid,
+        name: &str,
+        parent_id: Option<Uuid>,
+        position: i32,
+    ) -> Uuid {
+        service
+            .create(
+                tenant_id,
+                SecurityContext::system(),
+                CreateCategoryInput {
+                    locale: "en".to_string(),
+                    name: name.to_string(),
+                    slug: None,
+                    description: None,
+                    parent_id,
+                    position: Some(position),
+                    settings: serde_json::json!({}),
+                },
+            )
+            .await
+            .expect("create category")
+    }
+
+    async fn set_parent(db: &DatabaseConnection, id: Uuid, parent_id: Uuid) {
+        let cat = category::Entity::find_by_id(id)
+            .one(db)
+            .await
+            .unwrap()
+            .unwrap();
+        let mut active: category::ActiveModel = cat.into();
+        active.parent_id = Set(Some(parent_id));
+        active.update(db).await.expect("reparent category");
+    }
+
+    #[tokio::test]
+    async fn returns_nested_tree_ordered_by_position() {
+        let service = CategoryService::new(test_db().await);
+        let tenant_id = Uuid::new_v4();
+
+        let docs = create(&service, tenant_id, "Docs", None, 1).await;
+        let news = create(&service, tenant_id, "News", None, 0).await;
+        let api = create(&service, tenant_id, "API", Some(docs), 1).await;
+        let guides = create(&service, tenant_id, "Guides", Some(docs), 0).await;
+        let setup = create(&service, tenant_id, "Setup", Some(guides), 0).await;
+        create(&service, Uuid::new_v4(), "Other tenant", None, 0).await;
+
+        let tree = service
+            .list_category_tree(tenant_id, "ru", None)
+            .await
+            .expect("load tree");
+
+        assert_eq!(
+            tree.iter().map(|node| node.id).collect::<Vec<_>>(),
+            [news, docs]
+        );
+        assert!(tree[0].children.is_empty());
+        assert_eq!(tree[1].name, "Docs");
+        assert_eq!(tree[1].effective_locale, "en");
+
+        let docs_children = &tree[1].children;
+        assert_eq!(
+            docs_children.iter().map(|node| node.id).collect::<Vec<_>>(),
+            [guides, api]
+        );
+        assert_eq!(docs_children[0].depth, 1);
+        assert_eq!(docs_children[0].parent_id, Some(docs));
+        assert_eq!(docs_children[0].children[0].id, setup);
+        assert_eq!(docs_children[0].children[0].depth, 2);
+
+        let shallow = service
+            .list_category_tree(tenant_id, "en", Some(1))
+            .await
+            .expect("load shallow tree");
+        assert_eq!(shallow[1].children.len(), 2);
+        assert!(shallow[1].children[0].children.is_empty());
+    }
+
+    async fn positions(db: &DatabaseConnection, parent_id: Uuid) -> Vec<(Uuid, i32)> {
+        category::Entity::find()
+            .filter(category::Column::ParentId.eq(parent_id))
+            .order_by_asc(category::Column::Position)
+            .all(db)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|cat| (cat.id, cat.position))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn reorder_assigns_contiguous_positions_from_zero() {
+        let db = test_db().await;
+        let service = CategoryService::new(db.clone());
+        let tenant_id = Uuid::new_v4();
+
+        let root = create(&service, tenant_id, "Root", None, 0).await;
+        let a = create(&service, tenant_id, "A", Some(root), 5).await;
+        let b = create(&service, tenant_id, "B", Some(root), 5).await;
+        let c = create(&service, tenant_id, "C", Some(root), 12).await;
+
+        service
+            .reorder_categories(tenant_id, SecurityContext::system(), Some(root), &[c, a, b])
+            .await
+            .expect("reorder");
+
+        assert_eq!(positions(&db, root).await, [(c, 0), (a, 1), (b, 2)]);
+
+        service
+            .reorder_categories(tenant_id, SecurityContext::system(), Some(root), &[b])
+            .await
+            .expect("partial reorder");
+
+        assert_eq!(positions(&db, root).await, [(b, 0), (c, 1), (a, 2)]);
+    }
+
+    #[tokio::test]
+    async fn reorder_rejects_category_from_another_parent() {
+        let db = test_db().await;
+        let service = CategoryService::new(db.clone());
+        let tenant_id = Uuid::new_v4();
+
+        let root = create(&service, tenant_id, "Root", None, 0).await;
+        let other_root = create(&service, tenant_id, "Other", None, 1).await;
+        let child = create(&service, tenant_id, "Child", Some(root), 3).await;
+        let stranger = create(&service, tenant_id, "Stranger", Some(other_root), 0).await;
+        let foreign = create(&service, Uuid::new_v4(), "Foreign", Some(root), 0).await;
+
+        for ids in [
+            vec![child, stranger],
+            vec![child, foreign],
+            vec![child, child],
+        ] {
+            let result = service
+                .reorder_categories(tenant_id, SecurityContext::system(), Some(root), &ids)
+                .await;
+            assert!(matches!(result, Err(ContentError::Validation(_))));
+        }
+
+        assert!(positions(&db, root).await.contains(&(child, 3)));
+    }
+
+    #[tokio::test]
+    async fn detects_parent_cycle_instead_of_looping() {
+        let db = test_db().await;
+        let service = CategoryService::new(db.clone());
+        let tenant_id = Uuid::new_v4();
+
+        let root = create(&service, tenant_id, "Root", None, 0).await;
+        let first = create(&service, tenant_id, "First", Some(root), 0).await;
+        let second = create(&service, tenant_id, "Second", Some(first), 0).await;
+        set_parent(&db, first, second).await;
+
+        let result = service.list_category_tree(tenant_id, "en", None).await;
+
+        assert!(matches!(
+            result,
+            Err(ContentError::CategoryCycle(id)) if id == first || id == second
+        ));
+    }
+}
@@ -0,0 +1,109 @@
+use std::collections::HashSet;
+
+use async_trait::async_trait;
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QuerySelect};
+use uuid::Uuid;
+
+use rustok_core::security::CrossTenantProbe;
+use rustok_core::SecurityContext;
+
+use crate::dto::ListNodesFilter;
+use crate::entities::node;
+use crate::error::ContentError;
+use crate::services::NodeService;
+
+const LIST_PAGE_SIZE: u64 = 100;
+
+/// [`CrossTenantProbe`] for [`NodeService`]: as the reader tenant, fetches each of the owner
+/// tenant's nodes by id, in one batch, and through the node list.
+pub struct NodeIsolationProbe {
+    service: NodeService,
+}
+
+impl NodeIsolationProbe {
+    pub fn new(service: NodeService) -> Self {
+        Self { service }
+    }
+
+    async fn listed_node_ids(&self, reader: Uuid) -> Result<Vec<Uuid>, ContentError> {
+        let mut ids = Vec::new();
+        for page in 1.. {
+            let (items, total) = self
+                .service
+                .list_nodes(
+                    reader,
+                    SecurityContext::system(),
+                    ListNodesFilter {
+                        page,
+                        per_page: LIST_PAGE_SIZE,
+                        include_deleted: true,
+                        ..ListNodesFilter::default()
+                    },
+                )
+                .await?;
+            let fetched = items.len();
+            ids.extend(items.into_iter().map(|item| item.id));
+            if fetched == 0 || ids.len() as u64 >= total {
+                break;
+            }
+        }
+        Ok(ids)
+    }
+}
+
+#[async_trait]
+impl CrossTenantProbe for NodeIsolationProbe {
+    fn name(&self) -> &str {
+        "content.nodes"
+    }
+
+    async fn read_foreign(
+        &self,
+        db: &DatabaseConnection,
+        reader: Uuid,
+        owner: Uuid,
+    ) -> Result<usize, String> {
+        let owned: Vec<Uuid> = node::Entity::find()
+            .select_only()
+            .column(node::Column::Id)
+            .filter(node::Column::TenantId.eq(owner))
+            .into_tuple()
+            .all(db)
+            .await
+            .map_err(|err| err.to_string())?;
+        if owned.is_empty() {
+            return Err(format!("tenant {owner} has no seeded nodes"));
+        }
+        let owned_set: HashSet<Uuid> = owned.iter().copied().collect();
+
+        let mut leaked = HashSet::new();
+        for &node_id in &owned {
+            match self.service.get_node(reader, node_id).await {
+                Ok(_) => {
+                    leaked.insert(node_id);
+                }
+                Err(ContentError::NodeNotFound(_)) => {}
+                Err(err) => return Err(err.to_string()),
+            }
+        }
+
+        let batch = self
+            .service
+            .get_nodes_batch(reader, &owned)
+            .await
+            .map_err(|err| err.to_string())?;
+        let listed = self
+            .listed_node_ids(reader)
+            .await
+            .map_err(|err| err.to_string())?;
+        leaked.extend(
+            batch
+                .into_iter()
+                .map(|node| node.id)
+                .chain(listed)
+                .filter(|id| owned_set.contains(id)),
+        );
+
+        Ok(leaked.len())
+    }
+}
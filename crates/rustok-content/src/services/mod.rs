@@ -1,7 +1,10 @@
 mod canonical_url_service;
 mod category_service;
 mod content_orchestration_service;
+mod isolation_probe;
+mod node_search_indexer;
 mod node_service;
+mod slug_redirects;
 
 pub use canonical_url_service::{CanonicalUrlService, ResolvedContentRoute};
 pub use category_service::CategoryService;
@@ -11,4 +14,7 @@ pub use content_orchestration_service::{
     OrchestrationResult, PromoteTopicToPostInput, PromoteTopicToPostOutput, RetiredCanonicalTarget,
     SplitTopicInput, SplitTopicOutput,
 };
-pub use node_service::NodeService;
+pub use isolation_probe::NodeIsolationProbe;
+pub use node_search_indexer::NodeSearchIndexer;
+pub use node_service::{node_cache_prefix, NodeService};
+pub use slug_redirects::{find_slug_redirect_entry, record_slug_change};
@@ -0,0 +1,123 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter};
+use tracing::debug;
+use uuid::Uuid;
+
+use rustok_core::events::{EventHandler, HandlerResult};
+use rustok_core::{Error, SearchBackend, SearchDocument};
+use rustok_events::{DomainEvent, EventEnvelope};
+
+use crate::entities::node::ContentStatus;
+use crate::entities::{body, node, node_translation};
+
+/// Keeps the search backend in step with nodes: every locale of a published node is
+/// indexed, and drafts, archived and deleted nodes are dropped from the index.
+pub struct NodeSearchIndexer {
+    db: DatabaseConnection,
+    backend: Arc<dyn SearchBackend>,
+}
+
+impl NodeSearchIndexer {
+    pub fn new(db: DatabaseConnection, backend: Arc<dyn SearchBackend>) -> Self {
+        Self { db, backend }
+    }
+
+    /// Replaces whatever is indexed for the node with its current published state.
+    pub async fn reindex_node(&self, tenant_id: Uuid, node_id: Uuid) -> HandlerResult {
+        self.backend.remove(tenant_id, node_id).await?;
+
+        let Some(node) = node::Entity::find_by_id(node_id)
+            .filter(node::Column::TenantId.eq(tenant_id))
+            .filter(node::Column::DeletedAt.is_null())
+            .one(&self.db)
+            .await
+            .map_err(Error::Database)?
+        else {
+            return Ok(());
+        };
+        if node.status != ContentStatus::Published {
+            debug!(node_id = %node_id, status = ?node.status, "Node not published, left out of search index");
+            return Ok(());
+        }
+
+        let translations = node_translation::Entity::find()
+            .filter(node_translation::Column::NodeId.eq(node_id))
+            .all(&self.db)
+            .await
+            .map_err(Error::Database)?;
+        let bodies = body::Entity::find()
+            .filter(body::Column::NodeId.eq(node_id))
+            .all(&self.db)
+            .await
+            .map_err(Error::Database)?;
+
+        let facets: BTreeMap<String, String> = node
+            .category_id
+            .map(|category_id| ("category".to_string(), category_id.to_string()))
+            .into_iter()
+            .collect();
+
+        for translation in translations {
+            let body = bodies
+                .iter()
+                .find(|body| body.locale == translation.locale)
+                .and_then(|body| body.body.clone())
+                .unwrap_or_default();
+
+            self.backend
+                .index(SearchDocument {
+                    tenant_id,
+                    document_id: node_id,
+                    entity_type: node.kind.clone(),
+                    locale: translation.locale,
+                    title: translation.title.unwrap_or_default(),
+                    slug: translation.slug,
+                    body,
+                    facets: facets.clone(),
+                })
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EventHandler for NodeSearchIndexer {
+    fn name(&self) -> &'static str {
+        "node_search_indexer"
+    }
+
+    fn handles(&self, event: &DomainEvent) -> bool {
+        matches!(
+            event,
+            DomainEvent::NodeCreated { .. }
+                | DomainEvent::NodeUpdated { .. }
+                | DomainEvent::NodeTranslationUpdated { .. }
+                | DomainEvent::NodePublished { .. }
+                | DomainEvent::NodeUnpublished { .. }
+                | DomainEvent::NodeDeleted { .. }
+                | DomainEvent::BodyUpdated { .. }
+        )
+    }
+
+    async fn handle(&self, envelope: &EventEnvelope) -> HandlerResult {
+        match &envelope.event {
+            DomainEvent::NodeDeleted { node_id, .. } => {
+                self.backend.remove(envelope.tenant_id, *node_id).await
+            }
+            DomainEvent::NodeCreated { node_id, .. }
+            | DomainEvent::NodeUpdated { node_id, .. }
+            | DomainEvent::NodeTranslationUpdated { node_id, .. }
+            | DomainEvent::NodePublished { node_id, .. }
+            | DomainEvent::NodeUnpublished { node_id, .. }
+            | DomainEvent::BodyUpdated { node_id, .. } => {
+                self.reindex_node(envelope.tenant_id, *node_id).await
+            }
+            _ => Ok(()),
+        }
+    }
+}
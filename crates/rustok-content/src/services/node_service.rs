@@ -1,29 +1,34 @@
+use std::sync::Arc;
+
 use chrono::Utc;
+use sea_orm::sea_query::{Alias, Expr, Order, Query};
 use sea_orm::{
     prelude::DateTimeWithTimeZone, ActiveModelTrait, ColumnTrait, ConnectionTrait,
-    DatabaseConnection, DatabaseTransaction, EntityTrait, PaginatorTrait, QueryFilter, Set,
-    TransactionTrait,
+    DatabaseConnection, DatabaseTransaction, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
+    Set, TransactionTrait,
 };
 use tracing::{debug, error, info, instrument, warn};
 use uuid::Uuid;
 use validator::Validate;
 
 use rustok_core::{
-    prepare_content_payload, Action, DomainEvent, PermissionScope, Resource, SecurityContext,
-    PLATFORM_FALLBACK_LOCALE,
+    locale_tags_match, prepare_content_payload, Action, CacheBackend, DomainEvent, PermissionScope,
+    Resource, SecurityContext, PLATFORM_FALLBACK_LOCALE,
 };
 use rustok_outbox::TransactionalEventBus;
 
 use crate::dto::{
-    BodyInput, BodyResponse, CreateNodeInput, ListNodesFilter, NodeListItem, NodeResponse,
-    NodeTranslationResponse, UpdateNodeInput,
+    BodyInput, BodyResponse, CreateNodeInput, ListNodesFilter, LocaleCompleteness,
+    LocalizationReport, NodeListItem, NodeLocalizationStatus, NodeResponse,
+    NodeTranslationResponse, SlugLookup, SlugRedirectTarget, UpdateNodeInput, SLUG_REDIRECT_STATUS,
 };
 use rustok_core::json_object_depth;
 use rustok_telemetry::metrics;
 
-use crate::entities::{body, node, node_translation};
+use crate::entities::{body, node, node_translation, slug_redirect};
 use crate::error::{ContentError, ContentResult};
 use crate::locale::resolve_by_locale_with_fallback;
+use crate::services::slug_redirects::{find_slug_redirect_entry, record_slug_change};
 use crate::state_machine::validate_status_transition;
 
 /// Maximum allowed JSON nesting depth for the `metadata` field.
@@ -32,11 +37,27 @@ const METADATA_MAX_DEPTH: usize = 5;
 pub struct NodeService {
     db: DatabaseConnection,
     event_bus: TransactionalEventBus,
+    cache: Option<Arc<dyn CacheBackend>>,
+}
+
+/// Prefix of every cache key derived from a node (rendered body, translations, SEO, ...);
+/// all of them are dropped when the node is updated.
+pub fn node_cache_prefix(node_id: Uuid) -> String {
+    format!("node:{node_id}:")
 }
 
 impl NodeService {
     pub fn new(db: DatabaseConnection, event_bus: TransactionalEventBus) -> Self {
-        Self { db, event_bus }
+        Self {
+            db,
+            event_bus,
+            cache: None,
+        }
+    }
+
+    pub fn with_cache(mut self, cache: Arc<dyn CacheBackend>) -> Self {
+        self.cache = Some(cache);
+        self
     }
 
     pub fn db(&self) -> &DatabaseConnection {
@@ -300,6 +321,7 @@ impl NodeService {
             .inspect_err(|e| metrics::record_span_error("content.node.update", e.kind()))?;
         txn.commit().await?;
         metrics::record_span_duration("content.node.update", started.elapsed().as_secs_f64());
+        self.invalidate_node_cache(node_id).await;
         let translations = node_translation::Entity::find()
             .filter(node_translation::Column::NodeId.eq(node_id))
             .all(&self.db)
@@ -311,6 +333,20 @@ impl NodeService {
         Ok(Self::to_response(updated, translations, bodies))
     }
 
+    /// Drops every cache entry under [`node_cache_prefix`]. The non-transactional mutations
+    /// call it after committing; callers of the `*_in_tx` variants call it once their own
+    /// transaction has committed. A stale cache entry is not worth failing a committed
+    /// change over, so errors are only logged.
+    pub async fn invalidate_node_cache(&self, node_id: Uuid) {
+        let Some(cache) = &self.cache else {
+            return;
+        };
+        match cache.invalidate_prefix(&node_cache_prefix(node_id)).await {
+            Ok(removed) => debug!(removed, "Invalidated node cache entries"),
+            Err(error) => warn!(error = %error, "Failed to invalidate node cache entries"),
+        }
+    }
+
     /// Update a node within an existing transaction. Does not begin or commit.
     /// Returns the updated node model. Callers must commit the transaction themselves.
     pub async fn update_node_in_tx(
@@ -407,6 +443,17 @@ impl NodeService {
                 }
             }
 
+            let previous_slugs: std::collections::HashMap<String, String> =
+                node_translation::Entity::find()
+                    .filter(node_translation::Column::NodeId.eq(node_id))
+                    .all(txn)
+                    .await?
+                    .into_iter()
+                    .filter_map(|translation| {
+                        translation.slug.map(|slug| (translation.locale, slug))
+                    })
+                    .collect();
+
             node_translation::Entity::delete_many()
                 .filter(node_translation::Column::NodeId.eq(node_id))
                 .exec(txn)
@@ -428,6 +475,18 @@ impl NodeService {
                         Some(node_id),
                     )
                     .await?;
+
+                    record_slug_change(
+                        txn,
+                        node_model.tenant_id,
+                        &node_model.kind,
+                        node_id,
+                        &translation.locale,
+                        previous_slugs.get(&translation.locale).map(String::as_str),
+                        s,
+                        now,
+                    )
+                    .await?;
                 }
 
                 node_translation::ActiveModel {
@@ -496,6 +555,7 @@ impl NodeService {
             .inspect_err(|e| metrics::record_span_error(op, e.kind()))?;
         txn.commit().await?;
         metrics::record_span_duration(op, started.elapsed().as_secs_f64());
+        self.invalidate_node_cache(node_id).await;
 
         let translations = node_translation::Entity::find()
             .filter(node_translation::Column::NodeId.eq(node_id))
@@ -721,6 +781,7 @@ impl NodeService {
         self.delete_node_in_tx(&txn, tenant_id, node_id, security)
             .await?;
         txn.commit().await?;
+        self.invalidate_node_cache(node_id).await;
         info!(node_id = %node_id, "Node soft-deleted successfully");
         Ok(())
     }
@@ -803,6 +864,7 @@ impl NodeService {
             .await?;
 
         txn.commit().await?;
+        self.invalidate_node_cache(node_id).await;
 
         let translations = node_translation::Entity::find()
             .filter(node_translation::Column::NodeId.eq(node_id))
@@ -848,9 +910,14 @@ impl NodeService {
             .filter(node_translation::Column::NodeId.eq(node_id))
             .exec(&txn)
             .await?;
+        slug_redirect::Entity::delete_many()
+            .filter(slug_redirect::Column::NodeId.eq(node_id))
+            .exec(&txn)
+            .await?;
         node::Entity::delete_by_id(node_id).exec(&txn).await?;
 
         txn.commit().await?;
+        self.invalidate_node_cache(node_id).await;
 
         info!(node_id = %node_id, "Node hard-deleted permanently");
         Ok(())
@@ -982,6 +1049,63 @@ impl NodeService {
         }
     }
 
+    /// Looks `slug` up among the node's former slugs and returns where it lives now.
+    ///
+    /// Only consulted for slugs no live node uses; see [`Self::get_by_slug_or_redirect`].
+    pub async fn find_slug_redirect(
+        &self,
+        tenant_id: Uuid,
+        kind: &str,
+        locale: &str,
+        slug: &str,
+    ) -> ContentResult<Option<SlugRedirectTarget>> {
+        let Some(redirect) =
+            find_slug_redirect_entry(&self.db, tenant_id, kind, locale, slug).await?
+        else {
+            return Ok(None);
+        };
+
+        let current = node_translation::Entity::find()
+            .inner_join(node::Entity)
+            .filter(node::Column::Id.eq(redirect.node_id))
+            .filter(node::Column::TenantId.eq(tenant_id))
+            .filter(node::Column::Kind.eq(kind))
+            .filter(node::Column::DeletedAt.is_null())
+            .filter(node_translation::Column::Locale.eq(locale))
+            .one(&self.db)
+            .await?;
+
+        Ok(current
+            .and_then(|translation| translation.slug)
+            .filter(|to_slug| to_slug != slug)
+            .map(|to_slug| SlugRedirectTarget {
+                node_id: redirect.node_id,
+                locale: redirect.locale,
+                from_slug: redirect.old_slug,
+                to_slug,
+                status: SLUG_REDIRECT_STATUS,
+            }))
+    }
+
+    /// Like [`Self::get_by_slug`], but falls back to a permanent redirect when `slug` used
+    /// to belong to a node that has since been renamed.
+    pub async fn get_by_slug_or_redirect(
+        &self,
+        tenant_id: Uuid,
+        kind: &str,
+        locale: &str,
+        slug: &str,
+    ) -> ContentResult<Option<SlugLookup>> {
+        if let Some(node) = self.get_by_slug(tenant_id, kind, locale, slug).await? {
+            return Ok(Some(SlugLookup::Found(Box::new(node))));
+        }
+
+        Ok(self
+            .find_slug_redirect(tenant_id, kind, locale, slug)
+            .await?
+            .map(SlugLookup::Redirect))
+    }
+
     #[instrument(skip(self, security, filter), fields(tenant_id = %tenant_id, user_id = ?security.user_id, kind = ?filter.kind))]
     pub async fn list_nodes(
         &self,
@@ -1115,6 +1239,107 @@ impl NodeService {
 
         Ok((items, total))
     }
+
+    /// Reports, for every live node of the tenant, which enabled locales have a translation
+    /// and a body. Disabled tenant locales are left out of the matrix.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id))]
+    pub async fn localization_report(&self, tenant_id: Uuid) -> ContentResult<LocalizationReport> {
+        let locales = self.load_enabled_locales(tenant_id).await?;
+
+        let nodes = node::Entity::find()
+            .filter(node::Column::TenantId.eq(tenant_id))
+            .filter(node::Column::DeletedAt.is_null())
+            .order_by_asc(node::Column::CreatedAt)
+            .all(&self.db)
+            .await?;
+        let node_ids: Vec<Uuid> = nodes.iter().map(|node| node.id).collect();
+
+        let mut translated: std::collections::HashMap<Uuid, Vec<String>> =
+            std::collections::HashMap::new();
+        for translation in node_translation::Entity::find()
+            .filter(node_translation::Column::NodeId.is_in(node_ids.clone()))
+            .all(&self.db)
+            .await?
+        {
+            translated
+                .entry(translation.node_id)
+                .or_default()
+                .push(translation.locale);
+        }
+
+        let mut with_body: std::collections::HashMap<Uuid, Vec<String>> =
+            std::collections::HashMap::new();
+        for body in body::Entity::find()
+            .filter(body::Column::NodeId.is_in(node_ids))
+            .all(&self.db)
+            .await?
+        {
+            if body
+                .body
+                .as_deref()
+                .is_some_and(|text| !text.trim().is_empty())
+            {
+                with_body.entry(body.node_id).or_default().push(body.locale);
+            }
+        }
+
+        let nodes = nodes
+            .into_iter()
+            .map(|node| {
+                let translated = translated.remove(&node.id).unwrap_or_default();
+                let with_body = with_body.remove(&node.id).unwrap_or_default();
+                let covers = |present: &[String], locale: &str| {
+                    present.iter().any(|item| locale_tags_match(item, locale))
+                };
+
+                let locales: Vec<LocaleCompleteness> = locales
+                    .iter()
+                    .map(|locale| LocaleCompleteness {
+                        locale: locale.clone(),
+                        has_translation: covers(&translated, locale),
+                        has_body: covers(&with_body, locale),
+                    })
+                    .collect();
+                let missing = |has: fn(&LocaleCompleteness) -> bool| -> Vec<String> {
+                    locales
+                        .iter()
+                        .filter(|entry| !has(entry))
+                        .map(|entry| entry.locale.clone())
+                        .collect()
+                };
+
+                NodeLocalizationStatus {
+                    node_id: node.id,
+                    kind: node.kind,
+                    missing_translations: missing(|entry| entry.has_translation),
+                    missing_bodies: missing(|entry| entry.has_body),
+                    locales,
+                }
+            })
+            .collect();
+
+        Ok(LocalizationReport { locales, nodes })
+    }
+
+    async fn load_enabled_locales(&self, tenant_id: Uuid) -> ContentResult<Vec<String>> {
+        let statement = Query::select()
+            .from(Alias::new("tenant_locales"))
+            .column(Alias::new("locale"))
+            .and_where(Expr::col(Alias::new("tenant_id")).eq(tenant_id))
+            .and_where(Expr::col(Alias::new("is_enabled")).eq(true))
+            .order_by(Alias::new("is_default"), Order::Desc)
+            .order_by(Alias::new("locale"), Order::Asc)
+            .to_owned();
+
+        let rows = self
+            .db
+            .query_all(self.db.get_database_backend().build(&statement))
+            .await?;
+
+        rows.into_iter()
+            .map(|row| row.try_get::<String>("", "locale").map_err(Into::into))
+            .collect()
+    }
 }
 
 fn normalize_body_input(input: BodyInput) -> ContentResult<BodyInput> {
@@ -0,0 +1,90 @@
+use sea_orm::{
+    prelude::DateTimeWithTimeZone, ActiveModelTrait, ColumnTrait, ConnectionTrait, DbErr,
+    EntityTrait, QueryFilter, Set,
+};
+use tracing::debug;
+use uuid::Uuid;
+
+use crate::entities::slug_redirect;
+
+/// Keeps `slug_redirects` in step with a slug of a `kind` item (a node, a page, a blog post):
+/// the slug being taken is no longer a redirect, and the one being given up starts pointing
+/// at `target_id`.
+#[allow(clippy::too_many_arguments)]
+pub async fn record_slug_change<C>(
+    conn: &C,
+    tenant_id: Uuid,
+    kind: &str,
+    target_id: Uuid,
+    locale: &str,
+    old_slug: Option<&str>,
+    new_slug: &str,
+    now: DateTimeWithTimeZone,
+) -> Result<(), DbErr>
+where
+    C: ConnectionTrait,
+{
+    delete_redirect(conn, tenant_id, kind, locale, new_slug).await?;
+
+    let Some(old_slug) = old_slug.filter(|old_slug| *old_slug != new_slug) else {
+        return Ok(());
+    };
+
+    delete_redirect(conn, tenant_id, kind, locale, old_slug).await?;
+
+    slug_redirect::ActiveModel {
+        id: Set(rustok_core::generate_id()),
+        tenant_id: Set(tenant_id),
+        kind: Set(kind.to_string()),
+        node_id: Set(target_id),
+        locale: Set(locale.to_string()),
+        old_slug: Set(old_slug.to_string()),
+        created_at: Set(now),
+    }
+    .insert(conn)
+    .await?;
+
+    debug!(target_id = %target_id, kind, locale, old_slug, new_slug, "Recorded slug redirect");
+    Ok(())
+}
+
+/// The redirect recorded for `slug`, if any. Callers still have to check that the item it
+/// points at exists and is readable, and look up its current slug.
+pub async fn find_slug_redirect_entry<C>(
+    conn: &C,
+    tenant_id: Uuid,
+    kind: &str,
+    locale: &str,
+    slug: &str,
+) -> Result<Option<slug_redirect::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    slug_redirect::Entity::find()
+        .filter(slug_redirect::Column::TenantId.eq(tenant_id))
+        .filter(slug_redirect::Column::Kind.eq(kind))
+        .filter(slug_redirect::Column::Locale.eq(locale))
+        .filter(slug_redirect::Column::OldSlug.eq(slug))
+        .one(conn)
+        .await
+}
+
+async fn delete_redirect<C>(
+    conn: &C,
+    tenant_id: Uuid,
+    kind: &str,
+    locale: &str,
+    old_slug: &str,
+) -> Result<(), DbErr>
+where
+    C: ConnectionTrait,
+{
+    slug_redirect::Entity::delete_many()
+        .filter(slug_redirect::Column::TenantId.eq(tenant_id))
+        .filter(slug_redirect::Column::Kind.eq(kind))
+        .filter(slug_redirect::Column::Locale.eq(locale))
+        .filter(slug_redirect::Column::OldSlug.eq(old_slug))
+        .exec(conn)
+        .await?;
+    Ok(())
+}
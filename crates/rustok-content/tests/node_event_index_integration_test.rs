@@ -3,11 +3,12 @@
 
 use rustok_content::dto::{BodyInput, CreateNodeInput, NodeTranslationInput, UpdateNodeInput};
 use rustok_content::entities::node::ContentStatus;
-use rustok_content::services::NodeService;
+use rustok_content::services::{NodeSearchIndexer, NodeService};
+use rustok_core::events::EventHandler;
 use rustok_core::{SecurityContext, UserRole};
-use rustok_events::DomainEvent;
+use rustok_events::{DomainEvent, EventEnvelope};
 use rustok_outbox::TransactionalEventBus;
-use rustok_test_utils::MockEventTransport;
+use rustok_test_utils::{MockEventTransport, MockSearchBackend};
 use sea_orm::{
     ConnectOptions, ConnectionTrait, Database, DatabaseConnection, DbBackend, Statement,
 };
@@ -117,6 +118,22 @@ async fn ensure_content_schema(db: &DatabaseConnection) {
     ))
     .await
     .expect("failed to create content bodies test table");
+
+    db.execute(Statement::from_string(
+        DbBackend::Sqlite,
+        "CREATE TABLE IF NOT EXISTS slug_redirects (
+            id TEXT PRIMARY KEY,
+            tenant_id TEXT NOT NULL,
+            kind TEXT NOT NULL,
+            node_id TEXT NOT NULL,
+            locale TEXT NOT NULL,
+            old_slug TEXT NOT NULL,
+            created_at TEXT NOT NULL
+        )"
+        .to_string(),
+    ))
+    .await
+    .expect("failed to create content slug_redirects test table");
 }
 
 #[test]
@@ -379,3 +396,146 @@ fn test_transactional_event_persistence() {
         println!("✅ Transactional event persistence verified");
     });
 }
+
+async fn forward_events(
+    indexer: &NodeSearchIndexer,
+    transport: &MockEventTransport,
+    tenant_id: Uuid,
+    event_type: &str,
+) {
+    for event in transport.events_of_type(event_type) {
+        assert!(indexer.handles(&event));
+        indexer
+            .handle(&EventEnvelope::new(tenant_id, None, event))
+            .await
+            .expect("search indexer should handle the event");
+    }
+    transport.clear();
+}
+
+#[test]
+fn test_node_publish_and_delete_update_search_index() {
+    run_async_test(|| async {
+        let db = setup_content_test_db().await;
+        ensure_content_schema(&db).await;
+        let transport = Arc::new(MockEventTransport::new());
+        let event_bus = TransactionalEventBus::new(transport.clone());
+        let service = NodeService::new(db.clone(), event_bus);
+        let search = Arc::new(MockSearchBackend::new());
+        let indexer = NodeSearchIndexer::new(db.clone(), search.clone());
+
+        let tenant_id = Uuid::new_v4();
+        let security = SecurityContext::new(UserRole::Admin, Some(Uuid::new_v4()));
+
+        let input = CreateNodeInput {
+            kind: "post".to_string(),
+            translations: vec![
+                NodeTranslationInput {
+                    locale: "en".to_string(),
+                    title: Some("Searchable".to_string()),
+                    slug: Some("searchable".to_string()),
+                    excerpt: None,
+                },
+                NodeTranslationInput {
+                    locale: "ru".to_string(),
+                    title: Some("Поиск".to_string()),
+                    slug: Some("poisk".to_string()),
+                    excerpt: None,
+                },
+            ],
+            bodies: vec![BodyInput {
+                locale: "en".to_string(),
+                body: Some("Full-text body".to_string()),
+                format: Some("markdown".to_string()),
+            }],
+            status: None,
+            parent_id: None,
+            author_id: None,
+            category_id: None,
+            position: None,
+            depth: None,
+            reply_count: None,
+            metadata: serde_json::json!({}),
+        };
+
+        let node = service
+            .create_node(tenant_id, security.clone(), input)
+            .await
+            .unwrap();
+        forward_events(&indexer, &transport, tenant_id, "NodeCreated").await;
+        assert!(
+            !search.is_indexed(node.id),
+            "drafts must stay out of the search index"
+        );
+
+        service
+            .publish_node(tenant_id, node.id, security.clone())
+            .await
+            .unwrap();
+        forward_events(&indexer, &transport, tenant_id, "NodePublished").await;
+
+        let documents = search.documents_for(node.id);
+        assert_eq!(documents.len(), 2);
+        assert_eq!(documents[0].locale, "en");
+        assert_eq!(documents[0].title, "Searchable");
+        assert_eq!(documents[0].body, "Full-text body");
+        assert_eq!(documents[1].locale, "ru");
+        assert_eq!(documents[1].slug.as_deref(), Some("poisk"));
+
+        service
+            .delete_node(tenant_id, node.id, security)
+            .await
+            .unwrap();
+        forward_events(&indexer, &transport, tenant_id, "NodeDeleted").await;
+        assert_eq!(search.document_count(), 0);
+    });
+}
+
+#[test]
+fn test_node_unpublish_removes_it_from_search_index() {
+    run_async_test(|| async {
+        let db = setup_content_test_db().await;
+        ensure_content_schema(&db).await;
+        let transport = Arc::new(MockEventTransport::new());
+        let event_bus = TransactionalEventBus::new(transport.clone());
+        let service = NodeService::new(db.clone(), event_bus);
+        let search = Arc::new(MockSearchBackend::new());
+        let indexer = NodeSearchIndexer::new(db.clone(), search.clone());
+
+        let tenant_id = Uuid::new_v4();
+        let security = SecurityContext::new(UserRole::Admin, Some(Uuid::new_v4()));
+
+        let input = CreateNodeInput {
+            kind: "post".to_string(),
+            translations: vec![NodeTranslationInput {
+                locale: "en".to_string(),
+                title: Some("Short-lived".to_string()),
+                slug: Some("short-lived".to_string()),
+                excerpt: None,
+            }],
+            bodies: vec![],
+            status: Some(ContentStatus::Published),
+            parent_id: None,
+            author_id: None,
+            category_id: None,
+            position: None,
+            depth: None,
+            reply_count: None,
+            metadata: serde_json::json!({}),
+        };
+
+        let node = service
+            .create_node(tenant_id, security.clone(), input)
+            .await
+            .unwrap();
+        forward_events(&indexer, &transport, tenant_id, "NodeCreated").await;
+        assert!(search.is_indexed(node.id));
+
+        service
+            .unpublish_node(tenant_id, node.id, security)
+            .await
+            .unwrap();
+        forward_events(&indexer, &transport, tenant_id, "NodeUnpublished").await;
+        assert!(!search.is_indexed(node.id));
+    });
+}
@@ -3,16 +3,22 @@
 // and multi-language support for content nodes.
 
 use rustok_content::dto::{
-    BodyInput, CreateNodeInput, ListNodesFilter, NodeTranslationInput, UpdateNodeInput,
+    BodyInput, CreateNodeInput, ListNodesFilter, NodeTranslationInput, SlugLookup, UpdateNodeInput,
 };
 use rustok_content::entities::node::ContentStatus;
-use rustok_content::services::NodeService;
+use rustok_content::services::{node_cache_prefix, NodeIsolationProbe, NodeService};
 use rustok_content::ContentError;
+use rustok_core::security::{
+    AsyncSecurityCheck, CrossTenantProbe, SecurityConfig, TenantIsolationCheck,
+};
+use rustok_core::{CacheBackend, InMemoryCacheBackend};
 use rustok_test_utils::{
     db::setup_test_db, helpers::admin_context, helpers::customer_context, helpers::manager_context,
     helpers::unique_slug, mock_transactional_event_bus,
 };
 use sea_orm::{ConnectionTrait, DatabaseConnection, DbBackend, Statement};
+use std::sync::Arc;
+use std::time::Duration;
 use uuid::Uuid;
 
 async fn ensure_content_schema(db: &DatabaseConnection) {
@@ -78,6 +84,22 @@ async fn ensure_content_schema(db: &DatabaseConnection) {
     ))
     .await
     .expect("failed to create content bodies test table");
+
+    db.execute(Statement::from_string(
+        DbBackend::Sqlite,
+        "CREATE TABLE IF NOT EXISTS slug_redirects (
+            id TEXT PRIMARY KEY,
+            tenant_id TEXT NOT NULL,
+            kind TEXT NOT NULL,
+            node_id TEXT NOT NULL,
+            locale TEXT NOT NULL,
+            old_slug TEXT NOT NULL,
+            created_at TEXT NOT NULL
+        )"
+        .to_string(),
+    ))
+    .await
+    .expect("failed to create content slug_redirects test table");
 }
 
 async fn setup() -> (DatabaseConnection, NodeService) {
@@ -230,6 +252,97 @@ async fn test_update_node_success() {
     assert_eq!(updated.status, ContentStatus::Published);
 }
 
+#[tokio::test]
+async fn test_update_node_invalidates_node_cache() {
+    let (db, _) = setup().await;
+    let cache = Arc::new(InMemoryCacheBackend::new(Duration::from_secs(300), 100));
+    let service = NodeService::new(db, mock_transactional_event_bus()).with_cache(cache.clone());
+    let tenant_id = Uuid::new_v4();
+    let security = admin_context();
+
+    let node = service
+        .create_node(tenant_id, security.clone(), create_test_input())
+        .await
+        .unwrap();
+    let body_key = format!("{}body", node_cache_prefix(node.id));
+    let other_key = format!("{}body", node_cache_prefix(Uuid::new_v4()));
+    for key in [&body_key, &other_key] {
+        cache.set(key.clone(), b"cached".to_vec()).await.unwrap();
+    }
+
+    service
+        .update_node(
+            tenant_id,
+            node.id,
+            security,
+            UpdateNodeInput {
+                status: Some(ContentStatus::Published),
+                ..UpdateNodeInput::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(cache.get(&body_key).await.unwrap(), None);
+    assert!(cache.get(&other_key).await.unwrap().is_some());
+}
+
+#[tokio::test]
+async fn test_status_changes_and_deletes_invalidate_node_cache() {
+    let (db, _) = setup().await;
+    let cache = Arc::new(InMemoryCacheBackend::new(Duration::from_secs(300), 100));
+    let service = NodeService::new(db, mock_transactional_event_bus()).with_cache(cache.clone());
+    let tenant_id = Uuid::new_v4();
+    let security = admin_context();
+
+    let node = service
+        .create_node(tenant_id, security.clone(), create_test_input())
+        .await
+        .unwrap();
+    let body_key = format!("{}body", node_cache_prefix(node.id));
+    let cache_body = || async {
+        cache
+            .set(body_key.clone(), b"cached".to_vec())
+            .await
+            .unwrap();
+    };
+
+    cache_body().await;
+    service
+        .publish_node(tenant_id, node.id, security.clone())
+        .await
+        .unwrap();
+    assert_eq!(cache.get(&body_key).await.unwrap(), None, "publish");
+
+    cache_body().await;
+    service
+        .unpublish_node(tenant_id, node.id, security.clone())
+        .await
+        .unwrap();
+    assert_eq!(cache.get(&body_key).await.unwrap(), None, "unpublish");
+
+    cache_body().await;
+    service
+        .delete_node(tenant_id, node.id, security.clone())
+        .await
+        .unwrap();
+    assert_eq!(cache.get(&body_key).await.unwrap(), None, "delete");
+
+    cache_body().await;
+    service
+        .restore_node(tenant_id, node.id, security.clone())
+        .await
+        .unwrap();
+    assert_eq!(cache.get(&body_key).await.unwrap(), None, "restore");
+
+    cache_body().await;
+    service
+        .hard_delete_node(tenant_id, node.id, security)
+        .await
+        .unwrap();
+    assert_eq!(cache.get(&body_key).await.unwrap(), None, "hard delete");
+}
+
 #[tokio::test]
 async fn test_delete_node_success() {
     let (_db, service) = setup().await;
@@ -320,6 +433,128 @@ async fn test_get_by_slug_success() {
     assert_eq!(node.translations[0].slug, Some(slug));
 }
 
+fn rename_input(slug: &str) -> UpdateNodeInput {
+    UpdateNodeInput {
+        translations: Some(vec![NodeTranslationInput {
+            locale: "en".to_string(),
+            title: Some("Renamed Post".to_string()),
+            slug: Some(slug.to_string()),
+            excerpt: None,
+        }]),
+        ..UpdateNodeInput::default()
+    }
+}
+
+#[tokio::test]
+async fn test_slug_rename_redirects_old_slug_to_new_one() {
+    let (_db, service) = setup().await;
+    let tenant_id = Uuid::new_v4();
+    let security = admin_context();
+
+    let old_slug = unique_slug("old-post");
+    let new_slug = unique_slug("new-post");
+    let mut input = create_test_input();
+    input.translations[0].slug = Some(old_slug.clone());
+    let created = service
+        .create_node(tenant_id, security.clone(), input)
+        .await
+        .unwrap();
+
+    service
+        .update_node(tenant_id, created.id, security, rename_input(&new_slug))
+        .await
+        .unwrap();
+
+    assert!(service
+        .get_by_slug(tenant_id, "post", "en", &old_slug)
+        .await
+        .unwrap()
+        .is_none());
+
+    match service
+        .get_by_slug_or_redirect(tenant_id, "post", "en", &old_slug)
+        .await
+        .unwrap()
+    {
+        Some(SlugLookup::Redirect(redirect)) => {
+            assert_eq!(redirect.node_id, created.id);
+            assert_eq!(redirect.from_slug, old_slug);
+            assert_eq!(redirect.to_slug, new_slug);
+            assert_eq!(redirect.status, 301);
+        }
+        other => panic!("Expected redirect, got {other:?}"),
+    }
+
+    assert!(matches!(
+        service
+            .get_by_slug_or_redirect(tenant_id, "post", "en", &new_slug)
+            .await
+            .unwrap(),
+        Some(SlugLookup::Found(node)) if node.id == created.id
+    ));
+    assert!(service
+        .find_slug_redirect(tenant_id, "post", "ru", &old_slug)
+        .await
+        .unwrap()
+        .is_none());
+    assert!(service
+        .find_slug_redirect(Uuid::new_v4(), "post", "en", &old_slug)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_slug_redirect_follows_repeated_renames_and_reclaims() {
+    let (_db, service) = setup().await;
+    let tenant_id = Uuid::new_v4();
+    let security = admin_context();
+
+    let first = unique_slug("first");
+    let second = unique_slug("second");
+    let third = unique_slug("third");
+    let mut input = create_test_input();
+    input.translations[0].slug = Some(first.clone());
+    let created = service
+        .create_node(tenant_id, security.clone(), input)
+        .await
+        .unwrap();
+
+    for slug in [&second, &third] {
+        service
+            .update_node(tenant_id, created.id, security.clone(), rename_input(slug))
+            .await
+            .unwrap();
+    }
+
+    let redirect = service
+        .find_slug_redirect(tenant_id, "post", "en", &first)
+        .await
+        .unwrap()
+        .expect("first slug should still redirect");
+    assert_eq!(redirect.to_slug, third);
+
+    service
+        .update_node(tenant_id, created.id, security, rename_input(&first))
+        .await
+        .unwrap();
+
+    assert!(service
+        .find_slug_redirect(tenant_id, "post", "en", &first)
+        .await
+        .unwrap()
+        .is_none());
+    assert_eq!(
+        service
+            .find_slug_redirect(tenant_id, "post", "en", &third)
+            .await
+            .unwrap()
+            .expect("third slug should redirect back")
+            .to_slug,
+        first
+    );
+}
+
 // =============================================================================
 // Content Status & Publishing Tests
 // =============================================================================
@@ -1326,3 +1561,144 @@ async fn test_metadata_depth_guard_allows_shallow_json() {
     let result = service.create_node(tenant_id, admin, input).await;
     assert!(result.is_ok(), "Shallow metadata must be accepted");
 }
+
+// =============================================================================
+// Localization Report Tests
+// =============================================================================
+
+async fn seed_tenant_locales(
+    db: &DatabaseConnection,
+    tenant_id: Uuid,
+    locales: &[(&str, bool, bool)],
+) {
+    db.execute(Statement::from_string(
+        DbBackend::Sqlite,
+        "CREATE TABLE IF NOT EXISTS tenant_locales (
+            id TEXT PRIMARY KEY,
+            tenant_id TEXT NOT NULL,
+            locale TEXT NOT NULL,
+            name TEXT NOT NULL,
+            native_name TEXT NULL,
+            is_default BOOLEAN NOT NULL,
+            is_enabled BOOLEAN NOT NULL,
+            fallback_locale TEXT NULL,
+            created_at TEXT NOT NULL
+        )"
+        .to_string(),
+    ))
+    .await
+    .expect("failed to create tenant_locales test table");
+
+    for (locale, is_default, is_enabled) in locales {
+        db.execute(Statement::from_sql_and_values(
+            DbBackend::Sqlite,
+            "INSERT INTO tenant_locales (id, tenant_id, locale, name, is_default, is_enabled, created_at)
+             VALUES (?, ?, ?, ?, ?, ?, datetime('now'))",
+            [
+                Uuid::new_v4().into(),
+                tenant_id.into(),
+                (*locale).into(),
+                (*locale).into(),
+                (*is_default).into(),
+                (*is_enabled).into(),
+            ],
+        ))
+        .await
+        .expect("failed to seed tenant locale");
+    }
+}
+
+#[tokio::test]
+async fn test_localization_report_flags_missing_enabled_locales() {
+    let (db, service) = setup().await;
+    let tenant_id = Uuid::new_v4();
+    seed_tenant_locales(
+        &db,
+        tenant_id,
+        &[
+            ("en", true, true),
+            ("ru", false, true),
+            ("de", false, true),
+            ("fr", false, false),
+        ],
+    )
+    .await;
+
+    let node = service
+        .create_node(tenant_id, admin_context(), create_test_input())
+        .await
+        .unwrap();
+
+    let report = service.localization_report(tenant_id).await.unwrap();
+
+    assert_eq!(report.locales, vec!["en", "de", "ru"]);
+    assert_eq!(report.nodes.len(), 1);
+    let status = &report.nodes[0];
+    assert_eq!(status.node_id, node.id);
+    assert_eq!(status.missing_translations, vec!["de", "ru"]);
+    assert_eq!(status.missing_bodies, vec!["de", "ru"]);
+    assert!(status.locales[0].has_translation && status.locales[0].has_body);
+    assert!(
+        status.locales.iter().all(|entry| entry.locale != "fr"),
+        "Disabled locales must not appear in the report"
+    );
+}
+
+#[tokio::test]
+async fn test_localization_report_separates_translation_and_body_gaps() {
+    let (db, service) = setup().await;
+    let tenant_id = Uuid::new_v4();
+    seed_tenant_locales(&db, tenant_id, &[("en", true, true), ("ru", false, true)]).await;
+
+    let mut input = create_test_input();
+    input.translations.push(NodeTranslationInput {
+        locale: "ru".to_string(),
+        title: Some("Тестовый пост".to_string()),
+        slug: Some(unique_slug("test-post-ru")),
+        excerpt: None,
+    });
+    service
+        .create_node(tenant_id, admin_context(), input)
+        .await
+        .unwrap();
+
+    let report = service.localization_report(tenant_id).await.unwrap();
+
+    let status = &report.nodes[0];
+    assert!(status.missing_translations.is_empty());
+    assert_eq!(status.missing_bodies, vec!["ru"]);
+}
+
+#[tokio::test]
+async fn test_node_isolation_probe_finds_no_foreign_nodes() {
+    let (db, service) = setup().await;
+    let tenant_a = Uuid::new_v4();
+    let tenant_b = Uuid::new_v4();
+    for tenant_id in [tenant_a, tenant_b] {
+        service
+            .create_node(tenant_id, admin_context(), create_test_input())
+            .await
+            .unwrap();
+    }
+
+    let probe = NodeIsolationProbe::new(service);
+    assert_eq!(probe.read_foreign(&db, tenant_a, tenant_b).await, Ok(0));
+    assert_eq!(probe.read_foreign(&db, tenant_b, tenant_a).await, Ok(0));
+
+    let findings = TenantIsolationCheck::new(tenant_a, tenant_b)
+        .with_probe(probe)
+        .execute(&SecurityConfig::default(), &db)
+        .await;
+    assert!(findings.is_empty(), "{findings:?}");
+}
+
+#[tokio::test]
+async fn test_node_isolation_probe_fails_without_seeded_nodes() {
+    let (db, service) = setup().await;
+
+    let result = NodeIsolationProbe::new(service)
+        .read_foreign(&db, Uuid::new_v4(), Uuid::new_v4())
+        .await;
+
+    assert!(result.unwrap_err().contains("no seeded nodes"));
+}
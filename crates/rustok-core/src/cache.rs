@@ -147,6 +147,19 @@ impl CacheBackend for InMemoryCacheBackend {
         Ok(())
     }
 
+    async fn invalidate_prefix(&self, prefix: &str) -> Result<u64> {
+        let keys: Vec<_> = self
+            .cache
+            .iter()
+            .filter(|(key, _)| key.starts_with(prefix))
+            .map(|(key, _)| key)
+            .collect();
+        for key in &keys {
+            self.cache.invalidate(key.as_str()).await;
+        }
+        Ok(keys.len() as u64)
+    }
+
     fn stats(&self) -> CacheStats {
         CacheStats {
             entries: self.cache.iter().count() as u64,
@@ -262,11 +275,65 @@ impl CacheBackend for RedisCacheBackend {
             })
     }
 
+    async fn invalidate_prefix(&self, prefix: &str) -> Result<u64> {
+        let mut manager = self.manager.clone();
+        let pattern = format!("{}*", escape_glob(&self.key(prefix)));
+
+        self.circuit_breaker
+            .call(|| async move {
+                let mut cursor = 0_u64;
+                let mut removed = 0_u64;
+                loop {
+                    let (next, keys): (u64, Vec<String>) = redis::cmd("SCAN")
+                        .arg(cursor)
+                        .arg("MATCH")
+                        .arg(&pattern)
+                        .arg("COUNT")
+                        .arg(500)
+                        .query_async(&mut manager)
+                        .await
+                        .map_err(|err| crate::Error::Cache(err.to_string()))?;
+                    if !keys.is_empty() {
+                        removed += redis::cmd("DEL")
+                            .arg(keys)
+                            .query_async::<u64>(&mut manager)
+                            .await
+                            .map_err(|err| crate::Error::Cache(err.to_string()))?;
+                    }
+                    if next == 0 {
+                        return Ok::<u64, crate::Error>(removed);
+                    }
+                    cursor = next;
+                }
+            })
+            .await
+            .map_err(|e| match e {
+                CircuitBreakerError::Open => {
+                    tracing::debug!("Redis cache prefix DEL failed: circuit breaker open");
+                    crate::Error::Cache("Redis unavailable (circuit breaker open)".to_string())
+                }
+                CircuitBreakerError::Upstream(err) => err,
+            })
+    }
+
     fn stats(&self) -> CacheStats {
         CacheStats::default()
     }
 }
 
+/// Escapes the characters `SCAN MATCH` treats as glob syntax.
+#[cfg(feature = "redis-cache")]
+fn escape_glob(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for ch in value.chars() {
+        if matches!(ch, '*' | '?' | '[' | ']' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(ch);
+    }
+    escaped
+}
+
 /// `FallbackCacheBackend` wraps a primary `CacheBackend` (e.g. Redis) with an in-memory
 /// fallback. When the primary backend returns a `Cache` error (e.g. circuit breaker open),
 /// reads are served from the in-memory cache and writes go to both backends so the in-memory
@@ -353,6 +420,18 @@ impl CacheBackend for FallbackCacheBackend {
         }
     }
 
+    async fn invalidate_prefix(&self, prefix: &str) -> Result<u64> {
+        let fallback_removed = self.fallback.invalidate_prefix(prefix).await.unwrap_or(0);
+
+        match self.primary.invalidate_prefix(prefix).await {
+            Ok(removed) => Ok(removed),
+            Err(e) => {
+                tracing::debug!(error = %e, prefix, "Primary cache prefix INVALIDATE failed, in-memory entries removed");
+                Ok(fallback_removed)
+            }
+        }
+    }
+
     fn stats(&self) -> CacheStats {
         self.primary.stats()
     }
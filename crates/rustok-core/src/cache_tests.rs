@@ -111,8 +111,9 @@ mod redis_circuit_breaker_tests {
 
 #[cfg(test)]
 mod in_memory_cache_tests {
-    use crate::context::CacheBackend;
+    use crate::context::{CacheBackend, CacheBackendExt};
     use crate::InMemoryCacheBackend;
+    use std::sync::atomic::{AtomicUsize, Ordering};
     use std::time::Duration;
 
     #[tokio::test]
@@ -211,4 +212,106 @@ mod in_memory_cache_tests {
         let expired = cache.get("default").await.unwrap();
         assert_eq!(expired, None);
     }
+
+    #[tokio::test]
+    async fn test_in_memory_cache_invalidates_by_prefix() {
+        let cache = InMemoryCacheBackend::new(Duration::from_secs(300), 100);
+        let node = "node:42:";
+        for key in [
+            "node:42:body",
+            "node:42:translations",
+            "node:42:seo",
+            "node:420:body",
+        ] {
+            cache.set(key.to_string(), b"value".to_vec()).await.unwrap();
+        }
+
+        let removed = cache.invalidate_prefix(node).await.unwrap();
+
+        assert_eq!(removed, 3);
+        for key in ["node:42:body", "node:42:translations", "node:42:seo"] {
+            assert_eq!(cache.get(key).await.unwrap(), None);
+        }
+        assert!(cache.get("node:420:body").await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_get_or_compute_runs_closure_once_within_ttl() {
+        let cache = InMemoryCacheBackend::new(Duration::from_secs(300), 100);
+        let calls = AtomicUsize::new(0);
+        let compute = || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Ok(vec!["news".to_string(), "guides".to_string()])
+        };
+
+        let first: Vec<String> = cache
+            .get_or_compute("categories", Duration::from_secs(60), compute)
+            .await
+            .unwrap();
+        let second: Vec<String> = cache
+            .get_or_compute("categories", Duration::from_secs(60), compute)
+            .await
+            .unwrap();
+
+        assert_eq!(first, second);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    /// Reads from memory, rejects every write.
+    struct ReadOnlyCache(InMemoryCacheBackend);
+
+    #[async_trait::async_trait]
+    impl CacheBackend for ReadOnlyCache {
+        async fn health(&self) -> crate::Result<()> {
+            self.0.health().await
+        }
+
+        async fn get(&self, key: &str) -> crate::Result<Option<Vec<u8>>> {
+            self.0.get(key).await
+        }
+
+        async fn set(&self, _key: String, _value: Vec<u8>) -> crate::Result<()> {
+            Err(crate::Error::Cache("cache is read-only".to_string()))
+        }
+
+        async fn set_with_ttl(
+            &self,
+            _key: String,
+            _value: Vec<u8>,
+            _ttl: Duration,
+        ) -> crate::Result<()> {
+            Err(crate::Error::Cache("cache is read-only".to_string()))
+        }
+
+        async fn invalidate(&self, key: &str) -> crate::Result<()> {
+            self.0.invalidate(key).await
+        }
+
+        async fn invalidate_prefix(&self, prefix: &str) -> crate::Result<u64> {
+            self.0.invalidate_prefix(prefix).await
+        }
+
+        fn stats(&self) -> crate::cache::CacheStats {
+            self.0.stats()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_or_compute_returns_value_when_caching_fails() {
+        let cache = ReadOnlyCache(InMemoryCacheBackend::new(Duration::from_secs(300), 100));
+        let calls = AtomicUsize::new(0);
+        let compute = || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Ok(42_u64)
+        };
+
+        for _ in 0..2 {
+            let value: u64 = cache
+                .get_or_compute("answer", Duration::from_secs(60), compute)
+                .await
+                .unwrap();
+            assert_eq!(value, 42);
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
 }
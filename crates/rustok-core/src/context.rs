@@ -1,8 +1,13 @@
+use std::collections::{BTreeMap, HashMap};
+use std::future::Future;
 use std::sync::Arc;
 use std::time::Duration;
 
 use async_trait::async_trait;
 use sea_orm::DatabaseConnection;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 use crate::cache::CacheStats;
 use crate::events::EventTransport;
@@ -15,12 +20,88 @@ pub trait CacheBackend: Send + Sync {
     async fn set(&self, key: String, value: Vec<u8>) -> Result<()>;
     async fn set_with_ttl(&self, key: String, value: Vec<u8>, ttl: Duration) -> Result<()>;
     async fn invalidate(&self, key: &str) -> Result<()>;
+    /// Removes every key starting with `prefix` (e.g. `node:{id}:`) and returns how many
+    /// were removed.
+    async fn invalidate_prefix(&self, prefix: &str) -> Result<u64>;
     fn stats(&self) -> CacheStats;
 }
 
+/// Typed helpers over any [`CacheBackend`], including `dyn CacheBackend`.
+#[async_trait]
+pub trait CacheBackendExt: CacheBackend {
+    /// Returns the JSON-cached value under `key`, or runs `compute` and caches its result for
+    /// `ttl`. Errors from `compute` are returned and nothing is cached; an entry that no
+    /// longer deserializes as `T` is recomputed. A failure to store the computed value is
+    /// only logged, since the value itself is still good.
+    async fn get_or_compute<T, F, Fut>(&self, key: &str, ttl: Duration, compute: F) -> Result<T>
+    where
+        T: Serialize + DeserializeOwned + Send,
+        F: FnOnce() -> Fut + Send,
+        Fut: Future<Output = Result<T>> + Send,
+    {
+        if let Some(bytes) = self.get(key).await? {
+            match serde_json::from_slice(&bytes) {
+                Ok(value) => return Ok(value),
+                Err(error) => {
+                    tracing::debug!(%error, key, "Cached value does not match, recomputing");
+                }
+            }
+        }
+
+        let value = compute().await?;
+        let stored = match serde_json::to_vec(&value) {
+            Ok(bytes) => self.set_with_ttl(key.to_string(), bytes, ttl).await,
+            Err(error) => Err(error.into()),
+        };
+        if let Err(error) = stored {
+            tracing::warn!(%error, key, "Failed to cache computed value");
+        }
+        Ok(value)
+    }
+}
+
+impl<C: CacheBackend + ?Sized> CacheBackendExt for C {}
+
+/// One locale of an entity as stored in a search index.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SearchDocument {
+    pub tenant_id: Uuid,
+    pub document_id: Uuid,
+    pub entity_type: String,
+    pub locale: String,
+    pub title: String,
+    pub slug: Option<String>,
+    pub body: String,
+    /// Filterable attributes such as `category` or `status`, counted by
+    /// [`SearchBackend::search_faceted`].
+    #[serde(default)]
+    pub facets: BTreeMap<String, String>,
+}
+
+/// Hits of a faceted search plus, per requested facet, `(value, count)` pairs ordered by
+/// count, highest first. Counts are per document, not per indexed locale.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FacetedResults {
+    pub hits: Vec<SearchDocument>,
+    pub facets: HashMap<String, Vec<(String, u64)>>,
+}
+
 #[async_trait]
 pub trait SearchBackend: Send + Sync {
     async fn health(&self) -> Result<()>;
+    /// Inserts or replaces the document for its `(document_id, locale)` pair.
+    async fn index(&self, document: SearchDocument) -> Result<()>;
+    /// Drops every locale indexed for `document_id`.
+    async fn remove(&self, tenant_id: Uuid, document_id: Uuid) -> Result<()>;
+    /// Searches `tenant_id`'s documents of entity type `index` and counts the hits per
+    /// value of each of `facets`; an empty `query` matches every document.
+    async fn search_faceted(
+        &self,
+        tenant_id: Uuid,
+        index: &str,
+        query: &str,
+        facets: &[String],
+    ) -> Result<FacetedResults>;
 }
 
 pub struct AppContext {
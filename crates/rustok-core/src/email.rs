@@ -0,0 +1,301 @@
+//! Localized email templates.
+//!
+//! A [`Template`] holds a subject, HTML body and text body per locale. Bodies use
+//! `{{var}}` placeholders (dotted paths such as `{{order.number}}` reach into nested
+//! objects) that are filled from a JSON object at render time. A placeholder without
+//! a matching variable is an error rather than an empty string, so a broken template
+//! never reaches a customer.
+//!
+//! Locale selection follows the same rules as content translations: the requested tag,
+//! then its primary language, then [`PLATFORM_FALLBACK_LOCALE`].
+
+use std::collections::HashMap;
+
+use serde_json::Value;
+use thiserror::Error;
+
+use crate::locale::{build_locale_candidates, normalize_locale_tag, PLATFORM_FALLBACK_LOCALE};
+use crate::utils::html_escape;
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum TemplateError {
+    #[error("Unknown email template '{0}'")]
+    UnknownTemplate(String),
+
+    #[error("Email template '{template}' has no variant for locale '{locale}'")]
+    MissingLocale { template: String, locale: String },
+
+    #[error("Email template '{template}' references missing variable '{variable}'")]
+    MissingVariable { template: String, variable: String },
+
+    #[error("Email template '{template}' is malformed: {message}")]
+    Syntax { template: String, message: String },
+}
+
+pub type TemplateResult<T> = std::result::Result<T, TemplateError>;
+
+/// Template sources for one locale.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TemplateContent {
+    pub subject: String,
+    pub html: String,
+    pub text: String,
+}
+
+impl TemplateContent {
+    pub fn new(
+        subject: impl Into<String>,
+        html: impl Into<String>,
+        text: impl Into<String>,
+    ) -> Self {
+        Self {
+            subject: subject.into(),
+            html: html.into(),
+            text: text.into(),
+        }
+    }
+}
+
+/// Output of [`Template::render`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderedTemplate {
+    /// Locale of the variant that was actually used.
+    pub locale: String,
+    pub subject: String,
+    pub html: String,
+    pub text: String,
+}
+
+/// A named email template with one variant per locale.
+#[derive(Debug, Clone)]
+pub struct Template {
+    name: String,
+    variants: HashMap<String, TemplateContent>,
+}
+
+impl Template {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            variants: HashMap::new(),
+        }
+    }
+
+    /// Adds or replaces the variant for `locale`; invalid locale tags are ignored.
+    pub fn with_locale(mut self, locale: &str, content: TemplateContent) -> Self {
+        if let Some(locale) = normalize_locale_tag(locale) {
+            self.variants.insert(locale, content);
+        }
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Locale of the variant used for `requested`, if any.
+    pub fn resolve_locale(&self, requested: &str) -> Option<&str> {
+        build_locale_candidates([Some(requested), Some(PLATFORM_FALLBACK_LOCALE)], true)
+            .into_iter()
+            .find_map(|candidate| {
+                self.variants
+                    .get_key_value(candidate.as_str())
+                    .map(|(locale, _)| locale.as_str())
+            })
+    }
+
+    /// Renders the variant for `locale`; values inserted into the HTML body are escaped.
+    pub fn render(&self, locale: &str, vars: &Value) -> TemplateResult<RenderedTemplate> {
+        let resolved = self
+            .resolve_locale(locale)
+            .ok_or_else(|| TemplateError::MissingLocale {
+                template: self.name.clone(),
+                locale: locale.to_string(),
+            })?;
+        let content = &self.variants[resolved];
+
+        Ok(RenderedTemplate {
+            locale: resolved.to_string(),
+            subject: self.interpolate(&content.subject, vars, false)?,
+            html: self.interpolate(&content.html, vars, true)?,
+            text: self.interpolate(&content.text, vars, false)?,
+        })
+    }
+
+    fn interpolate(&self, source: &str, vars: &Value, escape_html: bool) -> TemplateResult<String> {
+        let mut output = String::with_capacity(source.len());
+        let mut rest = source;
+
+        while let Some(start) = rest.find("{{") {
+            output.push_str(&rest[..start]);
+            let after_open = &rest[start + 2..];
+            let end = after_open.find("}}").ok_or_else(|| TemplateError::Syntax {
+                template: self.name.clone(),
+                message: "unclosed '{{'".to_string(),
+            })?;
+            let variable = after_open[..end].trim();
+            if variable.is_empty() {
+                return Err(TemplateError::Syntax {
+                    template: self.name.clone(),
+                    message: "empty placeholder".to_string(),
+                });
+            }
+
+            let value = lookup(vars, variable).ok_or_else(|| TemplateError::MissingVariable {
+                template: self.name.clone(),
+                variable: variable.to_string(),
+            })?;
+            let value = match value {
+                Value::String(value) => value.clone(),
+                Value::Null => String::new(),
+                other => other.to_string(),
+            };
+            if escape_html {
+                output.push_str(&html_escape(&value));
+            } else {
+                output.push_str(&value);
+            }
+
+            rest = &after_open[end + 2..];
+        }
+        output.push_str(rest);
+
+        Ok(output)
+    }
+}
+
+fn lookup<'a>(vars: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.')
+        .try_fold(vars, |value, segment| value.get(segment.trim()))
+}
+
+/// Named templates, looked up by id such as `commerce/order_placed`.
+#[derive(Debug, Clone, Default)]
+pub struct TemplateRegistry {
+    templates: HashMap<String, Template>,
+}
+
+impl TemplateRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the template, replacing any earlier one with the same name.
+    pub fn register(&mut self, template: Template) -> &mut Self {
+        self.templates.insert(template.name.clone(), template);
+        self
+    }
+
+    pub fn with(mut self, template: Template) -> Self {
+        self.register(template);
+        self
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Template> {
+        self.templates.get(name)
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.templates.contains_key(name)
+    }
+
+    pub fn render(
+        &self,
+        name: &str,
+        locale: &str,
+        vars: &Value,
+    ) -> TemplateResult<RenderedTemplate> {
+        self.get(name)
+            .ok_or_else(|| TemplateError::UnknownTemplate(name.to_string()))?
+            .render(locale, vars)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn order_confirmation() -> Template {
+        Template::new("commerce/order_placed")
+            .with_locale(
+                "en",
+                TemplateContent::new(
+                    "Order {{order.number}} confirmed",
+                    "<p>Hi {{customer}}, your total is {{order.total}} {{order.currency}}.</p>",
+                    "Hi {{customer}}, your total is {{order.total}} {{order.currency}}.",
+                ),
+            )
+            .with_locale(
+                "ru",
+                TemplateContent::new(
+                    "Заказ {{ order.number }} подтверждён",
+                    "<p>{{customer}}, сумма заказа: {{order.total}} {{order.currency}}.</p>",
+                    "{{customer}}, сумма заказа: {{order.total}} {{order.currency}}.",
+                ),
+            )
+    }
+
+    fn vars() -> Value {
+        json!({
+            "customer": "Anna <VIP>",
+            "order": { "number": "R-1001", "total": 4398, "currency": "RUB" }
+        })
+    }
+
+    #[test]
+    fn order_confirmation_renders_in_the_users_locale() {
+        let rendered = order_confirmation().render("ru-RU", &vars()).unwrap();
+
+        assert_eq!(rendered.locale, "ru");
+        assert_eq!(rendered.subject, "Заказ R-1001 подтверждён");
+        assert_eq!(rendered.text, "Anna <VIP>, сумма заказа: 4398 RUB.");
+        assert_eq!(
+            rendered.html,
+            "<p>Anna &lt;VIP&gt;, сумма заказа: 4398 RUB.</p>"
+        );
+    }
+
+    #[test]
+    fn unknown_locale_falls_back_to_platform_locale() {
+        let rendered = order_confirmation().render("de", &vars()).unwrap();
+
+        assert_eq!(rendered.locale, PLATFORM_FALLBACK_LOCALE);
+        assert_eq!(rendered.subject, "Order R-1001 confirmed");
+    }
+
+    #[test]
+    fn missing_variable_is_an_error() {
+        let error = order_confirmation()
+            .render(
+                "en",
+                &json!({ "customer": "Anna", "order": { "number": "R-1" } }),
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            error,
+            TemplateError::MissingVariable {
+                template: "commerce/order_placed".to_string(),
+                variable: "order.total".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn registry_reports_unknown_templates_and_malformed_placeholders() {
+        let registry = TemplateRegistry::new().with(
+            Template::new("auth/welcome")
+                .with_locale("en", TemplateContent::new("Welcome {{name", "", "")),
+        );
+
+        assert!(matches!(
+            registry.render("auth/missing", "en", &json!({})),
+            Err(TemplateError::UnknownTemplate(_))
+        ));
+        assert!(matches!(
+            registry.render("auth/welcome", "en", &json!({ "name": "Anna" })),
+            Err(TemplateError::Syntax { .. })
+        ));
+    }
+}
@@ -183,6 +183,16 @@ pub enum Error {
     External(String),
 }
 
+impl Error {
+    /// Transient failures (database, cache, external service) that may succeed on retry.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            Error::Database(_) | Error::Cache(_) | Error::External(_)
+        )
+    }
+}
+
 // Conversion from old Error to RichError
 impl From<Error> for RichError {
     fn from(err: Error) -> Self {
@@ -80,6 +80,18 @@ impl EventBus {
         receiver
     }
 
+    /// Subscribes to the events matching `predicate` only, so a consumer such as an indexer
+    /// does not have to receive and ignore everything else.
+    pub fn subscribe_filtered(
+        &self,
+        predicate: impl Fn(&DomainEvent) -> bool + Send + Sync + 'static,
+    ) -> FilteredReceiver {
+        FilteredReceiver {
+            receiver: self.subscribe(),
+            predicate: Box::new(predicate),
+        }
+    }
+
     #[tracing::instrument(
         name = "eventbus.publish",
         skip(self, event),
@@ -170,6 +182,41 @@ impl EventBus {
     }
 }
 
+/// Receiver returned by [`EventBus::subscribe_filtered`]. Non-matching events are skipped
+/// inside [`FilteredReceiver::recv`]; they still count towards the channel's lag.
+pub struct FilteredReceiver {
+    receiver: broadcast::Receiver<EventEnvelope>,
+    predicate: Box<dyn Fn(&DomainEvent) -> bool + Send + Sync>,
+}
+
+impl FilteredReceiver {
+    pub async fn recv(&mut self) -> Result<EventEnvelope, broadcast::error::RecvError> {
+        loop {
+            let envelope = self.receiver.recv().await?;
+            if (self.predicate)(&envelope.event) {
+                return Ok(envelope);
+            }
+        }
+    }
+
+    pub fn try_recv(&mut self) -> Result<EventEnvelope, broadcast::error::TryRecvError> {
+        loop {
+            let envelope = self.receiver.try_recv()?;
+            if (self.predicate)(&envelope.event) {
+                return Ok(envelope);
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for FilteredReceiver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FilteredReceiver")
+            .field("receiver", &self.receiver)
+            .finish_non_exhaustive()
+    }
+}
+
 impl Default for EventBus {
     fn default() -> Self {
         Self::new()
@@ -185,3 +232,57 @@ impl Clone for EventBus {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node_event(event_type: &str) -> DomainEvent {
+        let node_id = Uuid::new_v4();
+        let kind = "article".to_string();
+        match event_type {
+            "node.created" => DomainEvent::NodeCreated {
+                node_id,
+                kind,
+                author_id: None,
+            },
+            "node.published" => DomainEvent::NodePublished { node_id, kind },
+            _ => DomainEvent::NodeDeleted { node_id, kind },
+        }
+    }
+
+    #[tokio::test]
+    async fn filtered_subscriber_sees_only_matching_events() {
+        let bus = EventBus::new();
+        let mut all = bus.subscribe();
+        let mut indexer = bus.subscribe_filtered(|event| {
+            matches!(
+                event,
+                DomainEvent::NodeCreated { .. } | DomainEvent::NodePublished { .. }
+            )
+        });
+
+        let tenant_id = Uuid::new_v4();
+        for event_type in [
+            "node.created",
+            "node.deleted",
+            "node.published",
+            "node.deleted",
+        ] {
+            bus.publish(tenant_id, None, node_event(event_type))
+                .unwrap();
+        }
+
+        let mut seen = Vec::new();
+        while let Ok(envelope) = indexer.try_recv() {
+            seen.push(envelope.event.event_type());
+        }
+        assert_eq!(seen, ["node.created", "node.published"]);
+
+        let mut unfiltered = 0;
+        while all.try_recv().is_ok() {
+            unfiltered += 1;
+        }
+        assert_eq!(unfiltered, 4);
+    }
+}
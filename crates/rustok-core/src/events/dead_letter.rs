@@ -0,0 +1,52 @@
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use super::EventEnvelope;
+
+/// An envelope a handler still failed on after its last retry.
+#[derive(Debug, Clone)]
+pub struct DeadLetter {
+    pub handler: &'static str,
+    pub envelope: EventEnvelope,
+    pub error: String,
+    pub attempts: usize,
+    pub failed_at: DateTime<Utc>,
+}
+
+/// Where [`EventDispatcher`](super::EventDispatcher) puts envelopes whose handler exhausted
+/// `DispatcherConfig::retry_count`, instead of dropping them.
+#[async_trait]
+pub trait DeadLetterSink: Send + Sync + std::fmt::Debug + 'static {
+    async fn store(&self, letter: DeadLetter);
+}
+
+/// Keeps dead letters in memory; meant for tests and local development.
+#[derive(Debug, Default)]
+pub struct MemoryDeadLetterSink {
+    letters: Mutex<Vec<DeadLetter>>,
+}
+
+impl MemoryDeadLetterSink {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn letters(&self) -> Vec<DeadLetter> {
+        self.letters
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+}
+
+#[async_trait]
+impl DeadLetterSink for MemoryDeadLetterSink {
+    async fn store(&self, letter: DeadLetter) {
+        self.letters
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .push(letter);
+    }
+}
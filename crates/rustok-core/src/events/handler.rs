@@ -1,4 +1,6 @@
 use async_trait::async_trait;
+use chrono::Utc;
+use std::collections::HashMap;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::time::Instant;
@@ -8,11 +10,16 @@ use tracing::{debug, error, info, warn, Instrument};
 
 use super::bus::EventBus;
 use super::consumer::EventConsumerRuntime;
+use super::dead_letter::{DeadLetter, DeadLetterSink};
+use super::handler_stats::{HandlerStats, HandlerStatsRegistry};
+use super::store::EventStore;
 use super::types::{DomainEvent, EventEnvelope};
 use crate::Error;
 
 pub type HandlerResult = Result<(), Error>;
 
+const REPLAY_BATCH_SIZE: usize = 100;
+
 #[async_trait]
 pub trait EventHandler: Send + Sync + 'static {
     fn name(&self) -> &'static str;
@@ -39,6 +46,8 @@ pub struct DispatcherConfig {
     pub retry_count: usize,
     pub retry_delay_ms: u64,
     pub max_queue_depth: usize,
+    /// Receives envelopes a handler still fails on after `retry_count` retries.
+    pub dead_letter: Option<Arc<dyn DeadLetterSink>>,
 }
 
 impl Default for DispatcherConfig {
@@ -49,6 +58,7 @@ impl Default for DispatcherConfig {
             retry_count: 0,
             retry_delay_ms: 100,
             max_queue_depth: 10000,
+            dead_letter: None,
         }
     }
 }
@@ -82,6 +92,19 @@ impl EventDispatcher {
         self
     }
 
+    /// Registers `handler` for the listed event types (as in [`DomainEvent::event_type`]) only;
+    /// the handler's own [`EventHandler::handles`] still applies on top.
+    pub fn register_for<H: EventHandler>(
+        &mut self,
+        event_types: &[&'static str],
+        handler: H,
+    ) -> &mut Self {
+        self.register(EventTypeFilter {
+            event_types: event_types.to_vec(),
+            inner: handler,
+        })
+    }
+
     pub fn register_boxed(&mut self, handler: Arc<dyn EventHandler>) -> &mut Self {
         info!(handler = handler.name(), "Registering event handler");
         self.handlers.push(handler);
@@ -99,6 +122,10 @@ impl EventDispatcher {
         let bus = self.bus.clone();
         let backpressure = bus.backpressure();
         let consumer_runtime = EventConsumerRuntime::new("event_dispatcher");
+        let stats = Arc::new(HandlerStatsRegistry::default());
+        let dispatch_stats = Arc::clone(&stats);
+        let replay_handlers = Arc::clone(&handlers);
+        let replay_config = config.clone();
 
         let handle = tokio::spawn(
             async move {
@@ -122,6 +149,7 @@ impl EventDispatcher {
                             let config = config.clone();
                             let semaphore = semaphore.clone();
                             let consumer_runtime = consumer_runtime;
+                            let stats = Arc::clone(&dispatch_stats);
 
                             tokio::spawn(
                                 async move {
@@ -132,6 +160,7 @@ impl EventDispatcher {
                                         semaphore,
                                         bp,
                                         consumer_runtime,
+                                        stats,
                                     )
                                     .await;
                                 }
@@ -151,7 +180,13 @@ impl EventDispatcher {
             .in_current_span(),
         );
 
-        RunningDispatcher { handle, bus }
+        RunningDispatcher {
+            handle,
+            bus,
+            handlers: replay_handlers,
+            config: replay_config,
+            stats,
+        }
     }
 
     async fn dispatch_to_handlers(
@@ -161,6 +196,7 @@ impl EventDispatcher {
         semaphore: Arc<Semaphore>,
         backpressure: Option<Arc<super::backpressure::BackpressureController>>,
         consumer_runtime: EventConsumerRuntime,
+        stats: Arc<HandlerStatsRegistry>,
     ) {
         let dispatch_started_at = Instant::now();
         let event_type = envelope.event.event_type().to_string();
@@ -191,7 +227,9 @@ impl EventDispatcher {
         if config.fail_fast {
             for handler in matching_handlers {
                 let envelope = envelope.clone();
-                if let Err(error) = Self::handle_with_retry(handler, envelope, &config).await {
+                if let Err(error) =
+                    Self::handle_with_retry(handler, envelope, &config, &stats).await
+                {
                     error!(
                         event_type = event_type.as_str(),
                         error = %error,
@@ -218,6 +256,7 @@ impl EventDispatcher {
             let bp = backpressure.clone();
             let count = Arc::clone(&completion_count);
             let event_type = event_type.clone();
+            let stats = Arc::clone(&stats);
 
             tokio::spawn(async move {
                 let _permit = permit;
@@ -255,7 +294,7 @@ impl EventDispatcher {
                     dispatch_started_at,
                 };
 
-                let _ = Self::handle_with_retry(handler, envelope, &config).await;
+                let _ = Self::handle_with_retry(handler, envelope, &config, &stats).await;
             });
         }
     }
@@ -264,14 +303,19 @@ impl EventDispatcher {
         handler: Arc<dyn EventHandler>,
         envelope: EventEnvelope,
         config: &DispatcherConfig,
+        stats: &HandlerStatsRegistry,
     ) -> Result<(), Error> {
         let mut attempts = 0;
         let max_attempts = config.retry_count + 1;
 
         loop {
             attempts += 1;
-            match handler.handle(&envelope).await {
+            let started_at = Instant::now();
+            let result = handler.handle(&envelope).await;
+            stats.record_call(handler.name(), started_at.elapsed());
+            match result {
                 Ok(()) => {
+                    stats.record_outcome(handler.name(), true);
                     debug!(
                         handler = handler.name(),
                         event_type = envelope.event.event_type(),
@@ -288,12 +332,24 @@ impl EventDispatcher {
                             error = %error,
                             "Handler failed, retrying"
                         );
+                        stats.record_retry(handler.name());
                         tokio::time::sleep(tokio::time::Duration::from_millis(
                             config.retry_delay_ms,
                         ))
                         .await;
                     } else {
+                        stats.record_outcome(handler.name(), false);
                         handler.on_error(&envelope, &error).await;
+                        if let Some(sink) = &config.dead_letter {
+                            sink.store(DeadLetter {
+                                handler: handler.name(),
+                                envelope,
+                                error: error.to_string(),
+                                attempts,
+                                failed_at: Utc::now(),
+                            })
+                            .await;
+                        }
                         return Err(error);
                     }
                 }
@@ -302,9 +358,36 @@ impl EventDispatcher {
     }
 }
 
+struct EventTypeFilter<H> {
+    event_types: Vec<&'static str>,
+    inner: H,
+}
+
+#[async_trait]
+impl<H: EventHandler> EventHandler for EventTypeFilter<H> {
+    fn name(&self) -> &'static str {
+        self.inner.name()
+    }
+
+    fn handles(&self, event: &DomainEvent) -> bool {
+        self.event_types.contains(&event.event_type()) && self.inner.handles(event)
+    }
+
+    async fn handle(&self, envelope: &EventEnvelope) -> HandlerResult {
+        self.inner.handle(envelope).await
+    }
+
+    async fn on_error(&self, envelope: &EventEnvelope, error: &Error) {
+        self.inner.on_error(envelope, error).await
+    }
+}
+
 pub struct RunningDispatcher {
     handle: JoinHandle<()>,
     bus: EventBus,
+    handlers: Arc<Vec<Arc<dyn EventHandler>>>,
+    config: DispatcherConfig,
+    stats: Arc<HandlerStatsRegistry>,
 }
 
 impl RunningDispatcher {
@@ -312,6 +395,48 @@ impl RunningDispatcher {
         &self.bus
     }
 
+    /// Counters per handler name, for the handlers that have seen at least one event.
+    pub fn handler_stats(&self) -> HashMap<String, HandlerStats> {
+        self.stats.snapshot()
+    }
+
+    /// Feeds the events stored from `from_offset` onwards through the registered handlers,
+    /// one at a time and in order, without publishing them on the bus; retries, dead letters
+    /// and handler stats apply as for live events. Returns how many events were replayed.
+    pub async fn replay(&self, store: &dyn EventStore, from_offset: u64) -> crate::Result<u64> {
+        let mut offset = from_offset;
+        loop {
+            let batch = store.read_from(offset, REPLAY_BATCH_SIZE).await?;
+            if batch.is_empty() {
+                break;
+            }
+            offset += batch.len() as u64;
+
+            for envelope in batch {
+                let matching = self
+                    .handlers
+                    .iter()
+                    .filter(|handler| handler.handles(&envelope.event));
+                for handler in matching {
+                    let _ = EventDispatcher::handle_with_retry(
+                        Arc::clone(handler),
+                        envelope.clone(),
+                        &self.config,
+                        &self.stats,
+                    )
+                    .await;
+                }
+            }
+        }
+
+        info!(
+            from_offset,
+            replayed = offset - from_offset,
+            "Event replay finished"
+        );
+        Ok(offset - from_offset)
+    }
+
     pub fn stop(self) {
         self.handle.abort();
     }
@@ -375,3 +500,184 @@ macro_rules! event_handler {
         $crate::events::handler::HandlerBuilder::new($name, $predicate, $handler)
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::events::{MemoryDeadLetterSink, MemoryEventStore};
+
+    #[test]
+    fn register_for_limits_handler_to_listed_event_types() {
+        let mut dispatcher = EventDispatcher::new(EventBus::new());
+        dispatcher.register_for(
+            &["node.created", "node.published"],
+            HandlerBuilder::new("indexer", |_| true, |_| async { Ok(()) }),
+        );
+
+        let node_id = Uuid::new_v4();
+        let kind = "article".to_string();
+        let handler = &dispatcher.handlers[0];
+        assert_eq!(handler.name(), "indexer");
+        assert!(handler.handles(&DomainEvent::NodePublished {
+            node_id,
+            kind: kind.clone(),
+        }));
+        assert!(!handler.handles(&DomainEvent::NodeDeleted { node_id, kind }));
+    }
+
+    #[tokio::test]
+    async fn exhausted_retries_land_in_dead_letter_sink() {
+        let sink = Arc::new(MemoryDeadLetterSink::new());
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let bus = EventBus::new();
+        let mut dispatcher = EventDispatcher::with_config(
+            bus.clone(),
+            DispatcherConfig {
+                retry_count: 2,
+                retry_delay_ms: 1,
+                dead_letter: Some(sink.clone()),
+                ..DispatcherConfig::default()
+            },
+        );
+        let counter = Arc::clone(&attempts);
+        dispatcher.register(HandlerBuilder::new(
+            "always_fails",
+            |_| true,
+            move |_| {
+                counter.fetch_add(1, Ordering::Relaxed);
+                async { Err(Error::External("search index unavailable".to_string())) }
+            },
+        ));
+        let running = dispatcher.start();
+
+        let event = DomainEvent::NodeCreated {
+            node_id: Uuid::new_v4(),
+            kind: "article".to_string(),
+            author_id: None,
+        };
+        bus.publish(Uuid::new_v4(), None, event).unwrap();
+
+        let letters = tokio::time::timeout(Duration::from_secs(5), async {
+            loop {
+                let letters = sink.letters();
+                if !letters.is_empty() {
+                    return letters;
+                }
+                tokio::time::sleep(Duration::from_millis(5)).await;
+            }
+        })
+        .await
+        .expect("dead letter was not stored");
+        running.stop();
+
+        assert_eq!(letters.len(), 1);
+        assert_eq!(letters[0].handler, "always_fails");
+        assert_eq!(letters[0].attempts, 3);
+        assert_eq!(letters[0].envelope.event.event_type(), "node.created");
+        assert!(letters[0].error.contains("search index unavailable"));
+        assert_eq!(attempts.load(Ordering::Relaxed), 3);
+    }
+
+    #[tokio::test]
+    async fn handler_stats_track_latency_and_failures() {
+        let bus = EventBus::new();
+        let mut dispatcher = EventDispatcher::with_config(
+            bus.clone(),
+            DispatcherConfig {
+                retry_count: 1,
+                retry_delay_ms: 1,
+                ..DispatcherConfig::default()
+            },
+        );
+        dispatcher
+            .register(HandlerBuilder::new(
+                "slow",
+                |_| true,
+                |_| async {
+                    tokio::time::sleep(Duration::from_millis(20)).await;
+                    Ok(())
+                },
+            ))
+            .register(HandlerBuilder::new(
+                "failing",
+                |_| true,
+                |_| async { Err(Error::External("downstream unavailable".to_string())) },
+            ));
+        let running = dispatcher.start();
+
+        let tenant_id = Uuid::new_v4();
+        for _ in 0..3 {
+            let event = DomainEvent::NodeUpdated {
+                node_id: Uuid::new_v4(),
+                kind: "article".to_string(),
+            };
+            bus.publish(tenant_id, None, event).unwrap();
+        }
+
+        let stats = tokio::time::timeout(Duration::from_secs(5), async {
+            loop {
+                let stats = running.handler_stats();
+                let done = |name: &str, field: fn(&HandlerStats) -> u64| {
+                    stats.get(name).map(field) == Some(3)
+                };
+                if done("slow", |s| s.processed) && done("failing", |s| s.failed) {
+                    return stats;
+                }
+                tokio::time::sleep(Duration::from_millis(5)).await;
+            }
+        })
+        .await
+        .expect("handlers did not finish the batch");
+        running.stop();
+
+        let slow = &stats["slow"];
+        assert_eq!((slow.failed, slow.retried), (0, 0));
+        assert!(slow.avg_latency_ms >= 20.0, "{slow:?}");
+
+        let failing = &stats["failing"];
+        assert_eq!(failing.processed, 0);
+        assert_eq!(failing.retried, 3);
+        assert!(failing.avg_latency_ms < slow.avg_latency_ms, "{failing:?}");
+    }
+
+    #[tokio::test]
+    async fn replay_feeds_stored_events_from_offset_to_handlers() {
+        let store = MemoryEventStore::new();
+        let tenant_id = Uuid::new_v4();
+        for _ in 0..10 {
+            let event = DomainEvent::NodeUpdated {
+                node_id: Uuid::new_v4(),
+                kind: "article".to_string(),
+            };
+            store
+                .append(EventEnvelope::new(tenant_id, None, event))
+                .await
+                .unwrap();
+        }
+
+        let seen = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&seen);
+        let bus = EventBus::new();
+        let mut dispatcher = EventDispatcher::new(bus.clone());
+        dispatcher.register(HandlerBuilder::new(
+            "indexer",
+            |_| true,
+            move |_| {
+                counter.fetch_add(1, Ordering::Relaxed);
+                async { Ok(()) }
+            },
+        ));
+        let running = dispatcher.start();
+
+        let replayed = running.replay(&store, 5).await.unwrap();
+        running.stop();
+
+        assert_eq!(replayed, 5);
+        assert_eq!(seen.load(Ordering::Relaxed), 5);
+        assert_eq!(bus.stats().events_published(), 0);
+    }
+}
@@ -0,0 +1,87 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Per-handler counters reported by [`RunningDispatcher::handler_stats`](super::RunningDispatcher::handler_stats).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HandlerStats {
+    /// Envelopes the handler completed, possibly after retries.
+    pub processed: u64,
+    /// Envelopes the handler still failed on after its last retry.
+    pub failed: u64,
+    /// Re-runs after a failed attempt.
+    pub retried: u64,
+    /// Mean duration of a single `EventHandler::handle` call, retries included.
+    pub avg_latency_ms: f64,
+}
+
+#[derive(Debug, Default)]
+struct HandlerCounters {
+    processed: u64,
+    failed: u64,
+    retried: u64,
+    calls: u64,
+    total_latency: Duration,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct HandlerStatsRegistry {
+    handlers: Mutex<HashMap<&'static str, HandlerCounters>>,
+}
+
+impl HandlerStatsRegistry {
+    fn update(&self, handler: &'static str, apply: impl FnOnce(&mut HandlerCounters)) {
+        let mut handlers = self
+            .handlers
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        apply(handlers.entry(handler).or_default());
+    }
+
+    pub(crate) fn record_call(&self, handler: &'static str, latency: Duration) {
+        self.update(handler, |counters| {
+            counters.calls += 1;
+            counters.total_latency += latency;
+        });
+    }
+
+    pub(crate) fn record_retry(&self, handler: &'static str) {
+        self.update(handler, |counters| counters.retried += 1);
+    }
+
+    pub(crate) fn record_outcome(&self, handler: &'static str, succeeded: bool) {
+        self.update(handler, |counters| {
+            if succeeded {
+                counters.processed += 1;
+            } else {
+                counters.failed += 1;
+            }
+        });
+    }
+
+    pub(crate) fn snapshot(&self) -> HashMap<String, HandlerStats> {
+        let handlers = self
+            .handlers
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        handlers
+            .iter()
+            .map(|(name, counters)| {
+                let avg_latency_ms = if counters.calls == 0 {
+                    0.0
+                } else {
+                    counters.total_latency.as_secs_f64() * 1000.0 / counters.calls as f64
+                };
+                (
+                    name.to_string(),
+                    HandlerStats {
+                        processed: counters.processed,
+                        failed: counters.failed,
+                        retried: counters.retried,
+                        avg_latency_ms,
+                    },
+                )
+            })
+            .collect()
+    }
+}
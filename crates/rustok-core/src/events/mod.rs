@@ -1,9 +1,13 @@
 pub mod backpressure;
 mod bus;
 mod consumer;
+mod dead_letter;
 mod handler;
+mod handler_stats;
 mod memory;
+mod retry;
 mod schema;
+mod store;
 mod transport;
 mod types;
 pub mod validation;
@@ -12,14 +16,18 @@ pub use backpressure::{
     BackpressureConfig, BackpressureController, BackpressureError, BackpressureMetrics,
     BackpressureState,
 };
-pub use bus::{EventBus, EventBusStats};
+pub use bus::{EventBus, EventBusStats, FilteredReceiver};
 pub use consumer::EventConsumerRuntime;
+pub use dead_letter::{DeadLetter, DeadLetterSink, MemoryDeadLetterSink};
 pub use handler::{
     DispatcherConfig, EventDispatcher, EventHandler, HandlerBuilder, HandlerResult,
     RunningDispatcher,
 };
+pub use handler_stats::HandlerStats;
 pub use memory::MemoryTransport;
+pub use retry::with_retry;
 pub use schema::{event_schema, EventSchema, FieldSchema, EVENT_SCHEMAS};
+pub use store::{EventStore, MemoryEventStore};
 pub use transport::{EventTransport, ReliabilityLevel};
 pub use types::{DomainEvent, EventEnvelope};
 pub use validation::{EventValidationError, ValidateEvent};
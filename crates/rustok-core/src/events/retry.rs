@@ -0,0 +1,104 @@
+use std::future::Future;
+
+use tracing::warn;
+
+use crate::async_utils::BackoffConfig;
+use crate::Error;
+
+/// Retries `operation` from inside an [`EventHandler`](super::EventHandler).
+///
+/// Runs at most `attempts` times (at least once). Only [`Error::is_retryable`] failures are
+/// retried; anything else is returned immediately. The delay before retry `n` is
+/// `backoff.delay_for_attempt(n)`, jittered so handlers failing together do not retry in
+/// lockstep. `backoff.max_retries` is ignored in favour of `attempts`.
+///
+/// This complements [`DispatcherConfig::retry_count`](super::DispatcherConfig): the
+/// dispatcher re-runs the whole handler, `with_retry` wraps only the flaky call.
+pub async fn with_retry<F, Fut, T>(
+    attempts: u32,
+    backoff: &BackoffConfig,
+    mut operation: F,
+) -> Result<T, Error>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, Error>>,
+{
+    let attempts = attempts.max(1);
+    let mut attempt = 1;
+
+    loop {
+        match operation().await {
+            Ok(value) => return Ok(value),
+            Err(error) if attempt < attempts && error.is_retryable() => {
+                let delay = backoff.delay_for_attempt(attempt);
+                warn!(
+                    attempt,
+                    max_attempts = attempts,
+                    delay_ms = delay.as_millis() as u64,
+                    error = %error,
+                    "Handler operation failed, retrying"
+                );
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+            }
+            Err(error) => return Err(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::time::Duration;
+
+    fn fast_backoff() -> BackoffConfig {
+        BackoffConfig::default()
+            .with_initial_delay(Duration::from_millis(1))
+            .with_max_delay(Duration::from_millis(5))
+    }
+
+    #[tokio::test]
+    async fn succeeds_after_two_retryable_failures() {
+        let calls = AtomicU32::new(0);
+
+        let result = with_retry(5, &fast_backoff(), || async {
+            match calls.fetch_add(1, Ordering::SeqCst) {
+                0 | 1 => Err(Error::External("upstream unavailable".to_string())),
+                _ => Ok("delivered"),
+            }
+        })
+        .await;
+
+        assert_eq!(result.unwrap(), "delivered");
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_configured_attempts() {
+        let calls = AtomicU32::new(0);
+
+        let result: Result<(), Error> = with_retry(3, &fast_backoff(), || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Err(Error::Cache("connection reset".to_string()))
+        })
+        .await;
+
+        assert!(matches!(result, Err(Error::Cache(_))));
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn non_retryable_error_is_returned_immediately() {
+        let calls = AtomicU32::new(0);
+
+        let result: Result<(), Error> = with_retry(3, &fast_backoff(), || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Err(Error::Validation("bad payload".to_string()))
+        })
+        .await;
+
+        assert!(matches!(result, Err(Error::Validation(_))));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+}
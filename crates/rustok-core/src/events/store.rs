@@ -0,0 +1,63 @@
+use std::sync::RwLock;
+
+use async_trait::async_trait;
+
+use super::EventEnvelope;
+
+/// Append-only log of envelopes, addressed by a zero-based offset, that
+/// [`RunningDispatcher::replay`](super::RunningDispatcher::replay) reads back from.
+#[async_trait]
+pub trait EventStore: Send + Sync {
+    /// Stores `envelope` and returns its offset.
+    async fn append(&self, envelope: EventEnvelope) -> crate::Result<u64>;
+
+    /// Up to `limit` envelopes starting at `offset`, in append order.
+    async fn read_from(&self, offset: u64, limit: usize) -> crate::Result<Vec<EventEnvelope>>;
+}
+
+#[derive(Debug, Default)]
+pub struct MemoryEventStore {
+    events: RwLock<Vec<EventEnvelope>>,
+}
+
+impl MemoryEventStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.events
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[async_trait]
+impl EventStore for MemoryEventStore {
+    async fn append(&self, envelope: EventEnvelope) -> crate::Result<u64> {
+        let mut events = self
+            .events
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        events.push(envelope);
+        Ok(events.len() as u64 - 1)
+    }
+
+    async fn read_from(&self, offset: u64, limit: usize) -> crate::Result<Vec<EventEnvelope>> {
+        let events = self
+            .events
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        Ok(events
+            .iter()
+            .skip(offset as usize)
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+}
@@ -100,6 +100,18 @@ fn translate_en(key: &str) -> Option<&'static str> {
         "oauth.pkce_invalid" => "PKCE code verifier is invalid",
         "oauth.redirect_uri_mismatch" => "Redirect URI mismatch",
         "oauth.refresh_no_user" => "Refresh token has no associated user",
+        // Security
+        "security.severity.critical" => "Critical",
+        "security.severity.high" => "High",
+        "security.severity.medium" => "Medium",
+        "security.severity.warning" => "Warning",
+        "security.severity.low" => "Low",
+        "security.severity.info" => "Info",
+        "security.status.open" => "Open",
+        "security.status.acknowledged" => "Acknowledged",
+        "security.status.in_progress" => "In progress",
+        "security.status.resolved" => "Resolved",
+        "security.status.accepted" => "Accepted",
         _ => return None,
     })
 }
@@ -138,6 +150,18 @@ fn translate_ru(key: &str) -> Option<&'static str> {
         "oauth.pkce_invalid" => "Неверный верификатор кода PKCE",
         "oauth.redirect_uri_mismatch" => "Несоответствие redirect URI",
         "oauth.refresh_no_user" => "Refresh-токен не связан ни с одним пользователем",
+        // Security
+        "security.severity.critical" => "Критический",
+        "security.severity.high" => "Высокий",
+        "security.severity.medium" => "Средний",
+        "security.severity.warning" => "Предупреждение",
+        "security.severity.low" => "Низкий",
+        "security.severity.info" => "Информация",
+        "security.status.open" => "Открыто",
+        "security.status.acknowledged" => "Подтверждено",
+        "security.status.in_progress" => "В работе",
+        "security.status.resolved" => "Исправлено",
+        "security.status.accepted" => "Риск принят",
         _ => return None,
     })
 }
@@ -178,6 +202,18 @@ fn translate_es(key: &str) -> Option<&'static str> {
         "oauth.pkce_invalid" => "El verificador de código PKCE es inválido",
         "oauth.redirect_uri_mismatch" => "Discrepancia en el URI de redirección",
         "oauth.refresh_no_user" => "El token de actualización no tiene usuario asociado",
+        // Security
+        "security.severity.critical" => "Crítico",
+        "security.severity.high" => "Alto",
+        "security.severity.medium" => "Medio",
+        "security.severity.warning" => "Advertencia",
+        "security.severity.low" => "Bajo",
+        "security.severity.info" => "Información",
+        "security.status.open" => "Abierto",
+        "security.status.acknowledged" => "Reconocido",
+        "security.status.in_progress" => "En curso",
+        "security.status.resolved" => "Resuelto",
+        "security.status.accepted" => "Aceptado",
         _ => return None,
     })
 }
@@ -218,6 +254,18 @@ fn translate_de(key: &str) -> Option<&'static str> {
         "oauth.pkce_invalid" => "PKCE-Code-Verifizierer ist ungültig",
         "oauth.redirect_uri_mismatch" => "Redirect-URI stimmt nicht überein",
         "oauth.refresh_no_user" => "Auffrischungstoken hat keinen zugehörigen Benutzer",
+        // Security
+        "security.severity.critical" => "Kritisch",
+        "security.severity.high" => "Hoch",
+        "security.severity.medium" => "Mittel",
+        "security.severity.warning" => "Warnung",
+        "security.severity.low" => "Niedrig",
+        "security.severity.info" => "Info",
+        "security.status.open" => "Offen",
+        "security.status.acknowledged" => "Bestätigt",
+        "security.status.in_progress" => "In Bearbeitung",
+        "security.status.resolved" => "Behoben",
+        "security.status.accepted" => "Akzeptiert",
         _ => return None,
     })
 }
@@ -256,6 +304,18 @@ fn translate_fr(key: &str) -> Option<&'static str> {
         "oauth.pkce_invalid"                => "Le vérificateur de code PKCE est invalide",
         "oauth.redirect_uri_mismatch"       => "Incompatibilité de l'URI de redirection",
         "oauth.refresh_no_user"             => "Le jeton d'actualisation n'a pas d'utilisateur associé",
+        // Security
+        "security.severity.critical"        => "Critique",
+        "security.severity.high"            => "Élevé",
+        "security.severity.medium"          => "Moyen",
+        "security.severity.warning"         => "Avertissement",
+        "security.severity.low"             => "Faible",
+        "security.severity.info"            => "Information",
+        "security.status.open"              => "Ouvert",
+        "security.status.acknowledged"      => "Pris en compte",
+        "security.status.in_progress"       => "En cours",
+        "security.status.resolved"          => "Résolu",
+        "security.status.accepted"          => "Accepté",
         _ => return None,
     })
 }
@@ -294,6 +354,18 @@ fn translate_zh(key: &str) -> Option<&'static str> {
         "oauth.pkce_invalid" => "PKCE代码验证器无效",
         "oauth.redirect_uri_mismatch" => "重定向URI不匹配",
         "oauth.refresh_no_user" => "刷新令牌没有关联用户",
+        // Security
+        "security.severity.critical" => "严重",
+        "security.severity.high" => "高",
+        "security.severity.medium" => "中",
+        "security.severity.warning" => "警告",
+        "security.severity.low" => "低",
+        "security.severity.info" => "信息",
+        "security.status.open" => "未处理",
+        "security.status.acknowledged" => "已确认",
+        "security.status.in_progress" => "处理中",
+        "security.status.resolved" => "已解决",
+        "security.status.accepted" => "已接受",
         _ => return None,
     })
 }
@@ -4,6 +4,7 @@ pub mod cache;
 pub mod config;
 pub mod content_format;
 pub mod context;
+pub mod email;
 pub mod error;
 pub mod events;
 pub mod field_schema;
@@ -49,16 +50,22 @@ pub use content_format::{
     normalize_content_format, prepare_content_payload, PreparedContent, CONTENT_FORMAT_GRAPESJS_V1,
     CONTENT_FORMAT_MARKDOWN, CONTENT_FORMAT_RT_JSON_V1,
 };
-pub use context::{AppContext, CacheBackend, SearchBackend};
+pub use context::{
+    AppContext, CacheBackend, CacheBackendExt, FacetedResults, SearchBackend, SearchDocument,
+};
+pub use email::{
+    RenderedTemplate, Template, TemplateContent, TemplateError, TemplateRegistry, TemplateResult,
+};
 pub use error::{
     Error, ErrorContext, ErrorKind, ErrorResponse, FieldError, Result, RichError,
     ValidationErrorBuilder,
 };
 pub use events::{
-    event_schema, DispatcherConfig, DomainEvent, EventBus, EventBusStats, EventConsumerRuntime,
-    EventDispatcher, EventEnvelope, EventHandler, EventSchema, EventTransport, FieldSchema,
-    HandlerBuilder, HandlerResult, MemoryTransport, ReliabilityLevel, RunningDispatcher,
-    EVENT_SCHEMAS,
+    event_schema, with_retry, DeadLetter, DeadLetterSink, DispatcherConfig, DomainEvent, EventBus,
+    EventBusStats, EventConsumerRuntime, EventDispatcher, EventEnvelope, EventHandler, EventSchema,
+    EventStore, EventTransport, FieldSchema, FilteredReceiver, HandlerBuilder, HandlerResult,
+    HandlerStats, MemoryDeadLetterSink, MemoryEventStore, MemoryTransport, ReliabilityLevel,
+    RunningDispatcher, EVENT_SCHEMAS,
 };
 pub use field_schema::{
     create_field_definitions_table, drop_field_definitions_table, is_valid_field_key,
@@ -104,7 +111,7 @@ pub use security::{
 pub use typed_error::{
     DomainError, ErrorCategory, ErrorCode, ErrorResponseBody, IntoTypedResult, TypedResult,
 };
-pub use types::{UserRole, UserStatus};
+pub use types::{UserRole, UserRoleParseError, UserStatus, UserStatusParseError};
 pub use utils::{
     all, any, base64_decode, base64_encode, capitalize, chunk, collect_results, dedup, filter_map,
     find_first, format_duration, get_or_default, group_by, hex_decode, hex_encode, html_escape,
@@ -140,8 +147,8 @@ pub mod prelude {
     #[cfg(feature = "redis-cache")]
     pub use crate::RedisCacheBackend;
     pub use crate::{
-        AppContext, CacheBackend, CacheStats, FallbackCacheBackend, InMemoryCacheBackend,
-        SearchBackend,
+        AppContext, CacheBackend, CacheBackendExt, CacheStats, FacetedResults,
+        FallbackCacheBackend, InMemoryCacheBackend, SearchBackend, SearchDocument,
     };
     pub use uuid::Uuid;
 }
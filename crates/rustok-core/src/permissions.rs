@@ -396,6 +396,7 @@ impl Permission {
 
     pub const LOGS_READ: Self = Self::new(Resource::Logs, Action::Read);
     pub const LOGS_LIST: Self = Self::new(Resource::Logs, Action::List);
+    pub const LOGS_MANAGE: Self = Self::new(Resource::Logs, Action::Manage);
 
     pub const BLOG_POSTS_CREATE: Self = Self::new(Resource::BlogPosts, Action::Create);
     pub const BLOG_POSTS_READ: Self = Self::new(Resource::BlogPosts, Action::Read);
@@ -0,0 +1,278 @@
+//! Dependency advisories from `cargo audit`
+//!
+//! Converts a `cargo audit --json` report into [`SecurityFinding`]s in the
+//! `VulnerableComponents` category so dependency advisories land in the same report as the
+//! configuration checks. Severity comes from the advisory's CVSS v3 vector; advisories
+//! without one are treated as `High`, informational warnings (unmaintained, unsound) as
+//! `Low`. The RUSTSEC id and any CVE aliases lead the finding description.
+
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+
+use super::{SecurityCategory, SecurityCheck, SecurityFinding, Severity};
+
+#[derive(Debug, Default, Deserialize)]
+struct CargoAuditReport {
+    #[serde(default)]
+    vulnerabilities: Vulnerabilities,
+    /// Keyed by warning kind (`unmaintained`, `unsound`, `yanked`, ...).
+    #[serde(default)]
+    warnings: BTreeMap<String, Vec<AdvisoryEntry>>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct Vulnerabilities {
+    #[serde(default)]
+    list: Vec<AdvisoryEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AdvisoryEntry {
+    /// `null` for warnings without an advisory, e.g. yanked crates.
+    advisory: Option<Advisory>,
+    package: Package,
+    #[serde(default)]
+    versions: Option<Versions>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Advisory {
+    id: String,
+    title: String,
+    #[serde(default)]
+    cvss: Option<String>,
+    #[serde(default)]
+    aliases: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Package {
+    name: String,
+    version: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Versions {
+    #[serde(default)]
+    patched: Vec<String>,
+}
+
+impl SecurityCheck {
+    /// Check that reports the advisories of a `cargo audit --json` report. The report is
+    /// parsed once, here.
+    pub fn cargo_audit(report_json: &str) -> Result<Self, serde_json::Error> {
+        let findings = findings_from_cargo_audit(report_json)?;
+        Ok(SecurityCheck::custom("cargo_audit", move |_| {
+            findings.clone()
+        }))
+    }
+}
+
+/// One finding per advisory in a `cargo audit --json` report.
+pub fn findings_from_cargo_audit(
+    report_json: &str,
+) -> Result<Vec<SecurityFinding>, serde_json::Error> {
+    let report: CargoAuditReport = serde_json::from_str(report_json)?;
+
+    let vulnerabilities = report.vulnerabilities.list.iter().filter_map(|entry| {
+        let advisory = entry.advisory.as_ref()?;
+        let severity = advisory
+            .cvss
+            .as_deref()
+            .and_then(cvss_v3_base_score)
+            .map(Severity::from_cvss)
+            .unwrap_or(Severity::High);
+        Some(advisory_finding(entry, advisory, severity))
+    });
+    let warnings = report.warnings.values().flatten().filter_map(|entry| {
+        let advisory = entry.advisory.as_ref()?;
+        Some(advisory_finding(entry, advisory, Severity::Low))
+    });
+
+    Ok(vulnerabilities.chain(warnings).collect())
+}
+
+fn advisory_finding(
+    entry: &AdvisoryEntry,
+    advisory: &Advisory,
+    severity: Severity,
+) -> SecurityFinding {
+    let mut ids = vec![advisory.id.clone()];
+    ids.extend(advisory.aliases.iter().cloned());
+    let Package { name, version } = &entry.package;
+
+    let patched = entry
+        .versions
+        .as_ref()
+        .map(|versions| versions.patched.join(" or "))
+        .unwrap_or_default();
+    let remediation = if patched.is_empty() {
+        format!("No patched release of {name}; replace the dependency or accept the risk")
+    } else {
+        format!("Upgrade {name} to {patched}")
+    };
+
+    SecurityFinding {
+        category: SecurityCategory::VulnerableComponents,
+        severity,
+        description: format!("{}: {name} {version}: {}", ids.join(", "), advisory.title),
+        remediation,
+    }
+}
+
+/// CVSS v3.0/v3.1 base score for a vector such as
+/// `CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H`; `None` if a base metric is missing.
+pub fn cvss_v3_base_score(vector: &str) -> Option<f32> {
+    let mut metrics = BTreeMap::new();
+    for part in vector
+        .split('/')
+        .skip_while(|part| part.starts_with("CVSS:"))
+    {
+        let (metric, value) = part.split_once(':')?;
+        metrics.insert(metric, value);
+    }
+    let scope_changed = match *metrics.get("S")? {
+        "U" => false,
+        "C" => true,
+        _ => return None,
+    };
+    let weight = |metric: &str| -> Option<f64> {
+        Some(match (metric, *metrics.get(metric)?) {
+            ("AV", "N") => 0.85,
+            ("AV", "A") => 0.62,
+            ("AV", "L") => 0.55,
+            ("AV", "P") => 0.2,
+            ("AC", "L") => 0.77,
+            ("AC", "H") => 0.44,
+            ("PR", "N") => 0.85,
+            ("PR", "L") if scope_changed => 0.68,
+            ("PR", "L") => 0.62,
+            ("PR", "H") if scope_changed => 0.5,
+            ("PR", "H") => 0.27,
+            ("UI", "N") => 0.85,
+            ("UI", "R") => 0.62,
+            ("C" | "I" | "A", "H") => 0.56,
+            ("C" | "I" | "A", "L") => 0.22,
+            ("C" | "I" | "A", "N") => 0.0,
+            _ => return None,
+        })
+    };
+
+    let iss = 1.0 - (1.0 - weight("C")?) * (1.0 - weight("I")?) * (1.0 - weight("A")?);
+    let impact = if scope_changed {
+        7.52 * (iss - 0.029) - 3.25 * (iss - 0.02).powi(15)
+    } else {
+        6.42 * iss
+    };
+    let exploitability = 8.22 * weight("AV")? * weight("AC")? * weight("PR")? * weight("UI")?;
+
+    if impact <= 0.0 {
+        return Some(0.0);
+    }
+    let base = if scope_changed {
+        (1.08 * (impact + exploitability)).min(10.0)
+    } else {
+        (impact + exploitability).min(10.0)
+    };
+    Some(round_up(base) as f32)
+}
+
+/// CVSS v3.1 "Roundup": smallest one-decimal number not below `value`.
+fn round_up(value: f64) -> f64 {
+    let scaled = (value * 100_000.0).round() as i64;
+    if scaled % 10_000 == 0 {
+        scaled as f64 / 100_000.0
+    } else {
+        ((scaled / 10_000) + 1) as f64 / 10.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REPORT: &str = r#"{
+        "vulnerabilities": {
+            "found": true,
+            "count": 2,
+            "list": [
+                {
+                    "advisory": {
+                        "id": "RUSTSEC-2022-0090",
+                        "package": "libsqlite3-sys",
+                        "title": "`libsqlite3-sys` via C SQLite CVE-2022-35737",
+                        "cvss": "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+                        "aliases": ["CVE-2022-35737"]
+                    },
+                    "versions": { "patched": [">=0.25.1"], "unaffected": [] },
+                    "package": { "name": "libsqlite3-sys", "version": "0.24.2" }
+                },
+                {
+                    "advisory": {
+                        "id": "RUSTSEC-2023-0034",
+                        "package": "h2",
+                        "title": "Resource exhaustion vulnerability in h2",
+                        "cvss": "CVSS:3.1/AV:N/AC:H/PR:N/UI:N/S:U/C:N/I:N/A:H",
+                        "aliases": []
+                    },
+                    "versions": { "patched": [">=0.3.17"], "unaffected": [] },
+                    "package": { "name": "h2", "version": "0.3.15" }
+                }
+            ]
+        },
+        "warnings": {
+            "unmaintained": [
+                {
+                    "kind": "unmaintained",
+                    "advisory": {
+                        "id": "RUSTSEC-2021-0139",
+                        "package": "ansi_term",
+                        "title": "ansi_term is Unmaintained",
+                        "cvss": null,
+                        "aliases": []
+                    },
+                    "versions": { "patched": [], "unaffected": [] },
+                    "package": { "name": "ansi_term", "version": "0.12.1" }
+                }
+            ],
+            "yanked": [
+                {
+                    "kind": "yanked",
+                    "advisory": null,
+                    "versions": null,
+                    "package": { "name": "foo", "version": "0.1.0" }
+                }
+            ]
+        }
+    }"#;
+
+    #[test]
+    fn test_cargo_audit_report_becomes_findings() {
+        let findings = findings_from_cargo_audit(REPORT).unwrap();
+
+        let summary: Vec<(&str, Severity)> = findings
+            .iter()
+            .map(|f| (f.description.split(':').next().unwrap(), f.severity))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("RUSTSEC-2022-0090, CVE-2022-35737", Severity::Critical),
+                ("RUSTSEC-2023-0034", Severity::Medium),
+                ("RUSTSEC-2021-0139", Severity::Low),
+            ]
+        );
+        assert!(findings
+            .iter()
+            .all(|f| f.category == SecurityCategory::VulnerableComponents));
+        assert_eq!(
+            findings[0].remediation,
+            "Upgrade libsqlite3-sys to >=0.25.1"
+        );
+        assert_eq!(
+            cvss_v3_base_score("CVSS:3.1/AV:N/AC:L/PR:L/UI:N/S:C/C:H/I:H/A:H"),
+            Some(9.9)
+        );
+    }
+}
@@ -0,0 +1,289 @@
+//! Alerting on security audit results
+//!
+//! [`SecurityMonitor`] turns the high and critical findings of an audit into a
+//! [`SecurityAlert`] and hands it to an [`AlertSink`] once their count reaches the
+//! configured threshold. [`LoggingAlertSink`] is the default; [`WebhookAlertSink`] posts to
+//! Slack incoming webhooks or any JSON webhook (e.g. a PagerDuty bridge), retrying with
+//! exponential backoff.
+//!
+//! With a cooldown, an unchanged set of alerting findings is reported once per cooldown;
+//! a finding that was not part of the last alert triggers a new one immediately.
+
+use std::collections::BTreeSet;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::async_utils::{retry, BackoffConfig};
+
+use super::csv::enum_label;
+use super::{SecurityAuditResult, SecurityFinding, Severity};
+
+/// High and critical findings of one audit.
+#[derive(Debug, Clone, Serialize)]
+pub struct SecurityAlert {
+    pub critical: usize,
+    pub high: usize,
+    pub score: u8,
+    pub findings: Vec<SecurityFinding>,
+}
+
+impl SecurityAlert {
+    pub fn from_result(result: &SecurityAuditResult) -> Self {
+        let findings: Vec<SecurityFinding> = result
+            .findings
+            .iter()
+            .filter(|finding| finding.severity >= Severity::High)
+            .cloned()
+            .collect();
+        let critical = findings
+            .iter()
+            .filter(|finding| finding.severity == Severity::Critical)
+            .count();
+
+        Self {
+            critical,
+            high: findings.len() - critical,
+            score: result.score,
+            findings,
+        }
+    }
+
+    pub fn alerting_findings(&self) -> usize {
+        self.critical + self.high
+    }
+
+    /// Human-readable summary, one line per finding.
+    pub fn summary(&self) -> String {
+        let mut summary = format!(
+            "Security audit: {} critical, {} high findings (score {})",
+            self.critical, self.high, self.score
+        );
+        for finding in &self.findings {
+            summary.push_str(&format!(
+                "\n• [{}] {}",
+                enum_label(&finding.severity),
+                finding.description
+            ));
+        }
+        summary
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum AlertError {
+    #[error("Alert webhook request failed: {0}")]
+    Request(#[from] reqwest::Error),
+
+    #[error("Alert webhook returned status {0}")]
+    Status(u16),
+
+    #[error("Alert delivery failed after {attempts} attempts: {source}")]
+    Exhausted {
+        attempts: u32,
+        #[source]
+        source: Box<AlertError>,
+    },
+}
+
+#[async_trait]
+pub trait AlertSink: Send + Sync {
+    async fn send(&self, alert: &SecurityAlert) -> Result<(), AlertError>;
+}
+
+/// Writes alerts to the tracing log.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LoggingAlertSink;
+
+#[async_trait]
+impl AlertSink for LoggingAlertSink {
+    async fn send(&self, alert: &SecurityAlert) -> Result<(), AlertError> {
+        tracing::warn!(
+            critical = alert.critical,
+            high = alert.high,
+            score = alert.score,
+            "{}",
+            alert.summary()
+        );
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookFormat {
+    /// `{"text": "<summary>"}`, as accepted by Slack incoming webhooks.
+    Slack,
+    /// The [`SecurityAlert`] as JSON plus a `text` summary.
+    #[default]
+    Generic,
+}
+
+/// Posts alerts to an HTTP webhook.
+#[derive(Debug, Clone)]
+pub struct WebhookAlertSink {
+    client: reqwest::Client,
+    url: String,
+    format: WebhookFormat,
+    backoff: BackoffConfig,
+}
+
+impl WebhookAlertSink {
+    pub fn new(url: impl Into<String>, format: WebhookFormat) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(5))
+            .build()
+            .unwrap_or_default();
+
+        Self {
+            client,
+            url: url.into(),
+            format,
+            backoff: BackoffConfig::default(),
+        }
+    }
+
+    pub fn with_backoff(mut self, backoff: BackoffConfig) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    fn payload(&self, alert: &SecurityAlert) -> serde_json::Value {
+        match self.format {
+            WebhookFormat::Slack => serde_json::json!({ "text": alert.summary() }),
+            WebhookFormat::Generic => serde_json::json!({
+                "text": alert.summary(),
+                "critical": alert.critical,
+                "high": alert.high,
+                "score": alert.score,
+                "findings": alert.findings,
+            }),
+        }
+    }
+
+    async fn post(&self, payload: &serde_json::Value) -> Result<(), AlertError> {
+        let response = self.client.post(&self.url).json(payload).send().await?;
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(AlertError::Status(response.status().as_u16()))
+        }
+    }
+}
+
+#[async_trait]
+impl AlertSink for WebhookAlertSink {
+    async fn send(&self, alert: &SecurityAlert) -> Result<(), AlertError> {
+        let payload = self.payload(alert);
+        retry(|| self.post(&payload), self.backoff.clone())
+            .await
+            .map_err(|e| AlertError::Exhausted {
+                attempts: e.attempts,
+                source: Box::new(e.error),
+            })
+    }
+}
+
+/// Finding keys of the last alert sent and when it was sent.
+#[derive(Debug)]
+struct LastAlert {
+    finding_keys: BTreeSet<String>,
+    sent_at: Instant,
+}
+
+/// Sends an alert whenever an audit has at least `threshold` high or critical findings.
+#[derive(Clone)]
+pub struct SecurityMonitor {
+    sink: Arc<dyn AlertSink>,
+    threshold: usize,
+    cooldown: Duration,
+    last_alert: Arc<Mutex<Option<LastAlert>>>,
+}
+
+impl Default for SecurityMonitor {
+    fn default() -> Self {
+        Self {
+            sink: Arc::new(LoggingAlertSink),
+            threshold: 1,
+            cooldown: Duration::ZERO,
+            last_alert: Arc::new(Mutex::new(None)),
+        }
+    }
+}
+
+impl SecurityMonitor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_sink(mut self, sink: impl AlertSink + 'static) -> Self {
+        self.sink = Arc::new(sink);
+        self
+    }
+
+    /// Minimum number of high/critical findings that triggers an alert (at least 1).
+    pub fn with_threshold(mut self, threshold: usize) -> Self {
+        self.threshold = threshold.max(1);
+        self
+    }
+
+    /// Suppresses repeat alerts for the same findings within `cooldown` (zero: no
+    /// de-duplication).
+    pub fn with_cooldown(mut self, cooldown: Duration) -> Self {
+        self.cooldown = cooldown;
+        self
+    }
+
+    /// Alerts on `result` if it crosses the threshold and is not a repeat within the
+    /// cooldown; returns whether an alert was sent.
+    pub async fn observe(&self, result: &SecurityAuditResult) -> Result<bool, AlertError> {
+        let alert = SecurityAlert::from_result(result);
+        if alert.alerting_findings() < self.threshold {
+            return Ok(false);
+        }
+
+        let finding_keys: BTreeSet<String> = alert
+            .findings
+            .iter()
+            .map(SecurityFinding::finding_key)
+            .collect();
+        if self.is_repeat(&finding_keys) {
+            tracing::debug!(
+                findings = finding_keys.len(),
+                "Security alert suppressed within cooldown"
+            );
+            return Ok(false);
+        }
+
+        self.send_alert(&alert).await?;
+        *self.last_alert.lock().unwrap_or_else(|e| e.into_inner()) = Some(LastAlert {
+            finding_keys,
+            sent_at: Instant::now(),
+        });
+        Ok(true)
+    }
+
+    /// Whether every finding was already alerted on within the cooldown.
+    fn is_repeat(&self, finding_keys: &BTreeSet<String>) -> bool {
+        let last_alert = self.last_alert.lock().unwrap_or_else(|e| e.into_inner());
+        last_alert.as_ref().is_some_and(|last| {
+            last.sent_at.elapsed() < self.cooldown && finding_keys.is_subset(&last.finding_keys)
+        })
+    }
+
+    pub async fn send_alert(&self, alert: &SecurityAlert) -> Result<(), AlertError> {
+        self.sink.send(alert).await
+    }
+}
+
+impl std::fmt::Debug for SecurityMonitor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SecurityMonitor")
+            .field("threshold", &self.threshold)
+            .field("cooldown", &self.cooldown)
+            .finish_non_exhaustive()
+    }
+}
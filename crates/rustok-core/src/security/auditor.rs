@@ -0,0 +1,140 @@
+//! Composable security auditor
+//!
+//! [`SecurityAuditor`] bundles a [`SecurityConfig`] with application-specific checks: sync
+//! [`SecurityCheck`] closures and [`AsyncSecurityCheck`] implementations that need the
+//! database (e.g. scanning rows for missing `tenant_id`). [`SecurityAuditor::run_full_audit`]
+//! runs the built-in OWASP checks, the sync checks and all async checks concurrently, then
+//! sorts and scores everything together. Registered [`FindingSuppression`]s that are
+//! still active are applied to every run. [`TenantSecurityCheck`]s only run in tenant-scoped
+//! audits (see [`SecurityAuditor::run_scoped_audit`]).
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use futures::future::join_all;
+use sea_orm::DatabaseConnection;
+
+use super::{
+    run_security_audit_with_checks, sort_findings, FindingSuppression, SecurityAuditResult,
+    SecurityCheck, SecurityConfig, SecurityFinding, TenantSecurityCheck,
+};
+
+#[async_trait]
+pub trait AsyncSecurityCheck: Send + Sync {
+    fn name(&self) -> &str;
+
+    async fn execute(
+        &self,
+        config: &SecurityConfig,
+        db: &DatabaseConnection,
+    ) -> Vec<SecurityFinding>;
+}
+
+#[derive(Clone, Default)]
+pub struct SecurityAuditor {
+    config: SecurityConfig,
+    checks: Vec<SecurityCheck>,
+    async_checks: Vec<Arc<dyn AsyncSecurityCheck>>,
+    tenant_checks: Vec<Arc<dyn TenantSecurityCheck>>,
+    suppressions: Vec<FindingSuppression>,
+}
+
+impl SecurityAuditor {
+    pub fn new(config: SecurityConfig) -> Self {
+        Self {
+            config,
+            checks: Vec::new(),
+            async_checks: Vec::new(),
+            tenant_checks: Vec::new(),
+            suppressions: Vec::new(),
+        }
+    }
+
+    pub fn config(&self) -> &SecurityConfig {
+        &self.config
+    }
+
+    pub fn register_check(&mut self, check: SecurityCheck) -> &mut Self {
+        self.checks.push(check);
+        self
+    }
+
+    pub fn register_async_check(&mut self, check: impl AsyncSecurityCheck + 'static) -> &mut Self {
+        self.async_checks.push(Arc::new(check));
+        self
+    }
+
+    /// Check run only by tenant-scoped audits ([`SecurityAuditor::run_scoped_audit`]).
+    pub fn register_tenant_check(
+        &mut self,
+        check: impl TenantSecurityCheck + 'static,
+    ) -> &mut Self {
+        self.tenant_checks.push(Arc::new(check));
+        self
+    }
+
+    pub(super) fn tenant_checks(&self) -> &[Arc<dyn TenantSecurityCheck>] {
+        &self.tenant_checks
+    }
+
+    pub(super) fn suppressions(&self) -> &[FindingSuppression] {
+        &self.suppressions
+    }
+
+    pub fn register_suppression(&mut self, suppression: FindingSuppression) -> &mut Self {
+        self.suppressions.push(suppression);
+        self
+    }
+
+    /// Built-in and sync checks only; no database access.
+    pub async fn run(&self) -> SecurityAuditResult {
+        run_security_audit_with_checks(&self.config, &self.checks)
+            .await
+            .apply_suppressions(&self.suppressions, Utc::now())
+    }
+
+    /// Built-in, sync and async checks, all run concurrently.
+    pub async fn run_full_audit(&self, db: &DatabaseConnection) -> SecurityAuditResult {
+        let (builtin, async_findings) = futures::join!(
+            run_security_audit_with_checks(&self.config, &self.checks),
+            join_all(
+                self.async_checks
+                    .iter()
+                    .map(|check| check.execute(&self.config, db)),
+            ),
+        );
+        let mut findings = builtin.findings;
+        findings.extend(async_findings.into_iter().flatten());
+        sort_findings(&mut findings);
+
+        SecurityAuditResult::from_findings(findings)
+            .apply_suppressions(&self.suppressions, Utc::now())
+    }
+}
+
+impl std::fmt::Debug for SecurityAuditor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SecurityAuditor")
+            .field("config", &self.config)
+            .field("checks", &self.checks)
+            .field(
+                "async_checks",
+                &self
+                    .async_checks
+                    .iter()
+                    .map(|check| check.name())
+                    .collect::<Vec<_>>(),
+            )
+            .field(
+                "tenant_checks",
+                &self
+                    .tenant_checks
+                    .iter()
+                    .map(|check| check.name())
+                    .collect::<Vec<_>>(),
+            )
+            .field("suppressions", &self.suppressions)
+            .finish()
+    }
+}
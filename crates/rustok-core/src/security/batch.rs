@@ -0,0 +1,81 @@
+//! Auditing many systems at once
+//!
+//! [`BatchSecurityAuditor`] runs one [`SecurityAuditor`] per named system, at most
+//! `max_parallelism` at a time. A system whose checks panic is reported in
+//! [`BatchAuditReport::failures`] without affecting the others; results are keyed by system
+//! name so report order does not depend on completion order.
+
+use std::collections::BTreeMap;
+use std::panic::AssertUnwindSafe;
+
+use futures::{FutureExt, StreamExt};
+use sea_orm::DatabaseConnection;
+
+use super::{SecurityAuditResult, SecurityAuditor};
+
+#[derive(Debug, Clone)]
+pub struct BatchSecurityAuditor {
+    systems: Vec<(String, SecurityAuditor)>,
+    max_parallelism: usize,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct BatchAuditReport {
+    pub results: BTreeMap<String, SecurityAuditResult>,
+    /// Systems whose audit panicked, with the panic message.
+    pub failures: BTreeMap<String, String>,
+}
+
+impl BatchAuditReport {
+    /// All successful systems merged with [`SecurityAuditResult::consolidate`].
+    pub fn consolidated(&self) -> SecurityAuditResult {
+        SecurityAuditResult::consolidate(self.results.values().cloned())
+    }
+}
+
+impl BatchSecurityAuditor {
+    /// `max_parallelism` is clamped to at least 1.
+    pub fn new(max_parallelism: usize) -> Self {
+        Self {
+            systems: Vec::new(),
+            max_parallelism: max_parallelism.max(1),
+        }
+    }
+
+    pub fn add_system(&mut self, name: impl Into<String>, auditor: SecurityAuditor) -> &mut Self {
+        self.systems.push((name.into(), auditor));
+        self
+    }
+
+    pub async fn run_all(&self, db: &DatabaseConnection) -> BatchAuditReport {
+        let outcomes: Vec<_> = futures::stream::iter(&self.systems)
+            .map(|(name, auditor)| async move {
+                let outcome = AssertUnwindSafe(auditor.run_full_audit(db))
+                    .catch_unwind()
+                    .await;
+                (name.clone(), outcome)
+            })
+            .buffer_unordered(self.max_parallelism)
+            .collect()
+            .await;
+
+        let mut report = BatchAuditReport::default();
+        for (name, outcome) in outcomes {
+            match outcome {
+                Ok(result) => {
+                    report.results.insert(name, result);
+                }
+                Err(panic) => {
+                    let message = panic
+                        .downcast_ref::<&str>()
+                        .map(|message| message.to_string())
+                        .or_else(|| panic.downcast_ref::<String>().cloned())
+                        .unwrap_or_else(|| "audit panicked".to_string());
+                    tracing::error!(system = %name, %message, "Security audit failed");
+                    report.failures.insert(name, message);
+                }
+            }
+        }
+        report
+    }
+}
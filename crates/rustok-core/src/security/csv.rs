@@ -0,0 +1,146 @@
+//! CSV export for security audit results
+//!
+//! Renders [`SecurityAuditResult`] findings as RFC 4180 CSV for spreadsheet tracking:
+//! one header row ([`CSV_HEADER`]) and one CRLF-terminated row per finding at or above
+//! the requested minimum severity. Fields containing commas, quotes or line breaks are
+//! quoted, with inner quotes doubled.
+
+use super::sarif::owasp_taxon;
+use super::{SecurityAuditResult, SecurityFinding, Severity};
+
+pub const CSV_HEADER: &str = "index,category,severity,owasp,description,remediation";
+
+impl SecurityAuditResult {
+    /// Serializes findings with `severity >= min_severity` as CSV.
+    pub fn to_csv(&self, min_severity: Severity) -> String {
+        let mut csv = format!("{CSV_HEADER}\r\n");
+        for (index, finding) in self.findings.iter().enumerate() {
+            if finding.severity >= min_severity {
+                csv.push_str(&csv_row(index, finding));
+                csv.push_str("\r\n");
+            }
+        }
+        csv
+    }
+}
+
+fn csv_row(index: usize, finding: &SecurityFinding) -> String {
+    let fields = [
+        index.to_string(),
+        enum_label(&finding.category),
+        enum_label(&finding.severity),
+        owasp_taxon(finding.category)
+            .map(|(id, _)| id.to_string())
+            .unwrap_or_default(),
+        finding.description.clone(),
+        finding.remediation.clone(),
+    ];
+    fields
+        .iter()
+        .map(|field| escape_field(field))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// snake_case serde name of a unit enum variant.
+pub(super) fn enum_label<T: serde::Serialize>(value: &T) -> String {
+    serde_json::to_value(value)
+        .ok()
+        .and_then(|value| value.as_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+fn escape_field(field: &str) -> String {
+    if field.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::SecurityCategory;
+
+    /// Minimal RFC 4180 reader, enough to check the writer round-trips.
+    fn parse_csv(input: &str) -> Vec<Vec<String>> {
+        let mut rows = Vec::new();
+        let mut row = Vec::new();
+        let mut field = String::new();
+        let mut quoted = false;
+        let mut chars = input.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match (quoted, c) {
+                (true, '"') if chars.peek() == Some(&'"') => {
+                    field.push('"');
+                    chars.next();
+                }
+                (true, '"') => quoted = false,
+                (true, c) => field.push(c),
+                (false, '"') => quoted = true,
+                (false, ',') => row.push(std::mem::take(&mut field)),
+                (false, '\r') => {}
+                (false, '\n') => {
+                    row.push(std::mem::take(&mut field));
+                    rows.push(std::mem::take(&mut row));
+                }
+                (false, c) => field.push(c),
+            }
+        }
+        rows
+    }
+
+    fn audit_result() -> SecurityAuditResult {
+        SecurityAuditResult {
+            passed: false,
+            score: 72,
+            findings: vec![
+                SecurityFinding {
+                    category: SecurityCategory::Injection,
+                    severity: Severity::High,
+                    description: "Unescaped \"q\" param, reflected in search".to_string(),
+                    remediation: "Escape output,\nvalidate input".to_string(),
+                },
+                SecurityFinding {
+                    category: SecurityCategory::Other,
+                    severity: Severity::Info,
+                    description: "Informational".to_string(),
+                    remediation: "None".to_string(),
+                },
+            ],
+            suppressed: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_csv_round_trips_commas_and_quotes() {
+        let rows = parse_csv(&audit_result().to_csv(Severity::Info));
+
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0].join(","), CSV_HEADER);
+        assert_eq!(
+            rows[1],
+            [
+                "0",
+                "injection",
+                "high",
+                "A03",
+                "Unescaped \"q\" param, reflected in search",
+                "Escape output,\nvalidate input",
+            ]
+        );
+        assert_eq!(rows[2][3], "");
+    }
+
+    #[test]
+    fn test_csv_honors_min_severity() {
+        let csv = audit_result().to_csv(Severity::Medium);
+        let rows = parse_csv(&csv);
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[1][0], "0");
+        assert!(csv.ends_with("\r\n"));
+    }
+}
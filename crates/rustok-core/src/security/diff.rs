@@ -0,0 +1,203 @@
+//! Run-to-run comparison of security audits
+//!
+//! Scheduled audits usually only need to alert on what changed. [`SecurityAuditor::diff`]
+//! compares two sets of per-system results, matching findings by
+//! [`SecurityFinding::finding_key`] so wording-stable findings line up across runs.
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde::Serialize;
+
+use super::{SecurityAuditResult, SecurityAuditor, Severity};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SeverityChange {
+    pub finding_key: String,
+    pub previous: Severity,
+    pub current: Severity,
+}
+
+impl SeverityChange {
+    pub fn is_regression(&self) -> bool {
+        self.current > self.previous
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct SystemDiff {
+    pub new_findings: Vec<String>,
+    pub resolved_findings: Vec<String>,
+    pub severity_changes: Vec<SeverityChange>,
+}
+
+impl SystemDiff {
+    pub fn is_empty(&self) -> bool {
+        self.new_findings.is_empty()
+            && self.resolved_findings.is_empty()
+            && self.severity_changes.is_empty()
+    }
+
+    /// New findings or findings whose severity went up.
+    pub fn has_regressions(&self) -> bool {
+        !self.new_findings.is_empty()
+            || self
+                .severity_changes
+                .iter()
+                .any(SeverityChange::is_regression)
+    }
+}
+
+/// Changes per system; systems without changes are omitted.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct AuditDiff {
+    pub systems: BTreeMap<String, SystemDiff>,
+}
+
+impl AuditDiff {
+    pub fn is_empty(&self) -> bool {
+        self.systems.is_empty()
+    }
+
+    pub fn has_regressions(&self) -> bool {
+        self.systems.values().any(SystemDiff::has_regressions)
+    }
+}
+
+impl SecurityAuditor {
+    /// Compares two runs keyed by system name. A system missing from `previous` reports
+    /// all of its findings as new; one missing from `current` reports them as resolved.
+    pub fn diff(
+        previous: &HashMap<String, SecurityAuditResult>,
+        current: &HashMap<String, SecurityAuditResult>,
+    ) -> AuditDiff {
+        let empty = BTreeMap::new();
+        let mut systems = BTreeMap::new();
+
+        for system in previous.keys().chain(current.keys()) {
+            if systems.contains_key(system) {
+                continue;
+            }
+            let before = previous.get(system).map(severities_by_key);
+            let after = current.get(system).map(severities_by_key);
+            let diff = diff_system(
+                before.as_ref().unwrap_or(&empty),
+                after.as_ref().unwrap_or(&empty),
+            );
+            systems.insert(system.clone(), diff);
+        }
+
+        systems.retain(|_, diff| !diff.is_empty());
+        AuditDiff { systems }
+    }
+}
+
+/// Highest severity per finding key.
+fn severities_by_key(result: &SecurityAuditResult) -> BTreeMap<String, Severity> {
+    let mut severities = BTreeMap::new();
+    for finding in &result.findings {
+        severities
+            .entry(finding.finding_key())
+            .and_modify(|severity: &mut Severity| *severity = (*severity).max(finding.severity))
+            .or_insert(finding.severity);
+    }
+    severities
+}
+
+fn diff_system(
+    before: &BTreeMap<String, Severity>,
+    after: &BTreeMap<String, Severity>,
+) -> SystemDiff {
+    let mut diff = SystemDiff::default();
+
+    for (key, &current) in after {
+        match before.get(key) {
+            None => diff.new_findings.push(key.clone()),
+            Some(&previous) if previous != current => diff.severity_changes.push(SeverityChange {
+                finding_key: key.clone(),
+                previous,
+                current,
+            }),
+            Some(_) => {}
+        }
+    }
+    diff.resolved_findings = before
+        .keys()
+        .filter(|key| !after.contains_key(*key))
+        .cloned()
+        .collect();
+
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::{SecurityCategory, SecurityFinding};
+
+    fn result(findings: &[(&str, Severity)]) -> SecurityAuditResult {
+        SecurityAuditResult::from_findings(
+            findings
+                .iter()
+                .map(|(description, severity)| SecurityFinding {
+                    category: SecurityCategory::SecurityMisconfiguration,
+                    severity: *severity,
+                    description: description.to_string(),
+                    remediation: "Fix".to_string(),
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_diff_reports_new_resolved_and_changed_findings() {
+        let previous = HashMap::from([
+            (
+                "api".to_string(),
+                result(&[
+                    ("CSP missing", Severity::Medium),
+                    ("HSTS off", Severity::High),
+                ]),
+            ),
+            (
+                "admin".to_string(),
+                result(&[("CSP missing", Severity::Medium)]),
+            ),
+        ]);
+        let current = HashMap::from([
+            (
+                "api".to_string(),
+                result(&[
+                    ("CSP missing", Severity::High),
+                    ("Audit log off", Severity::Low),
+                ]),
+            ),
+            (
+                "admin".to_string(),
+                result(&[("CSP missing", Severity::Medium)]),
+            ),
+        ]);
+
+        let diff = SecurityAuditor::diff(&previous, &current);
+
+        assert_eq!(diff.systems.len(), 1);
+        let api = &diff.systems["api"];
+        assert_eq!(
+            api.new_findings,
+            vec!["security_misconfiguration:Audit log off"]
+        );
+        assert_eq!(
+            api.resolved_findings,
+            vec!["security_misconfiguration:HSTS off"]
+        );
+        assert_eq!(
+            api.severity_changes,
+            vec![SeverityChange {
+                finding_key: "security_misconfiguration:CSP missing".to_string(),
+                previous: Severity::Medium,
+                current: Severity::High,
+            }]
+        );
+        assert!(diff.has_regressions());
+        assert!(SecurityAuditor::diff(&current, &current).is_empty());
+    }
+}
@@ -0,0 +1,202 @@
+//! Format-agnostic export of security audit results
+//!
+//! [`ExportOptions`] is plain config (format, minimum severity, whether remediation text is
+//! included, which values are redacted) so it can live in settings and be applied at runtime
+//! through [`SecurityAuditResult::export`]. Score and pass/fail always describe the full
+//! audit, not the filtered findings.
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use super::{RedactionRuleset, SecurityAuditResult, Severity};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReportFormat {
+    #[default]
+    Json,
+    Sarif,
+    Csv,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportOptions {
+    #[serde(default)]
+    pub format: ReportFormat,
+    /// Findings below this severity are left out of the export.
+    #[serde(default = "default_min_severity")]
+    pub min_severity: Severity,
+    #[serde(default = "default_include_remediation")]
+    pub include_remediation: bool,
+    /// Applied to finding descriptions and remediations in every format.
+    #[serde(default)]
+    pub redaction: RedactionRuleset,
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        Self {
+            format: ReportFormat::default(),
+            min_severity: default_min_severity(),
+            include_remediation: default_include_remediation(),
+            redaction: RedactionRuleset::default(),
+        }
+    }
+}
+
+fn default_min_severity() -> Severity {
+    Severity::Info
+}
+
+fn default_include_remediation() -> bool {
+    true
+}
+
+#[derive(Debug, Error)]
+pub enum ReportError {
+    #[error("Security report serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+    #[error("Invalid redaction pattern: {0}")]
+    InvalidRedactionPattern(#[from] regex::Error),
+}
+
+impl SecurityAuditResult {
+    /// Renders the audit in `options.format` after applying the finding filters.
+    pub fn export(&self, options: &ExportOptions) -> Result<String, ReportError> {
+        let report = self.filtered(options)?;
+        let rendered = match options.format {
+            ReportFormat::Json => serde_json::to_string_pretty(&report)?,
+            ReportFormat::Sarif => report.to_sarif()?,
+            ReportFormat::Csv => report.to_csv(options.min_severity),
+        };
+        Ok(rendered)
+    }
+
+    fn filtered(&self, options: &ExportOptions) -> Result<SecurityAuditResult, ReportError> {
+        let redaction = options.redaction.compile()?;
+        let findings = self
+            .findings
+            .iter()
+            .filter(|finding| finding.severity >= options.min_severity)
+            .cloned()
+            .map(|mut finding| {
+                finding.description = redaction.redact(&finding.description);
+                finding.remediation = if options.include_remediation {
+                    redaction.redact(&finding.remediation)
+                } else {
+                    String::new()
+                };
+                finding
+            })
+            .collect();
+
+        Ok(SecurityAuditResult {
+            passed: self.passed,
+            findings,
+            score: self.score,
+            suppressed: self.suppressed.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::{SecurityCategory, SecurityFinding};
+
+    fn audit_result() -> SecurityAuditResult {
+        let finding = |severity| SecurityFinding {
+            category: SecurityCategory::Injection,
+            severity,
+            description: "Test".to_string(),
+            remediation: "Parameterize queries".to_string(),
+        };
+        SecurityAuditResult {
+            passed: false,
+            findings: vec![finding(Severity::High), finding(Severity::Low)],
+            score: 82,
+            suppressed: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_export_dispatches_on_format() {
+        let report = audit_result();
+
+        for format in [ReportFormat::Json, ReportFormat::Sarif] {
+            let options = ExportOptions {
+                format,
+                ..ExportOptions::default()
+            };
+            let rendered = report.export(&options).unwrap();
+            assert!(serde_json::from_str::<serde_json::Value>(&rendered).is_ok());
+        }
+
+        let csv = report
+            .export(&ExportOptions {
+                format: ReportFormat::Csv,
+                ..ExportOptions::default()
+            })
+            .unwrap();
+        assert_eq!(csv.lines().count(), 3);
+    }
+
+    #[test]
+    fn test_export_applies_severity_and_remediation_filters() {
+        let options = ExportOptions {
+            format: ReportFormat::Json,
+            min_severity: Severity::Medium,
+            include_remediation: false,
+            redaction: RedactionRuleset::default(),
+        };
+
+        let json: serde_json::Value =
+            serde_json::from_str(&audit_result().export(&options).unwrap()).unwrap();
+
+        let findings = json["findings"].as_array().unwrap();
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0]["severity"], "high");
+        assert_eq!(findings[0]["remediation"], "");
+        assert_eq!(json["score"], 82);
+    }
+
+    #[test]
+    fn test_export_options_deserialize_with_defaults() {
+        let options: ExportOptions = serde_json::from_str(r#"{"format":"sarif"}"#).unwrap();
+
+        assert_eq!(options.format, ReportFormat::Sarif);
+        assert_eq!(options.min_severity, Severity::Info);
+        assert!(options.include_remediation);
+    }
+
+    #[test]
+    fn test_export_redacts_email_and_token_in_every_format() {
+        let report = SecurityAuditResult::from_findings(vec![SecurityFinding {
+            category: SecurityCategory::LoggingFailures,
+            severity: Severity::High,
+            description: "Log line leaks admin@example.com with token=ghp_secret123".to_string(),
+            remediation: "Stop logging request headers".to_string(),
+        }]);
+
+        for format in [ReportFormat::Json, ReportFormat::Sarif, ReportFormat::Csv] {
+            let rendered = report
+                .export(&ExportOptions {
+                    format,
+                    ..ExportOptions::default()
+                })
+                .unwrap();
+            assert!(rendered.contains("[REDACTED_EMAIL]"), "{format:?}");
+            assert!(rendered.contains("token=[REDACTED]"), "{format:?}");
+            assert!(!rendered.contains("admin@example.com"), "{format:?}");
+            assert!(!rendered.contains("ghp_secret123"), "{format:?}");
+        }
+
+        let raw = report
+            .export(&ExportOptions {
+                redaction: RedactionRuleset::none(),
+                ..ExportOptions::default()
+            })
+            .unwrap();
+        assert!(raw.contains("admin@example.com"));
+    }
+}
@@ -16,6 +16,7 @@ use crate::security::SecurityConfig;
 
 /// Security headers configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct SecurityHeadersConfig {
     /// Content-Security-Policy directive
     pub csp: String,
@@ -0,0 +1,130 @@
+//! Active tenant-isolation testing
+//!
+//! [`TenantIsolationCheck`] turns "test your tenant isolation" into a gate: given two tenants
+//! with seeded data, every registered [`CrossTenantProbe`] reads through the application's
+//! own repository or service layer as one tenant and asks for the other tenant's records.
+//! Anything that comes back is a critical `BrokenAccessControl` finding; a probe that
+//! cannot run is reported too, and so is a check without probes, so isolation that was
+//! never tested never passes silently.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sea_orm::DatabaseConnection;
+use uuid::Uuid;
+
+use super::{AsyncSecurityCheck, SecurityCategory, SecurityConfig, SecurityFinding, Severity};
+
+#[async_trait]
+pub trait CrossTenantProbe: Send + Sync {
+    fn name(&self) -> &str;
+
+    /// Reads `owner`'s seeded records while scoped to `reader`; returns how many came back.
+    async fn read_foreign(
+        &self,
+        db: &DatabaseConnection,
+        reader: Uuid,
+        owner: Uuid,
+    ) -> Result<usize, String>;
+}
+
+/// Runs every probe in both directions between two seeded tenants.
+#[derive(Clone)]
+pub struct TenantIsolationCheck {
+    tenants: (Uuid, Uuid),
+    probes: Vec<Arc<dyn CrossTenantProbe>>,
+}
+
+impl TenantIsolationCheck {
+    pub fn new(tenant_a: Uuid, tenant_b: Uuid) -> Self {
+        Self {
+            tenants: (tenant_a, tenant_b),
+            probes: Vec::new(),
+        }
+    }
+
+    pub fn with_probe(mut self, probe: impl CrossTenantProbe + 'static) -> Self {
+        self.probes.push(Arc::new(probe));
+        self
+    }
+
+    async fn run_probe(
+        probe: &dyn CrossTenantProbe,
+        db: &DatabaseConnection,
+        reader: Uuid,
+        owner: Uuid,
+    ) -> Option<SecurityFinding> {
+        match probe.read_foreign(db, reader, owner).await {
+            Ok(0) => None,
+            Ok(leaked) => Some(SecurityFinding {
+                category: SecurityCategory::BrokenAccessControl,
+                severity: Severity::Critical,
+                description: format!(
+                    "Cross-tenant read: probe '{}' as tenant {reader} returned {leaked} record(s) of tenant {owner}",
+                    probe.name()
+                ),
+                remediation: "Filter every query by tenant_id or enforce row-level security"
+                    .to_string(),
+            }),
+            Err(error) => Some(SecurityFinding {
+                category: SecurityCategory::BrokenAccessControl,
+                severity: Severity::Medium,
+                description: format!(
+                    "Tenant isolation probe '{}' could not run: {error}",
+                    probe.name()
+                ),
+                remediation: "Fix the probe or its seeded data so isolation is actually tested"
+                    .to_string(),
+            }),
+        }
+    }
+}
+
+#[async_trait]
+impl AsyncSecurityCheck for TenantIsolationCheck {
+    fn name(&self) -> &str {
+        "tenant_isolation"
+    }
+
+    async fn execute(
+        &self,
+        _config: &SecurityConfig,
+        db: &DatabaseConnection,
+    ) -> Vec<SecurityFinding> {
+        if self.probes.is_empty() {
+            return vec![SecurityFinding {
+                category: SecurityCategory::BrokenAccessControl,
+                severity: Severity::High,
+                description: "No cross-tenant probes registered: tenant isolation was not tested"
+                    .to_string(),
+                remediation: "Register a CrossTenantProbe for every tenant-scoped service"
+                    .to_string(),
+            }];
+        }
+
+        let (a, b) = self.tenants;
+        let mut findings = Vec::new();
+        for probe in &self.probes {
+            for (reader, owner) in [(a, b), (b, a)] {
+                findings.extend(Self::run_probe(probe.as_ref(), db, reader, owner).await);
+            }
+        }
+        findings
+    }
+}
+
+impl std::fmt::Debug for TenantIsolationCheck {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TenantIsolationCheck")
+            .field("tenants", &self.tenants)
+            .field(
+                "probes",
+                &self
+                    .probes
+                    .iter()
+                    .map(|probe| probe.name())
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
@@ -0,0 +1,58 @@
+//! Localized labels for the admin security UI
+//!
+//! Severity and finding-state names go through [`translate`] under the
+//! `security.severity.*` and `security.status.*` keys, so locales without a translation
+//! fall back to English like every other system string.
+
+use crate::i18n::{translate, Locale};
+
+use super::{FindingState, Severity};
+
+impl Severity {
+    pub fn label(self, locale: Locale) -> String {
+        let key = match self {
+            Severity::Critical => "security.severity.critical",
+            Severity::High => "security.severity.high",
+            Severity::Medium => "security.severity.medium",
+            Severity::Warning => "security.severity.warning",
+            Severity::Low => "security.severity.low",
+            Severity::Info => "security.severity.info",
+        };
+        translate(locale, key)
+    }
+}
+
+impl FindingState {
+    pub fn label(self, locale: Locale) -> String {
+        let key = match self {
+            FindingState::Open => "security.status.open",
+            FindingState::Acknowledged => "security.status.acknowledged",
+            FindingState::InProgress => "security.status.in_progress",
+            FindingState::Resolved => "security.status.resolved",
+            FindingState::Accepted => "security.status.accepted",
+        };
+        translate(locale, key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_labels_are_translated() {
+        assert_eq!(Severity::Critical.label(Locale::En), "Critical");
+        assert_eq!(Severity::Critical.label(Locale::Ru), "Критический");
+        assert_eq!(Severity::Warning.label(Locale::Ru), "Предупреждение");
+        assert_eq!(FindingState::InProgress.label(Locale::En), "In progress");
+        assert_eq!(FindingState::Accepted.label(Locale::Ru), "Риск принят");
+    }
+
+    #[test]
+    fn test_unknown_locale_falls_back_to_english() {
+        let locale = Locale::parse("pt-BR").unwrap_or_default();
+
+        assert_eq!(Severity::High.label(locale), "High");
+        assert_eq!(FindingState::Resolved.label(locale), "Resolved");
+    }
+}
@@ -0,0 +1,236 @@
+//! Finding lifecycle
+//!
+//! [`FindingStateStore`] tracks each finding (by [`SecurityFinding::finding_key`]) through
+//! open → acknowledged → in progress → resolved, or accepted as a known risk, recording who
+//! made each transition and when. Findings without a record are open.
+//! [`FindingStateStore::track`] annotates an audit with the current states and flags
+//! findings that were marked resolved but showed up again as regressed.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use super::{SecurityAuditResult, SecurityFinding};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FindingState {
+    #[default]
+    Open,
+    Acknowledged,
+    InProgress,
+    Resolved,
+    Accepted,
+}
+
+impl FindingState {
+    /// Work moves forward from open to resolved; resolved and accepted findings can only be
+    /// reopened.
+    pub fn can_transition_to(self, next: FindingState) -> bool {
+        use FindingState::*;
+
+        matches!(
+            (self, next),
+            (Open, Acknowledged | InProgress | Resolved | Accepted)
+                | (Acknowledged, InProgress | Resolved | Accepted)
+                | (InProgress, Resolved | Accepted)
+                | (Resolved | Accepted, Open)
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StateTransition {
+    pub from: FindingState,
+    pub to: FindingState,
+    pub actor: String,
+    pub at: DateTime<Utc>,
+}
+
+/// Current state of one finding and how it got there, oldest transition first.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FindingStatus {
+    pub state: FindingState,
+    pub history: Vec<StateTransition>,
+}
+
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+#[error("Finding '{finding_key}' cannot move from {from:?} to {to:?}")]
+pub struct InvalidTransition {
+    pub finding_key: String,
+    pub from: FindingState,
+    pub to: FindingState,
+}
+
+/// A finding of an audit together with its lifecycle state.
+#[derive(Debug, Clone, Serialize)]
+pub struct TrackedFinding {
+    pub finding: SecurityFinding,
+    pub state: FindingState,
+    /// Marked resolved, yet reported again.
+    pub regressed: bool,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FindingStateStore {
+    statuses: BTreeMap<String, FindingStatus>,
+}
+
+impl FindingStateStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn state(&self, finding_key: &str) -> FindingState {
+        self.statuses
+            .get(finding_key)
+            .map(|status| status.state)
+            .unwrap_or_default()
+    }
+
+    pub fn status(&self, finding_key: &str) -> Option<&FindingStatus> {
+        self.statuses.get(finding_key)
+    }
+
+    /// Moves the finding to `to`, recording `actor` and `at`.
+    pub fn transition(
+        &mut self,
+        finding_key: impl Into<String>,
+        to: FindingState,
+        actor: impl Into<String>,
+        at: DateTime<Utc>,
+    ) -> Result<&FindingStatus, InvalidTransition> {
+        let finding_key = finding_key.into();
+        let from = self.state(&finding_key);
+        if !from.can_transition_to(to) {
+            return Err(InvalidTransition {
+                finding_key,
+                from,
+                to,
+            });
+        }
+
+        let status = self.statuses.entry(finding_key).or_default();
+        status.state = to;
+        status.history.push(StateTransition {
+            from,
+            to,
+            actor: actor.into(),
+            at,
+        });
+        Ok(status)
+    }
+
+    /// The findings of `result` with their current state, in report order.
+    pub fn track(&self, result: &SecurityAuditResult) -> Vec<TrackedFinding> {
+        result
+            .findings
+            .iter()
+            .map(|finding| {
+                let state = self.state(&finding.finding_key());
+                TrackedFinding {
+                    finding: finding.clone(),
+                    state,
+                    regressed: state == FindingState::Resolved,
+                }
+            })
+            .collect()
+    }
+
+    /// Findings of `result` that were marked resolved.
+    pub fn regressions(&self, result: &SecurityAuditResult) -> Vec<TrackedFinding> {
+        self.track(result)
+            .into_iter()
+            .filter(|tracked| tracked.regressed)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::{SecurityCategory, Severity};
+
+    const CSP_KEY: &str = "security_misconfiguration:Content-Security-Policy header is not set";
+
+    fn audit_result() -> SecurityAuditResult {
+        SecurityAuditResult::from_findings(vec![
+            SecurityFinding {
+                category: SecurityCategory::SecurityMisconfiguration,
+                severity: Severity::High,
+                description: "Content-Security-Policy header is not set".to_string(),
+                remediation: "Configure CSP".to_string(),
+            },
+            SecurityFinding {
+                category: SecurityCategory::AuthFailures,
+                severity: Severity::Low,
+                description: "Test".to_string(),
+                remediation: "Fix".to_string(),
+            },
+        ])
+    }
+
+    #[test]
+    fn test_transitions_record_actor_and_time() {
+        let mut store = FindingStateStore::new();
+        let now = Utc::now();
+
+        store
+            .transition(CSP_KEY, FindingState::Acknowledged, "alice", now)
+            .unwrap();
+        store
+            .transition(CSP_KEY, FindingState::InProgress, "bob", now)
+            .unwrap();
+        let status = store
+            .transition(CSP_KEY, FindingState::Resolved, "bob", now)
+            .unwrap();
+
+        assert_eq!(status.state, FindingState::Resolved);
+        assert_eq!(status.history.len(), 3);
+        assert_eq!(status.history[0].from, FindingState::Open);
+        assert_eq!(status.history[0].actor, "alice");
+        assert_eq!(store.state("other:finding"), FindingState::Open);
+    }
+
+    #[test]
+    fn test_invalid_transition_is_rejected() {
+        let mut store = FindingStateStore::new();
+        let now = Utc::now();
+        store
+            .transition(CSP_KEY, FindingState::Accepted, "alice", now)
+            .unwrap();
+
+        let error = store
+            .transition(CSP_KEY, FindingState::InProgress, "bob", now)
+            .unwrap_err();
+
+        assert_eq!(
+            error,
+            InvalidTransition {
+                finding_key: CSP_KEY.to_string(),
+                from: FindingState::Accepted,
+                to: FindingState::InProgress,
+            }
+        );
+        assert_eq!(store.status(CSP_KEY).unwrap().history.len(), 1);
+    }
+
+    #[test]
+    fn test_resolved_finding_that_reappears_is_regressed() {
+        let mut store = FindingStateStore::new();
+        store
+            .transition(CSP_KEY, FindingState::Resolved, "alice", Utc::now())
+            .unwrap();
+
+        let tracked = store.track(&audit_result());
+
+        assert_eq!(tracked.len(), 2);
+        assert!(tracked[0].regressed);
+        assert_eq!(tracked[0].state, FindingState::Resolved);
+        assert!(!tracked[1].regressed);
+        assert_eq!(tracked[1].state, FindingState::Open);
+        assert_eq!(store.regressions(&audit_result()).len(), 1);
+    }
+}
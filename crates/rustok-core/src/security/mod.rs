@@ -11,21 +11,84 @@
 //! 8. Data Integrity - Request signing, tamper detection
 //! 9. Logging Failures - Security audit logging
 //! 10. SSRF - URL validation, allowlist enforcement
+//!
+//! Policies can be loaded from versioned JSON/YAML/TOML documents via
+//! [`SecurityConfig::from_policy_file`].
+//!
+//! Audit results can be exported as SARIF 2.1.0 via [`SecurityAuditResult::to_sarif`] and as
+//! RFC 4180 CSV via [`SecurityAuditResult::to_csv`], or in any [`ReportFormat`] through
+//! [`SecurityAuditResult::export`]; [`SecurityAuditResult::to_tracker_issues`] prepares
+//! issue-tracker payloads.
+//!
+//! Hardcoded secrets are caught by the opt-in [`SecurityCheck::secrets_scan`];
+//! [`SecurityCheck::cargo_audit`] folds `cargo audit` advisories into the same report.
+//!
+//! Completed audits are kept by a [`SecurityReportStore`] for trend and baseline analysis;
+//! [`FindingStateStore`] tracks each finding's triage state and flags resolved findings that
+//! come back.
 
+pub mod advisories;
+pub mod alerts;
 pub mod audit;
+pub mod auditor;
+pub mod batch;
+pub mod csv;
+pub mod diff;
+pub mod export;
 pub mod headers;
+pub mod isolation;
+pub mod labels;
+pub mod lifecycle;
+pub mod policy;
 pub mod rate_limit;
+pub mod redaction;
+pub mod sarif;
+pub mod secrets;
+pub mod store;
+pub mod summary;
+pub mod suppression;
+pub mod tenant;
+pub mod tracker;
 pub mod validation;
 
+pub use advisories::{cvss_v3_base_score, findings_from_cargo_audit};
+pub use alerts::{
+    AlertError, AlertSink, LoggingAlertSink, SecurityAlert, SecurityMonitor, WebhookAlertSink,
+    WebhookFormat,
+};
 pub use audit::{AuditEvent, AuditEventType, AuditLogger, SecurityAudit, SiemConfig};
+pub use auditor::{AsyncSecurityCheck, SecurityAuditor};
+pub use batch::{BatchAuditReport, BatchSecurityAuditor};
+pub use diff::{AuditDiff, SeverityChange, SystemDiff};
+pub use export::{ExportOptions, ReportError, ReportFormat};
 pub use headers::{FrameOptions, SecurityHeaders, SecurityHeadersConfig};
+pub use isolation::{CrossTenantProbe, TenantIsolationCheck};
+pub use lifecycle::{
+    FindingState, FindingStateStore, FindingStatus, InvalidTransition, StateTransition,
+    TrackedFinding,
+};
 pub use rate_limit::{RateLimitConfig, RateLimitResult, RateLimiter};
+pub use redaction::{CompiledRedaction, RedactionRule, RedactionRuleset};
+pub use secrets::{scan_for_secrets, SecretSource};
+pub use store::{
+    ReportSummary, SeaOrmSecurityReportStore, SecurityReportStore, SecurityReportsMigration,
+    StoredSecurityReport,
+};
+pub use summary::SecuritySummary;
+pub use suppression::{FindingSuppression, SuppressedFinding};
+pub use tenant::{AuditAccessDenied, AuditContext, TenantSecurityCheck};
+pub use tracker::TrackerIssue;
 pub use validation::{InputValidator, SsrfProtection, ValidationResult};
 
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
 use serde::{Deserialize, Serialize};
 
 /// Security configuration for the application
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct SecurityConfig {
     /// Security headers configuration
     pub headers: SecurityHeadersConfig,
@@ -56,15 +119,17 @@ impl Default for SecurityConfig {
 }
 
 /// Security audit result
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct SecurityAuditResult {
     pub passed: bool,
     pub findings: Vec<SecurityFinding>,
     pub score: u8, // 0-100
+    /// Findings hidden by an active [`FindingSuppression`]; not part of `score`.
+    pub suppressed: Vec<SuppressedFinding>,
 }
 
 /// Individual security finding
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SecurityFinding {
     pub category: SecurityCategory,
     pub severity: Severity,
@@ -72,8 +137,75 @@ pub struct SecurityFinding {
     pub remediation: String,
 }
 
+impl SecurityFinding {
+    /// Identity of the underlying issue, independent of severity and remediation wording.
+    pub fn stable_key(&self) -> (SecurityCategory, &str) {
+        (self.category, self.description.trim())
+    }
+
+    /// Deterministic id derived from [`SecurityFinding::finding_key`]: identical findings get
+    /// the same id in every run, process and toolchain (64-bit FNV-1a, as 16 hex digits).
+    pub fn stable_id(&self) -> String {
+        const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+        let hash = self
+            .finding_key()
+            .bytes()
+            .fold(FNV_OFFSET_BASIS, |hash, byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+            });
+        format!("{hash:016x}")
+    }
+}
+
+impl SecurityAuditResult {
+    /// Scores `findings`; an audit passes at a score of 80 or more.
+    pub fn from_findings(findings: Vec<SecurityFinding>) -> Self {
+        let score = calculate_security_score(&findings);
+        SecurityAuditResult {
+            passed: score >= 80,
+            findings,
+            score,
+            suppressed: Vec::new(),
+        }
+    }
+
+    /// See [`calculate_risk_score`].
+    pub fn risk_score(&self) -> u8 {
+        calculate_risk_score(&self.findings)
+    }
+
+    /// Merges audits from several systems into one result.
+    ///
+    /// Findings with the same [`SecurityFinding::stable_key`] collapse into one entry that
+    /// keeps the highest severity (and that finding's remediation); score and pass/fail are
+    /// recomputed from the merged findings.
+    pub fn consolidate(results: impl IntoIterator<Item = SecurityAuditResult>) -> Self {
+        let mut findings: Vec<SecurityFinding> = Vec::new();
+        let mut index_by_key: HashMap<(SecurityCategory, String), usize> = HashMap::new();
+
+        for finding in results.into_iter().flat_map(|result| result.findings) {
+            let (category, description) = finding.stable_key();
+            let key = (category, description.to_string());
+            match index_by_key.get(&key) {
+                Some(&index) if findings[index].severity < finding.severity => {
+                    findings[index] = finding;
+                }
+                Some(_) => {}
+                None => {
+                    index_by_key.insert(key, findings.len());
+                    findings.push(finding);
+                }
+            }
+        }
+
+        Self::from_findings(findings)
+    }
+}
+
 /// OWASP Top 10 categories
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum SecurityCategory {
     BrokenAccessControl,
@@ -101,21 +233,114 @@ pub enum Severity {
     Critical,
 }
 
-/// Run full OWASP Top 10 security audit
-pub async fn run_security_audit(config: &SecurityConfig) -> SecurityAuditResult {
-    let mut findings = Vec::new();
+impl Severity {
+    /// CVSS v3 qualitative band for this severity, inclusive on both ends.
+    ///
+    /// `Warning` has no CVSS counterpart and shares the `Medium` band.
+    pub fn cvss_range(self) -> (f32, f32) {
+        match self {
+            Severity::Info => (0.0, 0.0),
+            Severity::Low => (0.1, 3.9),
+            Severity::Warning | Severity::Medium => (4.0, 6.9),
+            Severity::High => (7.0, 8.9),
+            Severity::Critical => (9.0, 10.0),
+        }
+    }
 
-    // Check security headers
-    findings.extend(headers::audit_headers(config).await);
+    /// Severity whose CVSS v3 band contains `score`; never returns `Warning`.
+    pub fn from_cvss(score: f32) -> Self {
+        match score {
+            s if s >= 9.0 => Severity::Critical,
+            s if s >= 7.0 => Severity::High,
+            s if s >= 4.0 => Severity::Medium,
+            s if s > 0.0 => Severity::Low,
+            _ => Severity::Info,
+        }
+    }
+
+    /// Whether an explicit CVSS score falls inside [`Severity::cvss_range`].
+    pub fn is_consistent_cvss(self, score: f32) -> bool {
+        let (min, max) = self.cvss_range();
+        (min..=max).contains(&score)
+    }
+
+    /// Clamps `score` into this severity's band, warning when it had to be adjusted.
+    pub fn reconcile_cvss(self, score: f32) -> f32 {
+        if self.is_consistent_cvss(score) {
+            return score;
+        }
+        let (min, max) = self.cvss_range();
+        let clamped = if score.is_nan() {
+            min
+        } else {
+            score.clamp(min, max)
+        };
+        tracing::warn!(
+            severity = ?self,
+            score,
+            clamped,
+            "CVSS score is inconsistent with finding severity"
+        );
+        clamped
+    }
+}
+
+type CheckFn = dyn Fn(&SecurityConfig) -> Vec<SecurityFinding> + Send + Sync;
+
+/// Application-specific audit check run alongside the built-in OWASP checks.
+///
+/// The check is a closure, so it can capture state such as tenant lists or policy data.
+#[derive(Clone)]
+pub struct SecurityCheck {
+    pub name: String,
+    check: Arc<CheckFn>,
+}
+
+impl SecurityCheck {
+    pub fn custom(
+        name: impl Into<String>,
+        check: impl Fn(&SecurityConfig) -> Vec<SecurityFinding> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            check: Arc::new(check),
+        }
+    }
 
-    // Check rate limiting
-    findings.extend(rate_limit::audit_rate_limiting(config).await);
+    pub fn run(&self, config: &SecurityConfig) -> Vec<SecurityFinding> {
+        (self.check)(config)
+    }
+}
 
-    // Check input validation
-    findings.extend(validation::audit_validation(config).await);
+impl fmt::Debug for SecurityCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SecurityCheck")
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
 
-    // Check audit logging
-    findings.extend(audit::audit_logging(config).await);
+/// Run full OWASP Top 10 security audit
+///
+/// Category checks run concurrently; findings are sorted with [`sort_findings`] so the
+/// report does not depend on completion order.
+pub async fn run_security_audit(config: &SecurityConfig) -> SecurityAuditResult {
+    let (headers, rate_limit, validation, logging) = futures::join!(
+        // Check security headers
+        headers::audit_headers(config),
+        // Check rate limiting
+        rate_limit::audit_rate_limiting(config),
+        // Check input validation
+        validation::audit_validation(config),
+        // Check audit logging
+        audit::audit_logging(config),
+    );
+
+    let mut findings: Vec<SecurityFinding> = [headers, rate_limit, validation, logging]
+        .into_iter()
+        .flatten()
+        .collect();
+    sort_findings(&mut findings);
 
     let score = calculate_security_score(&findings);
     let passed = score >= 80;
@@ -124,26 +349,112 @@ pub async fn run_security_audit(config: &SecurityConfig) -> SecurityAuditResult
         passed,
         findings,
         score,
+        suppressed: Vec::new(),
+    }
+}
+
+/// Runs the full audit plus `checks`, scoring all findings together.
+pub async fn run_security_audit_with_checks(
+    config: &SecurityConfig,
+    checks: &[SecurityCheck],
+) -> SecurityAuditResult {
+    let mut findings = run_security_audit(config).await.findings;
+    for check in checks {
+        findings.extend(check.run(config));
     }
+    sort_findings(&mut findings);
+
+    SecurityAuditResult::from_findings(findings)
+}
+
+/// Re-runs only the checks covering `changed` categories and merges them into `baseline`.
+///
+/// Baseline findings in `changed` categories are replaced by the fresh results; findings
+/// in every other category are kept as-is. Score and pass/fail are recomputed.
+pub async fn run_incremental_security_audit(
+    config: &SecurityConfig,
+    baseline: &SecurityAuditResult,
+    changed: &[SecurityCategory],
+) -> SecurityAuditResult {
+    let touches = |categories: &[SecurityCategory]| {
+        categories.iter().any(|category| changed.contains(category))
+    };
+    let mut fresh = Vec::new();
+
+    if touches(&[
+        SecurityCategory::SecurityMisconfiguration,
+        SecurityCategory::CryptographicFailures,
+    ]) {
+        fresh.extend(headers::audit_headers(config).await);
+    }
+    if touches(&[SecurityCategory::AuthFailures]) {
+        fresh.extend(rate_limit::audit_rate_limiting(config).await);
+    }
+    if touches(&[SecurityCategory::Injection, SecurityCategory::Ssrf]) {
+        fresh.extend(validation::audit_validation(config).await);
+    }
+    if touches(&[SecurityCategory::LoggingFailures]) {
+        fresh.extend(audit::audit_logging(config).await);
+    }
+
+    let findings: Vec<SecurityFinding> = baseline
+        .findings
+        .iter()
+        .filter(|finding| !changed.contains(&finding.category))
+        .cloned()
+        .chain(
+            fresh
+                .into_iter()
+                .filter(|finding| changed.contains(&finding.category)),
+        )
+        .collect();
+
+    SecurityAuditResult::from_findings(findings)
+}
+
+/// Stable report order: OWASP category, then highest severity first. Ties keep their
+/// original relative order.
+pub fn sort_findings(findings: &mut [SecurityFinding]) {
+    findings.sort_by(|left, right| {
+        left.category
+            .cmp(&right.category)
+            .then_with(|| right.severity.cmp(&left.severity))
+    });
 }
 
 pub fn calculate_security_score(findings: &[SecurityFinding]) -> u8 {
     let base_score = 100i16;
-    let deductions: i16 = findings
-        .iter()
-        .map(|f| match f.severity {
-            Severity::Critical => 25,
-            Severity::High => 15,
-            Severity::Medium => 8,
-            Severity::Warning => 5,
-            Severity::Low => 3,
-            Severity::Info => 0,
-        })
-        .sum();
+    let deductions: i16 = findings.iter().map(|f| severity_weight(f.severity)).sum();
 
     (base_score - deductions).clamp(0, 100) as u8
 }
 
+/// Saturating 0-100 risk score: the sum of severity weights capped at 100, with a floor of
+/// 90 when any critical finding is present and 70 for any high one.
+///
+/// Monotonic by construction: adding findings never lowers the risk.
+pub fn calculate_risk_score(findings: &[SecurityFinding]) -> u8 {
+    let weighted: i16 = findings.iter().map(|f| severity_weight(f.severity)).sum();
+    let floor = match findings.iter().map(|f| f.severity).max() {
+        Some(Severity::Critical) => 90,
+        Some(Severity::High) => 70,
+        _ => 0,
+    };
+
+    weighted.clamp(floor, 100) as u8
+}
+
+fn severity_weight(severity: Severity) -> i16 {
+    match severity {
+        Severity::Critical => 25,
+        Severity::High => 15,
+        Severity::Medium => 8,
+        Severity::Warning => 5,
+        Severity::Low => 3,
+        Severity::Info => 0,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -168,6 +479,154 @@ mod tests {
         assert_eq!(calculate_security_score(&findings), 77);
     }
 
+    #[test]
+    fn test_stable_id_is_deterministic() {
+        let finding = |severity, description: &str| SecurityFinding {
+            category: SecurityCategory::SecurityMisconfiguration,
+            severity,
+            description: description.to_string(),
+            remediation: "Configure CSP".to_string(),
+        };
+
+        let id = finding(Severity::High, "CSP header is not set").stable_id();
+        assert_eq!(
+            id,
+            finding(Severity::Low, "CSP header is not set ").stable_id()
+        );
+        assert_ne!(
+            id,
+            finding(Severity::High, "HSTS header is not set").stable_id()
+        );
+        assert_eq!(id.len(), 16);
+    }
+
+    #[test]
+    fn test_cvss_ranges_match_standard_bands() {
+        assert_eq!(Severity::Info.cvss_range(), (0.0, 0.0));
+        assert_eq!(Severity::Low.cvss_range(), (0.1, 3.9));
+        assert_eq!(Severity::Medium.cvss_range(), (4.0, 6.9));
+        assert_eq!(Severity::High.cvss_range(), (7.0, 8.9));
+        assert_eq!(Severity::Critical.cvss_range(), (9.0, 10.0));
+    }
+
+    #[test]
+    fn test_inconsistent_cvss_is_flagged_and_clamped() {
+        assert!(!Severity::High.is_consistent_cvss(2.0));
+        assert!(Severity::High.is_consistent_cvss(7.5));
+        assert_eq!(Severity::High.reconcile_cvss(2.0), 7.0);
+        assert_eq!(Severity::Critical.reconcile_cvss(11.0), 10.0);
+        assert_eq!(Severity::Low.reconcile_cvss(3.2), 3.2);
+    }
+
+    #[test]
+    fn test_consolidate_deduplicates_identical_findings() {
+        let finding = |severity, remediation: &str| SecurityFinding {
+            category: SecurityCategory::SecurityMisconfiguration,
+            severity,
+            description: "CSP is missing default-src directive".to_string(),
+            remediation: remediation.to_string(),
+        };
+        let api = SecurityAuditResult {
+            passed: true,
+            findings: vec![finding(Severity::Medium, "Fix API CSP")],
+            score: 92,
+            suppressed: Vec::new(),
+        };
+        let storefront = SecurityAuditResult {
+            passed: true,
+            findings: vec![
+                finding(Severity::High, "Fix storefront CSP"),
+                SecurityFinding {
+                    category: SecurityCategory::Injection,
+                    severity: Severity::Low,
+                    description: "Test".to_string(),
+                    remediation: "Fix".to_string(),
+                },
+            ],
+            score: 82,
+            suppressed: Vec::new(),
+        };
+
+        let merged = SecurityAuditResult::consolidate([api, storefront]);
+
+        assert_eq!(merged.findings.len(), 2);
+        assert_eq!(merged.findings[0].severity, Severity::High);
+        assert_eq!(merged.findings[0].remediation, "Fix storefront CSP");
+        assert_eq!(merged.score, 82);
+        assert!(merged.passed);
+    }
+
+    fn findings(counts: &[(Severity, usize)]) -> Vec<SecurityFinding> {
+        counts
+            .iter()
+            .flat_map(|&(severity, count)| {
+                (0..count).map(move |_| SecurityFinding {
+                    category: SecurityCategory::Other,
+                    severity,
+                    description: "Test".to_string(),
+                    remediation: "Fix".to_string(),
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_risk_score_single_critical_hits_floor() {
+        assert_eq!(
+            calculate_risk_score(&findings(&[(Severity::Critical, 1)])),
+            90
+        );
+        assert_eq!(calculate_risk_score(&findings(&[(Severity::High, 1)])), 70);
+    }
+
+    #[test]
+    fn test_risk_score_never_drops_with_more_findings() {
+        let one_critical = calculate_risk_score(&findings(&[(Severity::Critical, 1)]));
+        let many = calculate_risk_score(&findings(&[(Severity::Critical, 5), (Severity::Low, 20)]));
+
+        assert_eq!(many, 100);
+        assert!(many >= one_critical);
+    }
+
+    #[test]
+    fn test_risk_score_only_info_is_zero() {
+        assert_eq!(calculate_risk_score(&findings(&[(Severity::Info, 10)])), 0);
+        assert_eq!(calculate_risk_score(&[]), 0);
+    }
+
+    async fn sequential_findings(config: &SecurityConfig) -> Vec<SecurityFinding> {
+        let mut findings = Vec::new();
+        findings.extend(headers::audit_headers(config).await);
+        findings.extend(rate_limit::audit_rate_limiting(config).await);
+        findings.extend(validation::audit_validation(config).await);
+        findings.extend(audit::audit_logging(config).await);
+        sort_findings(&mut findings);
+        findings
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_audit_matches_sequential_checks() {
+        let keys = |findings: &[SecurityFinding]| {
+            findings
+                .iter()
+                .map(|f| (f.category, f.severity, f.description.clone()))
+                .collect::<Vec<_>>()
+        };
+        let weakened = SecurityConfig {
+            enforce_https: false,
+            audit_logging: false,
+            ..SecurityConfig::default()
+        };
+
+        for config in [SecurityConfig::default(), weakened] {
+            let expected = sequential_findings(&config).await;
+            let concurrent = run_security_audit(&config).await;
+
+            assert_eq!(keys(&concurrent.findings), keys(&expected));
+            assert_eq!(concurrent.score, calculate_security_score(&expected));
+        }
+    }
+
     #[test]
     fn test_security_score_perfect() {
         let findings: Vec<SecurityFinding> = vec![];
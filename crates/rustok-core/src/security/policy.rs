@@ -0,0 +1,122 @@
+//! Security policy as code
+//!
+//! Loads a [`SecurityConfig`] from a versioned JSON/YAML/TOML policy document. Omitted
+//! keys fall back to [`SecurityConfig::default`], unknown keys are rejected so typos do not
+//! silently weaken a policy, and structurally unusable values fail
+//! [`SecurityConfig::validate`]. Weak-but-valid values are left for
+//! [`run_security_audit`](super::run_security_audit) to flag as findings.
+
+use crate::config::{ConfigError, ConfigFormat};
+
+use super::SecurityConfig;
+
+impl SecurityConfig {
+    /// Parses and validates a policy document.
+    pub fn from_policy_str(content: &str, format: ConfigFormat) -> Result<Self, ConfigError> {
+        let parse_error = |message: String| ConfigError::ParseError {
+            source: "security policy".to_string(),
+            format,
+            message,
+        };
+        let config: SecurityConfig = match format {
+            ConfigFormat::Json => {
+                serde_json::from_str(content).map_err(|e| parse_error(e.to_string()))?
+            }
+            ConfigFormat::Yaml => {
+                serde_yaml::from_str(content).map_err(|e| parse_error(e.to_string()))?
+            }
+            ConfigFormat::Toml => {
+                toml::from_str(content).map_err(|e| parse_error(e.to_string()))?
+            }
+        };
+
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Loads a policy file, detecting the format from its extension.
+    pub fn from_policy_file(path: &str) -> Result<Self, ConfigError> {
+        let format = ConfigFormat::from_extension(path).ok_or_else(|| {
+            ConfigError::Other(format!("Cannot detect policy format from path: {}", path))
+        })?;
+        let content = std::fs::read_to_string(path).map_err(|e| ConfigError::ReadError {
+            path: path.to_string(),
+            message: e.to_string(),
+        })?;
+
+        Self::from_policy_str(&content, format)
+    }
+
+    /// Rejects values the security primitives cannot operate with.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if self.rate_limit.requests_per_minute > 0 && self.rate_limit.burst_size == 0 {
+            return Err(ConfigError::InvalidValue {
+                key: "rate_limit.burst_size".to_string(),
+                value: "0".to_string(),
+                reason: "must be at least 1 while rate limiting is enabled".to_string(),
+            });
+        }
+
+        if let Some(host) = self
+            .allowed_hosts
+            .iter()
+            .find(|host| host.trim().is_empty() || host.contains("://") || host.contains('/'))
+        {
+            return Err(ConfigError::InvalidValue {
+                key: "allowed_hosts".to_string(),
+                value: host.clone(),
+                reason: "entries must be bare host names".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::run_security_audit;
+
+    #[tokio::test]
+    async fn test_policy_document_is_loaded_and_audited() {
+        let policy = r#"
+            enforce_https = true
+            allowed_hosts = ["api.example.com"]
+
+            [rate_limit]
+            login_attempts_per_minute = 50
+        "#;
+
+        let config = SecurityConfig::from_policy_str(policy, ConfigFormat::Toml).unwrap();
+        assert_eq!(config.rate_limit.login_attempts_per_minute, 50);
+        assert_eq!(config.rate_limit.requests_per_minute, 60);
+
+        let audit = run_security_audit(&config).await;
+        assert!(audit
+            .findings
+            .iter()
+            .any(|f| f.description.contains("Login rate limit is too high")));
+    }
+
+    #[test]
+    fn test_invalid_policy_documents_are_rejected() {
+        let typo = SecurityConfig::from_policy_str(
+            r#"{"rate_limit": {"login_attemps_per_minute": 5}}"#,
+            ConfigFormat::Json,
+        )
+        .unwrap_err();
+        assert!(matches!(typo, ConfigError::ParseError { .. }));
+        assert!(typo.to_string().contains("login_attemps_per_minute"));
+
+        let unusable = SecurityConfig::from_policy_str(
+            "allowed_hosts:\n  - https://api.example.com\n",
+            ConfigFormat::Yaml,
+        )
+        .unwrap_err();
+        assert!(matches!(
+            unusable,
+            ConfigError::InvalidValue { ref key, .. } if key == "allowed_hosts"
+        ));
+    }
+}
@@ -19,6 +19,7 @@ use crate::security::SecurityConfig;
 
 /// Rate limit configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct RateLimitConfig {
     /// Requests per minute per IP
     pub requests_per_minute: u32,
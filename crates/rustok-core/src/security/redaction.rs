@@ -0,0 +1,132 @@
+//! Redaction of sensitive values in exported reports
+//!
+//! Finding descriptions can quote what a check saw: a log line, a config value, a request
+//! header. A [`RedactionRuleset`] is a list of regex rules applied to finding text by
+//! [`SecurityAuditResult::export`](super::SecurityAuditResult::export) before any format is
+//! rendered. The default ruleset masks e-mail addresses, bearer tokens, JWTs,
+//! `key=value` credentials and IPv4 addresses; [`RedactionRuleset::none`] turns redaction off.
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RedactionRule {
+    pub name: String,
+    pub pattern: String,
+    /// Replacement text; may refer to capture groups as `$1`.
+    pub replacement: String,
+}
+
+impl RedactionRule {
+    pub fn new(
+        name: impl Into<String>,
+        pattern: impl Into<String>,
+        replacement: impl Into<String>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            pattern: pattern.into(),
+            replacement: replacement.into(),
+        }
+    }
+}
+
+/// Rules are applied in order, each to the output of the previous one.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RedactionRuleset {
+    pub rules: Vec<RedactionRule>,
+}
+
+impl Default for RedactionRuleset {
+    fn default() -> Self {
+        Self {
+            rules: vec![
+                RedactionRule::new(
+                    "email",
+                    r"[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Za-z]{2,}",
+                    "[REDACTED_EMAIL]",
+                ),
+                RedactionRule::new(
+                    "jwt",
+                    r"\beyJ[A-Za-z0-9_-]{10,}\.eyJ[A-Za-z0-9_-]{10,}\.[A-Za-z0-9_-]{10,}",
+                    "[REDACTED_TOKEN]",
+                ),
+                RedactionRule::new(
+                    "bearer",
+                    r"(?i)\b(bearer\s+)[A-Za-z0-9._~+/=-]+",
+                    "${1}[REDACTED_TOKEN]",
+                ),
+                RedactionRule::new(
+                    "credential",
+                    r"(?i)\b((?:api[_-]?key|token|secret|password)\s*[=:]\s*)[^\s,;]+",
+                    "${1}[REDACTED]",
+                ),
+                RedactionRule::new("ipv4", r"\b(?:\d{1,3}\.){3}\d{1,3}\b", "[REDACTED_IP]"),
+            ],
+        }
+    }
+}
+
+impl RedactionRuleset {
+    pub fn none() -> Self {
+        Self { rules: Vec::new() }
+    }
+
+    pub fn with_rule(mut self, rule: RedactionRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    pub fn compile(&self) -> Result<CompiledRedaction, regex::Error> {
+        let rules = self
+            .rules
+            .iter()
+            .map(|rule| Ok((Regex::new(&rule.pattern)?, rule.replacement.clone())))
+            .collect::<Result<_, regex::Error>>()?;
+        Ok(CompiledRedaction { rules })
+    }
+}
+
+/// A [`RedactionRuleset`] with its patterns compiled.
+#[derive(Debug, Clone)]
+pub struct CompiledRedaction {
+    rules: Vec<(Regex, String)>,
+}
+
+impl CompiledRedaction {
+    pub fn redact(&self, text: &str) -> String {
+        self.rules
+            .iter()
+            .fold(text.to_string(), |text, (pattern, replacement)| {
+                pattern
+                    .replace_all(&text, replacement.as_str())
+                    .into_owned()
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_rules_mask_credentials_and_addresses() {
+        let redaction = RedactionRuleset::default().compile().unwrap();
+
+        let redacted = redaction.redact(
+            "Request from 10.0.0.12 by ops@example.com with Authorization: Bearer abc.def-123, api_key=sk_live_42",
+        );
+
+        assert_eq!(
+            redacted,
+            "Request from [REDACTED_IP] by [REDACTED_EMAIL] with Authorization: Bearer [REDACTED_TOKEN], api_key=[REDACTED]"
+        );
+    }
+
+    #[test]
+    fn test_invalid_pattern_fails_to_compile() {
+        let ruleset = RedactionRuleset::none().with_rule(RedactionRule::new("broken", "(", ""));
+
+        assert!(ruleset.compile().is_err());
+    }
+}
@@ -0,0 +1,200 @@
+//! SARIF 2.1.0 export for security audit results
+//!
+//! Renders a [`SecurityAuditResult`] as a SARIF log so `run_security_audit` output can be
+//! uploaded to code scanning tools (e.g. GitHub code scanning):
+//! - one `result` per finding, `ruleId` derived from its OWASP category;
+//! - `level` from severity: critical/high → `error`, medium/warning → `warning`,
+//!   low/info → `note`;
+//! - categories map to the OWASP Top 10 2021 taxonomy via `taxa`.
+//!
+//! Audit findings describe configuration, not source files, so results carry no
+//! `locations`.
+
+use serde_json::{json, Value};
+
+use super::{SecurityAuditResult, SecurityCategory, SecurityFinding, Severity};
+
+pub const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+pub const SARIF_VERSION: &str = "2.1.0";
+
+const TOOL_NAME: &str = "rustok-security-audit";
+const OWASP_TAXONOMY: &str = "OWASP Top 10 2021";
+
+impl SecurityAuditResult {
+    /// Serializes the audit as a pretty-printed SARIF 2.1.0 log.
+    pub fn to_sarif(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(&sarif_log(self))
+    }
+}
+
+fn sarif_log(result: &SecurityAuditResult) -> Value {
+    let mut categories: Vec<SecurityCategory> = Vec::new();
+    for finding in &result.findings {
+        if !categories.contains(&finding.category) {
+            categories.push(finding.category);
+        }
+    }
+
+    let rules: Vec<Value> = categories
+        .iter()
+        .map(|category| sarif_rule(*category))
+        .collect();
+    let taxa: Vec<Value> = categories
+        .iter()
+        .filter_map(|category| owasp_taxon(*category))
+        .map(|(id, name)| json!({ "id": id, "name": name }))
+        .collect();
+    let results: Vec<Value> = result.findings.iter().map(sarif_result).collect();
+
+    json!({
+        "$schema": SARIF_SCHEMA,
+        "version": SARIF_VERSION,
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": TOOL_NAME,
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "rules": rules,
+                    "supportedTaxonomies": [{ "name": OWASP_TAXONOMY }],
+                }
+            },
+            "taxonomies": [{ "name": OWASP_TAXONOMY, "taxa": taxa }],
+            "results": results,
+            "properties": {
+                "score": result.score,
+                "passed": result.passed,
+            }
+        }]
+    })
+}
+
+fn sarif_rule(category: SecurityCategory) -> Value {
+    let mut rule = json!({
+        "id": rule_id(category),
+        "shortDescription": { "text": format!("{category:?}") },
+    });
+    if let Some((id, _)) = owasp_taxon(category) {
+        rule["relationships"] = json!([{
+            "target": { "id": id, "toolComponent": { "name": OWASP_TAXONOMY } },
+            "kinds": ["superset"],
+        }]);
+    }
+    rule
+}
+
+fn sarif_result(finding: &SecurityFinding) -> Value {
+    let mut result = json!({
+        "ruleId": rule_id(finding.category),
+        "level": sarif_level(finding.severity),
+        "message": { "text": finding.description },
+        "properties": {
+            "severity": finding.severity,
+            "remediation": finding.remediation,
+        }
+    });
+    if let Some((id, _)) = owasp_taxon(finding.category) {
+        result["taxa"] = json!([{ "id": id, "toolComponent": { "name": OWASP_TAXONOMY } }]);
+    }
+    result
+}
+
+fn rule_id(category: SecurityCategory) -> String {
+    let category = serde_json::to_value(category)
+        .ok()
+        .and_then(|value| value.as_str().map(str::to_string))
+        .unwrap_or_else(|| "other".to_string());
+    format!("rustok/{category}")
+}
+
+fn sarif_level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Critical | Severity::High => "error",
+        Severity::Medium | Severity::Warning => "warning",
+        Severity::Low | Severity::Info => "note",
+    }
+}
+
+/// OWASP Top 10 2021 id and name for `category`; `None` for [`SecurityCategory::Other`].
+pub(super) fn owasp_taxon(category: SecurityCategory) -> Option<(&'static str, &'static str)> {
+    let taxon = match category {
+        SecurityCategory::BrokenAccessControl => ("A01", "Broken Access Control"),
+        SecurityCategory::CryptographicFailures => ("A02", "Cryptographic Failures"),
+        SecurityCategory::Injection => ("A03", "Injection"),
+        SecurityCategory::InsecureDesign => ("A04", "Insecure Design"),
+        SecurityCategory::SecurityMisconfiguration => ("A05", "Security Misconfiguration"),
+        SecurityCategory::VulnerableComponents => ("A06", "Vulnerable and Outdated Components"),
+        SecurityCategory::AuthFailures => ("A07", "Identification and Authentication Failures"),
+        SecurityCategory::DataIntegrity => ("A08", "Software and Data Integrity Failures"),
+        SecurityCategory::LoggingFailures => ("A09", "Security Logging and Monitoring Failures"),
+        SecurityCategory::Ssrf => ("A10", "Server-Side Request Forgery"),
+        SecurityCategory::Other => return None,
+    };
+    Some(taxon)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn finding(category: SecurityCategory, severity: Severity) -> SecurityFinding {
+        SecurityFinding {
+            category,
+            severity,
+            description: "Test".to_string(),
+            remediation: "Fix".to_string(),
+        }
+    }
+
+    fn audit_result() -> SecurityAuditResult {
+        let findings = vec![
+            finding(SecurityCategory::SecurityMisconfiguration, Severity::High),
+            finding(SecurityCategory::SecurityMisconfiguration, Severity::Low),
+            finding(SecurityCategory::Injection, Severity::Medium),
+            finding(SecurityCategory::Other, Severity::Info),
+        ];
+        SecurityAuditResult {
+            passed: false,
+            score: super::super::calculate_security_score(&findings),
+            findings,
+            suppressed: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_sarif_has_one_result_per_finding() {
+        let sarif: Value = serde_json::from_str(&audit_result().to_sarif().unwrap()).unwrap();
+
+        assert_eq!(sarif["$schema"], SARIF_SCHEMA);
+        assert_eq!(sarif["version"], SARIF_VERSION);
+        let results = sarif["runs"][0]["results"].as_array().unwrap();
+        assert_eq!(results.len(), 4);
+        assert_eq!(results[0]["ruleId"], "rustok/security_misconfiguration");
+        assert_eq!(
+            sarif["runs"][0]["tool"]["driver"]["rules"]
+                .as_array()
+                .unwrap()
+                .len(),
+            3
+        );
+    }
+
+    #[test]
+    fn test_sarif_levels_and_taxa() {
+        let sarif: Value = serde_json::from_str(&audit_result().to_sarif().unwrap()).unwrap();
+        let results = &sarif["runs"][0]["results"];
+
+        let levels: Vec<&str> = (0..4)
+            .map(|i| results[i]["level"].as_str().unwrap())
+            .collect();
+        assert_eq!(levels, ["error", "note", "warning", "note"]);
+        assert_eq!(results[2]["taxa"][0]["id"], "A03");
+        assert!(results[3].get("taxa").is_none());
+        assert_eq!(
+            sarif["runs"][0]["taxonomies"][0]["taxa"]
+                .as_array()
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+}
@@ -0,0 +1,240 @@
+//! Persistence of audit results
+//!
+//! [`SecurityReportStore`] keeps completed audits so trends and baselines can be computed
+//! across runs. [`SeaOrmSecurityReportStore`] writes them to the `security_reports` table
+//! created by [`SecurityReportsMigration`]: the summary (pass/fail, score, counts) and the
+//! findings are stored as JSON next to the completion time, which is indexed for range
+//! queries and retention pruning.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, QueryOrder,
+    Set,
+};
+use sea_orm_migration::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::id::generate_id;
+
+use super::{SecurityAuditResult, SecurityFinding};
+
+pub mod entity {
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "security_reports")]
+    pub struct Model {
+        #[sea_orm(primary_key, auto_increment = false)]
+        pub id: Uuid,
+        pub completed_at: DateTimeUtc,
+        /// [`super::ReportSummary`] as JSON.
+        pub summary: Json,
+        /// JSON array of [`crate::security::SecurityFinding`]s.
+        pub findings: Json,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+/// Headline numbers of a stored report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReportSummary {
+    pub passed: bool,
+    pub score: u8,
+    pub findings: usize,
+    pub suppressed: usize,
+}
+
+/// A completed audit as persisted by a [`SecurityReportStore`]. Suppressed findings are
+/// only counted in the summary.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StoredSecurityReport {
+    pub id: Uuid,
+    pub completed_at: DateTime<Utc>,
+    pub summary: ReportSummary,
+    pub findings: Vec<SecurityFinding>,
+}
+
+impl StoredSecurityReport {
+    pub fn new(result: &SecurityAuditResult, completed_at: DateTime<Utc>) -> Self {
+        Self {
+            id: generate_id(),
+            completed_at,
+            summary: ReportSummary {
+                passed: result.passed,
+                score: result.score,
+                findings: result.findings.len(),
+                suppressed: result.suppressed.len(),
+            },
+            findings: result.findings.clone(),
+        }
+    }
+
+    /// The audit result without its suppressed findings.
+    pub fn to_result(&self) -> SecurityAuditResult {
+        SecurityAuditResult {
+            passed: self.summary.passed,
+            findings: self.findings.clone(),
+            score: self.summary.score,
+            suppressed: Vec::new(),
+        }
+    }
+}
+
+#[async_trait]
+pub trait SecurityReportStore: Send + Sync {
+    async fn save(&self, report: &StoredSecurityReport) -> Result<(), DbErr>;
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<StoredSecurityReport>, DbErr>;
+
+    /// Reports completed in `[from, to)`, oldest first.
+    async fn find_between(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<StoredSecurityReport>, DbErr>;
+
+    /// Deletes reports completed before `cutoff`; returns how many were removed.
+    async fn prune_before(&self, cutoff: DateTime<Utc>) -> Result<u64, DbErr>;
+
+    /// Keeps only the reports completed within `retention` of now.
+    async fn prune(&self, retention: Duration) -> Result<u64, DbErr> {
+        self.prune_before(Utc::now() - retention).await
+    }
+}
+
+#[derive(Clone)]
+pub struct SeaOrmSecurityReportStore {
+    db: DatabaseConnection,
+}
+
+impl SeaOrmSecurityReportStore {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl SecurityReportStore for SeaOrmSecurityReportStore {
+    async fn save(&self, report: &StoredSecurityReport) -> Result<(), DbErr> {
+        entity::ActiveModel {
+            id: Set(report.id),
+            completed_at: Set(report.completed_at),
+            summary: Set(to_json(&report.summary)?),
+            findings: Set(to_json(&report.findings)?),
+        }
+        .insert(&self.db)
+        .await?;
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<StoredSecurityReport>, DbErr> {
+        entity::Entity::find_by_id(id)
+            .one(&self.db)
+            .await?
+            .map(from_model)
+            .transpose()
+    }
+
+    async fn find_between(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<StoredSecurityReport>, DbErr> {
+        entity::Entity::find()
+            .filter(entity::Column::CompletedAt.gte(from))
+            .filter(entity::Column::CompletedAt.lt(to))
+            .order_by_asc(entity::Column::CompletedAt)
+            .all(&self.db)
+            .await?
+            .into_iter()
+            .map(from_model)
+            .collect()
+    }
+
+    async fn prune_before(&self, cutoff: DateTime<Utc>) -> Result<u64, DbErr> {
+        let result = entity::Entity::delete_many()
+            .filter(entity::Column::CompletedAt.lt(cutoff))
+            .exec(&self.db)
+            .await?;
+        Ok(result.rows_affected)
+    }
+}
+
+fn to_json<T: Serialize>(value: &T) -> Result<serde_json::Value, DbErr> {
+    serde_json::to_value(value).map_err(|e| DbErr::Json(e.to_string()))
+}
+
+fn from_json<T: DeserializeOwned>(value: serde_json::Value) -> Result<T, DbErr> {
+    serde_json::from_value(value).map_err(|e| DbErr::Json(e.to_string()))
+}
+
+fn from_model(model: entity::Model) -> Result<StoredSecurityReport, DbErr> {
+    Ok(StoredSecurityReport {
+        id: model.id,
+        completed_at: model.completed_at,
+        summary: from_json(model.summary)?,
+        findings: from_json(model.findings)?,
+    })
+}
+
+#[derive(DeriveMigrationName)]
+pub struct SecurityReportsMigration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for SecurityReportsMigration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SecurityReports::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SecurityReports::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SecurityReports::CompletedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(SecurityReports::Summary).json().not_null())
+                    .col(ColumnDef::new(SecurityReports::Findings).json().not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_security_reports_completed_at")
+                    .table(SecurityReports::Table)
+                    .col(SecurityReports::CompletedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SecurityReports::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum SecurityReports {
+    Table,
+    Id,
+    CompletedAt,
+    Summary,
+    Findings,
+}
@@ -0,0 +1,101 @@
+//! Summary-only audits
+//!
+//! Dashboards only need counts and scores. [`SecurityAuditor::run_summary`] runs the
+//! database-free checks and reduces the result to a [`SecuritySummary`] right away, so
+//! finding descriptions and remediations never leave the auditor.
+
+use serde::{Deserialize, Serialize};
+
+use super::{SecurityAuditResult, SecurityAuditor, Severity};
+
+/// Finding counts per severity plus the audit's scores.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SecuritySummary {
+    pub passed: bool,
+    pub score: u8,
+    pub risk_score: u8,
+    pub critical: usize,
+    pub high: usize,
+    pub medium: usize,
+    pub warning: usize,
+    pub low: usize,
+    pub info: usize,
+    pub suppressed: usize,
+}
+
+impl SecuritySummary {
+    pub fn total(&self) -> usize {
+        self.critical + self.high + self.medium + self.warning + self.low + self.info
+    }
+}
+
+impl SecurityAuditResult {
+    pub fn summary(&self) -> SecuritySummary {
+        let mut summary = SecuritySummary {
+            passed: self.passed,
+            score: self.score,
+            risk_score: self.risk_score(),
+            suppressed: self.suppressed.len(),
+            ..SecuritySummary::default()
+        };
+        for finding in &self.findings {
+            let count = match finding.severity {
+                Severity::Critical => &mut summary.critical,
+                Severity::High => &mut summary.high,
+                Severity::Medium => &mut summary.medium,
+                Severity::Warning => &mut summary.warning,
+                Severity::Low => &mut summary.low,
+                Severity::Info => &mut summary.info,
+            };
+            *count += 1;
+        }
+        summary
+    }
+}
+
+impl SecurityAuditor {
+    /// Summary of [`SecurityAuditor::run`]: built-in and sync checks, suppressions applied.
+    pub async fn run_summary(&self) -> SecuritySummary {
+        self.run().await.summary()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::{SecurityCategory, SecurityCheck, SecurityConfig, SecurityFinding};
+
+    #[tokio::test]
+    async fn test_summary_matches_full_report() {
+        let mut auditor = SecurityAuditor::new(SecurityConfig {
+            enforce_https: false,
+            audit_logging: false,
+            ..SecurityConfig::default()
+        });
+        auditor.register_check(SecurityCheck::custom("exposed_admin", |_| {
+            vec![SecurityFinding {
+                category: SecurityCategory::BrokenAccessControl,
+                severity: Severity::Critical,
+                description: "Admin API is exposed without auth".to_string(),
+                remediation: "Require admin auth".to_string(),
+            }]
+        }));
+
+        let report = auditor.run().await;
+        let summary = auditor.run_summary().await;
+
+        assert_eq!(summary, report.summary());
+        assert_eq!(summary.total(), report.findings.len());
+        assert_eq!(
+            summary.critical,
+            report
+                .findings
+                .iter()
+                .filter(|finding| finding.severity == Severity::Critical)
+                .count()
+        );
+        assert!(summary.critical >= 1);
+        assert_eq!(summary.risk_score, report.risk_score());
+        assert_eq!(summary.score, report.score);
+    }
+}
@@ -0,0 +1,134 @@
+//! Time-boxed finding suppressions
+//!
+//! A [`FindingSuppression`] accepts one finding (by [`SecurityFinding::finding_key`]) until
+//! `expires_at`, recording who accepted it and why. While active, matching findings move
+//! from `findings` to `suppressed` and stop counting towards the score; once expired they
+//! resurface on the next audit.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::csv::enum_label;
+use super::{SecurityAuditResult, SecurityFinding};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FindingSuppression {
+    /// [`SecurityFinding::finding_key`] of the accepted finding.
+    pub finding_key: String,
+    pub justification: String,
+    pub suppressed_by: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl FindingSuppression {
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        now < self.expires_at
+    }
+
+    pub fn matches(&self, finding: &SecurityFinding) -> bool {
+        self.finding_key == finding.finding_key()
+    }
+}
+
+/// A finding hidden from an audit together with the suppression that hid it.
+#[derive(Debug, Clone, Serialize)]
+pub struct SuppressedFinding {
+    pub finding: SecurityFinding,
+    pub suppression: FindingSuppression,
+}
+
+impl SecurityFinding {
+    /// String form of [`SecurityFinding::stable_key`], e.g.
+    /// `security_misconfiguration:CSP is missing default-src directive`.
+    pub fn finding_key(&self) -> String {
+        let (category, description) = self.stable_key();
+        format!("{}:{description}", enum_label(&category))
+    }
+}
+
+impl SecurityAuditResult {
+    /// Moves findings covered by a suppression active at `now` into `suppressed` and
+    /// rescores the remaining findings.
+    pub fn apply_suppressions(
+        self,
+        suppressions: &[FindingSuppression],
+        now: DateTime<Utc>,
+    ) -> Self {
+        let mut findings = Vec::new();
+        let mut suppressed = self.suppressed;
+
+        for finding in self.findings {
+            let suppression = suppressions
+                .iter()
+                .find(|suppression| suppression.is_active(now) && suppression.matches(&finding));
+            match suppression {
+                Some(suppression) => suppressed.push(SuppressedFinding {
+                    finding,
+                    suppression: suppression.clone(),
+                }),
+                None => findings.push(finding),
+            }
+        }
+
+        SecurityAuditResult {
+            suppressed,
+            ..SecurityAuditResult::from_findings(findings)
+        }
+    }
+
+    pub fn suppressed_count(&self) -> usize {
+        self.suppressed.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use super::*;
+    use crate::security::{SecurityCategory, Severity};
+
+    fn audit_result() -> SecurityAuditResult {
+        SecurityAuditResult::from_findings(vec![
+            SecurityFinding {
+                category: SecurityCategory::SecurityMisconfiguration,
+                severity: Severity::High,
+                description: "Content-Security-Policy header is not set".to_string(),
+                remediation: "Configure CSP".to_string(),
+            },
+            SecurityFinding {
+                category: SecurityCategory::AuthFailures,
+                severity: Severity::Low,
+                description: "Test".to_string(),
+                remediation: "Fix".to_string(),
+            },
+        ])
+    }
+
+    #[test]
+    fn test_suppression_hides_finding_until_expiry() {
+        let now = Utc::now();
+        let suppression = FindingSuppression {
+            finding_key: "security_misconfiguration:Content-Security-Policy header is not set"
+                .to_string(),
+            justification: "CSP rollout tracked separately".to_string(),
+            suppressed_by: "security-team".to_string(),
+            expires_at: now + Duration::days(7),
+        };
+        let unsuppressed = audit_result();
+
+        let active = audit_result().apply_suppressions(std::slice::from_ref(&suppression), now);
+        assert_eq!(active.findings.len(), 1);
+        assert_eq!(active.suppressed_count(), 1);
+        assert_eq!(
+            active.suppressed[0].suppression.suppressed_by,
+            "security-team"
+        );
+        assert!(active.score > unsuppressed.score);
+
+        let expired = audit_result().apply_suppressions(&[suppression], now + Duration::days(8));
+        assert_eq!(expired.findings.len(), 2);
+        assert_eq!(expired.suppressed_count(), 0);
+        assert_eq!(expired.score, unsuppressed.score);
+    }
+}
@@ -0,0 +1,211 @@
+//! Tenant-scoped audits
+//!
+//! An [`AuditContext`] says who is auditing and which tenant, if any, the audit covers.
+//! [`SecurityAuditor::run_scoped_audit`] checks [`AuditContext::can_audit_tenant`] first:
+//! super admins may audit any tenant and the whole platform, admins only their own tenant.
+//! A tenant audit runs the registered [`TenantSecurityCheck`]s for that tenant (its locale
+//! settings, its scripts' capabilities, ...) and leaves out the platform-wide checks, whose
+//! findings are not the tenant's to fix.
+
+use async_trait::async_trait;
+use chrono::Utc;
+use futures::future::join_all;
+use sea_orm::DatabaseConnection;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::types::UserRole;
+
+use super::{sort_findings, SecurityAuditResult, SecurityAuditor, SecurityConfig, SecurityFinding};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditContext {
+    pub role: UserRole,
+    /// Tenant the auditing user belongs to.
+    pub actor_tenant_id: Option<Uuid>,
+    /// Tenant under audit; `None` audits the whole platform.
+    pub tenant_id: Option<Uuid>,
+}
+
+impl AuditContext {
+    /// Platform-wide audit.
+    pub fn new(role: UserRole, actor_tenant_id: Option<Uuid>) -> Self {
+        Self {
+            role,
+            actor_tenant_id,
+            tenant_id: None,
+        }
+    }
+
+    pub fn for_tenant(mut self, tenant_id: Uuid) -> Self {
+        self.tenant_id = Some(tenant_id);
+        self
+    }
+
+    pub fn can_audit_tenant(&self, tenant_id: Uuid) -> bool {
+        match self.role {
+            UserRole::SuperAdmin => true,
+            UserRole::Admin => self.actor_tenant_id == Some(tenant_id),
+            UserRole::Manager | UserRole::Customer => false,
+        }
+    }
+
+    pub fn can_audit_platform(&self) -> bool {
+        self.role == UserRole::SuperAdmin
+    }
+}
+
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+#[error("{role} may not audit {}", audit_target(*tenant_id))]
+pub struct AuditAccessDenied {
+    pub role: UserRole,
+    pub tenant_id: Option<Uuid>,
+}
+
+fn audit_target(tenant_id: Option<Uuid>) -> String {
+    tenant_id.map_or_else(|| "the platform".to_string(), |id| format!("tenant {id}"))
+}
+
+#[async_trait]
+pub trait TenantSecurityCheck: Send + Sync {
+    fn name(&self) -> &str;
+
+    async fn execute(
+        &self,
+        tenant_id: Uuid,
+        config: &SecurityConfig,
+        db: &DatabaseConnection,
+    ) -> Vec<SecurityFinding>;
+}
+
+impl SecurityAuditor {
+    /// Platform audits ([`SecurityAuditor::run_full_audit`]) need a super admin; tenant
+    /// audits run only the tenant checks, for a user allowed to audit that tenant.
+    pub async fn run_scoped_audit(
+        &self,
+        ctx: &AuditContext,
+        db: &DatabaseConnection,
+    ) -> Result<SecurityAuditResult, AuditAccessDenied> {
+        let denied = || AuditAccessDenied {
+            role: ctx.role.clone(),
+            tenant_id: ctx.tenant_id,
+        };
+
+        let Some(tenant_id) = ctx.tenant_id else {
+            if !ctx.can_audit_platform() {
+                return Err(denied());
+            }
+            return Ok(self.run_full_audit(db).await);
+        };
+        if !ctx.can_audit_tenant(tenant_id) {
+            return Err(denied());
+        }
+
+        let mut findings: Vec<SecurityFinding> = join_all(
+            self.tenant_checks()
+                .iter()
+                .map(|check| check.execute(tenant_id, self.config(), db)),
+        )
+        .await
+        .into_iter()
+        .flatten()
+        .collect();
+        sort_findings(&mut findings);
+
+        Ok(SecurityAuditResult::from_findings(findings)
+            .apply_suppressions(self.suppressions(), Utc::now()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::Database;
+
+    use super::*;
+    use crate::security::{SecurityCategory, SecurityCheck, Severity};
+
+    struct LocaleCheck;
+
+    #[async_trait]
+    impl TenantSecurityCheck for LocaleCheck {
+        fn name(&self) -> &str {
+            "tenant_locale"
+        }
+
+        async fn execute(
+            &self,
+            tenant_id: Uuid,
+            _config: &SecurityConfig,
+            _db: &DatabaseConnection,
+        ) -> Vec<SecurityFinding> {
+            vec![SecurityFinding {
+                category: SecurityCategory::SecurityMisconfiguration,
+                severity: Severity::Low,
+                description: format!("Tenant {tenant_id} has no default locale"),
+                remediation: "Set a default locale".to_string(),
+            }]
+        }
+    }
+
+    fn auditor() -> SecurityAuditor {
+        let mut auditor = SecurityAuditor::new(SecurityConfig::default());
+        auditor
+            .register_check(SecurityCheck::custom("platform_only", |_| {
+                vec![SecurityFinding {
+                    category: SecurityCategory::SecurityMisconfiguration,
+                    severity: Severity::High,
+                    description: "Platform admin API is exposed".to_string(),
+                    remediation: "Restrict the admin API".to_string(),
+                }]
+            }))
+            .register_tenant_check(LocaleCheck);
+        auditor
+    }
+
+    #[tokio::test]
+    async fn test_admin_can_audit_only_own_tenant() {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        let (tenant_a, tenant_b) = (Uuid::new_v4(), Uuid::new_v4());
+        let admin = AuditContext::new(UserRole::Admin, Some(tenant_a));
+
+        let own = auditor()
+            .run_scoped_audit(&admin.clone().for_tenant(tenant_a), &db)
+            .await
+            .unwrap();
+        assert_eq!(own.findings.len(), 1);
+        assert!(own.findings[0].description.contains(&tenant_a.to_string()));
+
+        let other = auditor()
+            .run_scoped_audit(&admin.clone().for_tenant(tenant_b), &db)
+            .await;
+        assert_eq!(
+            other.unwrap_err(),
+            AuditAccessDenied {
+                role: UserRole::Admin,
+                tenant_id: Some(tenant_b),
+            }
+        );
+        assert!(auditor().run_scoped_audit(&admin, &db).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_super_admin_can_audit_any_tenant_and_platform() {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        let super_admin = AuditContext::new(UserRole::SuperAdmin, None);
+
+        let tenant = auditor()
+            .run_scoped_audit(&super_admin.clone().for_tenant(Uuid::new_v4()), &db)
+            .await
+            .unwrap();
+        let platform = auditor().run_scoped_audit(&super_admin, &db).await.unwrap();
+
+        assert!(tenant
+            .findings
+            .iter()
+            .all(|finding| finding.description != "Platform admin API is exposed"));
+        assert!(platform
+            .findings
+            .iter()
+            .any(|finding| finding.description == "Platform admin API is exposed"));
+    }
+}
@@ -0,0 +1,127 @@
+//! Issue-tracker payloads for security audit findings
+//!
+//! Turns each finding into a tracker-neutral [`TrackerIssue`] (title, Markdown body,
+//! labels) that GitHub, GitLab or Jira integrations can post as-is. Posting itself is the
+//! caller's job.
+
+use serde::Serialize;
+
+use super::csv::enum_label;
+use super::sarif::owasp_taxon;
+use super::{SecurityAuditResult, SecurityFinding, Severity};
+
+/// Issue titles are cut to this many characters.
+const MAX_TITLE_CHARS: usize = 120;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TrackerIssue {
+    pub title: String,
+    /// Markdown body.
+    pub body: String,
+    /// `security`, `severity:<level>`, `category:<category>` and, when known, `owasp:<id>`.
+    pub labels: Vec<String>,
+    pub severity: Severity,
+}
+
+impl SecurityAuditResult {
+    /// One tracker issue per finding, highest severity first.
+    pub fn to_tracker_issues(&self) -> Vec<TrackerIssue> {
+        let mut issues: Vec<TrackerIssue> = self.findings.iter().map(tracker_issue).collect();
+        issues.sort_by_key(|issue| std::cmp::Reverse(issue.severity));
+        issues
+    }
+}
+
+fn tracker_issue(finding: &SecurityFinding) -> TrackerIssue {
+    let severity = enum_label(&finding.severity);
+    let category = enum_label(&finding.category);
+    let owasp = owasp_taxon(finding.category);
+
+    let mut labels = vec![
+        "security".to_string(),
+        format!("severity:{severity}"),
+        format!("category:{category}"),
+    ];
+    if let Some((id, _)) = owasp {
+        labels.push(format!("owasp:{id}"));
+    }
+
+    let mut body = format!(
+        "## Description\n\n{}\n\n## Classification\n\n- Severity: {severity}\n- Category: {category}\n",
+        finding.description.trim()
+    );
+    if let Some((id, name)) = owasp {
+        body.push_str(&format!("- OWASP Top 10 2021: {id} {name}\n"));
+    }
+    body.push_str(&format!(
+        "\n## Remediation\n\n{}\n",
+        finding.remediation.trim()
+    ));
+
+    TrackerIssue {
+        title: issue_title(&severity, &finding.description),
+        body,
+        labels,
+        severity: finding.severity,
+    }
+}
+
+fn issue_title(severity: &str, description: &str) -> String {
+    let summary = description.lines().next().unwrap_or_default().trim();
+    let title = format!("[security/{severity}] {summary}");
+    if title.chars().count() <= MAX_TITLE_CHARS {
+        return title;
+    }
+    let mut title: String = title.chars().take(MAX_TITLE_CHARS - 1).collect();
+    title.push('…');
+    title
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::SecurityCategory;
+
+    #[test]
+    fn test_high_finding_becomes_labelled_issue_with_remediation() {
+        let result = SecurityAuditResult {
+            passed: false,
+            findings: vec![
+                SecurityFinding {
+                    category: SecurityCategory::Other,
+                    severity: Severity::Low,
+                    description: "Test".to_string(),
+                    remediation: "Fix".to_string(),
+                },
+                SecurityFinding {
+                    category: SecurityCategory::SecurityMisconfiguration,
+                    severity: Severity::High,
+                    description: "Content-Security-Policy header is not set".to_string(),
+                    remediation: "Configure CSP to prevent XSS and data injection attacks"
+                        .to_string(),
+                },
+            ],
+            score: 82,
+            suppressed: Vec::new(),
+        };
+
+        let issues = result.to_tracker_issues();
+
+        assert_eq!(issues.len(), 2);
+        let issue = &issues[0];
+        assert_eq!(issue.severity, Severity::High);
+        assert_eq!(
+            issue.title,
+            "[security/high] Content-Security-Policy header is not set"
+        );
+        assert!(issue.labels.contains(&"severity:high".to_string()));
+        assert!(issue.labels.contains(&"owasp:A05".to_string()));
+        assert!(issue
+            .body
+            .contains("## Remediation\n\nConfigure CSP to prevent XSS and data injection attacks"));
+        assert!(!issues[1]
+            .labels
+            .iter()
+            .any(|label| label.starts_with("owasp:")));
+    }
+}
@@ -1,4 +1,5 @@
 use sea_orm::entity::prelude::*;
+use sea_orm::Iterable;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::str::FromStr;
@@ -10,6 +11,12 @@ pub enum UserRoleParseError {
     Invalid(String),
 }
 
+#[derive(Debug, thiserror::Error)]
+pub enum UserStatusParseError {
+    #[error("Invalid user status: {0}")]
+    Invalid(String),
+}
+
 #[derive(
     Clone,
     Debug,
@@ -24,27 +31,40 @@ pub enum UserRoleParseError {
     ToSchema,
 )]
 #[sea_orm(rs_type = "String", db_type = "String(StringLen::N(32))")]
+#[serde(rename_all = "snake_case")]
 pub enum UserRole {
+    // Aliases accept the variant names serialized before the snake_case switch
+    // (e.g. in still-valid JWT claims).
     #[sea_orm(string_value = "super_admin")]
+    #[serde(alias = "SuperAdmin")]
     SuperAdmin,
     #[sea_orm(string_value = "admin")]
+    #[serde(alias = "Admin")]
     Admin,
     #[sea_orm(string_value = "manager")]
+    #[serde(alias = "Manager")]
     Manager,
     #[sea_orm(string_value = "customer")]
+    #[serde(alias = "Customer")]
     #[default]
     Customer,
 }
 
-impl fmt::Display for UserRole {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let value = match self {
+impl UserRole {
+    /// Canonical string form, shared by `Display`, `FromStr`, serde and the database.
+    pub fn as_str(&self) -> &'static str {
+        match self {
             Self::SuperAdmin => "super_admin",
             Self::Admin => "admin",
             Self::Manager => "manager",
             Self::Customer => "customer",
-        };
-        write!(f, "{value}")
+        }
+    }
+}
+
+impl fmt::Display for UserRole {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
     }
 }
 
@@ -52,13 +72,9 @@ impl FromStr for UserRole {
     type Err = UserRoleParseError;
 
     fn from_str(value: &str) -> Result<Self, Self::Err> {
-        match value {
-            "super_admin" => Ok(Self::SuperAdmin),
-            "admin" => Ok(Self::Admin),
-            "manager" => Ok(Self::Manager),
-            "customer" => Ok(Self::Customer),
-            _ => Err(UserRoleParseError::Invalid(value.to_string())),
-        }
+        Self::iter()
+            .find(|role| role.as_str() == value)
+            .ok_or_else(|| UserRoleParseError::Invalid(value.to_string()))
     }
 }
 
@@ -75,23 +91,94 @@ impl FromStr for UserRole {
     ToSchema,
 )]
 #[sea_orm(rs_type = "String", db_type = "String(StringLen::N(32))")]
+#[serde(rename_all = "snake_case")]
 pub enum UserStatus {
     #[sea_orm(string_value = "active")]
+    #[serde(alias = "Active")]
     #[default]
     Active,
     #[sea_orm(string_value = "inactive")]
+    #[serde(alias = "Inactive")]
     Inactive,
     #[sea_orm(string_value = "banned")]
+    #[serde(alias = "Banned")]
     Banned,
 }
 
-impl fmt::Display for UserStatus {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let value = match self {
+impl UserStatus {
+    /// Canonical string form, shared by `Display`, `FromStr`, serde and the database.
+    pub fn as_str(&self) -> &'static str {
+        match self {
             Self::Active => "active",
             Self::Inactive => "inactive",
             Self::Banned => "banned",
-        };
-        write!(f, "{value}")
+        }
+    }
+}
+
+impl fmt::Display for UserStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for UserStatus {
+    type Err = UserStatusParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::iter()
+            .find(|status| status.as_str() == value)
+            .ok_or_else(|| UserStatusParseError::Invalid(value.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_role_round_trips() {
+        for role in UserRole::iter() {
+            assert_eq!(role.to_string().parse::<UserRole>().unwrap(), role);
+            assert_eq!(
+                serde_json::to_string(&role).unwrap(),
+                format!("\"{}\"", role.as_str())
+            );
+        }
+        assert_eq!(
+            "super_admin".parse::<UserRole>().unwrap(),
+            UserRole::SuperAdmin
+        );
+    }
+
+    #[test]
+    fn test_every_status_round_trips() {
+        for status in UserStatus::iter() {
+            assert_eq!(status.to_string().parse::<UserStatus>().unwrap(), status);
+            assert_eq!(
+                serde_json::from_str::<UserStatus>(&format!("\"{status}\"")).unwrap(),
+                status
+            );
+        }
+    }
+
+    #[test]
+    fn test_unknown_strings_are_rejected() {
+        assert!("root".parse::<UserRole>().is_err());
+        assert!("SuperAdmin".parse::<UserRole>().is_err());
+        assert!("deleted".parse::<UserStatus>().is_err());
+        assert!(serde_json::from_str::<UserStatus>("\"deleted\"").is_err());
+    }
+
+    #[test]
+    fn test_legacy_variant_names_still_deserialize() {
+        assert_eq!(
+            serde_json::from_str::<UserRole>("\"SuperAdmin\"").unwrap(),
+            UserRole::SuperAdmin
+        );
+        assert_eq!(
+            serde_json::from_str::<UserStatus>("\"Banned\"").unwrap(),
+            UserStatus::Banned
+        );
     }
 }
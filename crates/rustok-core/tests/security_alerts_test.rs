@@ -0,0 +1,160 @@
+//! Integration tests for security audit alert sinks
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use rustok_core::async_utils::BackoffConfig;
+use rustok_core::security::{
+    AlertError, AlertSink, SecurityAlert, SecurityAuditResult, SecurityCategory, SecurityFinding,
+    SecurityMonitor, Severity, WebhookAlertSink, WebhookFormat,
+};
+use wiremock::matchers::{body_partial_json, body_string_contains, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn finding(severity: Severity, description: &str) -> SecurityFinding {
+    SecurityFinding {
+        category: SecurityCategory::SecurityMisconfiguration,
+        severity,
+        description: description.to_string(),
+        remediation: "Fix".to_string(),
+    }
+}
+
+fn monitor(server: &MockServer, format: WebhookFormat) -> SecurityMonitor {
+    let sink = WebhookAlertSink::new(format!("{}/alerts", server.uri()), format).with_backoff(
+        BackoffConfig::default()
+            .with_initial_delay(Duration::from_millis(1))
+            .with_max_delay(Duration::from_millis(5)),
+    );
+    SecurityMonitor::new().with_sink(sink).with_threshold(2)
+}
+
+#[tokio::test]
+async fn crossing_threshold_posts_slack_summary_with_counts() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/alerts"))
+        .respond_with(ResponseTemplate::new(500))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/alerts"))
+        .and(body_string_contains("1 critical, 1 high findings"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let result = SecurityAuditResult::from_findings(vec![
+        finding(Severity::Critical, "Admin API is exposed without auth"),
+        finding(Severity::High, "Content-Security-Policy header is not set"),
+        finding(Severity::Low, "Referrer-Policy is lax"),
+    ]);
+
+    let sent = monitor(&server, WebhookFormat::Slack)
+        .observe(&result)
+        .await
+        .unwrap();
+
+    assert!(sent);
+}
+
+#[tokio::test]
+async fn below_threshold_does_not_post() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let result = SecurityAuditResult::from_findings(vec![
+        finding(Severity::High, "Content-Security-Policy header is not set"),
+        finding(Severity::Medium, "HSTS max-age is short"),
+    ]);
+
+    let sent = monitor(&server, WebhookFormat::Slack)
+        .observe(&result)
+        .await
+        .unwrap();
+
+    assert!(!sent);
+}
+
+#[tokio::test]
+async fn generic_webhook_receives_structured_counts() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/alerts"))
+        .and(body_partial_json(
+            serde_json::json!({ "critical": 2, "high": 0 }),
+        ))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let result = SecurityAuditResult::from_findings(vec![
+        finding(Severity::Critical, "Admin API is exposed without auth"),
+        finding(Severity::Critical, "Default credentials are enabled"),
+    ]);
+
+    assert!(monitor(&server, WebhookFormat::Generic)
+        .observe(&result)
+        .await
+        .unwrap());
+}
+
+/// Counts alerts instead of delivering them.
+#[derive(Clone, Default)]
+struct CountingSink {
+    sent: Arc<AtomicUsize>,
+}
+
+#[async_trait::async_trait]
+impl AlertSink for CountingSink {
+    async fn send(&self, _alert: &SecurityAlert) -> Result<(), AlertError> {
+        self.sent.fetch_add(1, Ordering::SeqCst);
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn unchanged_findings_alert_once_within_cooldown() {
+    let sink = CountingSink::default();
+    let monitor = SecurityMonitor::new()
+        .with_sink(sink.clone())
+        .with_cooldown(Duration::from_secs(3600));
+    let result = SecurityAuditResult::from_findings(vec![finding(
+        Severity::High,
+        "Content-Security-Policy header is not set",
+    )]);
+
+    assert!(monitor.observe(&result).await.unwrap());
+    assert!(!monitor.observe(&result).await.unwrap());
+    assert_eq!(sink.sent.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn new_finding_alerts_immediately_within_cooldown() {
+    let sink = CountingSink::default();
+    let monitor = SecurityMonitor::new()
+        .with_sink(sink.clone())
+        .with_cooldown(Duration::from_secs(3600));
+    let csp = finding(Severity::High, "Content-Security-Policy header is not set");
+
+    assert!(monitor
+        .observe(&SecurityAuditResult::from_findings(vec![csp.clone()]))
+        .await
+        .unwrap());
+    assert!(monitor
+        .observe(&SecurityAuditResult::from_findings(vec![
+            csp,
+            finding(Severity::Critical, "Admin API is exposed without auth"),
+        ]))
+        .await
+        .unwrap());
+    assert_eq!(sink.sent.load(Ordering::SeqCst), 2);
+}
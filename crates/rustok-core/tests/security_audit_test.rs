@@ -9,12 +9,19 @@
 
 use rustok_core::security::RateLimitResult;
 use rustok_core::security::{
-    run_security_audit, FrameOptions, InputValidator, RateLimitConfig, RateLimiter, SecurityConfig,
-    SecurityFinding, SecurityHeaders, SecurityHeadersConfig, Severity, SsrfProtection,
-    ValidationResult,
+    run_incremental_security_audit, run_security_audit, run_security_audit_with_checks,
+    AsyncSecurityCheck, BatchSecurityAuditor, CrossTenantProbe, FrameOptions, InputValidator,
+    RateLimitConfig, RateLimiter, SecurityAuditResult, SecurityAuditor, SecurityCategory,
+    SecurityCheck, SecurityConfig, SecurityFinding, SecurityHeaders, SecurityHeadersConfig,
+    Severity, SsrfProtection, TenantIsolationCheck, ValidationResult,
 };
+use sea_orm::{ConnectionTrait, Database, DatabaseConnection, Statement};
 use std::net::IpAddr;
 use std::net::Ipv4Addr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use uuid::Uuid;
 
 #[tokio::test]
 async fn test_security_audit_passes_with_default_config() {
@@ -46,6 +53,216 @@ async fn test_security_audit_fails_without_https() {
     assert!(has_https_finding, "Should report HTTPS enforcement issue");
 }
 
+#[tokio::test]
+async fn test_incremental_audit_only_replaces_changed_categories() {
+    let baseline = SecurityAuditResult {
+        passed: true,
+        findings: vec![
+            SecurityFinding {
+                category: SecurityCategory::AuthFailures,
+                severity: Severity::Low,
+                description: "Stale auth finding".to_string(),
+                remediation: "Fix".to_string(),
+            },
+            SecurityFinding {
+                category: SecurityCategory::SecurityMisconfiguration,
+                severity: Severity::Medium,
+                description: "CSP is missing default-src directive".to_string(),
+                remediation: "Fix".to_string(),
+            },
+        ],
+        score: 89,
+        suppressed: Vec::new(),
+    };
+    let config = SecurityConfig {
+        enforce_https: false,
+        rate_limit: RateLimitConfig {
+            requests_per_minute: 0,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let result =
+        run_incremental_security_audit(&config, &baseline, &[SecurityCategory::AuthFailures]).await;
+
+    let descriptions: Vec<&str> = result
+        .findings
+        .iter()
+        .map(|f| f.description.as_str())
+        .collect();
+    assert!(descriptions.contains(&"CSP is missing default-src directive"));
+    assert!(descriptions.contains(&"Rate limiting is disabled (requests_per_minute = 0)"));
+    assert!(!descriptions.contains(&"Stale auth finding"));
+    assert!(
+        !descriptions.iter().any(|d| d.contains("HTTPS")),
+        "unchanged categories must not be re-audited"
+    );
+}
+
+#[tokio::test]
+async fn test_custom_check_closure_captures_state() {
+    let weak_passwords: Vec<String> = ["admin", "password123"].map(String::from).into();
+    let configured_passwords: Vec<String> =
+        ["correct horse battery", "admin"].map(String::from).into();
+    let check = SecurityCheck::custom("weak-seed-passwords", move |_config| {
+        configured_passwords
+            .iter()
+            .filter(|password| weak_passwords.contains(password))
+            .map(|password| SecurityFinding {
+                category: SecurityCategory::AuthFailures,
+                severity: Severity::Critical,
+                description: format!("Seed account uses a weak password ({password})"),
+                remediation: "Rotate seeded credentials".to_string(),
+            })
+            .collect()
+    });
+
+    let config = SecurityConfig::default();
+    let baseline = run_security_audit(&config).await;
+    let result = run_security_audit_with_checks(&config, &[check.clone(), check]).await;
+
+    assert_eq!(result.findings.len(), baseline.findings.len() + 2);
+    assert!(result
+        .findings
+        .iter()
+        .any(|f| f.description == "Seed account uses a weak password (admin)"));
+    assert!(result.score < baseline.score);
+}
+
+struct MissingTenantIdCheck;
+
+#[async_trait::async_trait]
+impl AsyncSecurityCheck for MissingTenantIdCheck {
+    fn name(&self) -> &str {
+        "missing-tenant-id"
+    }
+
+    async fn execute(
+        &self,
+        _config: &SecurityConfig,
+        db: &DatabaseConnection,
+    ) -> Vec<SecurityFinding> {
+        let row = db
+            .query_one(Statement::from_string(
+                db.get_database_backend(),
+                "SELECT COUNT(*) AS orphans FROM documents WHERE tenant_id IS NULL",
+            ))
+            .await
+            .unwrap()
+            .unwrap();
+        let orphans: i64 = row.try_get("", "orphans").unwrap();
+
+        (orphans > 0)
+            .then(|| SecurityFinding {
+                category: SecurityCategory::BrokenAccessControl,
+                severity: Severity::Critical,
+                description: format!("{orphans} documents have no tenant_id"),
+                remediation: "Backfill tenant_id and make the column NOT NULL".to_string(),
+            })
+            .into_iter()
+            .collect()
+    }
+}
+
+#[tokio::test]
+async fn test_full_audit_awaits_async_checks() {
+    let db = Database::connect("sqlite::memory:").await.unwrap();
+    for sql in [
+        "CREATE TABLE documents (id INTEGER PRIMARY KEY, tenant_id TEXT)",
+        "INSERT INTO documents (tenant_id) VALUES ('acme'), (NULL), (NULL)",
+    ] {
+        db.execute(Statement::from_string(db.get_database_backend(), sql))
+            .await
+            .unwrap();
+    }
+
+    let mut auditor = SecurityAuditor::new(SecurityConfig::default());
+    auditor
+        .register_check(SecurityCheck::custom("noop", |_| Vec::new()))
+        .register_async_check(MissingTenantIdCheck);
+
+    let sync_only = auditor.run().await;
+    let full = auditor.run_full_audit(&db).await;
+
+    assert_eq!(full.findings.len(), sync_only.findings.len() + 1);
+    assert!(full
+        .findings
+        .iter()
+        .any(|f| f.description == "2 documents have no tenant_id"));
+    assert!(full.score < sync_only.score);
+}
+
+/// Reports its system name after a short delay, tracking how many run at once.
+struct SlowSystemCheck {
+    system: &'static str,
+    in_flight: Arc<AtomicUsize>,
+    peak: Arc<AtomicUsize>,
+}
+
+#[async_trait::async_trait]
+impl AsyncSecurityCheck for SlowSystemCheck {
+    fn name(&self) -> &str {
+        "slow-system"
+    }
+
+    async fn execute(
+        &self,
+        _config: &SecurityConfig,
+        _db: &DatabaseConnection,
+    ) -> Vec<SecurityFinding> {
+        let running = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+        self.peak.fetch_max(running, Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+        vec![SecurityFinding {
+            category: SecurityCategory::Other,
+            severity: Severity::Low,
+            description: format!("{} finding", self.system),
+            remediation: "Fix".to_string(),
+        }]
+    }
+}
+
+#[tokio::test]
+async fn test_batch_audit_runs_systems_concurrently_with_isolation() {
+    let db = Database::connect("sqlite::memory:").await.unwrap();
+    let in_flight = Arc::new(AtomicUsize::new(0));
+    let peak = Arc::new(AtomicUsize::new(0));
+
+    let mut batch = BatchSecurityAuditor::new(2);
+    for system in ["orders", "billing", "auth", "catalog"] {
+        let mut auditor = SecurityAuditor::new(SecurityConfig::default());
+        auditor.register_async_check(SlowSystemCheck {
+            system,
+            in_flight: Arc::clone(&in_flight),
+            peak: Arc::clone(&peak),
+        });
+        batch.add_system(system, auditor);
+    }
+    let mut broken = SecurityAuditor::new(SecurityConfig::default());
+    broken.register_check(SecurityCheck::custom("broken", |_| panic!("check crashed")));
+    batch.add_system("legacy", broken);
+
+    let report = batch.run_all(&db).await;
+
+    assert_eq!(
+        report.results.keys().collect::<Vec<_>>(),
+        ["auth", "billing", "catalog", "orders"]
+    );
+    assert_eq!(report.failures["legacy"], "check crashed");
+    assert!(peak.load(Ordering::SeqCst) <= 2);
+
+    let consolidated = report.consolidated();
+    for system in ["orders", "billing", "auth", "catalog"] {
+        assert!(consolidated
+            .findings
+            .iter()
+            .any(|f| f.description == format!("{system} finding")));
+    }
+}
+
 #[tokio::test]
 async fn test_security_headers_generation() {
     let config = SecurityHeadersConfig::default();
@@ -503,3 +720,102 @@ fn test_security_score_calculation() {
         60
     );
 }
+
+/// Loads another tenant's documents by id, the way a repository would.
+struct DocumentLookupProbe {
+    /// `false` reproduces a repository query that forgot the tenant filter.
+    filter_by_tenant: bool,
+}
+
+#[async_trait::async_trait]
+impl CrossTenantProbe for DocumentLookupProbe {
+    fn name(&self) -> &str {
+        "documents.find_by_id"
+    }
+
+    async fn read_foreign(
+        &self,
+        db: &DatabaseConnection,
+        reader: Uuid,
+        owner: Uuid,
+    ) -> Result<usize, String> {
+        let mut sql = format!(
+            "SELECT COUNT(*) AS leaked FROM documents WHERE id IN \
+             (SELECT id FROM documents WHERE tenant_id = '{owner}')"
+        );
+        if self.filter_by_tenant {
+            sql.push_str(&format!(" AND tenant_id = '{reader}'"));
+        }
+        let row = db
+            .query_one(Statement::from_string(db.get_database_backend(), sql))
+            .await
+            .map_err(|e| e.to_string())?
+            .ok_or("no row")?;
+        let leaked: i64 = row.try_get("", "leaked").map_err(|e| e.to_string())?;
+        Ok(leaked as usize)
+    }
+}
+
+async fn seeded_tenants() -> (DatabaseConnection, Uuid, Uuid) {
+    let db = Database::connect("sqlite::memory:").await.unwrap();
+    let (tenant_a, tenant_b) = (Uuid::new_v4(), Uuid::new_v4());
+    for sql in [
+        "CREATE TABLE documents (id INTEGER PRIMARY KEY, tenant_id TEXT NOT NULL)".to_string(),
+        format!("INSERT INTO documents (tenant_id) VALUES ('{tenant_a}'), ('{tenant_b}')"),
+    ] {
+        db.execute(Statement::from_string(db.get_database_backend(), sql))
+            .await
+            .unwrap();
+    }
+    (db, tenant_a, tenant_b)
+}
+
+#[tokio::test]
+async fn test_tenant_isolation_check_flags_cross_tenant_leak() {
+    let (db, tenant_a, tenant_b) = seeded_tenants().await;
+    let check = TenantIsolationCheck::new(tenant_a, tenant_b).with_probe(DocumentLookupProbe {
+        filter_by_tenant: false,
+    });
+
+    let findings = check.execute(&SecurityConfig::default(), &db).await;
+
+    assert_eq!(findings.len(), 2);
+    assert!(findings
+        .iter()
+        .all(|f| f.severity == Severity::Critical
+            && f.category == SecurityCategory::BrokenAccessControl));
+    assert!(findings[0].description.contains(&format!(
+        "as tenant {tenant_a} returned 1 record(s) of tenant {tenant_b}"
+    )));
+}
+
+#[tokio::test]
+async fn test_tenant_isolation_check_passes_with_tenant_filter() {
+    let (db, tenant_a, tenant_b) = seeded_tenants().await;
+    let mut auditor = SecurityAuditor::new(SecurityConfig::default());
+    auditor.register_async_check(TenantIsolationCheck::new(tenant_a, tenant_b).with_probe(
+        DocumentLookupProbe {
+            filter_by_tenant: true,
+        },
+    ));
+
+    let full = auditor.run_full_audit(&db).await;
+
+    assert_eq!(full.findings.len(), auditor.run().await.findings.len());
+}
+
+#[tokio::test]
+async fn test_tenant_isolation_check_without_probes_is_a_finding() {
+    let (db, tenant_a, tenant_b) = seeded_tenants().await;
+
+    let findings = TenantIsolationCheck::new(tenant_a, tenant_b)
+        .execute(&SecurityConfig::default(), &db)
+        .await;
+
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].severity, Severity::High);
+    assert_eq!(findings[0].category, SecurityCategory::BrokenAccessControl);
+    assert!(findings[0]
+        .description
+        .contains("No cross-tenant probes registered"));
+}
@@ -0,0 +1,79 @@
+//! Integration tests for security report persistence
+
+use chrono::{Duration, TimeZone, Utc};
+use rustok_core::security::{
+    SeaOrmSecurityReportStore, SecurityAuditResult, SecurityCategory, SecurityFinding,
+    SecurityReportStore, SecurityReportsMigration, Severity, StoredSecurityReport,
+};
+use sea_orm::Database;
+use sea_orm_migration::prelude::SchemaManager;
+use sea_orm_migration::MigrationTrait;
+
+async fn store() -> SeaOrmSecurityReportStore {
+    let db = Database::connect("sqlite::memory:").await.unwrap();
+    SecurityReportsMigration
+        .up(&SchemaManager::new(&db))
+        .await
+        .unwrap();
+    SeaOrmSecurityReportStore::new(db)
+}
+
+fn finding(severity: Severity, description: &str) -> SecurityFinding {
+    SecurityFinding {
+        category: SecurityCategory::SecurityMisconfiguration,
+        severity,
+        description: description.to_string(),
+        remediation: "Fix".to_string(),
+    }
+}
+
+#[tokio::test]
+async fn stored_report_round_trips_findings() {
+    let store = store().await;
+    let result = SecurityAuditResult::from_findings(vec![
+        finding(Severity::Critical, "Admin API is exposed without auth"),
+        finding(Severity::Low, "Referrer-Policy is lax"),
+    ]);
+    let report = StoredSecurityReport::new(&result, Utc::now());
+
+    store.save(&report).await.unwrap();
+    let loaded = store.find_by_id(report.id).await.unwrap().unwrap();
+
+    assert_eq!(loaded.findings, result.findings);
+    assert_eq!(loaded.summary, report.summary);
+    assert_eq!(loaded.to_result().score, result.score);
+    assert!(store
+        .find_by_id(uuid::Uuid::new_v4())
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn time_range_queries_and_pruning_select_by_completion_time() {
+    let store = store().await;
+    let start = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+    let result = SecurityAuditResult::from_findings(vec![]);
+    let reports: Vec<StoredSecurityReport> = (0..4)
+        .map(|day| StoredSecurityReport::new(&result, start + Duration::days(day)))
+        .collect();
+    for report in reports.iter().rev() {
+        store.save(report).await.unwrap();
+    }
+
+    let found = store
+        .find_between(start + Duration::days(1), start + Duration::days(3))
+        .await
+        .unwrap();
+    let ids: Vec<_> = found.iter().map(|report| report.id).collect();
+    assert_eq!(ids, vec![reports[1].id, reports[2].id]);
+
+    let pruned = store.prune_before(start + Duration::days(2)).await.unwrap();
+    assert_eq!(pruned, 2);
+    let remaining = store
+        .find_between(start, start + Duration::days(10))
+        .await
+        .unwrap();
+    assert_eq!(remaining.len(), 2);
+    assert_eq!(remaining[0].id, reports[2].id);
+}
@@ -1,18 +1,33 @@
 pub mod config;
 pub mod error;
+pub mod migrations;
+pub mod notifier;
+pub mod preferences;
 pub mod service;
+pub mod sms;
 pub mod template;
 
 pub use config::{EmailConfig, SmtpConfig};
 pub use error::EmailError;
+pub use notifier::{
+    CustomerRecipientResolver, NotificationChannels, NotificationHandler, NotificationRecipient,
+    NotificationRecipientResolver,
+};
+pub use preferences::{
+    NotificationCategory, NotificationPreference, NotificationPreferenceService,
+};
 pub use service::{
     EmailService, PasswordResetEmail, PasswordResetEmailSender, SmtpEmailSender,
     TransactionalEmailSender,
 };
-pub use template::{EmailTemplateProvider, RenderedEmail};
+pub use sms::{DisabledSmsSender, SmsSender};
+pub use template::{default_notification_templates, EmailTemplateProvider, RenderedEmail};
 
 use async_trait::async_trait;
-use rustok_core::module::{HealthStatus, MigrationSource, ModuleKind, RusToKModule};
+use rustok_core::module::{
+    HealthStatus, MigrationSource, ModuleEventListenerContext, ModuleEventListenerRegistry,
+    ModuleKind, RusToKModule,
+};
 use sea_orm_migration::MigrationTrait;
 
 /// Core email module — SMTP transport, templates, email lifecycle.
@@ -20,7 +35,7 @@ pub struct EmailModule;
 
 impl MigrationSource for EmailModule {
     fn migrations(&self) -> Vec<Box<dyn MigrationTrait>> {
-        Vec::new()
+        migrations::migrations()
     }
 }
 
@@ -46,6 +61,16 @@ impl RusToKModule for EmailModule {
         ModuleKind::Core
     }
 
+    fn register_event_listeners(
+        &self,
+        registry: &mut ModuleEventListenerRegistry,
+        ctx: &ModuleEventListenerContext<'_>,
+    ) {
+        if let Some(channels) = ctx.extensions.get::<NotificationChannels>() {
+            registry.register(NotificationHandler::new(ctx.db.clone(), channels.clone()));
+        }
+    }
+
     async fn health(&self) -> HealthStatus {
         HealthStatus::Healthy
     }
@@ -0,0 +1,9 @@
+mod m20261016_000001_create_notification_preferences;
+
+use sea_orm_migration::MigrationTrait;
+
+pub fn migrations() -> Vec<Box<dyn MigrationTrait>> {
+    vec![Box::new(
+        m20261016_000001_create_notification_preferences::Migration,
+    )]
+}
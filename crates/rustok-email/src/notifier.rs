@@ -0,0 +1,242 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use rustok_core::email::TemplateRegistry;
+use rustok_core::events::{EventHandler, HandlerResult};
+use rustok_core::{DomainEvent, Error, EventEnvelope, PLATFORM_FALLBACK_LOCALE};
+use sea_orm::sea_query::{Alias, Expr, Query};
+use sea_orm::{ConnectionTrait, DatabaseConnection, DbErr};
+use uuid::Uuid;
+
+use crate::preferences::{
+    NotificationCategory, NotificationPreference, NotificationPreferenceService,
+};
+use crate::service::TransactionalEmailSender;
+use crate::sms::SmsSender;
+
+/// Delivery channels for the notifier.
+///
+/// The host inserts this into `ModuleRuntimeExtensions`; without it `EmailModule`
+/// registers no notification listener.
+#[derive(Clone)]
+pub struct NotificationChannels {
+    pub email: Arc<dyn TransactionalEmailSender>,
+    pub sms: Arc<dyn SmsSender>,
+    /// Emails whose template is registered here are rendered by the notifier and sent
+    /// pre-rendered; the rest go through the sender's own template providers.
+    pub templates: Arc<TemplateRegistry>,
+}
+
+/// Who receives the notification for one event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotificationRecipient {
+    /// Account whose preferences apply; guests get the defaults.
+    pub user_id: Option<Uuid>,
+    pub email: Option<String>,
+    pub phone: Option<String>,
+    pub locale: String,
+}
+
+#[async_trait]
+pub trait NotificationRecipientResolver: Send + Sync {
+    async fn resolve(
+        &self,
+        tenant_id: Uuid,
+        event: &DomainEvent,
+    ) -> Result<Option<NotificationRecipient>, DbErr>;
+}
+
+/// Resolves order events to the customer who placed the order and account events to
+/// the user they describe.
+pub struct CustomerRecipientResolver {
+    db: DatabaseConnection,
+}
+
+impl CustomerRecipientResolver {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    async fn order_customer(
+        &self,
+        tenant_id: Uuid,
+        order_id: Uuid,
+    ) -> Result<Option<NotificationRecipient>, DbErr> {
+        let (orders, customers) = (Alias::new("orders"), Alias::new("customers"));
+        let query = Query::select()
+            .column((customers.clone(), Alias::new("user_id")))
+            .column((customers.clone(), Alias::new("email")))
+            .column((customers.clone(), Alias::new("phone")))
+            .column((customers.clone(), Alias::new("locale")))
+            .from(orders.clone())
+            .inner_join(
+                customers.clone(),
+                Expr::col((customers.clone(), Alias::new("id")))
+                    .equals((orders.clone(), Alias::new("customer_id"))),
+            )
+            .and_where(Expr::col((orders.clone(), Alias::new("id"))).eq(order_id))
+            .and_where(Expr::col((orders, Alias::new("tenant_id"))).eq(tenant_id))
+            .to_owned();
+        let backend = self.db.get_database_backend();
+        let Some(row) = self.db.query_one(backend.build(&query)).await? else {
+            return Ok(None);
+        };
+
+        Ok(Some(NotificationRecipient {
+            user_id: row.try_get("", "user_id")?,
+            email: Some(row.try_get::<String>("", "email")?),
+            phone: row.try_get("", "phone")?,
+            locale: row
+                .try_get::<Option<String>>("", "locale")?
+                .unwrap_or_else(|| PLATFORM_FALLBACK_LOCALE.to_string()),
+        }))
+    }
+}
+
+#[async_trait]
+impl NotificationRecipientResolver for CustomerRecipientResolver {
+    async fn resolve(
+        &self,
+        tenant_id: Uuid,
+        event: &DomainEvent,
+    ) -> Result<Option<NotificationRecipient>, DbErr> {
+        match event {
+            DomainEvent::OrderPlaced { order_id, .. }
+            | DomainEvent::OrderStatusChanged { order_id, .. }
+            | DomainEvent::OrderCompleted { order_id }
+            | DomainEvent::OrderCancelled { order_id, .. } => {
+                self.order_customer(tenant_id, *order_id).await
+            }
+            DomainEvent::UserRegistered { user_id, email } => Ok(Some(NotificationRecipient {
+                user_id: Some(*user_id),
+                email: Some(email.clone()),
+                phone: None,
+                locale: PLATFORM_FALLBACK_LOCALE.to_string(),
+            })),
+            _ => Ok(None),
+        }
+    }
+}
+
+/// Category and template for events that notify someone.
+///
+/// `OrderStatusChanged` only notifies for `paid` and `shipped`; delivery and
+/// cancellation have their own events.
+pub fn notification_for(event: &DomainEvent) -> Option<(NotificationCategory, &'static str)> {
+    let notification = match event {
+        DomainEvent::OrderPlaced { .. } => (NotificationCategory::Orders, "commerce/order_placed"),
+        DomainEvent::OrderStatusChanged { new_status, .. } => match new_status.as_str() {
+            "paid" => (NotificationCategory::Orders, "commerce/order_paid"),
+            "shipped" => (NotificationCategory::Orders, "commerce/order_shipped"),
+            _ => return None,
+        },
+        DomainEvent::OrderCompleted { .. } => {
+            (NotificationCategory::Orders, "commerce/order_completed")
+        }
+        DomainEvent::OrderCancelled { .. } => {
+            (NotificationCategory::Orders, "commerce/order_cancelled")
+        }
+        DomainEvent::UserRegistered { .. } => (NotificationCategory::Account, "auth/welcome"),
+        _ => return None,
+    };
+    Some(notification)
+}
+
+/// Sends email/SMS notifications for domain events, honouring each user's
+/// per-category preferences.
+pub struct NotificationHandler {
+    preferences: NotificationPreferenceService,
+    recipients: Arc<dyn NotificationRecipientResolver>,
+    channels: NotificationChannels,
+}
+
+impl NotificationHandler {
+    pub fn new(db: DatabaseConnection, channels: NotificationChannels) -> Self {
+        Self {
+            preferences: NotificationPreferenceService::new(db.clone()),
+            recipients: Arc::new(CustomerRecipientResolver::new(db)),
+            channels,
+        }
+    }
+
+    pub fn with_resolver(mut self, recipients: Arc<dyn NotificationRecipientResolver>) -> Self {
+        self.recipients = recipients;
+        self
+    }
+}
+
+#[async_trait]
+impl EventHandler for NotificationHandler {
+    fn name(&self) -> &'static str {
+        "email_notifier"
+    }
+
+    fn handles(&self, event: &DomainEvent) -> bool {
+        notification_for(event).is_some()
+    }
+
+    async fn handle(&self, envelope: &EventEnvelope) -> HandlerResult {
+        let Some((category, template_id)) = notification_for(&envelope.event) else {
+            return Ok(());
+        };
+        let Some(recipient) = self
+            .recipients
+            .resolve(envelope.tenant_id, &envelope.event)
+            .await?
+        else {
+            return Ok(());
+        };
+        let preference = match recipient.user_id {
+            Some(user_id) => {
+                self.preferences
+                    .get(envelope.tenant_id, user_id, category)
+                    .await?
+            }
+            None => NotificationPreference::default_for(Uuid::nil(), category),
+        };
+
+        let mut vars = serde_json::to_value(&envelope.event)?;
+        let vars = vars
+            .get_mut("data")
+            .map(serde_json::Value::take)
+            .unwrap_or_else(|| serde_json::json!({}));
+
+        if let Some(email) = recipient
+            .email
+            .as_deref()
+            .filter(|_| preference.email_enabled)
+        {
+            let sent = match self.channels.templates.get(template_id) {
+                Some(template) => {
+                    let rendered = template
+                        .render(&recipient.locale, &vars)
+                        .map_err(|error| Error::Validation(error.to_string()))?;
+                    self.channels
+                        .email
+                        .send_rendered(email, &rendered.into())
+                        .await
+                }
+                None => {
+                    self.channels
+                        .email
+                        .send_transactional(template_id, &recipient.locale, email, &vars)
+                        .await
+                }
+            };
+            sent.map_err(|error| Error::External(error.to_string()))?;
+        }
+        if let Some(phone) = recipient
+            .phone
+            .as_deref()
+            .filter(|_| preference.sms_enabled)
+        {
+            self.channels
+                .sms
+                .send_sms(template_id, &recipient.locale, phone, &vars)
+                .await
+                .map_err(|error| Error::External(error.to_string()))?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,178 @@
+use chrono::Utc;
+use sea_orm::entity::prelude::*;
+use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, Set};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "notification_preferences")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub tenant_id: Uuid,
+    pub user_id: Uuid,
+    pub category: String,
+    pub email_enabled: bool,
+    pub sms_enabled: bool,
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+/// Group of events a user can opt in or out of as a whole.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationCategory {
+    /// Order placed, paid, shipped, completed or cancelled.
+    Orders,
+    /// Account lifecycle such as registration.
+    Account,
+}
+
+impl NotificationCategory {
+    pub const ALL: [Self; 2] = [Self::Orders, Self::Account];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Orders => "orders",
+            Self::Account => "account",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|category| category.as_str() == value)
+    }
+}
+
+/// Effective per-channel switches of one user for one category.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NotificationPreference {
+    pub user_id: Uuid,
+    pub category: NotificationCategory,
+    pub email_enabled: bool,
+    pub sms_enabled: bool,
+}
+
+impl NotificationPreference {
+    /// Applies when the user never saved a choice: email on, SMS off.
+    pub fn default_for(user_id: Uuid, category: NotificationCategory) -> Self {
+        Self {
+            user_id,
+            category,
+            email_enabled: true,
+            sms_enabled: false,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct NotificationPreferenceService {
+    db: DatabaseConnection,
+}
+
+impl NotificationPreferenceService {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    pub async fn get(
+        &self,
+        tenant_id: Uuid,
+        user_id: Uuid,
+        category: NotificationCategory,
+    ) -> Result<NotificationPreference, DbErr> {
+        Ok(self
+            .find(tenant_id, user_id, category)
+            .await?
+            .map(|model| map_model(model, category))
+            .unwrap_or_else(|| NotificationPreference::default_for(user_id, category)))
+    }
+
+    /// Every category for the user, with defaults for the ones never saved.
+    pub async fn list(
+        &self,
+        tenant_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Vec<NotificationPreference>, DbErr> {
+        let stored = Entity::find()
+            .filter(Column::TenantId.eq(tenant_id))
+            .filter(Column::UserId.eq(user_id))
+            .all(&self.db)
+            .await?;
+
+        Ok(NotificationCategory::ALL
+            .into_iter()
+            .map(|category| {
+                stored
+                    .iter()
+                    .find(|model| model.category == category.as_str())
+                    .cloned()
+                    .map(|model| map_model(model, category))
+                    .unwrap_or_else(|| NotificationPreference::default_for(user_id, category))
+            })
+            .collect())
+    }
+
+    pub async fn set(
+        &self,
+        tenant_id: Uuid,
+        user_id: Uuid,
+        category: NotificationCategory,
+        email_enabled: bool,
+        sms_enabled: bool,
+    ) -> Result<NotificationPreference, DbErr> {
+        let now = Utc::now();
+        let model = match self.find(tenant_id, user_id, category).await? {
+            Some(existing) => {
+                let mut active: ActiveModel = existing.into();
+                active.email_enabled = Set(email_enabled);
+                active.sms_enabled = Set(sms_enabled);
+                active.updated_at = Set(now.into());
+                active.update(&self.db).await?
+            }
+            None => {
+                ActiveModel {
+                    id: Set(rustok_core::generate_id()),
+                    tenant_id: Set(tenant_id),
+                    user_id: Set(user_id),
+                    category: Set(category.as_str().to_string()),
+                    email_enabled: Set(email_enabled),
+                    sms_enabled: Set(sms_enabled),
+                    updated_at: Set(now.into()),
+                }
+                .insert(&self.db)
+                .await?
+            }
+        };
+
+        Ok(map_model(model, category))
+    }
+
+    async fn find(
+        &self,
+        tenant_id: Uuid,
+        user_id: Uuid,
+        category: NotificationCategory,
+    ) -> Result<Option<Model>, DbErr> {
+        Entity::find()
+            .filter(Column::TenantId.eq(tenant_id))
+            .filter(Column::UserId.eq(user_id))
+            .filter(Column::Category.eq(category.as_str()))
+            .one(&self.db)
+            .await
+    }
+}
+
+fn map_model(model: Model, category: NotificationCategory) -> NotificationPreference {
+    NotificationPreference {
+        user_id: model.user_id,
+        category,
+        email_enabled: model.email_enabled,
+        sms_enabled: model.sms_enabled,
+    }
+}
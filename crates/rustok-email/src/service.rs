@@ -39,6 +39,9 @@ pub trait TransactionalEmailSender: Send + Sync {
         to: &str,
         vars: &serde_json::Value,
     ) -> Result<()>;
+
+    /// Deliver an email that was already rendered by the caller.
+    async fn send_rendered(&self, to: &str, rendered: &RenderedEmail) -> Result<()>;
 }
 
 /// Top-level email service — disabled or SMTP-backed.
@@ -104,6 +107,20 @@ impl TransactionalEmailSender for EmailService {
             }
         }
     }
+
+    async fn send_rendered(&self, to: &str, rendered: &RenderedEmail) -> Result<()> {
+        match self {
+            Self::Disabled => {
+                tracing::info!(
+                    recipient = %to,
+                    subject = %rendered.subject,
+                    "Transactional email provider disabled; skipping outbound send"
+                );
+                Ok(())
+            }
+            Self::Smtp(sender) => sender.send_rendered(to, rendered).await,
+        }
+    }
 }
 
 /// SMTP-backed email sender.
@@ -225,4 +242,7 @@ impl TransactionalEmailSender for SmtpEmailSender {
             "No template provider handles '{template_id}'"
         )))
     }
+    async fn send_rendered(&self, to: &str, rendered: &RenderedEmail) -> Result<()> {
+        SmtpEmailSender::send_rendered(self, to, rendered).await
+    }
 }
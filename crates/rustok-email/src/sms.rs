@@ -0,0 +1,40 @@
+use async_trait::async_trait;
+
+use crate::error::Result;
+
+/// Outbound SMS delivery. Allows test doubles, like the email sender traits.
+///
+/// Rendering follows the transactional email convention: the implementation receives a
+/// `template_id` (`{module_slug}/{action}`) and JSON vars and turns them into a message.
+#[async_trait]
+pub trait SmsSender: Send + Sync {
+    async fn send_sms(
+        &self,
+        template_id: &str,
+        locale: &str,
+        to: &str,
+        vars: &serde_json::Value,
+    ) -> Result<()>;
+}
+
+/// SMS sender used when no provider is configured; logs and drops every message.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DisabledSmsSender;
+
+#[async_trait]
+impl SmsSender for DisabledSmsSender {
+    async fn send_sms(
+        &self,
+        template_id: &str,
+        _locale: &str,
+        to: &str,
+        _vars: &serde_json::Value,
+    ) -> Result<()> {
+        tracing::info!(
+            recipient = %to,
+            template_id,
+            "SMS provider disabled; skipping outbound send"
+        );
+        Ok(())
+    }
+}
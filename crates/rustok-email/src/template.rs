@@ -1,3 +1,5 @@
+use rustok_core::email::{RenderedTemplate, Template, TemplateContent, TemplateRegistry};
+
 use crate::error::{EmailError, Result};
 
 /// A rendered, ready-to-send email.
@@ -8,6 +10,16 @@ pub struct RenderedEmail {
     pub html: String,
 }
 
+impl From<RenderedTemplate> for RenderedEmail {
+    fn from(rendered: RenderedTemplate) -> Self {
+        Self {
+            subject: rendered.subject,
+            text: rendered.text,
+            html: rendered.html,
+        }
+    }
+}
+
 /// Contract for providing email templates.
 ///
 /// Modules that need to send transactional emails (order confirmations,
@@ -46,3 +58,30 @@ pub fn render_tera_string(template: &str, vars: &serde_json::Value) -> Result<St
     tera::Tera::one_off(template, &ctx, /*autoescape=*/ false)
         .map_err(|e| EmailError::Template(format!("Tera render error: {e}")))
 }
+
+/// Built-in notification templates rendered by the notifier before delivery.
+///
+/// Variables are the `data` fields of the domain event that triggered the notification.
+pub fn default_notification_templates() -> TemplateRegistry {
+    TemplateRegistry::new().with(
+        Template::new("commerce/order_placed")
+            .with_locale(
+                "en",
+                TemplateContent::new(
+                    "Order {{order_id}} received",
+                    "<p>Thank you for your order!</p>\
+                     <p>Order <strong>{{order_id}}</strong>, total {{total}} {{currency}}.</p>",
+                    "Thank you for your order!\n\nOrder {{order_id}}, total {{total}} {{currency}}.",
+                ),
+            )
+            .with_locale(
+                "ru",
+                TemplateContent::new(
+                    "Заказ {{order_id}} принят",
+                    "<p>Спасибо за заказ!</p>\
+                     <p>Заказ <strong>{{order_id}}</strong>, сумма {{total}} {{currency}}.</p>",
+                    "Спасибо за заказ!\n\nЗаказ {{order_id}}, сумма {{total}} {{currency}}.",
+                ),
+            ),
+    )
+}
@@ -0,0 +1,230 @@
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use rustok_core::{DomainEvent, EventEnvelope, EventHandler, MigrationSource};
+use rustok_email::{
+    default_notification_templates, EmailModule, NotificationCategory, NotificationChannels,
+    NotificationHandler, NotificationPreferenceService, NotificationRecipient,
+    NotificationRecipientResolver, RenderedEmail, SmsSender, TransactionalEmailSender,
+};
+use rustok_test_utils::db::setup_test_db;
+use sea_orm::{DatabaseConnection, DbErr};
+use sea_orm_migration::SchemaManager;
+use uuid::Uuid;
+
+#[derive(Default)]
+struct RecordingEmail {
+    transactional: Mutex<Vec<(String, String)>>,
+    rendered: Mutex<Vec<(String, RenderedEmail)>>,
+}
+
+#[async_trait]
+impl TransactionalEmailSender for RecordingEmail {
+    async fn send_transactional(
+        &self,
+        template_id: &str,
+        _locale: &str,
+        to: &str,
+        _vars: &serde_json::Value,
+    ) -> rustok_email::error::Result<()> {
+        self.transactional
+            .lock()
+            .unwrap()
+            .push((template_id.to_string(), to.to_string()));
+        Ok(())
+    }
+
+    async fn send_rendered(
+        &self,
+        to: &str,
+        rendered: &RenderedEmail,
+    ) -> rustok_email::error::Result<()> {
+        self.rendered
+            .lock()
+            .unwrap()
+            .push((to.to_string(), rendered.clone()));
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct RecordingSms(Mutex<Vec<(String, String)>>);
+
+#[async_trait]
+impl SmsSender for RecordingSms {
+    async fn send_sms(
+        &self,
+        template_id: &str,
+        _locale: &str,
+        to: &str,
+        _vars: &serde_json::Value,
+    ) -> rustok_email::error::Result<()> {
+        self.0
+            .lock()
+            .unwrap()
+            .push((template_id.to_string(), to.to_string()));
+        Ok(())
+    }
+}
+
+struct FixedRecipient(NotificationRecipient);
+
+#[async_trait]
+impl NotificationRecipientResolver for FixedRecipient {
+    async fn resolve(
+        &self,
+        _tenant_id: Uuid,
+        _event: &DomainEvent,
+    ) -> Result<Option<NotificationRecipient>, DbErr> {
+        Ok(Some(self.0.clone()))
+    }
+}
+
+struct Harness {
+    db: DatabaseConnection,
+    handler: NotificationHandler,
+    email: Arc<RecordingEmail>,
+    sms: Arc<RecordingSms>,
+    user_id: Uuid,
+}
+
+async fn setup() -> Harness {
+    setup_with_locale("en").await
+}
+
+async fn setup_with_locale(locale: &str) -> Harness {
+    let db = setup_test_db().await;
+    let schema = SchemaManager::new(&db);
+    for migration in EmailModule.migrations() {
+        migration
+            .up(&schema)
+            .await
+            .expect("failed to apply email migrations");
+    }
+
+    let email = Arc::new(RecordingEmail::default());
+    let sms = Arc::new(RecordingSms::default());
+    let user_id = Uuid::new_v4();
+    let handler = NotificationHandler::new(
+        db.clone(),
+        NotificationChannels {
+            email: email.clone(),
+            sms: sms.clone(),
+            templates: Arc::new(default_notification_templates()),
+        },
+    )
+    .with_resolver(Arc::new(FixedRecipient(NotificationRecipient {
+        user_id: Some(user_id),
+        email: Some("buyer@example.com".to_string()),
+        phone: Some("+15550100".to_string()),
+        locale: locale.to_string(),
+    })));
+
+    Harness {
+        db,
+        handler,
+        email,
+        sms,
+        user_id,
+    }
+}
+
+fn order_paid(tenant_id: Uuid) -> EventEnvelope {
+    EventEnvelope::new(
+        tenant_id,
+        None,
+        DomainEvent::OrderStatusChanged {
+            order_id: Uuid::new_v4(),
+            old_status: "confirmed".to_string(),
+            new_status: "paid".to_string(),
+        },
+    )
+}
+
+#[tokio::test]
+async fn order_paid_skips_email_when_user_disabled_order_emails() {
+    let harness = setup().await;
+    let tenant_id = Uuid::new_v4();
+    NotificationPreferenceService::new(harness.db.clone())
+        .set(
+            tenant_id,
+            harness.user_id,
+            NotificationCategory::Orders,
+            false,
+            true,
+        )
+        .await
+        .unwrap();
+
+    let envelope = order_paid(tenant_id);
+    assert!(harness.handler.handles(&envelope.event));
+    harness.handler.handle(&envelope).await.unwrap();
+
+    assert!(harness.email.transactional.lock().unwrap().is_empty());
+    assert_eq!(
+        *harness.sms.0.lock().unwrap(),
+        [("commerce/order_paid".to_string(), "+15550100".to_string())]
+    );
+}
+
+#[tokio::test]
+async fn order_paid_sends_email_when_enabled() {
+    let harness = setup().await;
+    let tenant_id = Uuid::new_v4();
+
+    harness
+        .handler
+        .handle(&order_paid(tenant_id))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        *harness.email.transactional.lock().unwrap(),
+        [(
+            "commerce/order_paid".to_string(),
+            "buyer@example.com".to_string()
+        )]
+    );
+    assert!(
+        harness.sms.0.lock().unwrap().is_empty(),
+        "SMS stays off until the user opts in"
+    );
+
+    let confirmed = EventEnvelope::new(
+        tenant_id,
+        None,
+        DomainEvent::OrderStatusChanged {
+            order_id: Uuid::new_v4(),
+            old_status: "pending".to_string(),
+            new_status: "confirmed".to_string(),
+        },
+    );
+    assert!(!harness.handler.handles(&confirmed.event));
+}
+
+#[tokio::test]
+async fn order_placed_email_is_rendered_in_the_recipients_locale() {
+    let harness = setup_with_locale("ru-RU").await;
+    let order_id = Uuid::new_v4();
+    let envelope = EventEnvelope::new(
+        Uuid::new_v4(),
+        None,
+        DomainEvent::OrderPlaced {
+            order_id,
+            customer_id: None,
+            total: 4398,
+            currency: "RUB".to_string(),
+        },
+    );
+
+    harness.handler.handle(&envelope).await.unwrap();
+
+    assert!(harness.email.transactional.lock().unwrap().is_empty());
+    let rendered = harness.email.rendered.lock().unwrap();
+    assert_eq!(rendered.len(), 1);
+    let (to, email) = &rendered[0];
+    assert_eq!(to, "buyer@example.com");
+    assert_eq!(email.subject, format!("Заказ {order_id} принят"));
+    assert!(email.text.contains("сумма 4398 RUB"));
+    assert!(email.html.contains(&format!("<strong>{order_id}</strong>")));
+}
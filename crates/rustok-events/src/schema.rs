@@ -244,6 +244,13 @@ const URL_ALIAS_PURGED_FIELDS: &[FieldSchema] = &[
 
 const TENANT_ID_FIELDS: &[FieldSchema] = &[field!("tenant_id", "uuid")];
 const LOCALE_FIELDS: &[FieldSchema] = &[field!("tenant_id", "uuid"), field!("locale", "string")];
+const SCRIPT_FAILED_FIELDS: &[FieldSchema] = &[
+    field!("script_id", "uuid"),
+    field!("script_name", "string"),
+    field!("phase", "string"),
+    field!("event", "string", optional),
+    field!("error", "string"),
+];
 
 pub const EVENT_SCHEMAS: &[EventSchema] = &[
     EventSchema {
@@ -803,6 +810,12 @@ pub const EVENT_SCHEMAS: &[EventSchema] = &[
             },
         ],
     },
+    EventSchema {
+        event_type: "script.failed",
+        version: 1,
+        description: "A production hook script failed.",
+        fields: SCRIPT_FAILED_FIELDS,
+    },
 ];
 
 pub fn event_schema(event_type: &str) -> Option<&'static EventSchema> {
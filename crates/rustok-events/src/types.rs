@@ -425,6 +425,20 @@ pub enum DomainEvent {
         schema_id: Uuid,
         entry_id: Uuid,
     },
+
+    // ════════════════════════════════════════════════════════════════
+    // SCRIPTING EVENTS
+    // ════════════════════════════════════════════════════════════════
+    /// A production hook script failed (dry runs do not emit this).
+    ScriptFailed {
+        script_id: Uuid,
+        script_name: String,
+        /// Execution phase, e.g. "before", "after", "on_commit".
+        phase: String,
+        /// Hook event, e.g. "before_create"; `None` outside entity hooks.
+        event: Option<String>,
+        error: String,
+    },
 }
 
 impl DomainEvent {
@@ -512,6 +526,8 @@ impl DomainEvent {
             Self::FlexEntryCreated { .. } => "flex.entry.created",
             Self::FlexEntryUpdated { .. } => "flex.entry.updated",
             Self::FlexEntryDeleted { .. } => "flex.entry.deleted",
+
+            Self::ScriptFailed { .. } => "script.failed",
         }
     }
 
@@ -615,6 +631,9 @@ impl DomainEvent {
             Self::FlexEntryCreated { .. } => 1,
             Self::FlexEntryUpdated { .. } => 1,
             Self::FlexEntryDeleted { .. } => 1,
+
+            // Scripting events (v1)
+            Self::ScriptFailed { .. } => 1,
         }
     }
 
@@ -1353,6 +1372,28 @@ impl ValidateEvent for DomainEvent {
                 validators::validate_not_nil_uuid("entry_id", entry_id)?;
                 Ok(())
             }
+
+            // ════════════════════════════════════════════════════════════════
+            // SCRIPTING EVENTS
+            // ════════════════════════════════════════════════════════════════
+            Self::ScriptFailed {
+                script_id,
+                script_name,
+                phase,
+                event,
+                error,
+            } => {
+                validators::validate_not_nil_uuid("script_id", script_id)?;
+                validators::validate_not_empty("script_name", script_name)?;
+                validators::validate_max_length("script_name", script_name, 255)?;
+                validators::validate_not_empty("phase", phase)?;
+                validators::validate_max_length("phase", phase, 32)?;
+                if let Some(event) = event {
+                    validators::validate_max_length("event", event, 32)?;
+                }
+                validators::validate_max_length("error", error, 2000)?;
+                Ok(())
+            }
         }
     }
 }
@@ -283,6 +283,13 @@ fn sample_events() -> Vec<DomainEvent> {
             schema_id: id(102),
             entry_id: id(103),
         },
+        DomainEvent::ScriptFailed {
+            script_id: id(104),
+            script_name: "normalize_order".to_string(),
+            phase: "before".to_string(),
+            event: Some("before_create".to_string()),
+            error: "Runtime error: boom".to_string(),
+        },
     ]
 }
 
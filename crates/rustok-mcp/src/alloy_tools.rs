@@ -114,7 +114,10 @@ impl From<Script> for AlloyScriptInfo {
             ScriptTrigger::Event { entity_type, event } => {
                 format!("event:{}:{}", entity_type, event.as_str())
             }
-            ScriptTrigger::Cron { expression } => format!("cron:{}", expression),
+            ScriptTrigger::Cron {
+                expression,
+                timezone,
+            } => format!("cron:{}:{}", timezone, expression),
             ScriptTrigger::Manual => "manual".to_string(),
             ScriptTrigger::Api { path, method } => format!("api:{}:{}", method.as_str(), path),
         };
@@ -160,7 +163,7 @@ pub struct CreateScriptRequest {
     pub description: Option<String>,
     /// JSON-encoded trigger object. Examples:
     /// `{"type":"manual"}`,
-    /// `{"type":"cron","expression":"0 * * * * *"}`,
+    /// `{"type":"cron","expression":"0 * * * * *","timezone":"Europe/Berlin"}` (UTC if omitted),
     /// `{"type":"event","entity_type":"order","event":"before_create"}`,
     /// `{"type":"api","path":"/my-endpoint","method":"POST"}`
     pub trigger: serde_json::Value,
@@ -410,8 +413,13 @@ pub async fn alloy_create_script<R: ScriptRegistry>(
     let trigger: ScriptTrigger =
         serde_json::from_value(request.trigger).map_err(|e| e.to_string())?;
 
-    if let ScriptTrigger::Cron { ref expression } = trigger {
+    if let ScriptTrigger::Cron {
+        ref expression,
+        ref timezone,
+    } = trigger
+    {
         alloy::utils::validate_cron_expression(expression)?;
+        alloy::utils::validate_timezone(timezone)?;
     }
 
     let mut scope = rhai::Scope::new();
@@ -776,7 +784,7 @@ pub async fn alloy_run_script<R: ScriptRegistry>(
             );
             (true, None, rv, Some(ch))
         }
-        ExecutionOutcome::Aborted { reason } => (false, Some(reason.clone()), None, None),
+        ExecutionOutcome::Aborted { reason, .. } => (false, Some(reason.clone()), None, None),
         ExecutionOutcome::Failed { error } => (false, Some(error.to_string()), None, None),
     };
 
@@ -99,6 +99,39 @@ pub struct CancelOrderInput {
     pub reason: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreateCouponInput {
+    #[validate(length(min = 1, max = 64))]
+    pub code: String,
+    /// `percentage` or `fixed`.
+    pub discount_type: String,
+    /// Percent off the order subtotal, or a fixed amount in `currency_code`.
+    pub value: Decimal,
+    #[validate(length(equal = 3))]
+    pub currency_code: Option<String>,
+    pub starts_at: Option<DateTime<Utc>>,
+    pub ends_at: Option<DateTime<Utc>>,
+    #[validate(range(min = 1))]
+    pub usage_limit: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CouponResponse {
+    pub id: Uuid,
+    pub tenant_id: Uuid,
+    pub code: String,
+    pub discount_type: String,
+    pub value: Decimal,
+    pub currency_code: Option<String>,
+    pub starts_at: Option<DateTime<Utc>>,
+    pub ends_at: Option<DateTime<Utc>>,
+    pub usage_limit: Option<i32>,
+    pub usage_count: i32,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct OrderResponse {
     pub id: Uuid,
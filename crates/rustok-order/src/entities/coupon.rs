@@ -0,0 +1,36 @@
+use rust_decimal::Decimal;
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "order_coupons")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub tenant_id: Uuid,
+    pub code: String,
+    pub discount_type: String,
+    pub value: Decimal,
+    pub currency_code: Option<String>,
+    pub starts_at: Option<DateTimeWithTimeZone>,
+    pub ends_at: Option<DateTimeWithTimeZone>,
+    pub usage_limit: Option<i32>,
+    pub usage_count: i32,
+    pub is_active: bool,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(has_many = "super::coupon_redemption::Entity")]
+    Redemptions,
+}
+
+impl Related<super::coupon_redemption::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Redemptions.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
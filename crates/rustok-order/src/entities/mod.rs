@@ -1,3 +1,5 @@
+pub mod coupon;
+pub mod coupon_redemption;
 pub mod order;
 pub mod order_adjustment;
 pub mod order_line_item;
@@ -12,6 +12,12 @@ pub enum OrderError {
     OrderNotFound(Uuid),
     #[error("invalid order status transition: {from} -> {to}")]
     InvalidTransition { from: String, to: String },
+    #[error("coupon {0} is not valid")]
+    InvalidCoupon(String),
+    #[error("coupon {0} has expired")]
+    CouponExpired(String),
+    #[error("coupon {0} has reached its usage limit")]
+    CouponUsageLimitReached(String),
     #[error(transparent)]
     Database(#[from] DbErr),
     #[error(transparent)]
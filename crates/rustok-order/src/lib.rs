@@ -8,11 +8,16 @@ pub mod entities;
 pub mod error;
 pub mod migrations;
 pub mod services;
+pub mod totals;
+
+#[cfg(test)]
+mod totals_proptest;
 
 pub use dto::*;
 pub use entities::*;
 pub use error::{OrderError, OrderResult};
 pub use services::OrderService;
+pub use totals::{recompute_totals, OrderTotals};
 
 pub struct OrderModule;
 
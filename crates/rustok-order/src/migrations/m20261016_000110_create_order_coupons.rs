@@ -0,0 +1,210 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(OrderCoupons::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(OrderCoupons::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(OrderCoupons::TenantId).uuid().not_null())
+                    .col(ColumnDef::new(OrderCoupons::Code).string_len(64).not_null())
+                    .col(
+                        ColumnDef::new(OrderCoupons::DiscountType)
+                            .string_len(32)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(OrderCoupons::Value).decimal().not_null())
+                    .col(ColumnDef::new(OrderCoupons::CurrencyCode).string_len(3))
+                    .col(ColumnDef::new(OrderCoupons::StartsAt).timestamp_with_time_zone())
+                    .col(ColumnDef::new(OrderCoupons::EndsAt).timestamp_with_time_zone())
+                    .col(ColumnDef::new(OrderCoupons::UsageLimit).integer())
+                    .col(
+                        ColumnDef::new(OrderCoupons::UsageCount)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(OrderCoupons::IsActive)
+                            .boolean()
+                            .not_null()
+                            .default(true),
+                    )
+                    .col(
+                        ColumnDef::new(OrderCoupons::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(OrderCoupons::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_order_coupons_tenant_code")
+                    .table(OrderCoupons::Table)
+                    .col(OrderCoupons::TenantId)
+                    .col(OrderCoupons::Code)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(OrderCouponRedemptions::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(OrderCouponRedemptions::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(OrderCouponRedemptions::TenantId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(OrderCouponRedemptions::CouponId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(OrderCouponRedemptions::OrderId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(OrderCouponRedemptions::Amount)
+                            .decimal()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(OrderCouponRedemptions::CurrencyCode)
+                            .string_len(3)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(OrderCouponRedemptions::AppliedBy)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(OrderCouponRedemptions::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(
+                                OrderCouponRedemptions::Table,
+                                OrderCouponRedemptions::CouponId,
+                            )
+                            .to(OrderCoupons::Table, OrderCoupons::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(
+                                OrderCouponRedemptions::Table,
+                                OrderCouponRedemptions::OrderId,
+                            )
+                            .to(Orders::Table, Orders::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_order_coupon_redemptions_unique")
+                    .table(OrderCouponRedemptions::Table)
+                    .col(OrderCouponRedemptions::CouponId)
+                    .col(OrderCouponRedemptions::OrderId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_order_coupon_redemptions_order")
+                    .table(OrderCouponRedemptions::Table)
+                    .col(OrderCouponRedemptions::OrderId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(OrderCouponRedemptions::Table)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_table(Table::drop().table(OrderCoupons::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum OrderCoupons {
+    Table,
+    Id,
+    TenantId,
+    Code,
+    DiscountType,
+    Value,
+    CurrencyCode,
+    StartsAt,
+    EndsAt,
+    UsageLimit,
+    UsageCount,
+    IsActive,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum OrderCouponRedemptions {
+    Table,
+    Id,
+    TenantId,
+    CouponId,
+    OrderId,
+    Amount,
+    CurrencyCode,
+    AppliedBy,
+    CreatedAt,
+}
+
+#[derive(Iden)]
+enum Orders {
+    Table,
+    Id,
+}
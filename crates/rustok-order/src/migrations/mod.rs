@@ -7,6 +7,7 @@ mod m20260411_000106_add_order_tax_lines;
 mod m20260411_000107_add_order_line_item_translations;
 mod m20260412_000108_add_order_shipping_total;
 mod m20260412_000109_add_order_tax_line_provider_id;
+mod m20261016_000110_create_order_coupons;
 
 use sea_orm_migration::MigrationTrait;
 
@@ -21,5 +22,6 @@ pub fn migrations() -> Vec<Box<dyn MigrationTrait>> {
         Box::new(m20260411_000107_add_order_line_item_translations::Migration),
         Box::new(m20260412_000108_add_order_shipping_total::Migration),
         Box::new(m20260412_000109_add_order_tax_line_provider_id::Migration),
+        Box::new(m20261016_000110_create_order_coupons::Migration),
     ]
 }
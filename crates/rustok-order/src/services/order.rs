@@ -2,6 +2,7 @@ use chrono::Utc;
 use flex::{persist_localized_values, prepare_attached_values_create, resolve_attached_payload};
 use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
+use sea_orm::sea_query::Expr;
 use sea_orm::{
     ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait,
     PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Set, Statement, TransactionTrait,
@@ -18,12 +19,13 @@ use rustok_events::DomainEvent;
 use rustok_outbox::TransactionalEventBus;
 
 use crate::dto::{
-    CreateOrderAdjustmentInput, CreateOrderInput, CreateOrderLineItemInput,
-    CreateOrderTaxLineInput, ListOrdersInput, OrderAdjustmentResponse, OrderLineItemResponse,
-    OrderResponse, OrderTaxLineResponse,
+    CouponResponse, CreateCouponInput, CreateOrderAdjustmentInput, CreateOrderInput,
+    CreateOrderLineItemInput, CreateOrderTaxLineInput, ListOrdersInput, OrderAdjustmentResponse,
+    OrderLineItemResponse, OrderResponse, OrderTaxLineResponse,
 };
 use crate::entities;
 use crate::error::{OrderError, OrderResult};
+use crate::totals::{recompute_totals, OrderTotals};
 
 const STATUS_PENDING: &str = "pending";
 const STATUS_CONFIRMED: &str = "confirmed";
@@ -32,6 +34,10 @@ const STATUS_SHIPPED: &str = "shipped";
 const STATUS_DELIVERED: &str = "delivered";
 const STATUS_CANCELLED: &str = "cancelled";
 
+const COUPON_PERCENTAGE: &str = "percentage";
+const COUPON_FIXED: &str = "fixed";
+const COUPON_ADJUSTMENT_SOURCE: &str = "coupon";
+
 mod order_field_definitions_storage {
     rustok_core::define_field_definitions_entity!("order_field_definitions");
 }
@@ -93,40 +99,26 @@ impl OrderService {
             .clone()
             .unwrap_or_else(|| serde_json::json!({}));
 
-        let mut subtotal_amount = Decimal::ZERO;
         for item in &input.line_items {
             Self::validate_line_item(item)?;
-            subtotal_amount += item.unit_price * Decimal::from(item.quantity);
         }
         if input.shipping_total < Decimal::ZERO {
             return Err(OrderError::Validation(
                 "shipping_total cannot be negative".to_string(),
             ));
         }
-        let adjustment_total =
-            Self::validate_adjustments(&input.adjustments, input.line_items.len())?;
-        let tax_total = Self::validate_tax_lines(
+        Self::validate_adjustments(&input.adjustments, input.line_items.len())?;
+        Self::validate_tax_lines(
             &input.tax_lines,
             input.line_items.len(),
             currency_code.as_str(),
         )?;
-        let tax_included = input.tax_lines.iter().any(|line| {
-            line.metadata
-                .get("tax_included")
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or(false)
-        });
-        if adjustment_total > subtotal_amount {
+        let totals = OrderTotals::from_input(&input);
+        if totals.adjustment_total > totals.subtotal_amount {
             return Err(OrderError::Validation(
                 "adjustment total cannot exceed order subtotal".to_string(),
             ));
         }
-        let base_total = subtotal_amount - adjustment_total + input.shipping_total;
-        let total_amount = if tax_included {
-            base_total
-        } else {
-            base_total + tax_total
-        };
 
         let order_id = generate_id();
         let now = Utc::now();
@@ -140,10 +132,10 @@ impl OrderService {
             customer_id: Set(input.customer_id),
             status: Set(STATUS_PENDING.to_string()),
             currency_code: Set(currency_code.clone()),
-            shipping_total: Set(input.shipping_total),
-            total_amount: Set(total_amount),
-            tax_total: Set(tax_total),
-            tax_included: Set(tax_included),
+            shipping_total: Set(totals.shipping_total),
+            total_amount: Set(totals.total_amount),
+            tax_total: Set(totals.tax_total),
+            tax_included: Set(totals.tax_included),
             metadata: Set(order_metadata),
             payment_id: Set(None),
             payment_method: Set(None),
@@ -257,7 +249,7 @@ impl OrderService {
                 DomainEvent::OrderPlaced {
                     order_id,
                     customer_id: input.customer_id,
-                    total: decimal_to_minor_units(total_amount).unwrap_or(0),
+                    total: decimal_to_minor_units(totals.total_amount).unwrap_or(0),
                     currency: currency_code,
                 },
             )
@@ -522,6 +514,235 @@ impl OrderService {
             .await
     }
 
+    #[instrument(skip(self, input), fields(tenant_id = %tenant_id))]
+    pub async fn create_coupon(
+        &self,
+        tenant_id: Uuid,
+        input: CreateCouponInput,
+    ) -> OrderResult<CouponResponse> {
+        input
+            .validate()
+            .map_err(|error| OrderError::Validation(error.to_string()))?;
+
+        let code = normalize_coupon_code(&input.code)?;
+        let discount_type = input.discount_type.trim().to_ascii_lowercase();
+        let currency_code = input
+            .currency_code
+            .as_deref()
+            .map(|value| value.trim().to_ascii_uppercase());
+        match discount_type.as_str() {
+            COUPON_PERCENTAGE => {
+                if input.value <= Decimal::ZERO || input.value > Decimal::from(100) {
+                    return Err(OrderError::Validation(
+                        "percentage coupon value must be between 0 and 100".to_string(),
+                    ));
+                }
+            }
+            COUPON_FIXED => {
+                if input.value <= Decimal::ZERO {
+                    return Err(OrderError::Validation(
+                        "fixed coupon value must be positive".to_string(),
+                    ));
+                }
+                if currency_code.is_none() {
+                    return Err(OrderError::Validation(
+                        "fixed coupons require currency_code".to_string(),
+                    ));
+                }
+            }
+            _ => {
+                return Err(OrderError::Validation(
+                    "discount_type must be percentage or fixed".to_string(),
+                ));
+            }
+        }
+        if let (Some(starts_at), Some(ends_at)) = (input.starts_at, input.ends_at) {
+            if ends_at <= starts_at {
+                return Err(OrderError::Validation(
+                    "coupon ends_at must be after starts_at".to_string(),
+                ));
+            }
+        }
+
+        let existing = entities::coupon::Entity::find()
+            .filter(entities::coupon::Column::TenantId.eq(tenant_id))
+            .filter(entities::coupon::Column::Code.eq(code.as_str()))
+            .one(&self.db)
+            .await?;
+        if existing.is_some() {
+            return Err(OrderError::Validation(format!(
+                "coupon {code} already exists"
+            )));
+        }
+
+        let now = Utc::now();
+        let coupon = entities::coupon::ActiveModel {
+            id: Set(generate_id()),
+            tenant_id: Set(tenant_id),
+            code: Set(code),
+            discount_type: Set(discount_type),
+            value: Set(input.value),
+            currency_code: Set(currency_code),
+            starts_at: Set(input.starts_at.map(Into::into)),
+            ends_at: Set(input.ends_at.map(Into::into)),
+            usage_limit: Set(input.usage_limit),
+            usage_count: Set(0),
+            is_active: Set(true),
+            created_at: Set(now.into()),
+            updated_at: Set(now.into()),
+        }
+        .insert(&self.db)
+        .await?;
+
+        Ok(coupon_response(coupon))
+    }
+
+    /// Applies a coupon to a pending order as an order-level adjustment.
+    ///
+    /// The discount is capped at what is left of the subtotal after existing adjustments,
+    /// and the coupon's usage counter is bumped with a guarded update so concurrent
+    /// redemptions cannot overshoot `usage_limit`.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id, order_id = %order_id))]
+    pub async fn apply_coupon(
+        &self,
+        tenant_id: Uuid,
+        actor_id: Uuid,
+        order_id: Uuid,
+        code: &str,
+    ) -> OrderResult<OrderResponse> {
+        let code = normalize_coupon_code(code)?;
+        let txn = self.db.begin().await?;
+        let existing = self
+            .load_order_model_in_tx(&txn, tenant_id, order_id)
+            .await?;
+        let preferred_locale = Self::preferred_order_locale_from_metadata(&existing.metadata)
+            .unwrap_or(load_tenant_default_locale(&txn, tenant_id).await?);
+        if existing.status != STATUS_PENDING {
+            return Err(OrderError::Validation(
+                "coupons can only be applied to pending orders".to_string(),
+            ));
+        }
+
+        let coupon = entities::coupon::Entity::find()
+            .filter(entities::coupon::Column::TenantId.eq(tenant_id))
+            .filter(entities::coupon::Column::Code.eq(code.as_str()))
+            .filter(entities::coupon::Column::IsActive.eq(true))
+            .one(&txn)
+            .await?
+            .ok_or_else(|| OrderError::InvalidCoupon(code.clone()))?;
+        let now = Utc::now();
+        if coupon
+            .starts_at
+            .is_some_and(|starts_at| starts_at.with_timezone(&Utc) > now)
+        {
+            return Err(OrderError::InvalidCoupon(code));
+        }
+        if coupon
+            .ends_at
+            .is_some_and(|ends_at| ends_at.with_timezone(&Utc) <= now)
+        {
+            return Err(OrderError::CouponExpired(code));
+        }
+        if coupon
+            .usage_limit
+            .is_some_and(|limit| coupon.usage_count >= limit)
+        {
+            return Err(OrderError::CouponUsageLimitReached(code));
+        }
+        let already_redeemed = entities::coupon_redemption::Entity::find()
+            .filter(entities::coupon_redemption::Column::CouponId.eq(coupon.id))
+            .filter(entities::coupon_redemption::Column::OrderId.eq(order_id))
+            .one(&txn)
+            .await?;
+        if already_redeemed.is_some() {
+            return Err(OrderError::Validation(format!(
+                "coupon {code} is already applied to this order"
+            )));
+        }
+
+        let line_items = entities::order_line_item::Entity::find()
+            .filter(entities::order_line_item::Column::OrderId.eq(order_id))
+            .all(&txn)
+            .await?;
+        let adjustments = entities::order_adjustment::Entity::find()
+            .filter(entities::order_adjustment::Column::OrderId.eq(order_id))
+            .all(&txn)
+            .await?;
+        let current = order_totals(&existing, &line_items, &adjustments, None);
+        let discount = match coupon.discount_type.as_str() {
+            COUPON_PERCENTAGE => {
+                (current.subtotal_amount * coupon.value / Decimal::from(100)).round_dp(2)
+            }
+            COUPON_FIXED if coupon.currency_code.as_deref() == Some(&existing.currency_code) => {
+                coupon.value
+            }
+            _ => return Err(OrderError::InvalidCoupon(code)),
+        }
+        .min(current.subtotal_amount - current.adjustment_total);
+        if discount <= Decimal::ZERO {
+            return Err(OrderError::Validation(
+                "order has no remaining amount to discount".to_string(),
+            ));
+        }
+
+        let mut claim = entities::coupon::Entity::update_many()
+            .col_expr(
+                entities::coupon::Column::UsageCount,
+                Expr::col(entities::coupon::Column::UsageCount).add(1),
+            )
+            .col_expr(
+                entities::coupon::Column::UpdatedAt,
+                Expr::value(chrono::DateTime::<chrono::FixedOffset>::from(now)),
+            )
+            .filter(entities::coupon::Column::Id.eq(coupon.id));
+        if let Some(limit) = coupon.usage_limit {
+            claim = claim.filter(entities::coupon::Column::UsageCount.lt(limit));
+        }
+        if claim.exec(&txn).await?.rows_affected == 0 {
+            return Err(OrderError::CouponUsageLimitReached(code));
+        }
+
+        entities::order_adjustment::ActiveModel {
+            id: Set(generate_id()),
+            order_id: Set(order_id),
+            order_line_item_id: Set(None),
+            source_type: Set(COUPON_ADJUSTMENT_SOURCE.to_string()),
+            source_id: Set(Some(code.clone())),
+            amount: Set(discount),
+            currency_code: Set(existing.currency_code.clone()),
+            metadata: Set(serde_json::json!({
+                "coupon_id": coupon.id,
+                "discount_type": coupon.discount_type,
+                "value": coupon.value,
+            })),
+            created_at: Set(now.into()),
+        }
+        .insert(&txn)
+        .await?;
+        entities::coupon_redemption::ActiveModel {
+            id: Set(generate_id()),
+            tenant_id: Set(tenant_id),
+            coupon_id: Set(coupon.id),
+            order_id: Set(order_id),
+            amount: Set(discount),
+            currency_code: Set(existing.currency_code.clone()),
+            applied_by: Set(actor_id),
+            created_at: Set(now.into()),
+        }
+        .insert(&txn)
+        .await?;
+
+        let totals = order_totals(&existing, &line_items, &adjustments, Some(discount));
+        let mut active: entities::order::ActiveModel = existing.into();
+        active.total_amount = Set(totals.total_amount);
+        active.updated_at = Set(now.into());
+        active.update(&txn).await?;
+
+        txn.commit().await?;
+        self.get_order_with_locale_fallback(tenant_id, order_id, preferred_locale.as_str(), None)
+            .await
+    }
+
     async fn transition_order<F>(
         &self,
         tenant_id: Uuid,
@@ -655,8 +876,7 @@ impl OrderService {
                 &order.metadata,
             )
             .await?;
-        let subtotal_amount = subtotal_amount(&line_items);
-        let adjustment_total = adjustment_total(&adjustments);
+        let totals = order_totals(&order, &line_items, &adjustments, None);
 
         Ok(OrderResponse {
             id: order.id,
@@ -666,8 +886,8 @@ impl OrderService {
             customer_id: order.customer_id,
             status: order.status,
             currency_code: order.currency_code,
-            subtotal_amount,
-            adjustment_total,
+            subtotal_amount: totals.subtotal_amount,
+            adjustment_total: totals.adjustment_total,
             shipping_total: order.shipping_total,
             total_amount: order.total_amount,
             tax_total: order.tax_total,
@@ -753,8 +973,7 @@ impl OrderService {
     fn validate_adjustments(
         adjustments: &[CreateOrderAdjustmentInput],
         line_item_count: usize,
-    ) -> OrderResult<Decimal> {
-        let mut adjustment_total = Decimal::ZERO;
+    ) -> OrderResult<()> {
         for adjustment in adjustments {
             adjustment
                 .validate()
@@ -771,17 +990,15 @@ impl OrderService {
                     )));
                 }
             }
-            adjustment_total += adjustment.amount;
         }
-        Ok(adjustment_total)
+        Ok(())
     }
 
     fn validate_tax_lines(
         tax_lines: &[CreateOrderTaxLineInput],
         line_item_count: usize,
         currency_code: &str,
-    ) -> OrderResult<Decimal> {
-        let mut tax_total = Decimal::ZERO;
+    ) -> OrderResult<()> {
         for tax_line in tax_lines {
             tax_line
                 .validate()
@@ -808,9 +1025,8 @@ impl OrderService {
                     "tax line currency_code must match order currency".to_string(),
                 ));
             }
-            tax_total += tax_line.amount;
         }
-        Ok(tax_total)
+        Ok(())
     }
 
     async fn prepare_order_custom_fields_for_create(
@@ -908,16 +1124,23 @@ fn decimal_to_minor_units(amount: Decimal) -> Option<i64> {
     (amount.round_dp(2) * Decimal::from(100)).to_i64()
 }
 
-fn subtotal_amount(line_items: &[entities::order_line_item::Model]) -> Decimal {
-    line_items
-        .iter()
-        .fold(Decimal::ZERO, |acc, item| acc + item.total_price)
-}
-
-fn adjustment_total(adjustments: &[entities::order_adjustment::Model]) -> Decimal {
-    adjustments
-        .iter()
-        .fold(Decimal::ZERO, |acc, adjustment| acc + adjustment.amount)
+/// Recomputes stored order totals, optionally with one extra order-level adjustment.
+fn order_totals(
+    order: &entities::order::Model,
+    line_items: &[entities::order_line_item::Model],
+    adjustments: &[entities::order_adjustment::Model],
+    extra_adjustment: Option<Decimal>,
+) -> OrderTotals {
+    recompute_totals(
+        line_items.iter().map(|item| item.total_price),
+        adjustments
+            .iter()
+            .map(|adjustment| adjustment.amount)
+            .chain(extra_adjustment),
+        [order.tax_total],
+        order.shipping_total,
+        order.tax_included,
+    )
 }
 
 async fn load_order_custom_fields_schema(
@@ -1005,6 +1228,34 @@ fn merge_reserved_order_metadata(
     Value::Object(reserved)
 }
 
+fn normalize_coupon_code(value: &str) -> OrderResult<String> {
+    let normalized = value.trim().to_ascii_uppercase();
+    if normalized.is_empty() || normalized.len() > 64 {
+        return Err(OrderError::Validation(
+            "coupon code must be 1-64 characters".to_string(),
+        ));
+    }
+    Ok(normalized)
+}
+
+fn coupon_response(coupon: entities::coupon::Model) -> CouponResponse {
+    CouponResponse {
+        id: coupon.id,
+        tenant_id: coupon.tenant_id,
+        code: coupon.code,
+        discount_type: coupon.discount_type,
+        value: coupon.value,
+        currency_code: coupon.currency_code,
+        starts_at: coupon.starts_at.map(|value| value.with_timezone(&Utc)),
+        ends_at: coupon.ends_at.map(|value| value.with_timezone(&Utc)),
+        usage_limit: coupon.usage_limit,
+        usage_count: coupon.usage_count,
+        is_active: coupon.is_active,
+        created_at: coupon.created_at.with_timezone(&Utc),
+        updated_at: coupon.updated_at.with_timezone(&Utc),
+    }
+}
+
 fn normalize_seller_id(value: Option<&str>) -> Option<String> {
     value
         .map(str::trim)
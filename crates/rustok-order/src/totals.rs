@@ -0,0 +1,69 @@
+use rust_decimal::Decimal;
+
+use crate::dto::CreateOrderInput;
+
+/// Money totals of an order snapshot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OrderTotals {
+    pub subtotal_amount: Decimal,
+    pub adjustment_total: Decimal,
+    pub shipping_total: Decimal,
+    pub tax_total: Decimal,
+    pub tax_included: bool,
+    pub total_amount: Decimal,
+}
+
+impl OrderTotals {
+    /// Totals for an order about to be created from `input`.
+    pub fn from_input(input: &CreateOrderInput) -> Self {
+        let tax_included = input.tax_lines.iter().any(|line| {
+            line.metadata
+                .get("tax_included")
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(false)
+        });
+
+        recompute_totals(
+            input
+                .line_items
+                .iter()
+                .map(|item| item.unit_price * Decimal::from(item.quantity)),
+            input.adjustments.iter().map(|adjustment| adjustment.amount),
+            input.tax_lines.iter().map(|line| line.amount),
+            input.shipping_total,
+            tax_included,
+        )
+    }
+}
+
+/// Single place where order totals are derived; every write path goes through it.
+///
+/// `total = subtotal - adjustments + shipping`, plus tax unless the line prices already
+/// include it. Inputs are expected to be validated: non-negative amounts and adjustments
+/// that do not exceed the subtotal.
+pub fn recompute_totals(
+    line_totals: impl IntoIterator<Item = Decimal>,
+    adjustments: impl IntoIterator<Item = Decimal>,
+    tax_amounts: impl IntoIterator<Item = Decimal>,
+    shipping_total: Decimal,
+    tax_included: bool,
+) -> OrderTotals {
+    let subtotal_amount = line_totals.into_iter().sum::<Decimal>();
+    let adjustment_total = adjustments.into_iter().sum::<Decimal>();
+    let tax_total = tax_amounts.into_iter().sum::<Decimal>();
+    let base_total = subtotal_amount - adjustment_total + shipping_total;
+    let total_amount = if tax_included {
+        base_total
+    } else {
+        base_total + tax_total
+    };
+
+    OrderTotals {
+        subtotal_amount,
+        adjustment_total,
+        shipping_total,
+        tax_total,
+        tax_included,
+        total_amount,
+    }
+}
@@ -0,0 +1,188 @@
+//! Property-Based Tests for Order Totals
+//!
+//! These tests use proptest to check the order totals math across randomly
+//! generated orders, so regressions in pricing arithmetic surface early.
+//!
+//! Properties tested:
+//! - total = subtotal + shipping + tax - discount (tax only when exclusive)
+//! - Totals are never negative
+//! - An empty order totals zero
+
+#[cfg(test)]
+mod tests {
+    use crate::dto::{
+        CreateOrderAdjustmentInput, CreateOrderInput, CreateOrderLineItemInput,
+        CreateOrderTaxLineInput,
+    };
+    use crate::totals::{recompute_totals, OrderTotals};
+    use proptest::prelude::*;
+    use rust_decimal::prelude::ToPrimitive;
+    use rust_decimal::Decimal;
+
+    // ============================================================================
+    // Strategy Definitions
+    // ============================================================================
+
+    /// Generate non-negative money amounts with two decimal places
+    fn amount_strategy(max_cents: i64) -> impl Strategy<Value = Decimal> {
+        (0..=max_cents).prop_map(|cents| Decimal::new(cents, 2))
+    }
+
+    /// Generate order line items
+    fn line_item_strategy() -> impl Strategy<Value = CreateOrderLineItemInput> {
+        (amount_strategy(100_000), 1i32..=20).prop_map(|(unit_price, quantity)| {
+            CreateOrderLineItemInput {
+                product_id: None,
+                variant_id: None,
+                shipping_profile_slug: "default".to_string(),
+                seller_id: None,
+                sku: None,
+                title: "Generated item".to_string(),
+                quantity,
+                unit_price,
+                metadata: serde_json::json!({}),
+            }
+        })
+    }
+
+    /// Generate tax lines, flagged as price-inclusive or not
+    fn tax_line_strategy() -> impl Strategy<Value = CreateOrderTaxLineInput> {
+        (amount_strategy(50_000), any::<bool>()).prop_map(|(amount, tax_included)| {
+            CreateOrderTaxLineInput {
+                line_item_index: None,
+                shipping_option_id: None,
+                description: None,
+                provider_id: "region_default".to_string(),
+                rate: Decimal::new(20, 0),
+                amount,
+                currency_code: "USD".to_string(),
+                metadata: serde_json::json!({ "tax_included": tax_included }),
+            }
+        })
+    }
+
+    /// Generate orders whose discounts never exceed the subtotal, as create-order enforces
+    fn arb_create_order_input() -> impl Strategy<Value = CreateOrderInput> {
+        (
+            prop::collection::vec(line_item_strategy(), 0..6),
+            prop::collection::vec(tax_line_strategy(), 0..3),
+            amount_strategy(5_000),
+        )
+            .prop_flat_map(|(line_items, tax_lines, shipping_total)| {
+                let subtotal_cents = line_items
+                    .iter()
+                    .filter_map(|item| {
+                        (item.unit_price * Decimal::from(item.quantity * 100)).to_i64()
+                    })
+                    .sum::<i64>();
+                let discounts = prop::collection::vec(0..=100u32, 0..4);
+                (
+                    Just(line_items),
+                    Just(tax_lines),
+                    Just(shipping_total),
+                    Just(subtotal_cents),
+                    discounts,
+                )
+            })
+            .prop_map(
+                |(line_items, tax_lines, shipping_total, subtotal_cents, shares)| {
+                    // Shares split at most the whole subtotal across the adjustments.
+                    let share_total = shares
+                        .iter()
+                        .map(|share| *share as i64)
+                        .sum::<i64>()
+                        .max(100);
+                    let adjustments = shares
+                        .into_iter()
+                        .map(|share| CreateOrderAdjustmentInput {
+                            line_item_index: None,
+                            source_type: "promotion".to_string(),
+                            source_id: None,
+                            amount: Decimal::new(subtotal_cents * share as i64 / share_total, 2),
+                            metadata: serde_json::json!({}),
+                        })
+                        .collect();
+
+                    CreateOrderInput {
+                        customer_id: None,
+                        currency_code: "USD".to_string(),
+                        shipping_total,
+                        line_items,
+                        adjustments,
+                        tax_lines,
+                        metadata: serde_json::json!({}),
+                    }
+                },
+            )
+    }
+
+    // ============================================================================
+    // Property Tests: Totals Invariants
+    // ============================================================================
+
+    proptest! {
+        /// Property: total is subtotal plus shipping and exclusive tax, minus discounts
+        #[test]
+        fn total_matches_components(input in arb_create_order_input()) {
+            let totals = OrderTotals::from_input(&input);
+            let tax = if totals.tax_included {
+                Decimal::ZERO
+            } else {
+                totals.tax_total
+            };
+
+            prop_assert_eq!(
+                totals.total_amount,
+                totals.subtotal_amount + totals.shipping_total + tax - totals.adjustment_total
+            );
+            prop_assert_eq!(
+                totals.subtotal_amount,
+                input
+                    .line_items
+                    .iter()
+                    .map(|item| item.unit_price * Decimal::from(item.quantity))
+                    .sum::<Decimal>()
+            );
+        }
+
+        /// Property: no total is ever negative
+        #[test]
+        fn totals_are_non_negative(input in arb_create_order_input()) {
+            let totals = OrderTotals::from_input(&input);
+
+            prop_assert!(totals.subtotal_amount >= Decimal::ZERO);
+            prop_assert!(totals.adjustment_total >= Decimal::ZERO);
+            prop_assert!(totals.adjustment_total <= totals.subtotal_amount);
+            prop_assert!(totals.tax_total >= Decimal::ZERO);
+            prop_assert!(totals.total_amount >= Decimal::ZERO);
+        }
+
+        /// Property: stored-row recomputation agrees with the create-order input path
+        #[test]
+        fn recompute_from_amounts_matches_input(input in arb_create_order_input()) {
+            let from_input = OrderTotals::from_input(&input);
+            let recomputed = recompute_totals(
+                input
+                    .line_items
+                    .iter()
+                    .map(|item| item.unit_price * Decimal::from(item.quantity)),
+                input.adjustments.iter().map(|adjustment| adjustment.amount),
+                [from_input.tax_total],
+                input.shipping_total,
+                from_input.tax_included,
+            );
+
+            prop_assert_eq!(recomputed, from_input);
+        }
+    }
+
+    #[test]
+    fn empty_order_totals_zero() {
+        let totals = recompute_totals([], [], [], Decimal::ZERO, false);
+
+        assert_eq!(totals.subtotal_amount, Decimal::ZERO);
+        assert_eq!(totals.adjustment_total, Decimal::ZERO);
+        assert_eq!(totals.tax_total, Decimal::ZERO);
+        assert_eq!(totals.total_amount, Decimal::ZERO);
+    }
+}
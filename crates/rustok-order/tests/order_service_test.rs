@@ -1,7 +1,9 @@
-use chrono::Utc;
+use chrono::{Duration, Utc};
 use flex::attached;
 use rust_decimal::Decimal;
-use rustok_order::dto::{CreateOrderAdjustmentInput, CreateOrderInput, CreateOrderLineItemInput};
+use rustok_order::dto::{
+    CreateCouponInput, CreateOrderAdjustmentInput, CreateOrderInput, CreateOrderLineItemInput,
+};
 use rustok_order::entities::order;
 use rustok_order::error::OrderError;
 use rustok_order::services::OrderService;
@@ -445,3 +447,146 @@ async fn create_order_rejects_missing_required_custom_field() {
         other => panic!("expected validation error, got {other:?}"),
     }
 }
+
+fn coupon_input(code: &str) -> CreateCouponInput {
+    CreateCouponInput {
+        code: code.to_string(),
+        discount_type: "percentage".to_string(),
+        value: Decimal::from(10),
+        currency_code: None,
+        starts_at: None,
+        ends_at: None,
+        usage_limit: None,
+    }
+}
+
+#[tokio::test]
+async fn apply_percentage_coupon_reduces_total_and_records_usage() {
+    let db = setup_test_db().await;
+    support::ensure_order_schema(&db).await;
+    let service = OrderService::new(db.clone(), mock_transactional_event_bus());
+    let tenant_id = Uuid::new_v4();
+    let actor_id = Uuid::new_v4();
+    service
+        .create_coupon(tenant_id, coupon_input("save10"))
+        .await
+        .unwrap();
+    let order = service
+        .create_order(tenant_id, actor_id, create_order_input())
+        .await
+        .unwrap();
+
+    let discounted = service
+        .apply_coupon(tenant_id, actor_id, order.id, "SAVE10")
+        .await
+        .unwrap();
+
+    assert_eq!(
+        discounted.subtotal_amount,
+        Decimal::from_str("43.98").unwrap()
+    );
+    assert_eq!(
+        discounted.adjustment_total,
+        Decimal::from_str("4.40").unwrap()
+    );
+    assert_eq!(discounted.total_amount, Decimal::from_str("39.58").unwrap());
+    assert_eq!(discounted.adjustments.len(), 1);
+    assert_eq!(discounted.adjustments[0].source_type, "coupon");
+    assert_eq!(
+        discounted.adjustments[0].source_id.as_deref(),
+        Some("SAVE10")
+    );
+
+    let again = service
+        .apply_coupon(tenant_id, actor_id, order.id, "save10")
+        .await;
+    assert!(matches!(again, Err(OrderError::Validation(_))));
+
+    let coupon = rustok_order::entities::coupon::Entity::find()
+        .filter(rustok_order::entities::coupon::Column::TenantId.eq(tenant_id))
+        .one(&db)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(coupon.usage_count, 1);
+}
+
+#[tokio::test]
+async fn expired_coupon_is_rejected() {
+    let service = setup().await;
+    let tenant_id = Uuid::new_v4();
+    let actor_id = Uuid::new_v4();
+    service
+        .create_coupon(
+            tenant_id,
+            CreateCouponInput {
+                starts_at: Some(Utc::now() - Duration::days(10)),
+                ends_at: Some(Utc::now() - Duration::days(1)),
+                ..coupon_input("SPRING")
+            },
+        )
+        .await
+        .unwrap();
+    let order = service
+        .create_order(tenant_id, actor_id, create_order_input())
+        .await
+        .unwrap();
+
+    let result = service
+        .apply_coupon(tenant_id, actor_id, order.id, "SPRING")
+        .await;
+    assert!(matches!(result, Err(OrderError::CouponExpired(code)) if code == "SPRING"));
+
+    let unknown = service
+        .apply_coupon(tenant_id, actor_id, order.id, "NOPE")
+        .await;
+    assert!(matches!(unknown, Err(OrderError::InvalidCoupon(_))));
+
+    let unchanged = service.get_order(tenant_id, order.id).await.unwrap();
+    assert_eq!(unchanged.total_amount, Decimal::from_str("43.98").unwrap());
+    assert!(unchanged.adjustments.is_empty());
+}
+
+#[tokio::test]
+async fn coupon_over_usage_limit_is_rejected() {
+    let service = setup().await;
+    let tenant_id = Uuid::new_v4();
+    let actor_id = Uuid::new_v4();
+    service
+        .create_coupon(
+            tenant_id,
+            CreateCouponInput {
+                discount_type: "fixed".to_string(),
+                value: Decimal::from(5),
+                currency_code: Some("usd".to_string()),
+                usage_limit: Some(1),
+                ..coupon_input("ONCE")
+            },
+        )
+        .await
+        .unwrap();
+    let first = service
+        .create_order(tenant_id, actor_id, create_order_input())
+        .await
+        .unwrap();
+    let second = service
+        .create_order(tenant_id, actor_id, create_order_input())
+        .await
+        .unwrap();
+
+    let discounted = service
+        .apply_coupon(tenant_id, actor_id, first.id, "ONCE")
+        .await
+        .unwrap();
+    assert_eq!(discounted.total_amount, Decimal::from_str("38.98").unwrap());
+
+    let result = service
+        .apply_coupon(tenant_id, actor_id, second.id, "ONCE")
+        .await;
+    assert!(matches!(
+        result,
+        Err(OrderError::CouponUsageLimitReached(_))
+    ));
+    let unchanged = service.get_order(tenant_id, second.id).await.unwrap();
+    assert_eq!(unchanged.total_amount, Decimal::from_str("43.98").unwrap());
+}
@@ -1,5 +1,6 @@
 use rustok_order::entities::{
-    order, order_adjustment, order_line_item, order_line_item_translation,
+    coupon, coupon_redemption, order, order_adjustment, order_line_item,
+    order_line_item_translation,
 };
 use sea_orm::{ConnectionTrait, DatabaseConnection, DbBackend, Schema};
 
@@ -34,6 +35,18 @@ pub async fn ensure_order_schema(db: &DatabaseConnection) {
         schema.create_table_from_entity(order_adjustment::Entity),
     )
     .await;
+    create_entity_table(
+        db,
+        &builder,
+        schema.create_table_from_entity(coupon::Entity),
+    )
+    .await;
+    create_entity_table(
+        db,
+        &builder,
+        schema.create_table_from_entity(coupon_redemption::Entity),
+    )
+    .await;
     create_entity_table(
         db,
         &builder,
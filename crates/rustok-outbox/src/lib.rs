@@ -1,25 +1,37 @@
 use async_trait::async_trait;
-use rustok_core::module::{HealthStatus, MigrationSource, ModuleKind, RusToKModule};
+use rustok_core::module::{
+    HealthStatus, MigrationSource, ModuleEventListenerContext, ModuleEventListenerRegistry,
+    ModuleKind, RusToKModule,
+};
 use sea_orm_migration::MigrationTrait;
 
 pub mod entity;
 pub mod migration;
+pub mod migrations;
 pub mod relay;
 pub mod transactional;
 pub mod transport;
+pub mod webhook;
 
 pub use entity::{Entity as SysEvents, Model as SysEvent};
 pub use migration::SysEventsMigration;
 pub use relay::{OutboxRelay, RelayConfig, RelayMetricsSnapshot};
 pub use transactional::TransactionalEventBus;
 pub use transport::OutboxTransport;
+pub use webhook::{
+    sign_payload, verify_signature, CreateWebhookSubscription, WebhookDeliveryStatus,
+    WebhookHandler, WebhookHealth, WebhookHealthProbe, WebhookHealthProbeConfig,
+    WebhookSubscriptionService,
+};
 
 /// Core outbox module — transactional event persistence and relay infrastructure.
 pub struct OutboxModule;
 
 impl MigrationSource for OutboxModule {
     fn migrations(&self) -> Vec<Box<dyn MigrationTrait>> {
-        vec![Box::new(SysEventsMigration)]
+        let mut migrations: Vec<Box<dyn MigrationTrait>> = vec![Box::new(SysEventsMigration)];
+        migrations.extend(migrations::migrations());
+        migrations
     }
 }
 
@@ -45,6 +57,14 @@ impl RusToKModule for OutboxModule {
         ModuleKind::Core
     }
 
+    fn register_event_listeners(
+        &self,
+        registry: &mut ModuleEventListenerRegistry,
+        ctx: &ModuleEventListenerContext<'_>,
+    ) {
+        registry.register(WebhookHandler::new(ctx.db.clone()));
+    }
+
     async fn health(&self) -> HealthStatus {
         HealthStatus::Healthy
     }
@@ -0,0 +1,69 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // One column per statement: SQLite cannot add several columns in one ALTER.
+        for column in [
+            ColumnDef::new(WebhookSubscriptions::HealthStatus)
+                .string_len(32)
+                .not_null()
+                .default("healthy")
+                .to_owned(),
+            ColumnDef::new(WebhookSubscriptions::ConsecutiveFailures)
+                .integer()
+                .not_null()
+                .default(0)
+                .to_owned(),
+            ColumnDef::new(WebhookSubscriptions::LastCheckedAt)
+                .timestamp_with_time_zone()
+                .to_owned(),
+            ColumnDef::new(WebhookSubscriptions::AutoDisabled)
+                .boolean()
+                .not_null()
+                .default(false)
+                .to_owned(),
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(WebhookSubscriptions::Table)
+                        .add_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for column in [
+            WebhookSubscriptions::AutoDisabled,
+            WebhookSubscriptions::LastCheckedAt,
+            WebhookSubscriptions::ConsecutiveFailures,
+            WebhookSubscriptions::HealthStatus,
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(WebhookSubscriptions::Table)
+                        .drop_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum WebhookSubscriptions {
+    Table,
+    HealthStatus,
+    ConsecutiveFailures,
+    LastCheckedAt,
+    AutoDisabled,
+}
@@ -0,0 +1,13 @@
+mod m20261016_000002_create_webhooks;
+mod m20261016_000003_create_webhook_deliveries;
+mod m20261016_000004_add_webhook_subscription_health;
+
+use sea_orm_migration::MigrationTrait;
+
+pub fn migrations() -> Vec<Box<dyn MigrationTrait>> {
+    vec![
+        Box::new(m20261016_000002_create_webhooks::Migration),
+        Box::new(m20261016_000003_create_webhook_deliveries::Migration),
+        Box::new(m20261016_000004_add_webhook_subscription_health::Migration),
+    ]
+}
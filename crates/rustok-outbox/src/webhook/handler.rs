@@ -0,0 +1,241 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use rustok_core::async_utils::BackoffConfig;
+use rustok_core::events::{with_retry, EventHandler, HandlerResult};
+use rustok_core::{DomainEvent, Error, EventEnvelope, Result};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, Set,
+};
+use uuid::Uuid;
+
+use super::delivery::{self, WebhookDeliveryStatus};
+use super::{
+    delivery_attempt, sign_payload, subscription, WebhookSubscriptionService, DELIVERY_ID_HEADER,
+    EVENT_TYPE_HEADER, SIGNATURE_HEADER,
+};
+
+pub const DEFAULT_DELIVERY_ATTEMPTS: u32 = 5;
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+const MAX_ERROR_LEN: usize = 2048;
+
+/// Delivers events to the tenant's webhook subscriptions.
+///
+/// Each matching subscription gets the JSON `EventEnvelope` as the request body. Every
+/// request is recorded in `webhook_delivery_attempts`. Network errors and non-2xx
+/// responses are retried with backoff; a delivery that still fails is marked `failed`
+/// and does not fail the handler, so one broken endpoint cannot hold back the others.
+pub struct WebhookHandler {
+    subscriptions: WebhookSubscriptionService,
+    db: DatabaseConnection,
+    client: reqwest::Client,
+    attempts: u32,
+    backoff: BackoffConfig,
+}
+
+impl WebhookHandler {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self {
+            subscriptions: WebhookSubscriptionService::new(db.clone()),
+            db,
+            client: reqwest::Client::builder()
+                .timeout(REQUEST_TIMEOUT)
+                .build()
+                .unwrap_or_default(),
+            attempts: DEFAULT_DELIVERY_ATTEMPTS,
+            backoff: BackoffConfig::default(),
+        }
+    }
+
+    pub fn with_retry(mut self, attempts: u32, backoff: BackoffConfig) -> Self {
+        self.attempts = attempts;
+        self.backoff = backoff;
+        self
+    }
+
+    /// Sends a failed delivery once more with the subscription's current URL and secret.
+    pub async fn replay_delivery(
+        &self,
+        tenant_id: Uuid,
+        delivery_id: Uuid,
+    ) -> Result<delivery::Model> {
+        let delivery = self.subscriptions.delivery(tenant_id, delivery_id).await?;
+        if delivery.status != WebhookDeliveryStatus::Failed {
+            return Err(Error::Validation(format!(
+                "Webhook delivery {delivery_id} has not failed"
+            )));
+        }
+        let subscription = subscription::Entity::find_by_id(delivery.subscription_id)
+            .one(&self.db)
+            .await?
+            .ok_or_else(|| {
+                Error::NotFound(format!("Webhook subscription {}", delivery.subscription_id))
+            })?;
+
+        let body = serde_json::to_vec(&delivery.payload)?;
+        let attempt = delivery.attempts + 1;
+        let result = self.attempt(&subscription, &delivery, attempt, &body).await;
+        self.finish(delivery, attempt, result).await
+    }
+
+    /// Makes one request and records it as attempt number `attempt`.
+    async fn attempt(
+        &self,
+        subscription: &subscription::Model,
+        delivery: &delivery::Model,
+        attempt: i32,
+        body: &[u8],
+    ) -> Result<()> {
+        let response = self
+            .client
+            .post(&subscription.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header(EVENT_TYPE_HEADER, &delivery.event_type)
+            .header(DELIVERY_ID_HEADER, delivery.event_id.to_string())
+            .header(SIGNATURE_HEADER, sign_payload(&subscription.secret, body))
+            .body(body.to_vec())
+            .send()
+            .await;
+
+        let (status_code, outcome) = match response {
+            Ok(response) if response.status().is_success() => {
+                (Some(response.status().as_u16() as i32), Ok(()))
+            }
+            Ok(response) => (
+                Some(response.status().as_u16() as i32),
+                Err(Error::External(format!(
+                    "Webhook endpoint responded {}",
+                    response.status()
+                ))),
+            ),
+            Err(err) => (
+                None,
+                Err(Error::External(format!("Webhook request failed: {err}"))),
+            ),
+        };
+
+        delivery_attempt::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            delivery_id: Set(delivery.id),
+            tenant_id: Set(delivery.tenant_id),
+            attempt: Set(attempt),
+            status_code: Set(status_code),
+            error: Set(outcome.as_ref().err().map(truncate_error)),
+            attempted_at: Set(Utc::now()),
+        }
+        .insert(&self.db)
+        .await?;
+
+        outcome
+    }
+
+    async fn deliver(
+        &self,
+        subscription: &subscription::Model,
+        envelope: &EventEnvelope,
+        payload: &serde_json::Value,
+        body: &[u8],
+    ) -> Result<delivery::Model> {
+        let now = Utc::now();
+        let delivery = delivery::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            tenant_id: Set(subscription.tenant_id),
+            subscription_id: Set(subscription.id),
+            event_id: Set(envelope.id),
+            event_type: Set(envelope.event_type.clone()),
+            payload: Set(payload.clone()),
+            status: Set(WebhookDeliveryStatus::Pending),
+            attempts: Set(0),
+            last_status_code: Set(None),
+            last_error: Set(None),
+            created_at: Set(now),
+            updated_at: Set(now),
+        }
+        .insert(&self.db)
+        .await?;
+
+        let mut attempt = 0;
+        let result = with_retry(self.attempts, &self.backoff, || {
+            attempt += 1;
+            self.attempt(subscription, &delivery, attempt, body)
+        })
+        .await;
+        self.finish(delivery, attempt, result).await
+    }
+
+    async fn finish(
+        &self,
+        delivery: delivery::Model,
+        attempts: i32,
+        result: Result<()>,
+    ) -> Result<delivery::Model> {
+        let last_attempt = delivery_attempt::Entity::find()
+            .filter(delivery_attempt::Column::DeliveryId.eq(delivery.id))
+            .order_by_desc(delivery_attempt::Column::Attempt)
+            .one(&self.db)
+            .await?;
+        let mut active: delivery::ActiveModel = delivery.into();
+        active.attempts = Set(attempts);
+        active.last_status_code = Set(last_attempt.and_then(|attempt| attempt.status_code));
+        active.updated_at = Set(Utc::now());
+        match &result {
+            Ok(()) => {
+                active.status = Set(WebhookDeliveryStatus::Delivered);
+                active.last_error = Set(None);
+            }
+            Err(error) => {
+                active.status = Set(WebhookDeliveryStatus::Failed);
+                active.last_error = Set(Some(truncate_error(error)));
+            }
+        }
+        let delivery = active.update(&self.db).await?;
+
+        if let Err(error) = result {
+            tracing::error!(
+                delivery_id = %delivery.id,
+                subscription_id = %delivery.subscription_id,
+                event_id = %delivery.event_id,
+                attempts,
+                error = %error,
+                "Webhook delivery failed"
+            );
+        }
+        Ok(delivery)
+    }
+}
+
+fn truncate_error(error: &Error) -> String {
+    error.to_string().chars().take(MAX_ERROR_LEN).collect()
+}
+
+#[async_trait]
+impl EventHandler for WebhookHandler {
+    fn name(&self) -> &'static str {
+        "webhook_dispatcher"
+    }
+
+    fn handles(&self, _event: &DomainEvent) -> bool {
+        true
+    }
+
+    async fn handle(&self, envelope: &EventEnvelope) -> HandlerResult {
+        let subscriptions = self
+            .subscriptions
+            .matching(envelope.tenant_id, &envelope.event_type)
+            .await?;
+        if subscriptions.is_empty() {
+            return Ok(());
+        }
+
+        let payload = serde_json::to_value(envelope)?;
+        let body = serde_json::to_vec(&payload)?;
+        for subscription in &subscriptions {
+            self.deliver(subscription, envelope, &payload, &body)
+                .await?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,167 @@
+use std::time::Duration;
+
+use chrono::Utc;
+use rustok_core::{Error, Result};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, EntityTrait, QueryFilter, Set,
+};
+
+use super::subscription::{self, WebhookHealth};
+use super::{sign_payload, EVENT_TYPE_HEADER, SIGNATURE_HEADER};
+
+/// `X-RusToK-Event` value of health probe requests.
+pub const PING_EVENT_TYPE: &str = "webhook.ping";
+
+#[derive(Clone, Debug)]
+pub struct WebhookHealthProbeConfig {
+    pub interval: Duration,
+    pub request_timeout: Duration,
+    /// Consecutive failed probes before a subscription is marked unhealthy.
+    pub unhealthy_after: u32,
+    /// Consecutive failed probes before the subscription is deactivated; `None` keeps
+    /// unhealthy subscriptions active.
+    pub disable_after: Option<u32>,
+}
+
+impl Default for WebhookHealthProbeConfig {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(15 * 60),
+            request_timeout: Duration::from_secs(10),
+            unhealthy_after: 3,
+            disable_after: Some(10),
+        }
+    }
+}
+
+/// Periodically sends a signed ping to every webhook subscription and tracks whether
+/// its endpoint answers.
+///
+/// Subscriptions the tenant deactivated are skipped; ones the probe deactivated keep
+/// being probed and are re-activated by the first successful ping.
+#[derive(Clone)]
+pub struct WebhookHealthProbe {
+    db: DatabaseConnection,
+    client: reqwest::Client,
+    config: WebhookHealthProbeConfig,
+}
+
+impl WebhookHealthProbe {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self::with_config(db, WebhookHealthProbeConfig::default())
+    }
+
+    pub fn with_config(db: DatabaseConnection, config: WebhookHealthProbeConfig) -> Self {
+        Self {
+            db,
+            client: reqwest::Client::builder()
+                .timeout(config.request_timeout)
+                .build()
+                .unwrap_or_default(),
+            config,
+        }
+    }
+
+    /// How often the host should call [`Self::probe_once`].
+    pub fn interval(&self) -> Duration {
+        self.config.interval
+    }
+
+    /// Probes every subscription once and returns how many were probed.
+    pub async fn probe_once(&self) -> Result<usize> {
+        let subscriptions = subscription::Entity::find()
+            .filter(
+                Condition::any()
+                    .add(subscription::Column::IsActive.eq(true))
+                    .add(subscription::Column::AutoDisabled.eq(true)),
+            )
+            .all(&self.db)
+            .await?;
+
+        let probed = subscriptions.len();
+        for subscription in subscriptions {
+            let outcome = self.ping(&subscription).await;
+            self.record(subscription, outcome).await?;
+        }
+        Ok(probed)
+    }
+
+    async fn ping(&self, subscription: &subscription::Model) -> Result<()> {
+        let body = serde_json::to_vec(&serde_json::json!({
+            "type": PING_EVENT_TYPE,
+            "subscription_id": subscription.id,
+            "timestamp": Utc::now(),
+        }))?;
+        let response = self
+            .client
+            .post(&subscription.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header(EVENT_TYPE_HEADER, PING_EVENT_TYPE)
+            .header(SIGNATURE_HEADER, sign_payload(&subscription.secret, &body))
+            .body(body)
+            .send()
+            .await
+            .map_err(|err| Error::External(format!("Webhook ping failed: {err}")))?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(Error::External(format!(
+                "Webhook ping responded {}",
+                response.status()
+            )))
+        }
+    }
+
+    async fn record(
+        &self,
+        subscription: subscription::Model,
+        outcome: Result<()>,
+    ) -> Result<subscription::Model> {
+        let now = Utc::now();
+        let id = subscription.id;
+        let was_auto_disabled = subscription.auto_disabled;
+        let failures = match &outcome {
+            Ok(()) => 0,
+            Err(_) => subscription.consecutive_failures.saturating_add(1),
+        };
+
+        let mut active: subscription::ActiveModel = subscription.into();
+        active.consecutive_failures = Set(failures);
+        active.last_checked_at = Set(Some(now));
+        match outcome {
+            Ok(()) => {
+                active.health_status = Set(WebhookHealth::Healthy);
+                if was_auto_disabled {
+                    tracing::info!(subscription_id = %id, "Webhook endpoint recovered, re-enabling");
+                    active.is_active = Set(true);
+                    active.auto_disabled = Set(false);
+                    active.updated_at = Set(now);
+                }
+            }
+            Err(error) => {
+                if failures >= self.config.unhealthy_after as i32 {
+                    active.health_status = Set(WebhookHealth::Unhealthy);
+                }
+                if !was_auto_disabled
+                    && self
+                        .config
+                        .disable_after
+                        .is_some_and(|limit| failures >= limit as i32)
+                {
+                    tracing::warn!(
+                        subscription_id = %id,
+                        failures,
+                        error = %error,
+                        "Webhook endpoint keeps failing, disabling subscription"
+                    );
+                    active.is_active = Set(false);
+                    active.auto_disabled = Set(true);
+                    active.updated_at = Set(now);
+                }
+            }
+        }
+
+        Ok(active.update(&self.db).await?)
+    }
+}
@@ -0,0 +1,56 @@
+use chrono::{DateTime, Utc};
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Clone, Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(32))")]
+pub enum WebhookHealth {
+    #[sea_orm(string_value = "healthy")]
+    Healthy,
+    /// The last health probes failed in a row; see `consecutive_failures`.
+    #[sea_orm(string_value = "unhealthy")]
+    Unhealthy,
+}
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "webhook_subscriptions")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub tenant_id: Uuid,
+    pub url: String,
+    /// JSON array of event types (e.g. `"node.published"`); `"*"` matches every event.
+    pub event_types: Json,
+    /// Shared secret for the `X-RusToK-Signature` HMAC; never returned by list APIs.
+    pub secret: String,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub health_status: WebhookHealth,
+    /// Failed health probes since the last successful one.
+    pub consecutive_failures: i32,
+    pub last_checked_at: Option<DateTime<Utc>>,
+    /// Deactivated by the health probe rather than by the tenant; a successful probe
+    /// re-activates it.
+    pub auto_disabled: bool,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl Model {
+    pub fn event_types(&self) -> Vec<String> {
+        serde_json::from_value(self.event_types.clone()).unwrap_or_default()
+    }
+
+    pub fn matches(&self, event_type: &str) -> bool {
+        self.is_active
+            && self
+                .event_types()
+                .iter()
+                .any(|subscribed| subscribed == "*" || subscribed == event_type)
+    }
+}
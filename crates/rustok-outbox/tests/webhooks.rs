@@ -0,0 +1,320 @@
+use std::time::Duration;
+
+use rustok_core::async_utils::BackoffConfig;
+use rustok_core::{DomainEvent, EventEnvelope, EventHandler};
+use rustok_outbox::{
+    migrations, verify_signature, CreateWebhookSubscription, WebhookDeliveryStatus, WebhookHandler,
+    WebhookHealth, WebhookHealthProbe, WebhookHealthProbeConfig, WebhookSubscriptionService,
+};
+use sea_orm::{Database, DatabaseConnection};
+use sea_orm_migration::SchemaManager;
+use uuid::Uuid;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const SECRET: &str = "whsec-integration-secret";
+
+async fn setup_db() -> DatabaseConnection {
+    let db = Database::connect("sqlite::memory:")
+        .await
+        .expect("failed to open sqlite");
+    let schema = SchemaManager::new(&db);
+    for migration in migrations::migrations() {
+        migration
+            .up(&schema)
+            .await
+            .expect("failed to apply webhook migrations");
+    }
+    db
+}
+
+async fn subscribe(db: &DatabaseConnection, tenant_id: Uuid, server: &MockServer) -> Uuid {
+    WebhookSubscriptionService::new(db.clone())
+        .create(
+            tenant_id,
+            CreateWebhookSubscription {
+                url: format!("{}/hooks", server.uri()),
+                event_types: vec!["node.published".to_string()],
+                secret: SECRET.to_string(),
+            },
+        )
+        .await
+        .expect("subscription should be created")
+        .id
+}
+
+fn handler(db: &DatabaseConnection) -> WebhookHandler {
+    WebhookHandler::new(db.clone()).with_retry(
+        3,
+        BackoffConfig::default()
+            .with_initial_delay(Duration::from_millis(1))
+            .with_max_delay(Duration::from_millis(5)),
+    )
+}
+
+fn probe(db: &DatabaseConnection) -> WebhookHealthProbe {
+    WebhookHealthProbe::with_config(
+        db.clone(),
+        WebhookHealthProbeConfig {
+            unhealthy_after: 2,
+            disable_after: Some(3),
+            ..Default::default()
+        },
+    )
+}
+
+fn node_published(tenant_id: Uuid) -> EventEnvelope {
+    EventEnvelope::new(
+        tenant_id,
+        None,
+        DomainEvent::NodePublished {
+            node_id: Uuid::new_v4(),
+            kind: "page".to_string(),
+        },
+    )
+}
+
+#[tokio::test]
+async fn node_published_is_delivered_with_a_valid_signature() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/hooks"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let db = setup_db().await;
+    let tenant_id = Uuid::new_v4();
+    subscribe(&db, tenant_id, &server).await;
+
+    let envelope = node_published(tenant_id);
+    handler(&db).handle(&envelope).await.unwrap();
+
+    let requests = server.received_requests().await.unwrap();
+    assert_eq!(requests.len(), 1);
+    let request = &requests[0];
+    let signature = request.headers["x-rustok-signature"].to_str().unwrap();
+    assert!(verify_signature(SECRET, &request.body, signature));
+    assert_eq!(request.headers["x-rustok-event"], "node.published");
+    assert_eq!(
+        request.headers["x-rustok-delivery"],
+        envelope.id.to_string().as_str()
+    );
+
+    let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+    assert_eq!(body["tenant_id"], tenant_id.to_string());
+    assert_eq!(body["event"]["type"], "NodePublished");
+}
+
+#[tokio::test]
+async fn events_of_other_types_or_tenants_are_not_delivered() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&server)
+        .await;
+    let db = setup_db().await;
+    let tenant_id = Uuid::new_v4();
+    subscribe(&db, tenant_id, &server).await;
+
+    let created = EventEnvelope::new(
+        tenant_id,
+        None,
+        DomainEvent::NodeCreated {
+            node_id: Uuid::new_v4(),
+            kind: "page".to_string(),
+            author_id: None,
+        },
+    );
+    handler(&db).handle(&created).await.unwrap();
+    handler(&db)
+        .handle(&node_published(Uuid::new_v4()))
+        .await
+        .unwrap();
+
+    assert!(server.received_requests().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn failed_delivery_is_recorded_with_every_attempt() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(503))
+        .expect(3)
+        .mount(&server)
+        .await;
+    let db = setup_db().await;
+    let tenant_id = Uuid::new_v4();
+    subscribe(&db, tenant_id, &server).await;
+
+    let envelope = node_published(tenant_id);
+    handler(&db).handle(&envelope).await.unwrap();
+
+    let service = WebhookSubscriptionService::new(db.clone());
+    let failed = service.failed_deliveries(tenant_id).await.unwrap();
+    assert_eq!(failed.len(), 1);
+    assert_eq!(failed[0].event_id, envelope.id);
+    assert_eq!(failed[0].status, WebhookDeliveryStatus::Failed);
+    assert_eq!(failed[0].attempts, 3);
+    assert_eq!(failed[0].last_status_code, Some(503));
+    assert!(failed[0].last_error.as_deref().unwrap().contains("503"));
+
+    let attempts = service
+        .delivery_attempts(tenant_id, failed[0].id)
+        .await
+        .unwrap();
+    assert_eq!(
+        attempts
+            .iter()
+            .map(|attempt| (attempt.attempt, attempt.status_code))
+            .collect::<Vec<_>>(),
+        [(1, Some(503)), (2, Some(503)), (3, Some(503))]
+    );
+    assert!(service
+        .failed_deliveries(Uuid::new_v4())
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn replaying_a_failed_delivery_records_a_new_attempt() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(500))
+        .up_to_n_times(3)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&server)
+        .await;
+    let db = setup_db().await;
+    let tenant_id = Uuid::new_v4();
+    subscribe(&db, tenant_id, &server).await;
+    let handler = handler(&db);
+    handler.handle(&node_published(tenant_id)).await.unwrap();
+
+    let service = WebhookSubscriptionService::new(db.clone());
+    let failed = service
+        .failed_deliveries(tenant_id)
+        .await
+        .unwrap()
+        .remove(0);
+
+    assert!(handler
+        .replay_delivery(Uuid::new_v4(), failed.id)
+        .await
+        .is_err());
+    let replayed = handler.replay_delivery(tenant_id, failed.id).await.unwrap();
+
+    assert_eq!(replayed.status, WebhookDeliveryStatus::Delivered);
+    assert_eq!(replayed.attempts, 4);
+    assert_eq!(replayed.last_status_code, Some(200));
+    assert_eq!(replayed.last_error, None);
+    assert!(service
+        .failed_deliveries(tenant_id)
+        .await
+        .unwrap()
+        .is_empty());
+
+    let attempts = service
+        .delivery_attempts(tenant_id, failed.id)
+        .await
+        .unwrap();
+    assert_eq!(attempts.len(), 4);
+    assert_eq!(attempts[3].attempt, 4);
+    assert_eq!(attempts[3].status_code, Some(200));
+
+    let requests = server.received_requests().await.unwrap();
+    assert_eq!(requests.len(), 4);
+    let signature = requests[3].headers["x-rustok-signature"].to_str().unwrap();
+    assert!(verify_signature(SECRET, &requests[3].body, signature));
+    assert_eq!(requests[3].body, requests[0].body);
+
+    assert!(matches!(
+        handler.replay_delivery(tenant_id, failed.id).await,
+        Err(rustok_core::Error::Validation(_))
+    ));
+}
+
+#[tokio::test]
+async fn repeated_ping_failures_mark_subscription_unhealthy_then_disable_it() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(500))
+        .mount(&server)
+        .await;
+    let db = setup_db().await;
+    let tenant_id = Uuid::new_v4();
+    let subscription_id = subscribe(&db, tenant_id, &server).await;
+    let service = WebhookSubscriptionService::new(db.clone());
+    let probe = probe(&db);
+
+    probe.probe_once().await.unwrap();
+    assert!(service.unhealthy(tenant_id).await.unwrap().is_empty());
+
+    probe.probe_once().await.unwrap();
+    let unhealthy = service.unhealthy(tenant_id).await.unwrap();
+    assert_eq!(unhealthy.len(), 1);
+    assert_eq!(unhealthy[0].id, subscription_id);
+    assert_eq!(unhealthy[0].consecutive_failures, 2);
+    assert!(unhealthy[0].is_active, "still active below disable_after");
+
+    probe.probe_once().await.unwrap();
+    let disabled = service.unhealthy(tenant_id).await.unwrap().remove(0);
+    assert!(!disabled.is_active);
+    assert!(disabled.auto_disabled);
+    assert!(disabled.last_checked_at.is_some());
+
+    let requests = server.received_requests().await.unwrap();
+    assert_eq!(requests.len(), 3);
+    assert_eq!(requests[0].headers["x-rustok-event"], "webhook.ping");
+    let signature = requests[0].headers["x-rustok-signature"].to_str().unwrap();
+    assert!(verify_signature(SECRET, &requests[0].body, signature));
+}
+
+#[tokio::test]
+async fn recovering_endpoint_is_re_enabled() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(502))
+        .up_to_n_times(3)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&server)
+        .await;
+    let db = setup_db().await;
+    let tenant_id = Uuid::new_v4();
+    subscribe(&db, tenant_id, &server).await;
+    let service = WebhookSubscriptionService::new(db.clone());
+    let probe = probe(&db);
+
+    for _ in 0..3 {
+        probe.probe_once().await.unwrap();
+    }
+    assert!(!service.list(tenant_id).await.unwrap()[0].is_active);
+    assert!(service
+        .matching(tenant_id, "node.published")
+        .await
+        .unwrap()
+        .is_empty());
+
+    assert_eq!(probe.probe_once().await.unwrap(), 1);
+    let recovered = service.list(tenant_id).await.unwrap().remove(0);
+    assert!(recovered.is_active);
+    assert!(!recovered.auto_disabled);
+    assert_eq!(recovered.health_status, WebhookHealth::Healthy);
+    assert_eq!(recovered.consecutive_failures, 0);
+    assert_eq!(
+        service
+            .matching(tenant_id, "node.published")
+            .await
+            .unwrap()
+            .len(),
+        1
+    );
+}
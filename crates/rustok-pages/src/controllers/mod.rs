@@ -1,6 +1,7 @@
 use axum::{
     extract::{Path, Query, State},
-    http::StatusCode,
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
     Json,
 };
 use loco_rs::{app::AppContext, controller::Routes, Error, Result};
@@ -8,14 +9,15 @@ use rustok_api::{
     has_any_effective_permission, loco::transactional_event_bus_from_context, AuthContext,
     RequestContext, TenantContext,
 };
+use rustok_content::{SlugLookup, SlugRedirectTarget};
 use rustok_core::{Action, Permission, Resource};
 use serde::Deserialize;
 use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
 use crate::{
-    BlockResponse, BlockService, CreateBlockInput, CreatePageInput, PageResponse, PageService,
-    UpdateBlockInput, UpdatePageInput,
+    BlockResponse, BlockService, CreateBlockInput, CreatePageInput, PagePreviewToken,
+    PagePreviewTokens, PageResponse, PageService, PagesError, UpdateBlockInput, UpdatePageInput,
 };
 
 #[derive(Debug, Deserialize, IntoParams, ToSchema)]
@@ -24,6 +26,12 @@ pub struct GetPageParams {
     pub locale: Option<String>,
 }
 
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct PreviewPageParams {
+    pub token: String,
+    pub locale: Option<String>,
+}
+
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct ReorderBlocksInput {
     pub block_ids: Vec<Uuid>,
@@ -36,6 +44,7 @@ pub struct ReorderBlocksInput {
     params(GetPageParams),
     responses(
         (status = 200, description = "Page content", body = PageResponse),
+        (status = 301, description = "Page was renamed; `Location` points at its current slug"),
         (status = 404, description = "Page not found"),
         (status = 401, description = "Unauthorized"),
         (status = 403, description = "Forbidden")
@@ -47,7 +56,7 @@ pub async fn get_page(
     auth: AuthContext,
     request_context: RequestContext,
     Query(params): Query<GetPageParams>,
-) -> Result<Json<PageResponse>> {
+) -> Result<Response> {
     ensure_pages_permission(&auth, Permission::PAGES_READ)?;
 
     let slug = params.slug.unwrap_or_else(|| "home".to_string());
@@ -56,8 +65,8 @@ pub async fn get_page(
         .unwrap_or_else(|| request_context.locale.clone());
 
     let service = PageService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx));
-    let page = service
-        .get_by_slug_with_locale_fallback(
+    let lookup = service
+        .get_by_slug_or_redirect_with_locale_fallback(
             tenant.id,
             auth.security_context(),
             &locale,
@@ -67,12 +76,27 @@ pub async fn get_page(
         .await
         .map_err(|err| Error::BadRequest(err.to_string()))?;
 
-    match page {
-        Some(page) => Ok(Json(page)),
+    page_lookup_response(lookup)
+}
+
+fn page_lookup_response(lookup: Option<SlugLookup<PageResponse>>) -> Result<Response> {
+    match lookup {
+        Some(SlugLookup::Found(page)) => Ok(Json(*page).into_response()),
+        Some(SlugLookup::Redirect(target)) => Ok(slug_redirect_response(&target)),
         None => Err(Error::NotFound),
     }
 }
 
+fn slug_redirect_response(target: &SlugRedirectTarget) -> Response {
+    let query = url::form_urlencoded::Serializer::new(String::new())
+        .append_pair("slug", &target.to_slug)
+        .append_pair("locale", &target.locale)
+        .finish();
+    let status = StatusCode::from_u16(target.status).unwrap_or(StatusCode::MOVED_PERMANENTLY);
+
+    (status, [(header::LOCATION, format!("/api/pages?{query}"))]).into_response()
+}
+
 #[utoipa::path(
     post,
     path = "/api/admin/pages",
@@ -164,6 +188,76 @@ pub async fn delete_page(
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/admin/pages/{id}/preview-token",
+    tag = "pages",
+    params(("id" = Uuid, Path, description = "Page ID")),
+    responses(
+        (status = 200, description = "Preview token issued", body = PagePreviewToken),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Page not found")
+    )
+)]
+pub async fn issue_preview_token(
+    State(ctx): State<AppContext>,
+    tenant: TenantContext,
+    auth: AuthContext,
+    Path(id): Path<Uuid>,
+) -> Result<Json<PagePreviewToken>> {
+    ensure_pages_permission(&auth, Permission::PAGES_UPDATE)?;
+
+    let service = PageService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx));
+    service
+        .get(tenant.id, auth.security_context(), id)
+        .await
+        .map_err(|err| match err {
+            PagesError::PageNotFound(_) => Error::NotFound,
+            other => Error::BadRequest(other.to_string()),
+        })?;
+
+    let token = preview_tokens(&ctx)?
+        .issue(tenant.id, id)
+        .map_err(|err| Error::Message(err.to_string()))?;
+    Ok(Json(token))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/pages/{id}/preview",
+    tag = "pages",
+    params(("id" = Uuid, Path, description = "Page ID"), PreviewPageParams),
+    responses(
+        (status = 200, description = "Page content in its current, possibly unpublished, state", body = PageResponse),
+        (status = 401, description = "Invalid or expired preview token"),
+        (status = 404, description = "Page not found")
+    )
+)]
+pub async fn preview_page(
+    State(ctx): State<AppContext>,
+    tenant: TenantContext,
+    request_context: RequestContext,
+    Path(id): Path<Uuid>,
+    Query(params): Query<PreviewPageParams>,
+) -> Result<Json<PageResponse>> {
+    preview_tokens(&ctx)?
+        .verify(&params.token, tenant.id, id)
+        .map_err(|err| Error::Unauthorized(err.to_string()))?;
+
+    let locale = params
+        .locale
+        .unwrap_or_else(|| request_context.locale.clone());
+    let service = PageService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx));
+    let page = service
+        .get_preview(tenant.id, id, &locale, Some(tenant.default_locale.as_str()))
+        .await
+        .map_err(|err| match err {
+            PagesError::PageNotFound(_) => Error::NotFound,
+            other => Error::BadRequest(other.to_string()),
+        })?;
+    Ok(Json(page))
+}
+
 #[utoipa::path(
     post,
     path = "/api/admin/pages/{id}/blocks",
@@ -293,11 +387,16 @@ pub fn routes() -> Routes {
     Routes::new()
         .prefix("api")
         .add("/pages", axum::routing::get(get_page))
+        .add("/pages/{id}/preview", axum::routing::get(preview_page))
         .add("/admin/pages", axum::routing::post(create_page))
         .add(
             "/admin/pages/{id}",
             axum::routing::put(update_page).delete(delete_page),
         )
+        .add(
+            "/admin/pages/{id}/preview-token",
+            axum::routing::post(issue_preview_token),
+        )
         .add(
             "/admin/pages/{id}/blocks",
             axum::routing::post(create_block),
@@ -312,6 +411,16 @@ pub fn routes() -> Routes {
         )
 }
 
+/// Preview links are signed with the application's JWT secret.
+fn preview_tokens(ctx: &AppContext) -> Result<PagePreviewTokens> {
+    ctx.config
+        .auth
+        .as_ref()
+        .and_then(|auth| auth.jwt.as_ref())
+        .map(|jwt| PagePreviewTokens::new(jwt.secret.clone()))
+        .ok_or_else(|| Error::Message("JWT auth is not configured".to_string()))
+}
+
 fn ensure_pages_permission(auth: &AuthContext, permission: Permission) -> Result<()> {
     if !has_any_effective_permission(&auth.permissions, &[permission]) {
         return Err(Error::Unauthorized(
@@ -321,3 +430,120 @@ fn ensure_pages_permission(auth: &AuthContext, permission: Permission) -> Result
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PageTranslationInput, PagesModule};
+    use rustok_core::{MigrationSource, SecurityContext};
+    use rustok_test_utils::{db::setup_test_db, mock_transactional_event_bus};
+    use sea_orm::{ConnectionTrait, DbBackend, Statement};
+    use sea_orm_migration::SchemaManager;
+
+    async fn setup() -> PageService {
+        let db = setup_test_db().await;
+        let schema = SchemaManager::new(&db);
+        for migration in PagesModule.migrations() {
+            migration
+                .up(&schema)
+                .await
+                .expect("failed to apply pages migrations");
+        }
+        db.execute(Statement::from_string(
+            DbBackend::Sqlite,
+            "CREATE TABLE IF NOT EXISTS slug_redirects (
+                id TEXT PRIMARY KEY,
+                tenant_id TEXT NOT NULL,
+                kind TEXT NOT NULL,
+                node_id TEXT NOT NULL,
+                locale TEXT NOT NULL,
+                old_slug TEXT NOT NULL,
+                created_at TEXT NOT NULL
+            )"
+            .to_string(),
+        ))
+        .await
+        .expect("failed to create content slug_redirects test table");
+
+        PageService::new(db, mock_transactional_event_bus())
+    }
+
+    fn translation(slug: &str) -> PageTranslationInput {
+        PageTranslationInput {
+            locale: "en".to_string(),
+            title: "About".to_string(),
+            slug: Some(slug.to_string()),
+            meta_title: None,
+            meta_description: None,
+        }
+    }
+
+    async fn lookup(service: &PageService, tenant_id: Uuid, slug: &str) -> Result<Response> {
+        let lookup = service
+            .get_by_slug_or_redirect_with_locale_fallback(
+                tenant_id,
+                SecurityContext::system(),
+                "en",
+                slug,
+                None,
+            )
+            .await
+            .expect("lookup should succeed");
+        page_lookup_response(lookup)
+    }
+
+    #[tokio::test]
+    async fn renamed_page_slug_answers_with_permanent_redirect() {
+        let service = setup().await;
+        let tenant_id = Uuid::new_v4();
+        let page = service
+            .create(
+                tenant_id,
+                SecurityContext::system(),
+                CreatePageInput {
+                    translations: vec![translation("about")],
+                    template: Some("default".to_string()),
+                    body: None,
+                    blocks: None,
+                    channel_slugs: None,
+                    publish: true,
+                },
+            )
+            .await
+            .expect("page should be created");
+        service
+            .update(
+                tenant_id,
+                SecurityContext::system(),
+                page.id,
+                UpdatePageInput {
+                    translations: Some(vec![translation("about-us")]),
+                    template: None,
+                    body: None,
+                    channel_slugs: None,
+                    status: None,
+                },
+            )
+            .await
+            .expect("page should be renamed");
+
+        let response = lookup(&service, tenant_id, "about")
+            .await
+            .expect("old slug should resolve");
+        assert_eq!(response.status(), StatusCode::MOVED_PERMANENTLY);
+        assert_eq!(
+            response.headers().get(header::LOCATION).unwrap(),
+            "/api/pages?slug=about-us&locale=en"
+        );
+
+        let response = lookup(&service, tenant_id, "about-us")
+            .await
+            .expect("current slug should resolve");
+        assert_eq!(response.status(), StatusCode::OK);
+
+        assert!(matches!(
+            lookup(&service, tenant_id, "missing").await,
+            Err(Error::NotFound)
+        ));
+    }
+}
@@ -36,6 +36,9 @@ pub enum PagesError {
     #[error("Forbidden: {0}")]
     Forbidden(String),
 
+    #[error("Invalid or expired preview token")]
+    InvalidPreviewToken,
+
     #[error("Content error: {0}")]
     Content(#[from] rustok_content::ContentError),
 
@@ -91,6 +94,11 @@ impl From<PagesError> for RichError {
             }
             PagesError::Forbidden(msg) => RichError::new(ErrorKind::Forbidden, msg)
                 .with_user_message("You do not have permission to perform this action"),
+            PagesError::InvalidPreviewToken => {
+                RichError::new(ErrorKind::Forbidden, "Invalid or expired preview token")
+                    .with_user_message("This preview link is invalid or has expired")
+                    .with_error_code("INVALID_PREVIEW_TOKEN")
+            }
             PagesError::Content(content_err) => content_err.into(),
             PagesError::Rich(rich) => *rich,
         }
@@ -1,9 +1,6 @@
-use async_graphql::{Context, FieldError, Object, Result};
-use rustok_api::{
-    graphql::{require_module_enabled, GraphQLError},
-    has_any_effective_permission, AuthContext,
-};
-use rustok_core::{Action, Permission, Resource};
+use async_graphql::{Context, Object, Result};
+use rustok_api::{graphql::require_module_enabled, require_permission};
+use rustok_core::{Action, Resource};
 use rustok_outbox::TransactionalEventBus;
 use sea_orm::DatabaseConnection;
 use uuid::Uuid;
@@ -31,9 +28,9 @@ impl PagesMutation {
         require_module_enabled(ctx, MODULE_SLUG).await?;
         let db = ctx.data::<DatabaseConnection>()?;
         let event_bus = ctx.data::<TransactionalEventBus>()?;
-        let auth = require_pages_permission(ctx, Permission::PAGES_CREATE)?;
+        let auth = require_permission!(ctx, Resource::Pages, Action::Create)?;
         if input.publish.unwrap_or(false) {
-            require_pages_permission(ctx, Permission::new(Resource::Pages, Action::Publish))?;
+            require_permission!(ctx, Resource::Pages, Action::Publish)?;
         }
         let tenant = ctx.data::<rustok_api::TenantContext>()?;
         let tenant_id = tenant_id.unwrap_or(tenant.id);
@@ -91,8 +88,7 @@ impl PagesMutation {
         require_module_enabled(ctx, MODULE_SLUG).await?;
         let db = ctx.data::<DatabaseConnection>()?;
         let event_bus = ctx.data::<TransactionalEventBus>()?;
-        let auth =
-            require_pages_permission(ctx, Permission::new(Resource::Pages, Action::Publish))?;
+        let auth = require_permission!(ctx, Resource::Pages, Action::Publish)?;
         let tenant = ctx.data::<rustok_api::TenantContext>()?;
         let tenant_id = tenant_id.unwrap_or(tenant.id);
 
@@ -141,8 +137,7 @@ impl PagesMutation {
         require_module_enabled(ctx, MODULE_SLUG).await?;
         let db = ctx.data::<DatabaseConnection>()?;
         let event_bus = ctx.data::<TransactionalEventBus>()?;
-        let auth =
-            require_pages_permission(ctx, Permission::new(Resource::Pages, Action::Publish))?;
+        let auth = require_permission!(ctx, Resource::Pages, Action::Publish)?;
         let tenant = ctx.data::<rustok_api::TenantContext>()?;
         let tenant_id = tenant_id.unwrap_or(tenant.id);
 
@@ -164,7 +159,7 @@ impl PagesMutation {
         require_module_enabled(ctx, MODULE_SLUG).await?;
         let db = ctx.data::<DatabaseConnection>()?;
         let event_bus = ctx.data::<TransactionalEventBus>()?;
-        let auth = require_pages_permission(ctx, Permission::PAGES_UPDATE)?;
+        let auth = require_permission!(ctx, Resource::Pages, Action::Update)?;
         let tenant = ctx.data::<rustok_api::TenantContext>()?;
         let tenant_id = tenant_id.unwrap_or(tenant.id);
 
@@ -186,7 +181,7 @@ impl PagesMutation {
         require_module_enabled(ctx, MODULE_SLUG).await?;
         let db = ctx.data::<DatabaseConnection>()?;
         let event_bus = ctx.data::<TransactionalEventBus>()?;
-        let auth = require_pages_permission(ctx, Permission::PAGES_DELETE)?;
+        let auth = require_permission!(ctx, Resource::Pages, Action::Delete)?;
         let tenant = ctx.data::<rustok_api::TenantContext>()?;
         let tenant_id = tenant_id.unwrap_or(tenant.id);
 
@@ -209,7 +204,7 @@ impl PagesMutation {
         require_module_enabled(ctx, MODULE_SLUG).await?;
         let db = ctx.data::<DatabaseConnection>()?;
         let event_bus = ctx.data::<TransactionalEventBus>()?;
-        let auth = require_pages_permission(ctx, Permission::PAGES_UPDATE)?;
+        let auth = require_permission!(ctx, Resource::Pages, Action::Update)?;
         let tenant = ctx.data::<rustok_api::TenantContext>()?;
         let tenant_id = tenant_id.unwrap_or(tenant.id);
 
@@ -237,7 +232,7 @@ impl PagesMutation {
         require_module_enabled(ctx, MODULE_SLUG).await?;
         let db = ctx.data::<DatabaseConnection>()?;
         let event_bus = ctx.data::<TransactionalEventBus>()?;
-        let auth = require_pages_permission(ctx, Permission::PAGES_UPDATE)?;
+        let auth = require_permission!(ctx, Resource::Pages, Action::Update)?;
         let tenant = ctx.data::<rustok_api::TenantContext>()?;
         let tenant_id = tenant_id.unwrap_or(tenant.id);
 
@@ -268,7 +263,7 @@ impl PagesMutation {
         require_module_enabled(ctx, MODULE_SLUG).await?;
         let db = ctx.data::<DatabaseConnection>()?;
         let event_bus = ctx.data::<TransactionalEventBus>()?;
-        let auth = require_pages_permission(ctx, Permission::PAGES_DELETE)?;
+        let auth = require_permission!(ctx, Resource::Pages, Action::Delete)?;
         let tenant = ctx.data::<rustok_api::TenantContext>()?;
         let tenant_id = tenant_id.unwrap_or(tenant.id);
 
@@ -291,7 +286,7 @@ impl PagesMutation {
         require_module_enabled(ctx, MODULE_SLUG).await?;
         let db = ctx.data::<DatabaseConnection>()?;
         let event_bus = ctx.data::<TransactionalEventBus>()?;
-        let auth = require_pages_permission(ctx, Permission::PAGES_UPDATE)?;
+        let auth = require_permission!(ctx, Resource::Pages, Action::Update)?;
         let tenant = ctx.data::<rustok_api::TenantContext>()?;
         let tenant_id = tenant_id.unwrap_or(tenant.id);
 
@@ -305,21 +300,6 @@ impl PagesMutation {
     }
 }
 
-fn require_pages_permission(ctx: &Context<'_>, permission: Permission) -> Result<AuthContext> {
-    let auth = ctx
-        .data::<AuthContext>()
-        .map_err(|_| <FieldError as GraphQLError>::unauthenticated())?
-        .clone();
-
-    if !has_any_effective_permission(&auth.permissions, &[permission]) {
-        return Err(<FieldError as GraphQLError>::permission_denied(
-            "Permission denied: pages:* required",
-        ));
-    }
-
-    Ok(auth)
-}
-
 fn map_create_block_input(input: CreateGqlBlockInput) -> Result<CreateBlockInput> {
     Ok(CreateBlockInput {
         block_type: parse_block_type(&input.block_type)?,
@@ -4,6 +4,7 @@ use rustok_api::{
     TenantContext,
 };
 use rustok_channel::ChannelService;
+use rustok_content::SlugLookup;
 use rustok_core::SecurityContext;
 use rustok_outbox::TransactionalEventBus;
 use rustok_telemetry::metrics;
@@ -86,16 +87,32 @@ impl PagesQuery {
         let locale = resolve_graphql_locale(ctx, locale.as_deref());
 
         let service = PageService::new(db.clone(), event_bus.clone());
-        let page = service
-            .get_by_slug_with_locale_fallback(
+        let lookup = service
+            .get_by_slug_or_redirect_with_locale_fallback(
                 tenant_id,
-                security,
+                security.clone(),
                 &locale,
                 &slug,
                 Some(tenant.default_locale.as_str()),
             )
             .await
             .map_err(|err| async_graphql::Error::new(err.to_string()))?;
+        // GraphQL has no 301: a renamed page is served from its current slug, which the
+        // client can read back from the translation to update its URL.
+        let page = match lookup {
+            Some(SlugLookup::Found(page)) => Some(*page),
+            Some(SlugLookup::Redirect(target)) => service
+                .get_by_slug_with_locale_fallback(
+                    tenant_id,
+                    security,
+                    &target.locale,
+                    &target.to_slug,
+                    Some(tenant.default_locale.as_str()),
+                )
+                .await
+                .map_err(|err| async_graphql::Error::new(err.to_string()))?,
+            None => None,
+        };
 
         let public_channel_slug = public_channel_slug(ctx);
         Ok(page
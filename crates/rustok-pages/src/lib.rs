@@ -44,7 +44,7 @@ pub use dto::*;
 pub use entities::{Block, Menu, Page};
 pub use error::{PagesError, PagesResult};
 pub use graphql::{PagesMutation, PagesQuery};
-pub use services::{BlockService, MenuService, PageService};
+pub use services::{BlockService, MenuService, PagePreviewToken, PagePreviewTokens, PageService};
 
 use async_trait::async_trait;
 use rustok_core::permissions::{Action, Permission, Resource};
@@ -2,8 +2,10 @@
 pub mod block;
 pub mod menu;
 pub mod page;
+pub mod preview;
 mod rbac;
 
 pub use block::BlockService;
 pub use menu::MenuService;
 pub use page::PageService;
+pub use preview::{PagePreviewToken, PagePreviewTokens};
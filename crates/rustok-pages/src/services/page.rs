@@ -11,7 +11,8 @@ use tracing::instrument;
 use uuid::Uuid;
 
 use rustok_content::{
-    available_locales_from, normalize_locale_code, resolve_by_locale_with_fallback,
+    available_locales_from, find_slug_redirect_entry, normalize_locale_code, record_slug_change,
+    resolve_by_locale_with_fallback, SlugLookup, SlugRedirectTarget, SLUG_REDIRECT_STATUS,
 };
 use rustok_core::{
     normalize_content_format, prepare_content_payload, Action, Resource, SecurityContext,
@@ -206,6 +207,28 @@ impl PageService {
         )
     }
 
+    /// Renders a page in any status for the holder of a verified preview token.
+    ///
+    /// Only the tenant scope is enforced here; callers must check the token with
+    /// [`PagePreviewTokens::verify`](crate::PagePreviewTokens::verify) first.
+    #[instrument(skip(self))]
+    pub async fn get_preview(
+        &self,
+        tenant_id: Uuid,
+        page_id: Uuid,
+        locale: &str,
+        fallback_locale: Option<&str>,
+    ) -> PagesResult<PageResponse> {
+        self.get_with_locale_fallback(
+            tenant_id,
+            SecurityContext::system(),
+            page_id,
+            locale,
+            fallback_locale,
+        )
+        .await
+    }
+
     #[instrument(skip(self))]
     pub async fn get_by_slug_with_locale_fallback(
         &self,
@@ -259,6 +282,66 @@ impl PageService {
         .map(Some)
     }
 
+    /// Like [`Self::get_by_slug_with_locale_fallback`], but answers a slug the page was
+    /// renamed away from with a redirect to its current slug in the same locale.
+    #[instrument(skip(self))]
+    pub async fn get_by_slug_or_redirect_with_locale_fallback(
+        &self,
+        tenant_id: Uuid,
+        security: SecurityContext,
+        locale: &str,
+        slug: &str,
+        fallback_locale: Option<&str>,
+    ) -> PagesResult<Option<SlugLookup<PageResponse>>> {
+        if let Some(page) = self
+            .get_by_slug_with_locale_fallback(tenant_id, security, locale, slug, fallback_locale)
+            .await?
+        {
+            return Ok(Some(SlugLookup::Found(Box::new(page))));
+        }
+
+        let slug = normalize_slug(slug);
+        let mut locales = vec![normalize_locale(locale)?];
+        if let Some(fallback_locale) = fallback_locale {
+            locales.push(normalize_locale(fallback_locale)?);
+        }
+        for locale in locales {
+            let Some(redirect) =
+                find_slug_redirect_entry(&self.db, tenant_id, PAGE_KIND, &locale, &slug).await?
+            else {
+                continue;
+            };
+            let Some(page) = page::Entity::find_by_id(redirect.node_id)
+                .filter(page::Column::TenantId.eq(tenant_id))
+                .one(&self.db)
+                .await?
+            else {
+                continue;
+            };
+            if storage_to_status(&page.status)?
+                != rustok_content::entities::node::ContentStatus::Published
+            {
+                continue;
+            }
+            let current = page_translation::Entity::find()
+                .filter(page_translation::Column::PageId.eq(page.id))
+                .filter(page_translation::Column::Locale.eq(&locale))
+                .one(&self.db)
+                .await?;
+            if let Some(current) = current.filter(|current| current.slug != slug) {
+                return Ok(Some(SlugLookup::Redirect(SlugRedirectTarget {
+                    node_id: page.id,
+                    locale,
+                    from_slug: slug,
+                    to_slug: current.slug,
+                    status: SLUG_REDIRECT_STATUS,
+                })));
+            }
+        }
+
+        Ok(None)
+    }
+
     #[instrument(skip(self))]
     pub async fn get_by_slug(
         &self,
@@ -729,6 +812,19 @@ impl PageService {
                 .await?;
             match existing {
                 Some(existing) => {
+                    if existing.slug != slug {
+                        record_slug_change(
+                            txn,
+                            tenant_id,
+                            PAGE_KIND,
+                            page_id,
+                            &locale,
+                            Some(existing.slug.as_str()),
+                            &slug,
+                            Utc::now().into(),
+                        )
+                        .await?;
+                    }
                     let mut active: page_translation::ActiveModel = existing.into();
                     active.title = Set(translation.title.clone());
                     active.slug = Set(slug);
@@ -0,0 +1,135 @@
+use chrono::{DateTime, Duration, Utc};
+use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::error::{PagesError, PagesResult};
+
+const PREVIEW_PURPOSE: &str = "page_preview";
+
+/// Default lifetime of a preview link.
+pub const DEFAULT_PREVIEW_TTL_SECS: i64 = 60 * 60;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PreviewClaims {
+    sub: Uuid,
+    tenant_id: Uuid,
+    purpose: String,
+    exp: i64,
+    iat: i64,
+}
+
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct PagePreviewToken {
+    pub token: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Issues and verifies signed tokens that let anyone holding the link view one draft page.
+#[derive(Clone)]
+pub struct PagePreviewTokens {
+    secret: String,
+    ttl: Duration,
+}
+
+impl PagePreviewTokens {
+    pub fn new(secret: impl Into<String>) -> Self {
+        Self {
+            secret: secret.into(),
+            ttl: Duration::seconds(DEFAULT_PREVIEW_TTL_SECS),
+        }
+    }
+
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    pub fn issue(&self, tenant_id: Uuid, page_id: Uuid) -> PagesResult<PagePreviewToken> {
+        self.issue_at(tenant_id, page_id, Utc::now())
+    }
+
+    pub fn issue_at(
+        &self,
+        tenant_id: Uuid,
+        page_id: Uuid,
+        now: DateTime<Utc>,
+    ) -> PagesResult<PagePreviewToken> {
+        let expires_at = now + self.ttl;
+        let claims = PreviewClaims {
+            sub: page_id,
+            tenant_id,
+            purpose: PREVIEW_PURPOSE.to_string(),
+            exp: expires_at.timestamp(),
+            iat: now.timestamp(),
+        };
+        let token = encode(
+            &Header::default(),
+            &claims,
+            &EncodingKey::from_secret(self.secret.as_bytes()),
+        )
+        .map_err(|err| PagesError::validation(format!("Failed to sign preview token: {err}")))?;
+
+        Ok(PagePreviewToken { token, expires_at })
+    }
+
+    /// Accepts the token only for the page and tenant it was issued for.
+    pub fn verify(&self, token: &str, tenant_id: Uuid, page_id: Uuid) -> PagesResult<()> {
+        let mut validation = Validation::default();
+        validation.leeway = 0;
+
+        let claims = decode::<PreviewClaims>(
+            token,
+            &DecodingKey::from_secret(self.secret.as_bytes()),
+            &validation,
+        )
+        .map_err(|_| PagesError::InvalidPreviewToken)?
+        .claims;
+
+        if claims.purpose != PREVIEW_PURPOSE
+            || claims.tenant_id != tenant_id
+            || claims.sub != page_id
+        {
+            return Err(PagesError::InvalidPreviewToken);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokens() -> PagePreviewTokens {
+        PagePreviewTokens::new("preview-test-secret").with_ttl(Duration::minutes(10))
+    }
+
+    #[test]
+    fn token_is_valid_for_its_page_until_it_expires() {
+        let (tenant_id, page_id) = (Uuid::new_v4(), Uuid::new_v4());
+
+        let fresh = tokens().issue(tenant_id, page_id).unwrap();
+        assert!(tokens().verify(&fresh.token, tenant_id, page_id).is_ok());
+
+        let stale = tokens()
+            .issue_at(tenant_id, page_id, Utc::now() - Duration::minutes(11))
+            .unwrap();
+        assert!(matches!(
+            tokens().verify(&stale.token, tenant_id, page_id),
+            Err(PagesError::InvalidPreviewToken)
+        ));
+    }
+
+    #[test]
+    fn token_does_not_open_other_pages_or_tenants() {
+        let (tenant_id, page_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let token = tokens().issue(tenant_id, page_id).unwrap().token;
+
+        assert!(tokens().verify(&token, tenant_id, Uuid::new_v4()).is_err());
+        assert!(tokens().verify(&token, Uuid::new_v4(), page_id).is_err());
+        assert!(PagePreviewTokens::new("other-secret")
+            .verify(&token, tenant_id, page_id)
+            .is_err());
+    }
+}
@@ -0,0 +1,110 @@
+use chrono::{Duration, Utc};
+use rustok_content::entities::node::ContentStatus;
+use rustok_core::{MigrationSource, SecurityContext};
+use rustok_pages::dto::{CreatePageInput, PageTranslationInput};
+use rustok_pages::{PagePreviewTokens, PageService, PagesError, PagesModule};
+use rustok_test_utils::{db::setup_test_db, mock_transactional_event_bus};
+use sea_orm_migration::SchemaManager;
+use uuid::Uuid;
+
+async fn setup() -> (PageService, Uuid) {
+    let db = setup_test_db().await;
+    let module = PagesModule;
+    let schema = SchemaManager::new(&db);
+    for migration in module.migrations() {
+        migration
+            .up(&schema)
+            .await
+            .expect("failed to apply pages migrations");
+    }
+
+    let event_bus = mock_transactional_event_bus();
+    (PageService::new(db, event_bus), Uuid::new_v4())
+}
+
+async fn create_draft(service: &PageService, tenant_id: Uuid, slug: &str) -> Uuid {
+    service
+        .create(
+            tenant_id,
+            SecurityContext::system(),
+            CreatePageInput {
+                translations: vec![PageTranslationInput {
+                    locale: "en".to_string(),
+                    title: "Upcoming launch".to_string(),
+                    slug: Some(slug.to_string()),
+                    meta_title: None,
+                    meta_description: None,
+                }],
+                template: Some("default".to_string()),
+                body: None,
+                blocks: None,
+                channel_slugs: None,
+                publish: false,
+            },
+        )
+        .await
+        .expect("page should be created")
+        .id
+}
+
+fn tokens() -> PagePreviewTokens {
+    PagePreviewTokens::new("preview-integration-secret").with_ttl(Duration::minutes(30))
+}
+
+#[tokio::test]
+async fn valid_preview_token_renders_the_draft() {
+    let (service, tenant_id) = setup().await;
+    let page_id = create_draft(&service, tenant_id, "launch").await;
+
+    let public = service
+        .get_by_slug(tenant_id, SecurityContext::system(), "en", "launch")
+        .await
+        .expect("lookup should succeed");
+    assert!(
+        public.is_none(),
+        "drafts stay hidden from the public read path"
+    );
+
+    let token = tokens().issue(tenant_id, page_id).unwrap();
+    tokens()
+        .verify(&token.token, tenant_id, page_id)
+        .expect("token should be accepted");
+    let page = service
+        .get_preview(tenant_id, page_id, "en", None)
+        .await
+        .expect("draft should render");
+
+    assert_eq!(page.id, page_id);
+    assert_eq!(page.status, ContentStatus::Draft);
+    assert_eq!(
+        page.translation.and_then(|translation| translation.slug),
+        Some("launch".to_string())
+    );
+}
+
+#[tokio::test]
+async fn expired_or_foreign_preview_token_is_rejected() {
+    let (service, tenant_id) = setup().await;
+    let page_id = create_draft(&service, tenant_id, "launch").await;
+    let other_page_id = create_draft(&service, tenant_id, "roadmap").await;
+
+    let expired = tokens()
+        .issue_at(tenant_id, page_id, Utc::now() - Duration::hours(1))
+        .unwrap();
+    assert!(matches!(
+        tokens().verify(&expired.token, tenant_id, page_id),
+        Err(PagesError::InvalidPreviewToken)
+    ));
+
+    let token = tokens().issue(tenant_id, page_id).unwrap();
+    assert!(matches!(
+        tokens().verify(&token.token, tenant_id, other_page_id),
+        Err(PagesError::InvalidPreviewToken)
+    ));
+
+    let other_tenant = Uuid::new_v4();
+    assert!(matches!(
+        service.get_preview(other_tenant, page_id, "en", None).await,
+        Err(PagesError::PageNotFound(_))
+    ));
+}
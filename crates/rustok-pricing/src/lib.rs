@@ -9,10 +9,11 @@ pub mod services;
 pub use services::{
     ActivePriceListOption, AdminPricingPrice, AdminPricingProductDetail, AdminPricingProductList,
     AdminPricingProductListItem, AdminPricingProductTranslation, AdminPricingVariant,
-    PriceAdjustmentKind, PriceAdjustmentPreview, PriceListRule, PriceListRuleKind,
-    PriceResolutionContext, PricingService, ResolvedPrice, StorefrontPricingPrice,
-    StorefrontPricingProductDetail, StorefrontPricingProductList, StorefrontPricingProductListItem,
-    StorefrontPricingProductTranslation, StorefrontPricingVariant,
+    PriceAdjustmentKind, PriceAdjustmentPreview, PriceHistoryEntry, PriceListRule,
+    PriceListRuleKind, PriceResolutionContext, PricingService, ResolvedPrice,
+    StorefrontPricingPrice, StorefrontPricingProductDetail, StorefrontPricingProductList,
+    StorefrontPricingProductListItem, StorefrontPricingProductTranslation,
+    StorefrontPricingVariant,
 };
 
 pub struct PricingModule;
@@ -0,0 +1,134 @@
+use super::shared::*;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ProductPriceHistory::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ProductPriceHistory::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(ProductPriceHistory::TenantId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ProductPriceHistory::ProductId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ProductPriceHistory::VariantId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ProductPriceHistory::PriceId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ProductPriceHistory::CurrencyCode)
+                            .string_len(3)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(ProductPriceHistory::PriceListId).uuid())
+                    .col(ColumnDef::new(ProductPriceHistory::ChannelId).uuid())
+                    .col(ColumnDef::new(ProductPriceHistory::ChannelSlug).string_len(100))
+                    .col(ColumnDef::new(ProductPriceHistory::RegionId).uuid())
+                    .col(ColumnDef::new(ProductPriceHistory::MinQuantity).integer())
+                    .col(ColumnDef::new(ProductPriceHistory::MaxQuantity).integer())
+                    .col(
+                        ColumnDef::new(ProductPriceHistory::OldAmount)
+                            .decimal_len(16, 6)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ProductPriceHistory::NewAmount)
+                            .decimal_len(16, 6)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(ProductPriceHistory::OldCompareAtAmount).decimal_len(16, 6))
+                    .col(ColumnDef::new(ProductPriceHistory::NewCompareAtAmount).decimal_len(16, 6))
+                    .col(ColumnDef::new(ProductPriceHistory::ChangedBy).uuid())
+                    .col(
+                        ColumnDef::new(ProductPriceHistory::ChangedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(ProductPriceHistory::Table, ProductPriceHistory::TenantId)
+                            .to(Tenants::Table, Tenants::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_product_price_history_product")
+                    .table(ProductPriceHistory::Table)
+                    .col(ProductPriceHistory::TenantId)
+                    .col(ProductPriceHistory::ProductId)
+                    .col(ProductPriceHistory::ChangedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_product_price_history_variant")
+                    .table(ProductPriceHistory::Table)
+                    .col(ProductPriceHistory::VariantId)
+                    .col(ProductPriceHistory::CurrencyCode)
+                    .col(ProductPriceHistory::ChangedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ProductPriceHistory::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ProductPriceHistory {
+    Table,
+    Id,
+    TenantId,
+    ProductId,
+    VariantId,
+    PriceId,
+    CurrencyCode,
+    PriceListId,
+    ChannelId,
+    ChannelSlug,
+    RegionId,
+    MinQuantity,
+    MaxQuantity,
+    OldAmount,
+    NewAmount,
+    OldCompareAtAmount,
+    NewCompareAtAmount,
+    ChangedBy,
+    ChangedAt,
+}
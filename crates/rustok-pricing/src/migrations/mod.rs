@@ -5,6 +5,7 @@ mod m20260325_000002_add_decimal_runtime_columns;
 mod m20260410_000003_add_price_list_rules;
 mod m20260410_000004_add_pricing_channel_scope;
 mod m20260411_000005_add_price_list_translations;
+mod m20261016_000006_create_product_price_history;
 
 use sea_orm_migration::MigrationTrait;
 
@@ -15,5 +16,6 @@ pub fn migrations() -> Vec<Box<dyn MigrationTrait>> {
         Box::new(m20260410_000003_add_price_list_rules::Migration),
         Box::new(m20260410_000004_add_pricing_channel_scope::Migration),
         Box::new(m20260411_000005_add_price_list_translations::Migration),
+        Box::new(m20261016_000006_create_product_price_history::Migration),
     ]
 }
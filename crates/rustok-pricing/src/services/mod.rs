@@ -3,8 +3,9 @@ pub mod pricing;
 pub use pricing::{
     ActivePriceListOption, AdminPricingPrice, AdminPricingProductDetail, AdminPricingProductList,
     AdminPricingProductListItem, AdminPricingProductTranslation, AdminPricingVariant,
-    PriceAdjustmentKind, PriceAdjustmentPreview, PriceListRule, PriceListRuleKind,
-    PriceResolutionContext, PricingService, ResolvedPrice, StorefrontPricingPrice,
-    StorefrontPricingProductDetail, StorefrontPricingProductList, StorefrontPricingProductListItem,
-    StorefrontPricingProductTranslation, StorefrontPricingVariant,
+    PriceAdjustmentKind, PriceAdjustmentPreview, PriceHistoryEntry, PriceListRule,
+    PriceListRuleKind, PriceResolutionContext, PricingService, ResolvedPrice,
+    StorefrontPricingPrice, StorefrontPricingProductDetail, StorefrontPricingProductList,
+    StorefrontPricingProductListItem, StorefrontPricingProductTranslation,
+    StorefrontPricingVariant,
 };
@@ -45,6 +45,39 @@ pub struct ResolvedPrice {
     pub channel_slug: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PriceHistoryEntry {
+    pub variant_id: Uuid,
+    pub currency_code: String,
+    pub price_list_id: Option<Uuid>,
+    pub channel_id: Option<Uuid>,
+    pub channel_slug: Option<String>,
+    pub old_amount: Decimal,
+    pub new_amount: Decimal,
+    pub old_compare_at_amount: Option<Decimal>,
+    pub new_compare_at_amount: Option<Decimal>,
+    pub changed_by: Option<Uuid>,
+    pub changed_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<entities::product_price_history::Model> for PriceHistoryEntry {
+    fn from(row: entities::product_price_history::Model) -> Self {
+        Self {
+            variant_id: row.variant_id,
+            currency_code: row.currency_code,
+            price_list_id: row.price_list_id,
+            channel_id: row.channel_id,
+            channel_slug: row.channel_slug,
+            old_amount: row.old_amount,
+            new_amount: row.new_amount,
+            old_compare_at_amount: row.old_compare_at_amount,
+            new_compare_at_amount: row.new_compare_at_amount,
+            changed_by: row.changed_by,
+            changed_at: row.changed_at.with_timezone(&chrono::Utc),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ActivePriceListOption {
     pub id: Uuid,
@@ -787,6 +820,7 @@ impl PricingService {
 
         match existing {
             Some(price) => {
+                let previous = price.clone();
                 let mut price_active: entities::price::ActiveModel = price.into();
                 price_active.amount = Set(amount);
                 price_active.compare_at_amount = Set(compare_at_amount);
@@ -798,7 +832,8 @@ impl PricingService {
                 price_active.channel_slug = Set(channel_slug.clone());
                 price_active.min_quantity = Set(min_quantity);
                 price_active.max_quantity = Set(max_quantity);
-                price_active.update(&txn).await?;
+                let updated = price_active.update(&txn).await?;
+                record_price_change(&txn, &variant, actor_id, &previous, &updated).await?;
             }
             None => {
                 let price = entities::price::ActiveModel {
@@ -896,6 +931,7 @@ impl PricingService {
 
             match existing {
                 Some(price) => {
+                    let previous = price.clone();
                     let mut price_active: entities::price::ActiveModel = price.into();
                     price_active.amount = Set(price_input.amount);
                     price_active.compare_at_amount = Set(price_input.compare_at_amount);
@@ -905,7 +941,8 @@ impl PricingService {
                     price_active.legacy_amount = Set(decimal_to_cents(price_input.amount));
                     price_active.legacy_compare_at_amount =
                         Set(price_input.compare_at_amount.and_then(decimal_to_cents));
-                    price_active.update(&txn).await?;
+                    let updated = price_active.update(&txn).await?;
+                    record_price_change(&txn, &variant, actor_id, &previous, &updated).await?;
                 }
                 None => {
                     let price = entities::price::ActiveModel {
@@ -973,6 +1010,115 @@ impl PricingService {
         Ok(price.map(|price| price.amount))
     }
 
+    /// Recorded price changes for every variant of the product, oldest first.
+    #[instrument(skip(self))]
+    pub async fn price_history(
+        &self,
+        tenant_id: Uuid,
+        product_id: Uuid,
+    ) -> CommerceResult<Vec<PriceHistoryEntry>> {
+        let rows = entities::product_price_history::Entity::find()
+            .filter(entities::product_price_history::Column::TenantId.eq(tenant_id))
+            .filter(entities::product_price_history::Column::ProductId.eq(product_id))
+            .order_by_asc(entities::product_price_history::Column::ChangedAt)
+            .all(&self.db)
+            .await?;
+
+        Ok(rows.into_iter().map(PriceHistoryEntry::from).collect())
+    }
+
+    /// Base price (no price list, channel or quantity tier) in effect at `at`, taking the
+    /// price history and scheduled changes into account.
+    #[instrument(skip(self))]
+    pub async fn effective_price_at(
+        &self,
+        tenant_id: Uuid,
+        variant_id: Uuid,
+        currency_code: &str,
+        at: chrono::DateTime<chrono::Utc>,
+    ) -> CommerceResult<Option<Decimal>> {
+        let Some(price) = find_base_price(&self.db, variant_id, currency_code).await? else {
+            return Ok(None);
+        };
+        let (amount, _) = effective_amounts_at(&self.db, tenant_id, &price, at).await?;
+        Ok(Some(amount))
+    }
+
+    /// Schedules a change of the variant's base price that takes effect at `effective_at`.
+    ///
+    /// The stored price row is left as is; [`Self::resolve_variant_price`] and
+    /// [`Self::effective_price_at`] return the new amount once `effective_at` has passed.
+    #[instrument(skip(self))]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn schedule_price_change(
+        &self,
+        tenant_id: Uuid,
+        actor_id: Uuid,
+        variant_id: Uuid,
+        currency_code: &str,
+        amount: Decimal,
+        compare_at_amount: Option<Decimal>,
+        effective_at: chrono::DateTime<chrono::Utc>,
+    ) -> CommerceResult<()> {
+        if effective_at <= chrono::Utc::now() {
+            return Err(CommerceError::Validation(
+                "Scheduled price change must take effect in the future".into(),
+            ));
+        }
+        if amount < Decimal::ZERO {
+            return Err(CommerceError::InvalidPrice(
+                "Amount cannot be negative".into(),
+            ));
+        }
+        if let Some(compare_at) = compare_at_amount {
+            if compare_at < amount {
+                return Err(CommerceError::InvalidPrice(
+                    "Compare at price must be greater than amount".into(),
+                ));
+            }
+        }
+
+        let variant = entities::product_variant::Entity::find_by_id(variant_id)
+            .filter(entities::product_variant::Column::TenantId.eq(tenant_id))
+            .one(&self.db)
+            .await?
+            .ok_or(CommerceError::VariantNotFound(variant_id))?;
+        let price = find_base_price(&self.db, variant_id, currency_code)
+            .await?
+            .ok_or_else(|| {
+                CommerceError::InvalidPrice(format!(
+                    "Variant {variant_id} has no {currency_code} base price to change"
+                ))
+            })?;
+        let (old_amount, old_compare_at_amount) =
+            effective_amounts_at(&self.db, tenant_id, &price, effective_at).await?;
+
+        entities::product_price_history::ActiveModel {
+            id: Set(generate_id()),
+            tenant_id: Set(tenant_id),
+            product_id: Set(variant.product_id),
+            variant_id: Set(variant_id),
+            price_id: Set(price.id),
+            currency_code: Set(price.currency_code),
+            price_list_id: Set(None),
+            channel_id: Set(None),
+            channel_slug: Set(None),
+            region_id: Set(None),
+            min_quantity: Set(None),
+            max_quantity: Set(None),
+            old_amount: Set(old_amount),
+            new_amount: Set(amount),
+            old_compare_at_amount: Set(old_compare_at_amount),
+            new_compare_at_amount: Set(compare_at_amount),
+            changed_by: Set(Some(actor_id)),
+            changed_at: Set(effective_at.fixed_offset()),
+        }
+        .insert(&self.db)
+        .await?;
+
+        Ok(())
+    }
+
     #[instrument(skip(self))]
     pub async fn get_variant_prices(
         &self,
@@ -986,12 +1132,28 @@ impl PricingService {
         Ok(prices)
     }
 
+    /// Price of the variant for `context` as of now; cart, checkout and order line pricing
+    /// go through here.
     #[instrument(skip(self, context), fields(tenant_id = %tenant_id, variant_id = %variant_id))]
     pub async fn resolve_variant_price(
         &self,
         tenant_id: Uuid,
         variant_id: Uuid,
         context: PriceResolutionContext,
+    ) -> CommerceResult<Option<ResolvedPrice>> {
+        self.resolve_variant_price_at(tenant_id, variant_id, context, chrono::Utc::now())
+            .await
+    }
+
+    /// Like [`Self::resolve_variant_price`], with the amounts of the selected price row taken
+    /// as they were (or are scheduled to be) at `at`.
+    #[instrument(skip(self, context), fields(tenant_id = %tenant_id, variant_id = %variant_id))]
+    pub async fn resolve_variant_price_at(
+        &self,
+        tenant_id: Uuid,
+        variant_id: Uuid,
+        context: PriceResolutionContext,
+        at: chrono::DateTime<chrono::Utc>,
     ) -> CommerceResult<Option<ResolvedPrice>> {
         let quantity = normalize_resolution_quantity(context.quantity)?;
         let currency_code = normalize_resolution_currency(&context.currency_code)?;
@@ -1019,41 +1181,44 @@ impl PricingService {
             .all(&self.db)
             .await?;
 
-        Ok(select_best_price(
+        let Some(mut price) = select_best_price(
             prices,
             context.region_id,
             active_price_list_id,
             context.channel_id,
             channel_slug.as_deref(),
             quantity,
-        )
-        .map(|price| {
-            if price.price_list_id.is_none() {
-                if let (Some(price_list_id), Some(rule)) =
-                    (active_price_list_id, active_price_list_rule.as_ref())
-                {
-                    return apply_price_list_rule_to_resolved_price(
-                        currency_code,
-                        price,
-                        price_list_id,
-                        rule,
-                    );
-                }
-            }
+        ) else {
+            return Ok(None);
+        };
+        (price.amount, price.compare_at_amount) =
+            effective_amounts_at(&self.db, tenant_id, &price, at).await?;
 
-            ResolvedPrice {
-                currency_code,
-                amount: price.amount,
-                compare_at_amount: price.compare_at_amount,
-                discount_percent: calculate_discount_percent(price.amount, price.compare_at_amount),
-                on_sale: is_sale_price(price.amount, price.compare_at_amount),
-                region_id: price.region_id,
-                min_quantity: price.min_quantity,
-                max_quantity: price.max_quantity,
-                price_list_id: price.price_list_id,
-                channel_id: price.channel_id,
-                channel_slug: price.channel_slug,
+        if price.price_list_id.is_none() {
+            if let (Some(price_list_id), Some(rule)) =
+                (active_price_list_id, active_price_list_rule.as_ref())
+            {
+                return Ok(Some(apply_price_list_rule_to_resolved_price(
+                    currency_code,
+                    price,
+                    price_list_id,
+                    rule,
+                )));
             }
+        }
+
+        Ok(Some(ResolvedPrice {
+            currency_code,
+            amount: price.amount,
+            compare_at_amount: price.compare_at_amount,
+            discount_percent: calculate_discount_percent(price.amount, price.compare_at_amount),
+            on_sale: is_sale_price(price.amount, price.compare_at_amount),
+            region_id: price.region_id,
+            min_quantity: price.min_quantity,
+            max_quantity: price.max_quantity,
+            price_list_id: price.price_list_id,
+            channel_id: price.channel_id,
+            channel_slug: price.channel_slug,
         }))
     }
 
@@ -1651,6 +1816,103 @@ where
     Ok(price_list)
 }
 
+async fn find_base_price<C>(
+    db: &C,
+    variant_id: Uuid,
+    currency_code: &str,
+) -> CommerceResult<Option<entities::price::Model>>
+where
+    C: sea_orm::ConnectionTrait,
+{
+    Ok(entities::price::Entity::find()
+        .filter(entities::price::Column::VariantId.eq(variant_id))
+        .filter(entities::price::Column::CurrencyCode.eq(currency_code))
+        .filter(entities::price::Column::RegionId.is_null())
+        .filter(entities::price::Column::PriceListId.is_null())
+        .filter(entities::price::Column::ChannelId.is_null())
+        .filter(entities::price::Column::ChannelSlug.is_null())
+        .filter(entities::price::Column::MinQuantity.is_null())
+        .filter(entities::price::Column::MaxQuantity.is_null())
+        .one(db)
+        .await?)
+}
+
+/// Amount and compare-at amount of `price` at `at`: the last change recorded for the same
+/// price scope up to `at` (scheduled changes carry a future `changed_at`), else what the
+/// first later change replaced, else the stored row.
+async fn effective_amounts_at<C>(
+    db: &C,
+    tenant_id: Uuid,
+    price: &entities::price::Model,
+    at: chrono::DateTime<chrono::Utc>,
+) -> CommerceResult<(Decimal, Option<Decimal>)>
+where
+    C: sea_orm::ConnectionTrait,
+{
+    use entities::product_price_history::{Column, Entity};
+
+    let scope_history = || {
+        Entity::find()
+            .filter(Column::TenantId.eq(tenant_id))
+            .filter(Column::VariantId.eq(price.variant_id))
+            .filter(Column::CurrencyCode.eq(price.currency_code.as_str()))
+            .filter(optional_uuid_filter(
+                Column::PriceListId,
+                price.price_list_id,
+            ))
+            .filter(optional_uuid_filter(Column::ChannelId, price.channel_id))
+            .filter(optional_string_filter(
+                Column::ChannelSlug,
+                price.channel_slug.clone(),
+            ))
+            .filter(optional_uuid_filter(Column::RegionId, price.region_id))
+            .filter(optional_int_filter(Column::MinQuantity, price.min_quantity))
+            .filter(optional_int_filter(Column::MaxQuantity, price.max_quantity))
+    };
+
+    let at = at.fixed_offset();
+    if let Some(last) = scope_history()
+        .filter(Column::ChangedAt.lte(at))
+        .order_by_desc(Column::ChangedAt)
+        .one(db)
+        .await?
+    {
+        return Ok((last.new_amount, last.new_compare_at_amount));
+    }
+    if let Some(next) = scope_history()
+        .filter(Column::ChangedAt.gt(at))
+        .order_by_asc(Column::ChangedAt)
+        .one(db)
+        .await?
+    {
+        return Ok((next.old_amount, next.old_compare_at_amount));
+    }
+
+    Ok((price.amount, price.compare_at_amount))
+}
+
+async fn record_price_change<C>(
+    db: &C,
+    variant: &entities::product_variant::Model,
+    actor_id: Uuid,
+    previous: &entities::price::Model,
+    current: &entities::price::Model,
+) -> CommerceResult<()>
+where
+    C: sea_orm::ConnectionTrait,
+{
+    if let Some(change) = entities::product_price_history::ActiveModel::for_change(
+        variant.tenant_id,
+        variant.product_id,
+        Some(actor_id),
+        previous,
+        current,
+    ) {
+        change.insert(db).await?;
+    }
+    Ok(())
+}
+
 #[allow(clippy::result_large_err)]
 fn validate_active_price_list(price_list: &entities::price_list::Model) -> CommerceResult<()> {
     if !price_list.status.eq_ignore_ascii_case("active") {
@@ -2076,27 +2338,21 @@ fn validate_discount_percent(discount_percent: Decimal) -> CommerceResult<()> {
     Ok(())
 }
 
-fn optional_int_filter(column: entities::price::Column, value: Option<i32>) -> sea_orm::Condition {
+fn optional_int_filter(column: impl ColumnTrait, value: Option<i32>) -> sea_orm::Condition {
     match value {
         Some(value) => sea_orm::Condition::all().add(column.eq(value)),
         None => sea_orm::Condition::all().add(column.is_null()),
     }
 }
 
-fn optional_uuid_filter(
-    column: entities::price::Column,
-    value: Option<Uuid>,
-) -> sea_orm::Condition {
+fn optional_uuid_filter(column: impl ColumnTrait, value: Option<Uuid>) -> sea_orm::Condition {
     match value {
         Some(value) => sea_orm::Condition::all().add(column.eq(value)),
         None => sea_orm::Condition::all().add(column.is_null()),
     }
 }
 
-fn optional_string_filter(
-    column: entities::price::Column,
-    value: Option<String>,
-) -> sea_orm::Condition {
+fn optional_string_filter(column: impl ColumnTrait, value: Option<String>) -> sea_orm::Condition {
     match value {
         Some(value) => sea_orm::Condition::all().add(column.eq(value)),
         None => sea_orm::Condition::all().add(column.is_null()),
@@ -0,0 +1,44 @@
+use sea_orm::ConnectionTrait;
+use sea_orm_migration::prelude::*;
+
+const INDEX_NAME: &str = "idx_product_variants_options_unique";
+
+/// One variant per option combination of a product. Options are compared the way
+/// `CatalogService` compares them: trimmed, lower-cased, with a blank option equal to none.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(&format!(
+                "CREATE UNIQUE INDEX IF NOT EXISTS {INDEX_NAME} ON product_variants (
+                    product_id,
+                    COALESCE(LOWER(TRIM(option1)), ''),
+                    COALESCE(LOWER(TRIM(option2)), ''),
+                    COALESCE(LOWER(TRIM(option3)), '')
+                )"
+            ))
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(INDEX_NAME)
+                    .table(ProductVariants::Table)
+                    .if_exists()
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum ProductVariants {
+    Table,
+}
@@ -114,6 +114,13 @@ impl CatalogService {
             warn!("Product creation rejected: no variants");
             return Err(CommerceError::NoVariants);
         }
+        Self::ensure_unique_variant_options(input.variants.iter().map(|variant| {
+            [
+                variant.option1.as_deref(),
+                variant.option2.as_deref(),
+                variant.option3.as_deref(),
+            ]
+        }))?;
 
         let product_id = generate_id();
         let now = Utc::now();
@@ -164,7 +171,7 @@ impl CatalogService {
                 None
             }),
         };
-        product.insert(&txn).await?;
+        let product = product.insert(&txn).await?;
         debug!("Product entity inserted");
 
         if let (Some(locale), Some(values)) = (
@@ -289,93 +296,16 @@ impl CatalogService {
         let default_stock_location = Self::ensure_default_stock_location(&txn, tenant_id).await?;
 
         for (position, var_input) in input.variants.iter().enumerate() {
-            let variant_id = generate_id();
-
-            if let Some(ref sku) = var_input.sku {
-                let existing = entities::product_variant::Entity::find()
-                    .filter(entities::product_variant::Column::TenantId.eq(tenant_id))
-                    .filter(entities::product_variant::Column::Sku.eq(sku))
-                    .one(&txn)
-                    .await?;
-                if existing.is_some() {
-                    warn!(sku = %sku, "Duplicate SKU detected");
-                    return Err(CommerceError::DuplicateSku(sku.clone()));
-                }
-            }
-
-            let variant = entities::product_variant::ActiveModel {
-                id: Set(variant_id),
-                product_id: Set(product_id),
-                tenant_id: Set(tenant_id),
-                sku: Set(var_input.sku.clone()),
-                barcode: Set(var_input.barcode.clone()),
-                shipping_profile_slug: Set(var_input
-                    .shipping_profile_slug
-                    .as_deref()
-                    .and_then(Self::normalize_shipping_profile_slug)),
-                ean: Set(None),
-                upc: Set(None),
-                inventory_policy: Set(var_input.inventory_policy.clone()),
-                inventory_management: Set("manual".into()),
-                inventory_quantity: Set(0),
-                weight: Set(var_input.weight),
-                weight_unit: Set(var_input.weight_unit.clone()),
-                option1: Set(var_input.option1.clone()),
-                option2: Set(var_input.option2.clone()),
-                option3: Set(var_input.option3.clone()),
-                position: Set(position as i32),
-                created_at: Set(now.into()),
-                updated_at: Set(now.into()),
-            };
-            variant.insert(&txn).await?;
-
-            Self::create_initial_inventory_records(
+            Self::insert_variant(
                 &txn,
+                &product,
+                position as i32,
+                var_input,
                 &default_stock_location,
-                variant_id,
-                var_input.sku.clone(),
-                var_input.inventory_quantity,
+                &translation_locales,
+                now,
             )
             .await?;
-
-            let variant_title = Self::generate_variant_title_from_inputs(
-                var_input.option1.as_deref(),
-                var_input.option2.as_deref(),
-                var_input.option3.as_deref(),
-            );
-            for locale in &translation_locales {
-                entities::variant_translation::ActiveModel {
-                    id: Set(generate_id()),
-                    variant_id: Set(variant_id),
-                    locale: Set(locale.clone()),
-                    title: Set(Some(variant_title.clone())),
-                }
-                .insert(&txn)
-                .await?;
-            }
-
-            for price_input in &var_input.prices {
-                let price = entities::price::ActiveModel {
-                    id: Set(generate_id()),
-                    variant_id: Set(variant_id),
-                    price_list_id: Set(None),
-                    channel_id: Set(price_input.channel_id),
-                    channel_slug: Set(normalize_public_channel_slug(
-                        price_input.channel_slug.as_deref(),
-                    )),
-                    currency_code: Set(price_input.currency_code.clone()),
-                    region_id: Set(None),
-                    amount: Set(price_input.amount),
-                    compare_at_amount: Set(price_input.compare_at_amount),
-                    legacy_amount: Set(Self::decimal_to_cents(price_input.amount)),
-                    legacy_compare_at_amount: Set(price_input
-                        .compare_at_amount
-                        .and_then(Self::decimal_to_cents)),
-                    min_quantity: Set(None),
-                    max_quantity: Set(None),
-                };
-                price.insert(&txn).await?;
-            }
         }
         debug!(
             variants_count = input.variants.len(),
@@ -1321,6 +1251,339 @@ impl CatalogService {
         Ok(())
     }
 
+    /// Adds a variant to an existing product.
+    ///
+    /// The new variant's option values must differ from those of every other variant of the
+    /// product.
+    #[instrument(skip(self, input), fields(tenant_id = %tenant_id))]
+    pub async fn add_variant(
+        &self,
+        tenant_id: Uuid,
+        actor_id: Uuid,
+        product_id: Uuid,
+        input: CreateVariantInput,
+    ) -> CommerceResult<ProductResponse> {
+        debug!(product_id = %product_id, "Adding product variant");
+
+        input
+            .validate()
+            .map_err(|e| CommerceError::Validation(e.to_string()))?;
+
+        let txn = self.db.begin().await?;
+
+        let product = entities::product::Entity::find_by_id(product_id)
+            .filter(entities::product::Column::TenantId.eq(tenant_id))
+            .one(&txn)
+            .await?
+            .ok_or(CommerceError::ProductNotFound(product_id))?;
+
+        let siblings = entities::product_variant::Entity::find()
+            .filter(entities::product_variant::Column::ProductId.eq(product_id))
+            .all(&txn)
+            .await?;
+        Self::ensure_unique_variant_options(siblings.iter().map(Self::variant_options).chain(
+            std::iter::once([
+                input.option1.as_deref(),
+                input.option2.as_deref(),
+                input.option3.as_deref(),
+            ]),
+        ))?;
+
+        let mut translation_locales = Vec::new();
+        for translation in entities::product_translation::Entity::find()
+            .filter(entities::product_translation::Column::ProductId.eq(product_id))
+            .all(&txn)
+            .await?
+        {
+            if !translation_locales.contains(&translation.locale) {
+                translation_locales.push(translation.locale);
+            }
+        }
+
+        let position = siblings
+            .iter()
+            .map(|variant| variant.position + 1)
+            .max()
+            .unwrap_or(0);
+        let default_stock_location = Self::ensure_default_stock_location(&txn, tenant_id).await?;
+        let variant_id = Self::insert_variant(
+            &txn,
+            &product,
+            position,
+            &input,
+            &default_stock_location,
+            &translation_locales,
+            Utc::now(),
+        )
+        .await?;
+
+        self.event_bus
+            .publish_in_tx(
+                &txn,
+                tenant_id,
+                Some(actor_id),
+                DomainEvent::VariantCreated {
+                    variant_id,
+                    product_id,
+                },
+            )
+            .await?;
+
+        txn.commit().await?;
+        info!(product_id = %product_id, variant_id = %variant_id, "Product variant added");
+
+        self.get_product_with_locale_fallback(
+            tenant_id,
+            product_id,
+            Self::preferred_product_locale_from_metadata(&product.metadata).as_str(),
+            None,
+        )
+        .await
+    }
+
+    /// Updates a variant in place. Omitted fields are left unchanged and `prices`, when
+    /// given, replaces the variant's base prices; changed amounts land in the price history.
+    ///
+    /// Stock is owned by the inventory service, so `inventory_quantity` is not applied here.
+    #[instrument(skip(self, input), fields(tenant_id = %tenant_id))]
+    pub async fn update_variant(
+        &self,
+        tenant_id: Uuid,
+        actor_id: Uuid,
+        variant_id: Uuid,
+        input: UpdateVariantInput,
+    ) -> CommerceResult<ProductResponse> {
+        debug!(variant_id = %variant_id, "Updating product variant");
+
+        input
+            .validate()
+            .map_err(|e| CommerceError::Validation(e.to_string()))?;
+
+        let txn = self.db.begin().await?;
+
+        let variant = entities::product_variant::Entity::find_by_id(variant_id)
+            .filter(entities::product_variant::Column::TenantId.eq(tenant_id))
+            .one(&txn)
+            .await?
+            .ok_or(CommerceError::VariantNotFound(variant_id))?;
+        let product_id = variant.product_id;
+
+        let options_changed =
+            input.option1.is_some() || input.option2.is_some() || input.option3.is_some();
+        let option1 = input.option1.clone().or_else(|| variant.option1.clone());
+        let option2 = input.option2.clone().or_else(|| variant.option2.clone());
+        let option3 = input.option3.clone().or_else(|| variant.option3.clone());
+
+        if options_changed {
+            let siblings = entities::product_variant::Entity::find()
+                .filter(entities::product_variant::Column::ProductId.eq(product_id))
+                .filter(entities::product_variant::Column::Id.ne(variant_id))
+                .all(&txn)
+                .await?;
+            Self::ensure_unique_variant_options(siblings.iter().map(Self::variant_options).chain(
+                std::iter::once([option1.as_deref(), option2.as_deref(), option3.as_deref()]),
+            ))?;
+        }
+
+        if let Some(sku) = input.sku.as_deref() {
+            Self::ensure_sku_available(&txn, tenant_id, sku, Some(variant_id)).await?;
+        }
+
+        let mut variant_active: entities::product_variant::ActiveModel = variant.into();
+        if input.sku.is_some() {
+            variant_active.sku = Set(input.sku.clone());
+        }
+        if input.barcode.is_some() {
+            variant_active.barcode = Set(input.barcode.clone());
+        }
+        if input.shipping_profile_slug.is_some() {
+            variant_active.shipping_profile_slug = Set(input
+                .shipping_profile_slug
+                .as_deref()
+                .and_then(Self::normalize_shipping_profile_slug));
+        }
+        if let Some(inventory_policy) = input.inventory_policy.clone() {
+            variant_active.inventory_policy = Set(inventory_policy);
+        }
+        if input.weight.is_some() {
+            variant_active.weight = Set(input.weight);
+        }
+        if input.weight_unit.is_some() {
+            variant_active.weight_unit = Set(input.weight_unit.clone());
+        }
+        if options_changed {
+            variant_active.option1 = Set(option1.clone());
+            variant_active.option2 = Set(option2.clone());
+            variant_active.option3 = Set(option3.clone());
+        }
+        variant_active.updated_at = Set(Utc::now().into());
+        variant_active.update(&txn).await?;
+
+        if options_changed {
+            let title = Self::generate_variant_title_from_inputs(
+                option1.as_deref(),
+                option2.as_deref(),
+                option3.as_deref(),
+            );
+            for translation in entities::variant_translation::Entity::find()
+                .filter(entities::variant_translation::Column::VariantId.eq(variant_id))
+                .all(&txn)
+                .await?
+            {
+                let mut translation: entities::variant_translation::ActiveModel =
+                    translation.into();
+                translation.title = Set(Some(title.clone()));
+                translation.update(&txn).await?;
+            }
+        }
+
+        if let Some(prices) = input.prices.as_deref() {
+            let previous_prices = entities::price::Entity::find()
+                .filter(entities::price::Column::VariantId.eq(variant_id))
+                .filter(entities::price::Column::PriceListId.is_null())
+                .all(&txn)
+                .await?;
+            entities::price::Entity::delete_many()
+                .filter(entities::price::Column::VariantId.eq(variant_id))
+                .filter(entities::price::Column::PriceListId.is_null())
+                .exec(&txn)
+                .await?;
+            let current_prices = Self::insert_variant_prices(&txn, variant_id, prices).await?;
+
+            for current in &current_prices {
+                let previous = previous_prices.iter().find(|previous| {
+                    previous.currency_code == current.currency_code
+                        && previous.channel_id == current.channel_id
+                        && previous.channel_slug == current.channel_slug
+                        && previous.region_id.is_none()
+                        && previous.min_quantity.is_none()
+                        && previous.max_quantity.is_none()
+                });
+                if let Some(change) = previous.and_then(|previous| {
+                    entities::product_price_history::ActiveModel::for_change(
+                        tenant_id,
+                        product_id,
+                        Some(actor_id),
+                        previous,
+                        current,
+                    )
+                }) {
+                    change.insert(&txn).await?;
+                }
+            }
+        }
+
+        self.event_bus
+            .publish_in_tx(
+                &txn,
+                tenant_id,
+                Some(actor_id),
+                DomainEvent::VariantUpdated {
+                    variant_id,
+                    product_id,
+                },
+            )
+            .await?;
+
+        txn.commit().await?;
+        info!(product_id = %product_id, variant_id = %variant_id, "Product variant updated");
+
+        self.get_product(tenant_id, product_id).await
+    }
+
+    /// Removes a variant together with its prices and inventory records. The last variant of
+    /// a product cannot be removed.
+    #[instrument(skip(self))]
+    pub async fn delete_variant(
+        &self,
+        tenant_id: Uuid,
+        actor_id: Uuid,
+        variant_id: Uuid,
+    ) -> CommerceResult<()> {
+        debug!(variant_id = %variant_id, "Deleting product variant");
+
+        let txn = self.db.begin().await?;
+
+        let variant = entities::product_variant::Entity::find_by_id(variant_id)
+            .filter(entities::product_variant::Column::TenantId.eq(tenant_id))
+            .one(&txn)
+            .await?
+            .ok_or(CommerceError::VariantNotFound(variant_id))?;
+        let product_id = variant.product_id;
+
+        let has_siblings = entities::product_variant::Entity::find()
+            .filter(entities::product_variant::Column::ProductId.eq(product_id))
+            .filter(entities::product_variant::Column::Id.ne(variant_id))
+            .one(&txn)
+            .await?
+            .is_some();
+        if !has_siblings {
+            warn!(variant_id = %variant_id, "Cannot delete the last variant of a product");
+            return Err(CommerceError::NoVariants);
+        }
+
+        let inventory_item_ids: Vec<Uuid> = entities::inventory_item::Entity::find()
+            .filter(entities::inventory_item::Column::VariantId.eq(variant_id))
+            .all(&txn)
+            .await?
+            .into_iter()
+            .map(|item| item.id)
+            .collect();
+        if !inventory_item_ids.is_empty() {
+            entities::reservation_item::Entity::delete_many()
+                .filter(
+                    entities::reservation_item::Column::InventoryItemId
+                        .is_in(inventory_item_ids.clone()),
+                )
+                .exec(&txn)
+                .await?;
+
+            entities::inventory_level::Entity::delete_many()
+                .filter(
+                    entities::inventory_level::Column::InventoryItemId
+                        .is_in(inventory_item_ids.clone()),
+                )
+                .exec(&txn)
+                .await?;
+
+            entities::inventory_item::Entity::delete_many()
+                .filter(entities::inventory_item::Column::Id.is_in(inventory_item_ids))
+                .exec(&txn)
+                .await?;
+        }
+
+        entities::price::Entity::delete_many()
+            .filter(entities::price::Column::VariantId.eq(variant_id))
+            .exec(&txn)
+            .await?;
+
+        entities::variant_translation::Entity::delete_many()
+            .filter(entities::variant_translation::Column::VariantId.eq(variant_id))
+            .exec(&txn)
+            .await?;
+
+        entities::product_variant::Entity::delete_by_id(variant_id)
+            .exec(&txn)
+            .await?;
+
+        self.event_bus
+            .publish_in_tx(
+                &txn,
+                tenant_id,
+                Some(actor_id),
+                DomainEvent::VariantDeleted {
+                    variant_id,
+                    product_id,
+                },
+            )
+            .await?;
+
+        txn.commit().await?;
+        info!(product_id = %product_id, variant_id = %variant_id, "Product variant deleted");
+
+        Ok(())
+    }
+
     fn slugify(text: &str) -> String {
         use unicode_normalization::UnicodeNormalization;
 
@@ -1365,6 +1628,38 @@ impl CatalogService {
         }
     }
 
+    fn variant_options(variant: &entities::product_variant::Model) -> [Option<&str>; 3] {
+        [
+            variant.option1.as_deref(),
+            variant.option2.as_deref(),
+            variant.option3.as_deref(),
+        ]
+    }
+
+    /// Rejects the set when two variants resolve to the same option values, compared
+    /// case-insensitively and ignoring surrounding whitespace.
+    #[allow(clippy::result_large_err)]
+    fn ensure_unique_variant_options<'a>(
+        variants: impl IntoIterator<Item = [Option<&'a str>; 3]>,
+    ) -> CommerceResult<()> {
+        let mut seen = HashSet::new();
+        for options in variants {
+            let key = options.map(|value| {
+                value
+                    .map(str::trim)
+                    .filter(|value| !value.is_empty())
+                    .map(str::to_lowercase)
+            });
+            if !seen.insert(key) {
+                let title =
+                    Self::generate_variant_title_from_inputs(options[0], options[1], options[2]);
+                warn!(options = %title, "Duplicate variant options detected");
+                return Err(CommerceError::DuplicateVariantOptions(title));
+            }
+        }
+        Ok(())
+    }
+
     fn generate_variant_title(variant: &entities::product_variant::Model) -> String {
         Self::generate_variant_title_from_inputs(
             variant.option1.as_deref(),
@@ -2031,6 +2326,136 @@ impl CatalogService {
         Ok(location)
     }
 
+    async fn ensure_sku_available<C>(
+        conn: &C,
+        tenant_id: Uuid,
+        sku: &str,
+        exclude_variant_id: Option<Uuid>,
+    ) -> CommerceResult<()>
+    where
+        C: ConnectionTrait,
+    {
+        let mut query = entities::product_variant::Entity::find()
+            .filter(entities::product_variant::Column::TenantId.eq(tenant_id))
+            .filter(entities::product_variant::Column::Sku.eq(sku));
+        if let Some(variant_id) = exclude_variant_id {
+            query = query.filter(entities::product_variant::Column::Id.ne(variant_id));
+        }
+        if query.one(conn).await?.is_some() {
+            warn!(sku = %sku, "Duplicate SKU detected");
+            return Err(CommerceError::DuplicateSku(sku.to_string()));
+        }
+        Ok(())
+    }
+
+    async fn insert_variant<C>(
+        conn: &C,
+        product: &entities::product::Model,
+        position: i32,
+        var_input: &CreateVariantInput,
+        default_stock_location: &entities::stock_location::Model,
+        translation_locales: &[String],
+        now: chrono::DateTime<Utc>,
+    ) -> CommerceResult<Uuid>
+    where
+        C: ConnectionTrait,
+    {
+        let variant_id = generate_id();
+
+        if let Some(ref sku) = var_input.sku {
+            Self::ensure_sku_available(conn, product.tenant_id, sku, None).await?;
+        }
+
+        let variant = entities::product_variant::ActiveModel {
+            id: Set(variant_id),
+            product_id: Set(product.id),
+            tenant_id: Set(product.tenant_id),
+            sku: Set(var_input.sku.clone()),
+            barcode: Set(var_input.barcode.clone()),
+            shipping_profile_slug: Set(var_input
+                .shipping_profile_slug
+                .as_deref()
+                .and_then(Self::normalize_shipping_profile_slug)),
+            ean: Set(None),
+            upc: Set(None),
+            inventory_policy: Set(var_input.inventory_policy.clone()),
+            inventory_management: Set("manual".into()),
+            inventory_quantity: Set(0),
+            weight: Set(var_input.weight),
+            weight_unit: Set(var_input.weight_unit.clone()),
+            option1: Set(var_input.option1.clone()),
+            option2: Set(var_input.option2.clone()),
+            option3: Set(var_input.option3.clone()),
+            position: Set(position),
+            created_at: Set(now.into()),
+            updated_at: Set(now.into()),
+        };
+        variant.insert(conn).await?;
+
+        Self::create_initial_inventory_records(
+            conn,
+            default_stock_location,
+            variant_id,
+            var_input.sku.clone(),
+            var_input.inventory_quantity,
+        )
+        .await?;
+
+        let variant_title = Self::generate_variant_title_from_inputs(
+            var_input.option1.as_deref(),
+            var_input.option2.as_deref(),
+            var_input.option3.as_deref(),
+        );
+        for locale in translation_locales {
+            entities::variant_translation::ActiveModel {
+                id: Set(generate_id()),
+                variant_id: Set(variant_id),
+                locale: Set(locale.clone()),
+                title: Set(Some(variant_title.clone())),
+            }
+            .insert(conn)
+            .await?;
+        }
+
+        Self::insert_variant_prices(conn, variant_id, &var_input.prices).await?;
+
+        Ok(variant_id)
+    }
+
+    async fn insert_variant_prices<C>(
+        conn: &C,
+        variant_id: Uuid,
+        prices: &[PriceInput],
+    ) -> CommerceResult<Vec<entities::price::Model>>
+    where
+        C: ConnectionTrait,
+    {
+        let mut inserted = Vec::with_capacity(prices.len());
+        for price_input in prices {
+            let price = entities::price::ActiveModel {
+                id: Set(generate_id()),
+                variant_id: Set(variant_id),
+                price_list_id: Set(None),
+                channel_id: Set(price_input.channel_id),
+                channel_slug: Set(normalize_public_channel_slug(
+                    price_input.channel_slug.as_deref(),
+                )),
+                currency_code: Set(price_input.currency_code.clone()),
+                region_id: Set(None),
+                amount: Set(price_input.amount),
+                compare_at_amount: Set(price_input.compare_at_amount),
+                legacy_amount: Set(Self::decimal_to_cents(price_input.amount)),
+                legacy_compare_at_amount: Set(price_input
+                    .compare_at_amount
+                    .and_then(Self::decimal_to_cents)),
+                min_quantity: Set(None),
+                max_quantity: Set(None),
+            };
+            inserted.push(price.insert(conn).await?);
+        }
+        Ok(inserted)
+    }
+
     async fn create_initial_inventory_records<C>(
         conn: &C,
         default_stock_location: &entities::stock_location::Model,
@@ -77,6 +77,7 @@ impl RusToKModule for RbacModule {
             Permission::SETTINGS_MANAGE,
             Permission::LOGS_READ,
             Permission::LOGS_LIST,
+            Permission::LOGS_MANAGE,
         ]
     }
 
@@ -1,7 +1,9 @@
+pub mod log_filter;
 pub mod metrics;
 pub mod otel;
 
 use lazy_static::lazy_static;
+pub use log_filter::{LogFilterHandle, LogFilterSnapshot};
 use once_cell::sync::OnceCell;
 use prometheus::{Encoder, IntGauge, Registry, TextEncoder};
 use std::sync::Arc;
@@ -10,6 +12,7 @@ use tracing_subscriber::{
 };
 
 static METRICS_HANDLE: OnceCell<Arc<MetricsHandle>> = OnceCell::new();
+static LOG_FILTER_HANDLE: OnceCell<LogFilterHandle> = OnceCell::new();
 static REGISTRY: OnceCell<Registry> = OnceCell::new();
 
 #[derive(Clone, Debug)]
@@ -77,6 +80,10 @@ pub enum TelemetryError {
     SubscriberAlreadySet,
     #[error("prometheus registry error: {0}")]
     Prometheus(#[from] prometheus::Error),
+    #[error("invalid log filter '{directives}': {message}")]
+    InvalidLogFilter { directives: String, message: String },
+    #[error("failed to reload log filter: {0}")]
+    Reload(String),
 }
 
 use prometheus::{CounterVec, HistogramOpts, HistogramVec, Opts};
@@ -141,7 +148,11 @@ lazy_static! {
 }
 
 pub fn init(config: TelemetryConfig) -> Result<TelemetryHandles, TelemetryError> {
-    let env_filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+    let base_directives = std::env::var(EnvFilter::DEFAULT_ENV)
+        .ok()
+        .filter(|directives| EnvFilter::try_new(directives).is_ok())
+        .unwrap_or_else(|| "info".to_string());
+    let (env_filter, log_filter) = log_filter::reloadable_filter(&base_directives)?;
     let fmt_layer: Box<dyn Layer<_> + Send + Sync> = match config.log_format {
         LogFormat::Json => fmt::layer()
             .with_span_events(fmt::format::FmtSpan::CLOSE)
@@ -192,6 +203,7 @@ pub fn init(config: TelemetryConfig) -> Result<TelemetryHandles, TelemetryError>
         tracing::info!("Telemetry initialized (OpenTelemetry disabled)");
     }
 
+    let _ = LOG_FILTER_HANDLE.set(log_filter);
     let metrics_handle = init_metrics_handle(config.metrics)?;
 
     Ok(TelemetryHandles {
@@ -241,6 +253,11 @@ pub fn metrics_handle() -> Option<Arc<MetricsHandle>> {
     METRICS_HANDLE.get().cloned()
 }
 
+/// Control handle for the global log filter; `None` until [`init`] has run.
+pub fn log_filter_handle() -> Option<LogFilterHandle> {
+    LOG_FILTER_HANDLE.get().cloned()
+}
+
 pub fn render_metrics() -> Result<String, prometheus::Error> {
     let encoder = TextEncoder::new();
     let metric_families = REGISTRY
@@ -0,0 +1,262 @@
+//! Runtime-reloadable log filter.
+//!
+//! [`reloadable_filter`] wraps an [`EnvFilter`] in a `tracing_subscriber::reload` layer
+//! and returns a [`LogFilterHandle`] that can layer per-target overrides such as
+//! `rustok_core::events=debug` on top of the startup directives without a restart.
+//! Every override expires after its TTL and the startup filter is restored; a newer
+//! override cancels the revert of an older one.
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+
+use tracing_subscriber::filter::Directive;
+use tracing_subscriber::{reload, EnvFilter};
+
+use crate::TelemetryError;
+
+type ApplyFilter = dyn Fn(EnvFilter) -> Result<(), reload::Error> + Send + Sync;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogFilterSnapshot {
+    /// Directives the subscriber was started with.
+    pub base: String,
+    /// Active override, if any.
+    pub overrides: Option<String>,
+    pub expires_at: Option<SystemTime>,
+}
+
+#[derive(Debug, Default)]
+struct OverrideState {
+    generation: u64,
+    overrides: Option<String>,
+    expires_at: Option<SystemTime>,
+}
+
+struct Inner {
+    base: String,
+    apply: Box<ApplyFilter>,
+    state: Mutex<OverrideState>,
+}
+
+#[derive(Clone)]
+pub struct LogFilterHandle {
+    inner: Arc<Inner>,
+}
+
+impl std::fmt::Debug for LogFilterHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LogFilterHandle")
+            .field("base", &self.inner.base)
+            .finish()
+    }
+}
+
+/// Builds the reloadable filter layer for `base` directives and its control handle.
+pub fn reloadable_filter<S>(
+    base: &str,
+) -> Result<(reload::Layer<EnvFilter, S>, LogFilterHandle), TelemetryError>
+where
+    S: tracing::Subscriber + 'static,
+{
+    let (layer, handle) = reload::Layer::new(parse_filter(base)?);
+    let apply = move |filter: EnvFilter| handle.reload(filter);
+
+    Ok((
+        layer,
+        LogFilterHandle {
+            inner: Arc::new(Inner {
+                base: base.to_string(),
+                apply: Box::new(apply),
+                state: Mutex::new(OverrideState::default()),
+            }),
+        },
+    ))
+}
+
+impl LogFilterHandle {
+    pub fn snapshot(&self) -> LogFilterSnapshot {
+        let state = self.lock_state();
+        LogFilterSnapshot {
+            base: self.inner.base.clone(),
+            overrides: state.overrides.clone(),
+            expires_at: state.expires_at,
+        }
+    }
+
+    /// Applies `directives` on top of the startup filter until `ttl` elapses.
+    ///
+    /// The revert runs on the current Tokio runtime; without one the override stays
+    /// until [`LogFilterHandle::reset`] is called.
+    pub fn set_overrides(
+        &self,
+        directives: &str,
+        ttl: Duration,
+    ) -> Result<LogFilterSnapshot, TelemetryError> {
+        let directives = normalize_directives(directives)?;
+        let filter = parse_filter(&format!("{},{directives}", self.inner.base))?;
+
+        let generation = {
+            let mut state = self.lock_state();
+            (self.inner.apply)(filter)
+                .map_err(|error| TelemetryError::Reload(error.to_string()))?;
+            state.generation += 1;
+            state.overrides = Some(directives);
+            state.expires_at = Some(SystemTime::now() + ttl);
+            state.generation
+        };
+
+        if let Ok(runtime) = tokio::runtime::Handle::try_current() {
+            let handle = self.clone();
+            runtime.spawn(async move {
+                tokio::time::sleep(ttl).await;
+                if let Err(error) = handle.reset_generation(generation) {
+                    tracing::warn!(error = %error, "failed to revert log filter override");
+                }
+            });
+        }
+
+        Ok(self.snapshot())
+    }
+
+    /// Drops any override and restores the startup filter.
+    pub fn reset(&self) -> Result<(), TelemetryError> {
+        let generation = self.lock_state().generation;
+        self.reset_generation(generation)
+    }
+
+    fn reset_generation(&self, generation: u64) -> Result<(), TelemetryError> {
+        let mut state = self.lock_state();
+        if state.generation != generation || state.overrides.is_none() {
+            return Ok(());
+        }
+
+        (self.inner.apply)(parse_filter(&self.inner.base)?)
+            .map_err(|error| TelemetryError::Reload(error.to_string()))?;
+        state.overrides = None;
+        state.expires_at = None;
+        Ok(())
+    }
+
+    fn lock_state(&self) -> std::sync::MutexGuard<'_, OverrideState> {
+        self.inner
+            .state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+fn parse_filter(directives: &str) -> Result<EnvFilter, TelemetryError> {
+    EnvFilter::try_new(directives).map_err(|error| TelemetryError::InvalidLogFilter {
+        directives: directives.to_string(),
+        message: error.to_string(),
+    })
+}
+
+/// Validates a comma-separated directive list and rejects empty input.
+fn normalize_directives(directives: &str) -> Result<String, TelemetryError> {
+    let parsed = directives
+        .split(',')
+        .map(str::trim)
+        .filter(|directive| !directive.is_empty())
+        .map(|directive| {
+            directive
+                .parse::<Directive>()
+                .map(|parsed| parsed.to_string())
+                .map_err(|error| TelemetryError::InvalidLogFilter {
+                    directives: directive.to_string(),
+                    message: error.to_string(),
+                })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if parsed.is_empty() {
+        return Err(TelemetryError::InvalidLogFilter {
+            directives: directives.to_string(),
+            message: "no directives given".to_string(),
+        });
+    }
+
+    Ok(parsed.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use tracing_subscriber::layer::{Context, SubscriberExt};
+    use tracing_subscriber::{Layer, Registry};
+
+    use super::*;
+
+    #[derive(Clone, Default)]
+    struct CountEvents(Arc<AtomicUsize>);
+
+    impl<S: tracing::Subscriber> Layer<S> for CountEvents {
+        fn on_event(&self, _event: &tracing::Event<'_>, _ctx: Context<'_, S>) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    fn emit_debug() {
+        tracing::debug!(target: "rustok_core::events", "dispatching event");
+    }
+
+    #[tokio::test]
+    async fn reloading_the_filter_changes_which_events_are_emitted() {
+        let (filter, handle) = reloadable_filter::<Registry>("info").unwrap();
+        let events = CountEvents::default();
+        let subscriber = Registry::default().with(filter).with(events.clone());
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        emit_debug();
+        assert_eq!(events.0.load(Ordering::SeqCst), 0);
+
+        let snapshot = handle
+            .set_overrides("rustok_core::events=debug", Duration::from_secs(60))
+            .unwrap();
+        assert_eq!(
+            snapshot.overrides.as_deref(),
+            Some("rustok_core::events=debug")
+        );
+        emit_debug();
+        assert_eq!(events.0.load(Ordering::SeqCst), 1);
+
+        handle.reset().unwrap();
+        emit_debug();
+        assert_eq!(events.0.load(Ordering::SeqCst), 1);
+        assert_eq!(handle.snapshot().overrides, None);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn override_reverts_after_ttl() {
+        let (_filter, handle) = reloadable_filter::<Registry>("info").unwrap();
+
+        handle
+            .set_overrides("rustok_core=trace", Duration::from_secs(30))
+            .unwrap();
+        handle
+            .set_overrides("rustok_outbox=debug", Duration::from_secs(120))
+            .unwrap();
+
+        tokio::time::sleep(Duration::from_secs(31)).await;
+        assert_eq!(
+            handle.snapshot().overrides.as_deref(),
+            Some("rustok_outbox=debug"),
+            "an older override's revert must not drop a newer one"
+        );
+
+        tokio::time::sleep(Duration::from_secs(90)).await;
+        assert_eq!(handle.snapshot().overrides, None);
+    }
+
+    #[test]
+    fn invalid_directives_are_rejected() {
+        let (_filter, handle) = reloadable_filter::<Registry>("info").unwrap();
+
+        assert!(matches!(
+            handle.set_overrides("rustok_core=loud", Duration::from_secs(1)),
+            Err(TelemetryError::InvalidLogFilter { .. })
+        ));
+        assert!(handle.set_overrides(" , ", Duration::from_secs(1)).is_err());
+    }
+}
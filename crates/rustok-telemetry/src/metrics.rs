@@ -372,6 +372,47 @@ lazy_static! {
     )
     .expect("Failed to create http_active_connections");
 
+    /// HTTP requests served, labelled by route template rather than raw path
+    pub static ref HTTP_SERVER_REQUESTS_TOTAL: IntCounterVec = IntCounterVec::new(
+        Opts::new(
+            "rustok_http_server_requests_total",
+            "HTTP requests served by route template and status class"
+        ),
+        &["method", "route", "status_class"]
+    )
+    .expect("Failed to create http_server_requests_total");
+
+    /// HTTP requests currently being handled
+    pub static ref HTTP_SERVER_REQUESTS_IN_FLIGHT: IntGaugeVec = IntGaugeVec::new(
+        Opts::new(
+            "rustok_http_server_requests_in_flight",
+            "HTTP requests currently in flight by route template"
+        ),
+        &["method", "route"]
+    )
+    .expect("Failed to create http_server_requests_in_flight");
+
+    /// HTTP request latency
+    pub static ref HTTP_SERVER_REQUEST_DURATION_SECONDS: HistogramVec = HistogramVec::new(
+        HistogramOpts::new(
+            "rustok_http_server_request_duration_seconds",
+            "HTTP request latency by route template and status class"
+        )
+        .buckets(vec![0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0]),
+        &["method", "route", "status_class"]
+    )
+    .expect("Failed to create http_server_request_duration_seconds");
+
+    /// Request handlers that panicked
+    pub static ref HTTP_SERVER_PANICS_TOTAL: IntCounterVec = IntCounterVec::new(
+        Opts::new(
+            "rustok_http_server_panics_total",
+            "HTTP request handlers that panicked by route template"
+        ),
+        &["method", "route"]
+    )
+    .expect("Failed to create http_server_panics_total");
+
     /// Requested read-path limits before clamping
     pub static ref READ_PATH_REQUESTED_LIMIT: HistogramVec = HistogramVec::new(
         HistogramOpts::new(
@@ -682,6 +723,10 @@ pub fn register_all(registry: &Registry) -> Result<(), prometheus::Error> {
     registry.register(Box::new(HTTP_REQUEST_SIZE_BYTES.clone()))?;
     registry.register(Box::new(HTTP_RESPONSE_SIZE_BYTES.clone()))?;
     registry.register(Box::new(HTTP_ACTIVE_CONNECTIONS.clone()))?;
+    registry.register(Box::new(HTTP_SERVER_REQUESTS_TOTAL.clone()))?;
+    registry.register(Box::new(HTTP_SERVER_REQUESTS_IN_FLIGHT.clone()))?;
+    registry.register(Box::new(HTTP_SERVER_REQUEST_DURATION_SECONDS.clone()))?;
+    registry.register(Box::new(HTTP_SERVER_PANICS_TOTAL.clone()))?;
     registry.register(Box::new(READ_PATH_REQUESTED_LIMIT.clone()))?;
     registry.register(Box::new(READ_PATH_EFFECTIVE_LIMIT.clone()))?;
     registry.register(Box::new(READ_PATH_RETURNED_ITEMS.clone()))?;
@@ -708,6 +753,9 @@ pub fn register_all(registry: &Registry) -> Result<(), prometheus::Error> {
     registry.register(Box::new(RATE_LIMIT_BACKEND_UNAVAILABLE_TOTAL.clone()))?;
     registry.register(Box::new(RATE_LIMIT_EXCEEDED_TOTAL.clone()))?;
 
+    // GraphQL
+    registry.register(Box::new(GRAPHQL_DEPRECATED_FIELD_USAGE_TOTAL.clone()))?;
+
     Ok(())
 }
 
@@ -886,6 +934,38 @@ pub fn record_db_query_error(query_type: &str, error_type: &str) {
         .inc();
 }
 
+/// Mark an HTTP request as started for the in-flight gauge.
+pub fn http_request_started(method: &str, route: &str) {
+    HTTP_SERVER_REQUESTS_IN_FLIGHT
+        .with_label_values(&[method, route])
+        .inc();
+}
+
+/// Release the in-flight slot taken by [`http_request_started`], whether the request
+/// completed or was cancelled.
+pub fn http_request_finished(method: &str, route: &str) {
+    HTTP_SERVER_REQUESTS_IN_FLIGHT
+        .with_label_values(&[method, route])
+        .dec();
+}
+
+/// Record a completed HTTP request.
+pub fn record_http_request(method: &str, route: &str, status_class: &str, duration_secs: f64) {
+    HTTP_SERVER_REQUESTS_TOTAL
+        .with_label_values(&[method, route, status_class])
+        .inc();
+    HTTP_SERVER_REQUEST_DURATION_SECONDS
+        .with_label_values(&[method, route, status_class])
+        .observe(duration_secs);
+}
+
+/// Record a request handler panic.
+pub fn record_http_panic(method: &str, route: &str) {
+    HTTP_SERVER_PANICS_TOTAL
+        .with_label_values(&[method, route])
+        .inc();
+}
+
 /// Record runtime budgets for bounded read-paths.
 pub fn record_read_path_budget(
     surface: &str,
@@ -1131,3 +1211,26 @@ pub fn update_storage_health(driver: &str, healthy: bool) {
         .with_label_values(&[driver])
         .set(if healthy { 1 } else { 0 });
 }
+
+// ============================================================================
+// GraphQL Metrics
+// ============================================================================
+
+lazy_static! {
+    /// Total resolutions of deprecated GraphQL fields, by owning type, field and client kind.
+    pub static ref GRAPHQL_DEPRECATED_FIELD_USAGE_TOTAL: IntCounterVec = IntCounterVec::new(
+        Opts::new(
+            "rustok_graphql_deprecated_field_usage_total",
+            "Total resolutions of deprecated GraphQL fields"
+        ),
+        &["parent_type", "field", "client_kind"]
+    )
+    .expect("Failed to create graphql_deprecated_field_usage_total");
+}
+
+/// Record that a deprecated GraphQL field was resolved.
+pub fn record_graphql_deprecated_field_usage(parent_type: &str, field: &str, client_kind: &str) {
+    GRAPHQL_DEPRECATED_FIELD_USAGE_TOTAL
+        .with_label_values(&[parent_type, field, client_kind])
+        .inc();
+}
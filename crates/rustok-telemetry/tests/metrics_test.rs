@@ -142,6 +142,41 @@ fn test_database_metrics() {
     metrics::record_db_query_error("INSERT", "ConnectionError");
 }
 
+#[test]
+fn test_http_server_metrics() {
+    let labels = ["GET", "/api/telemetry-test/{id}"];
+    metrics::http_request_started(labels[0], labels[1]);
+    assert_eq!(
+        metrics::HTTP_SERVER_REQUESTS_IN_FLIGHT
+            .with_label_values(&labels)
+            .get(),
+        1
+    );
+
+    metrics::record_http_request(labels[0], labels[1], "2xx", 0.042);
+    metrics::http_request_finished(labels[0], labels[1]);
+
+    let finished = [labels[0], labels[1], "2xx"];
+    assert_eq!(
+        metrics::HTTP_SERVER_REQUESTS_IN_FLIGHT
+            .with_label_values(&labels)
+            .get(),
+        0
+    );
+    assert_eq!(
+        metrics::HTTP_SERVER_REQUESTS_TOTAL
+            .with_label_values(&finished)
+            .get(),
+        1
+    );
+    assert_eq!(
+        metrics::HTTP_SERVER_REQUEST_DURATION_SECONDS
+            .with_label_values(&finished)
+            .get_sample_count(),
+        1
+    );
+}
+
 #[test]
 fn test_metric_labels() {
     // Test that metrics can be recorded with different label combinations
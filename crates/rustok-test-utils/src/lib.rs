@@ -3,6 +3,7 @@
 //! This crate provides testing utilities for RusToK modules:
 //! - Database setup and teardown utilities
 //! - Mock event bus for testing event publishing
+//! - Mock search backend for testing indexing
 //! - Test fixtures for common data types
 //! - Helper functions for creating test contexts
 //!
@@ -25,10 +26,12 @@ pub mod db;
 pub mod events;
 pub mod fixtures;
 pub mod helpers;
+pub mod search;
 
 pub use db::setup_test_db;
 pub use events::{mock_transactional_event_bus, MockEventBus, MockEventTransport};
 pub use helpers::*;
+pub use search::MockSearchBackend;
 
 #[cfg(test)]
 mod contract_tests;
@@ -0,0 +1,178 @@
+//! Search backend testing utilities
+//!
+//! Provides an in-memory search backend that records indexed documents.
+
+use rustok_core::{FacetedResults, SearchBackend, SearchDocument};
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+/// Mock search backend keeping indexed documents in memory.
+#[derive(Debug, Clone, Default)]
+pub struct MockSearchBackend {
+    documents: Arc<Mutex<Vec<SearchDocument>>>,
+}
+
+#[async_trait::async_trait]
+impl SearchBackend for MockSearchBackend {
+    async fn health(&self) -> rustok_core::Result<()> {
+        Ok(())
+    }
+
+    async fn index(&self, document: SearchDocument) -> rustok_core::Result<()> {
+        let mut documents = self.documents.lock().unwrap();
+        documents.retain(|existing| {
+            existing.document_id != document.document_id || existing.locale != document.locale
+        });
+        documents.push(document);
+        Ok(())
+    }
+
+    async fn remove(&self, tenant_id: Uuid, document_id: Uuid) -> rustok_core::Result<()> {
+        self.documents.lock().unwrap().retain(|existing| {
+            existing.tenant_id != tenant_id || existing.document_id != document_id
+        });
+        Ok(())
+    }
+
+    async fn search_faceted(
+        &self,
+        tenant_id: Uuid,
+        index: &str,
+        query: &str,
+        facets: &[String],
+    ) -> rustok_core::Result<FacetedResults> {
+        let query = query.to_lowercase();
+        let hits: Vec<SearchDocument> = self
+            .documents
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|document| document.tenant_id == tenant_id && document.entity_type == index)
+            .filter(|document| {
+                document.title.to_lowercase().contains(&query)
+                    || document.body.to_lowercase().contains(&query)
+            })
+            .cloned()
+            .collect();
+
+        let facets = facets
+            .iter()
+            .map(|facet| {
+                let mut documents: HashMap<&str, HashSet<Uuid>> = HashMap::new();
+                for hit in &hits {
+                    if let Some(value) = hit.facets.get(facet) {
+                        documents
+                            .entry(value.as_str())
+                            .or_default()
+                            .insert(hit.document_id);
+                    }
+                }
+                let mut counts: Vec<(String, u64)> = documents
+                    .into_iter()
+                    .map(|(value, ids)| (value.to_string(), ids.len() as u64))
+                    .collect();
+                counts.sort_by(|left, right| right.1.cmp(&left.1).then(left.0.cmp(&right.0)));
+                (facet.clone(), counts)
+            })
+            .collect();
+
+        Ok(FacetedResults { hits, facets })
+    }
+}
+
+impl MockSearchBackend {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn document_count(&self) -> usize {
+        self.documents.lock().unwrap().len()
+    }
+
+    /// Indexed locales of `document_id`, sorted.
+    pub fn documents_for(&self, document_id: Uuid) -> Vec<SearchDocument> {
+        let mut documents: Vec<SearchDocument> = self
+            .documents
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|document| document.document_id == document_id)
+            .cloned()
+            .collect();
+        documents.sort_by(|left, right| left.locale.cmp(&right.locale));
+        documents
+    }
+
+    pub fn is_indexed(&self, document_id: Uuid) -> bool {
+        !self.documents_for(document_id).is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    fn node(
+        tenant_id: Uuid,
+        locale: &str,
+        title: &str,
+        category: &str,
+        status: &str,
+    ) -> SearchDocument {
+        SearchDocument {
+            tenant_id,
+            document_id: Uuid::new_v4(),
+            entity_type: "post".to_string(),
+            locale: locale.to_string(),
+            title: title.to_string(),
+            slug: None,
+            body: String::new(),
+            facets: BTreeMap::from([
+                ("category".to_string(), category.to_string()),
+                ("status".to_string(), status.to_string()),
+            ]),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_search_faceted_counts_documents_per_value() {
+        let backend = MockSearchBackend::new();
+        let tenant_id = Uuid::new_v4();
+        let translated = node(tenant_id, "en", "Rust news", "news", "published");
+        let mut russian = translated.clone();
+        russian.locale = "ru".to_string();
+
+        for document in [
+            translated,
+            russian,
+            node(tenant_id, "en", "Rust guide", "guides", "published"),
+            node(tenant_id, "en", "Rust roadmap", "news", "draft"),
+            node(tenant_id, "en", "Cooking", "news", "published"),
+            node(Uuid::new_v4(), "en", "Rust elsewhere", "news", "published"),
+        ] {
+            backend.index(document).await.unwrap();
+        }
+
+        let results = backend
+            .search_faceted(
+                tenant_id,
+                "post",
+                "rust",
+                &["category".to_string(), "status".to_string()],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(results.hits.len(), 4);
+        assert_eq!(
+            results.facets["category"],
+            vec![("news".to_string(), 2), ("guides".to_string(), 1)]
+        );
+        assert_eq!(
+            results.facets["status"],
+            vec![("published".to_string(), 2), ("draft".to_string(), 1)]
+        );
+    }
+}